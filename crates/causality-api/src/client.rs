@@ -5,9 +5,15 @@
 
 use anyhow::Result;
 use reqwest::Client as HttpClient;
+use serde::Serialize;
 use serde_json::{json, Value};
-use std::time::{Duration, SystemTime};
-use tokio::time::sleep;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::Semaphore;
+use tokio::time::{sleep, timeout, Instant};
+use causality_core::lambda::Location;
 
 use crate::types::*;
 
@@ -15,6 +21,12 @@ use crate::types::*;
 // Local Types for Client Results
 //-----------------------------------------------------------------------------
 
+/// Domain a chain client submits to
+///
+/// Each configured chain is the domain `Location::Domain` named by its key
+/// in the configuration.
+pub type DomainId = Location;
+
 /// Result of a transaction submission or validation
 #[derive(Debug, Clone)]
 pub enum TransactionResult {
@@ -29,11 +41,62 @@ pub enum TransactionResult {
     },
 }
 
+/// Whether a chain's RPC endpoint is reachable and answering
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ConnectionStatus {
+    /// The endpoint answered a block number query
+    Connected { block_number: u64 },
+    
+    /// The endpoint could not be reached in time
+    Disconnected { error: String },
+    
+    /// The endpoint was reached but answered with an error or malformed data
+    Failed { error: String },
+}
+
+impl ConnectionStatus {
+    pub fn is_connected(&self) -> bool {
+        matches!(self, ConnectionStatus::Connected { .. })
+    }
+}
+
+/// How long a connectivity check waits for a chain's RPC endpoint
+const CONNECTION_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Errors from waiting on a submitted transaction
+#[derive(Error, Debug, Clone)]
+pub enum ClientError {
+    /// The transaction was mined but reverted
+    #[error("Transaction {} failed in block {}", .0.transaction_hash, .0.block_number)]
+    TransactionFailed(TransactionReceipt),
+    
+    /// The transaction was still pending when the wait timed out
+    #[error("Transaction {tx_hash} still pending after {waited:?}")]
+    ConfirmationTimeout { tx_hash: String, waited: Duration },
+    
+    /// No client is configured for the requested domain
+    #[error("Unknown domain: {0}")]
+    UnknownDomain(DomainId),
+    
+    /// Submitting the transaction failed before it could be confirmed
+    #[error("Submission failed: {0}")]
+    Submission(String),
+}
+
+impl From<anyhow::Error> for ClientError {
+    fn from(err: anyhow::Error) -> Self {
+        err.downcast::<ClientError>()
+            .unwrap_or_else(|err| ClientError::Submission(err.to_string()))
+    }
+}
+
 //-----------------------------------------------------------------------------
 // Chain Client Implementation
 //-----------------------------------------------------------------------------
 
 /// Client for interacting with blockchain networks
+#[derive(Debug)]
 pub struct ChainClient {
     /// Chain configuration
     config: ChainConfig,
@@ -84,13 +147,18 @@ impl ChainClient {
         let tx_hash = self.send_raw_transaction(&tx_data).await?;
         
         // Wait for confirmation
-        let receipt = self.wait_for_confirmation(&tx_hash).await?;
-        
-        Ok(TransactionResult::Success {
-            tx_hash,
-            gas_used: receipt.gas_used,
-            block_number: receipt.block_number,
-        })
+        match self.wait_for_confirmation(&tx_hash, ConfirmOptions::default()).await {
+            Ok(receipt) => Ok(TransactionResult::Success {
+                tx_hash,
+                gas_used: receipt.gas_used,
+                block_number: receipt.block_number,
+            }),
+            Err(ClientError::TransactionFailed(receipt)) => Ok(TransactionResult::Failure {
+                error: format!("Transaction {} reverted in block {}", tx_hash, receipt.block_number),
+                gas_estimate: Some(receipt.gas_used),
+            }),
+            Err(e) => Err(e.into()),
+        }
     }
     
     /// Validate a transaction without submitting it
@@ -181,22 +249,23 @@ impl ChainClient {
             .map(|s| s.to_string())
     }
     
-    /// Wait for transaction confirmation
-    async fn wait_for_confirmation(&self, tx_hash: &str) -> Result<TransactionReceipt> {
-        let start_time = SystemTime::now();
-        let timeout = Duration::from_secs(300); // 5 minutes
+    /// Poll until a submitted transaction is mined, backing off between polls
+    ///
+    /// Returns the receipt once the transaction succeeds. A reverted
+    /// transaction is reported as `ClientError::TransactionFailed`, and one
+    /// still pending after `opts.timeout` as `ClientError::ConfirmationTimeout`.
+    /// RPC errors while polling are treated as the transaction still pending.
+    pub async fn wait_for_confirmation(&self, tx_hash: &str, opts: ConfirmOptions) -> Result<TransactionReceipt, ClientError> {
+        let start_time = Instant::now();
+        let mut interval = opts.initial_interval;
         
         loop {
-            if start_time.elapsed()? > timeout {
-                return Err(anyhow::anyhow!("Transaction confirmation timeout"));
-            }
-            
             match self.get_transaction_receipt(tx_hash).await {
-                Ok(Some(receipt)) => {
-                    if receipt.block_number > 0 {
-                        return Ok(receipt);
-                    }
-                }
+                Ok(Some(receipt)) => match receipt.status() {
+                    TransactionStatus::Success => return Ok(receipt),
+                    TransactionStatus::Failed => return Err(ClientError::TransactionFailed(receipt)),
+                    _ => {}
+                },
                 Ok(None) => {
                     // Transaction not yet mined
                 }
@@ -205,7 +274,16 @@ impl ChainClient {
                 }
             }
             
-            sleep(Duration::from_secs(2)).await;
+            let waited = start_time.elapsed();
+            if waited >= opts.timeout {
+                return Err(ClientError::ConfirmationTimeout {
+                    tx_hash: tx_hash.to_string(),
+                    waited,
+                });
+            }
+            
+            sleep(interval.min(opts.timeout - waited)).await;
+            interval = interval.mul_f64(opts.backoff_multiplier).min(opts.max_interval);
         }
     }
     
@@ -319,6 +397,31 @@ impl ChainClient {
         Ok(response_json["result"].clone())
     }
     
+    /// Check that the chain's RPC endpoint is reachable by asking for the
+    /// latest block number
+    pub async fn connection_status(&self) -> ConnectionStatus {
+        let response = match timeout(CONNECTION_CHECK_TIMEOUT, self.rpc_call("eth_blockNumber", json!([]))).await {
+            Ok(response) => response,
+            Err(_) => return ConnectionStatus::Disconnected {
+                error: format!("No response within {:?}", CONNECTION_CHECK_TIMEOUT),
+            },
+        };
+        
+        let block_number = response.and_then(|value| {
+            let hex = value.as_str().ok_or_else(|| anyhow::anyhow!("Invalid block number response"))?;
+            self.parse_hex_u64(hex)
+        });
+        match block_number {
+            Ok(block_number) => ConnectionStatus::Connected { block_number },
+            Err(e) => match e.downcast_ref::<reqwest::Error>() {
+                Some(http) if http.is_connect() || http.is_timeout() => {
+                    ConnectionStatus::Disconnected { error: e.to_string() }
+                }
+                _ => ConnectionStatus::Failed { error: e.to_string() },
+            },
+        }
+    }
+    
     /// Parse hexadecimal string to u64
     fn parse_hex_u64(&self, hex_str: &str) -> Result<u64> {
         let hex_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
@@ -326,24 +429,116 @@ impl ChainClient {
     }
 }
 
+//-----------------------------------------------------------------------------
+// Multi-Chain Submission
+//-----------------------------------------------------------------------------
+
+/// Clients for every chain in a multi-chain deployment
+#[derive(Debug)]
+pub struct MultiChainClient {
+    /// Client per chain, keyed by the domain of the chain's configuration
+    clients: HashMap<DomainId, Arc<ChainClient>>,
+    
+    /// Bounds how many submissions run at once
+    submissions: Arc<Semaphore>,
+}
+
+impl MultiChainClient {
+    /// Create a client for each configured chain
+    pub async fn new(config: MultiChainConfig) -> Result<Self> {
+        let mut clients = HashMap::new();
+        for (name, chain) in config.chains {
+            clients.insert(Location::domain(name), Arc::new(ChainClient::new(chain).await?));
+        }
+        
+        let permits = config.global_settings.max_concurrent_submissions.max(1);
+        Ok(Self {
+            clients,
+            submissions: Arc::new(Semaphore::new(permits)),
+        })
+    }
+    
+    /// Client for a single domain
+    pub fn client(&self, domain: &DomainId) -> Option<&ChainClient> {
+        self.clients.get(domain).map(Arc::as_ref)
+    }
+    
+    /// Check every chain's connectivity concurrently, keyed by domain
+    pub async fn connection_statuses(&self) -> BTreeMap<DomainId, ConnectionStatus> {
+        let checks = self.clients.iter().map(|(domain, client)| async move {
+            (domain.clone(), client.connection_status().await)
+        });
+        futures::future::join_all(checks).await.into_iter().collect()
+    }
+    
+    /// Submit transactions to several domains concurrently
+    ///
+    /// Every target gets an outcome, in the order given; a failure on one
+    /// domain never stops or hides the others, so callers can handle partial
+    /// success. At most `max_concurrent_submissions` run at once.
+    pub async fn submit_multi(
+        &self,
+        targets: Vec<(DomainId, TransactionRequest)>,
+    ) -> Vec<(DomainId, Result<TransactionResult, ClientError>)> {
+        let submissions: Vec<_> = targets
+            .into_iter()
+            .map(|(domain, request)| {
+                let client = self.clients.get(&domain).cloned();
+                let permits = self.submissions.clone();
+                let unknown = domain.clone();
+                let task = tokio::spawn(async move {
+                    let client = client.ok_or(ClientError::UnknownDomain(unknown))?;
+                    let _permit = permits.acquire_owned().await
+                        .map_err(|e| ClientError::Submission(e.to_string()))?;
+                    client.submit_transaction(&request).await.map_err(ClientError::from)
+                });
+                (domain, task)
+            })
+            .collect();
+        
+        let mut results = Vec::with_capacity(submissions.len());
+        for (domain, task) in submissions {
+            let result = match task.await {
+                Ok(result) => result,
+                Err(e) => Err(ClientError::Submission(format!("Submission task failed: {}", e))),
+            };
+            results.push((domain, result));
+        }
+        results
+    }
+}
+
 //-----------------------------------------------------------------------------
 // Helper Types
 //-----------------------------------------------------------------------------
 
 /// Transaction receipt information
-#[derive(Debug, Clone)]
-struct TransactionReceipt {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionReceipt {
     /// Transaction hash
-    #[allow(dead_code)]
-    transaction_hash: String,
+    pub transaction_hash: String,
     
     /// Block number where transaction was included
-    block_number: u64,
+    pub block_number: u64,
     
     /// Gas used by the transaction
-    gas_used: u64,
+    pub gas_used: u64,
     
     /// Whether the transaction was successful
-    #[allow(dead_code)]
-    status: bool,
+    pub status: bool,
+}
+
+impl TransactionReceipt {
+    /// Status of the transaction this receipt describes
+    ///
+    /// A receipt without a block number has not been mined yet.
+    pub fn status(&self) -> TransactionStatus {
+        if self.block_number == 0 {
+            TransactionStatus::Pending
+        } else if self.status {
+            TransactionStatus::Success
+        } else {
+            TransactionStatus::Failed
+        }
+    }
 }
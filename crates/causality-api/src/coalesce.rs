@@ -0,0 +1,229 @@
+//! Coalescing of identical concurrent requests
+//!
+//! Expensive reads (e.g. polling a proof status) are often issued by many
+//! clients at once. [`SingleFlight`] lets the first caller for a key run the
+//! backend call while every concurrent caller for the same key waits for and
+//! shares its result. [`coalesce_reads`] applies this to whole HTTP responses.
+
+use axum::body::{Body, Bytes};
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderMap, HeaderName, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+
+use crate::types::ApiError;
+
+/// Runs at most one call per key at a time, sharing its result with all
+/// callers that arrive while it is in flight
+#[derive(Debug)]
+pub struct SingleFlight<K, V> {
+    in_flight: Arc<Mutex<HashMap<K, Arc<OnceCell<V>>>>>,
+}
+
+impl<K, V> Clone for SingleFlight<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            in_flight: self.in_flight.clone(),
+        }
+    }
+}
+
+impl<K, V> Default for SingleFlight<K, V> {
+    fn default() -> Self {
+        Self {
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> SingleFlight<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `call` for `key`, or join the call already in flight for it
+    ///
+    /// Results are not cached: once the leading call completes, the next
+    /// request for the key starts a fresh call. If the leader is cancelled,
+    /// one of the waiting callers takes over; if every caller is cancelled,
+    /// the key is forgotten.
+    pub async fn run<F, Fut>(&self, key: K, call: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V>,
+    {
+        let cell = self.in_flight.lock().unwrap()
+            .entry(key.clone())
+            .or_default()
+            .clone();
+
+        let flight = Flight {
+            in_flight: &self.in_flight,
+            key,
+            cell: Some(cell),
+        };
+        flight.cell().get_or_init(call).await.clone()
+    }
+
+    /// Number of keys with a call currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.lock().unwrap().len()
+    }
+}
+
+/// One caller's membership of a call in flight
+///
+/// Dropping it, whether the caller finished or was cancelled, removes the
+/// key's entry once the call has completed or no other caller is waiting.
+struct Flight<'a, K: Eq + Hash, V> {
+    in_flight: &'a Mutex<HashMap<K, Arc<OnceCell<V>>>>,
+    key: K,
+    cell: Option<Arc<OnceCell<V>>>,
+}
+
+impl<K: Eq + Hash, V> Flight<'_, K, V> {
+    fn cell(&self) -> &OnceCell<V> {
+        self.cell.as_deref().expect("cell is only taken on drop")
+    }
+}
+
+impl<K: Eq + Hash, V> Drop for Flight<'_, K, V> {
+    fn drop(&mut self) {
+        let mut in_flight = self.in_flight.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Release this caller's reference while holding the lock, so of two
+        // callers dropping at once the second sees it is the last
+        if let Some(cell) = self.cell.take() {
+            // One reference is held by the map and one by this caller
+            let last_caller = Arc::strong_count(&cell) == 2;
+            if (cell.initialized() || last_caller)
+                && in_flight.get(&self.key).is_some_and(|current| Arc::ptr_eq(current, &cell))
+            {
+                in_flight.remove(&self.key);
+            }
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+// HTTP Read Coalescing
+//-----------------------------------------------------------------------------
+
+/// Identity of a read: method, URI, credentials, and negotiated format, so
+/// responses are never shared between callers presenting different
+/// authorization or cookies, or asking for a different representation
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReadKey {
+    pub method: Method,
+    pub uri: String,
+    pub authorization: Option<Vec<u8>>,
+    pub cookie: Option<Vec<u8>>,
+    pub accept: Option<Vec<u8>>,
+}
+
+impl ReadKey {
+    /// Key identifying `request`
+    pub fn of(request: &Request) -> Self {
+        let header = |name: HeaderName| request.headers()
+            .get(name)
+            .map(|value| value.as_bytes().to_vec());
+        Self {
+            method: request.method().clone(),
+            uri: request.uri().to_string(),
+            authorization: header(header::AUTHORIZATION),
+            cookie: header(header::COOKIE),
+            accept: header(header::ACCEPT),
+        }
+    }
+}
+
+/// Buffered response shared between coalesced callers
+#[derive(Debug, Clone)]
+pub struct SharedResponse {
+    pub(crate) status: StatusCode,
+    pub(crate) headers: HeaderMap,
+    pub(crate) body: Bytes,
+}
+
+impl SharedResponse {
+    /// Buffer a response so it can be handed to more than one caller
+    pub(crate) async fn buffer(response: Response) -> Result<Self, ApiError> {
+        Self::buffer_limited(response, usize::MAX).await
+    }
+
+    /// Buffer a response whose body is at most `limit` bytes
+    pub(crate) async fn buffer_limited(response: Response, limit: usize) -> Result<Self, ApiError> {
+        let (parts, body) = response.into_parts();
+        let body = axum::body::to_bytes(body, limit).await.map_err(|err| ApiError::new(
+            "RESPONSE_BUFFER_ERROR",
+            format!("Failed to buffer response: {}", err),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ))?;
+        Ok(Self {
+            status: parts.status,
+            headers: parts.headers,
+            body,
+        })
+    }
+}
+
+impl IntoResponse for SharedResponse {
+    fn into_response(self) -> Response {
+        let mut response = Response::new(Body::from(self.body));
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers;
+        response
+    }
+}
+
+/// Single-flight group used to coalesce HTTP reads
+pub type ReadCoalescer = SingleFlight<ReadKey, Result<SharedResponse, ApiError>>;
+
+/// Middleware coalescing concurrent identical `GET` and `HEAD` requests
+///
+/// Only the first request reaches the handler; its buffered response is
+/// returned to every request that arrived while it was running. All other
+/// methods, requests for a session's event stream, and requests accepting
+/// an event stream pass through untouched.
+pub async fn coalesce_reads(
+    State(reads): State<ReadCoalescer>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if request.method() != Method::GET && request.method() != Method::HEAD {
+        return next.run(request).await;
+    }
+    if is_event_stream_route(request.uri().path()) || accepts_event_stream(request.headers()) {
+        // Streams never finish buffering and resume per caller
+        return next.run(request).await;
+    }
+
+    let key = ReadKey::of(&request);
+
+    let shared = reads.run(key, || async move {
+        SharedResponse::buffer(next.run(request).await).await
+    }).await;
+
+    match shared {
+        Ok(response) => response.into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+/// Whether `path` is `/sessions/{id}/events`, which always streams
+fn is_event_stream_route(path: &str) -> bool {
+    path.strip_prefix("/sessions/")
+        .and_then(|rest| rest.strip_suffix("/events"))
+        .is_some_and(|id| !id.is_empty() && !id.contains('/'))
+}
+
+/// Whether the client asked for a `text/event-stream` response
+pub(crate) fn accepts_event_stream(headers: &HeaderMap) -> bool {
+    headers.get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| accept.contains("text/event-stream"))
+}
@@ -1,12 +1,208 @@
 //! Configuration for the Causality API server
 
+use ::config::{Config, Environment, File};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Prefix of environment variables overriding configuration keys, e.g.
+/// `CAUSALITY_API_PORT` for `port`
+pub const ENV_PREFIX: &str = "CAUSALITY_API";
+
+/// Environment variable naming the config file when `--config` is not given
+pub const CONFIG_PATH_ENV: &str = "CAUSALITY_API_CONFIG";
+
+/// Keys whose environment values are comma-separated lists
+const LIST_KEYS: [&str; 3] = ["cors_allowed_origins", "cors_allowed_methods", "cors_allowed_headers"];
+
+/// Errors loading or validating an [`ApiConfig`]
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Config file not found: {}", .0.display())]
+    FileNotFound(PathBuf),
+    
+    /// The file could not be parsed, a required key is missing, or a value
+    /// has the wrong type; the message names the key and its source
+    #[error("Failed to load config: {0}")]
+    Load(#[from] ::config::ConfigError),
+    
+    #[error("Invalid value for {key}: {reason}")]
+    Invalid { key: &'static str, reason: String },
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ApiConfig {
     pub host: String,
     pub port: u16,
     pub max_sessions: usize,
+    
+    /// Origins allowed to make cross-origin requests; `*` allows any origin.
+    /// Empty disables cross-origin access entirely.
+    #[serde(default)]
+    pub cors_allowed_origins: Vec<String>,
+    
+    /// HTTP methods allowed for cross-origin requests
+    #[serde(default = "default_cors_allowed_methods")]
+    pub cors_allowed_methods: Vec<String>,
+    
+    /// Request headers allowed for cross-origin requests
+    #[serde(default = "default_cors_allowed_headers")]
+    pub cors_allowed_headers: Vec<String>,
+    
+    /// Value sent in the `Content-Security-Policy` response header
+    #[serde(default = "default_content_security_policy")]
+    pub content_security_policy: String,
+    
+    /// Secret used to sign session resume tokens; a random secret is
+    /// generated when omitted, so tokens do not survive a restart
+    #[serde(default = "default_session_secret", skip_serializing)]
+    pub session_secret: String,
+    
+    /// How long a session resume token stays valid, in seconds
+    #[serde(default = "default_resume_token_ttl_secs")]
+    pub resume_token_ttl_secs: u64,
+    
+    /// How long responses to requests with an `Idempotency-Key` are
+    /// replayed for retries, in seconds
+    #[serde(default = "default_idempotency_ttl_secs")]
+    pub idempotency_ttl_secs: u64,
+    
+    /// Largest request or response body buffered for an idempotent request,
+    /// in bytes; larger requests are rejected and larger responses are not
+    /// recorded
+    #[serde(default = "default_idempotency_max_body_bytes")]
+    pub idempotency_max_body_bytes: usize,
+    
+    /// Directory of the database holding state that must survive restarts
+    /// and be shared between instances, such as recorded idempotent
+    /// responses; kept in memory when omitted
+    #[serde(default)]
+    pub database_path: Option<PathBuf>,
+    
+    /// How long a finished session's event log is kept for streaming
+    /// clients, in seconds
+    #[serde(default = "default_event_retention_secs")]
+    pub event_retention_secs: u64,
+    
+    /// How often the background task prunes expired event logs, in seconds
+    #[serde(default = "default_prune_interval_secs")]
+    pub prune_interval_secs: u64,
+}
+
+fn default_cors_allowed_methods() -> Vec<String> {
+    ["GET", "POST", "OPTIONS"].iter().map(|m| m.to_string()).collect()
+}
+
+fn default_cors_allowed_headers() -> Vec<String> {
+    ["content-type", "authorization"].iter().map(|h| h.to_string()).collect()
+}
+
+fn default_content_security_policy() -> String {
+    // The API only serves JSON, so nothing should ever be loaded or framed
+    "default-src 'none'; frame-ancestors 'none'".to_string()
+}
+
+fn default_session_secret() -> String {
+    hex::encode(rand::random::<[u8; 32]>())
+}
+
+fn default_resume_token_ttl_secs() -> u64 {
+    60 * 60
+}
+
+fn default_idempotency_ttl_secs() -> u64 {
+    24 * 60 * 60
+}
+
+fn default_idempotency_max_body_bytes() -> usize {
+    1024 * 1024
+}
+
+fn default_event_retention_secs() -> u64 {
+    60 * 60
+}
+
+fn default_prune_interval_secs() -> u64 {
+    60
+}
+
+impl ApiConfig {
+    /// Load configuration from `path`, if given, then overlay `CAUSALITY_API_*`
+    /// environment variables
+    ///
+    /// `host`, `port`, and `max_sessions` must come from one of the two
+    /// sources; every other key falls back to its default. List keys such as
+    /// `CAUSALITY_API_CORS_ALLOWED_ORIGINS` are comma-separated.
+    pub fn from_env_and_file(path: Option<&Path>) -> Result<Self, ConfigError> {
+        Self::load(path, None)
+    }
+    
+    /// Like [`from_env_and_file`](Self::from_env_and_file), but reading the
+    /// overrides from `vars` instead of the process environment
+    pub fn from_file_and_vars(path: Option<&Path>, vars: HashMap<String, String>) -> Result<Self, ConfigError> {
+        Self::load(path, Some(vars))
+    }
+    
+    fn load(path: Option<&Path>, vars: Option<HashMap<String, String>>) -> Result<Self, ConfigError> {
+        let mut builder = Config::builder();
+        if let Some(path) = path {
+            if !path.is_file() {
+                return Err(ConfigError::FileNotFound(path.to_path_buf()));
+            }
+            builder = builder.add_source(File::from(path));
+        }
+        
+        let environment = LIST_KEYS.iter().fold(
+            Environment::with_prefix(ENV_PREFIX)
+                .try_parsing(true)
+                .list_separator(","),
+            |environment, key| environment.with_list_parse_key(key),
+        );
+        let config: Self = builder
+            .add_source(environment.source(vars.map(|vars| vars.into_iter().collect())))
+            .build()?
+            .try_deserialize()?;
+        
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Reject values that deserialize but cannot run a server
+    fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |key, reason: &str| Err(ConfigError::Invalid { key, reason: reason.to_string() });
+        if self.host.trim().is_empty() {
+            return invalid("host", "must not be empty");
+        }
+        if self.max_sessions == 0 {
+            return invalid("max_sessions", "must be at least 1");
+        }
+        if self.session_secret.is_empty() {
+            return invalid("session_secret", "must not be empty");
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for ApiConfig {
+    /// Formats the config with the session secret redacted
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiConfig")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("max_sessions", &self.max_sessions)
+            .field("cors_allowed_origins", &self.cors_allowed_origins)
+            .field("cors_allowed_methods", &self.cors_allowed_methods)
+            .field("cors_allowed_headers", &self.cors_allowed_headers)
+            .field("content_security_policy", &self.content_security_policy)
+            .field("session_secret", &"<redacted>")
+            .field("resume_token_ttl_secs", &self.resume_token_ttl_secs)
+            .field("idempotency_ttl_secs", &self.idempotency_ttl_secs)
+            .field("idempotency_max_body_bytes", &self.idempotency_max_body_bytes)
+            .field("database_path", &self.database_path)
+            .field("event_retention_secs", &self.event_retention_secs)
+            .field("prune_interval_secs", &self.prune_interval_secs)
+            .finish()
+    }
 }
 
 impl Default for ApiConfig {
@@ -15,6 +211,17 @@ impl Default for ApiConfig {
             host: "127.0.0.1".to_string(),
             port: 8080,
             max_sessions: 100,
+            cors_allowed_origins: Vec::new(),
+            cors_allowed_methods: default_cors_allowed_methods(),
+            cors_allowed_headers: default_cors_allowed_headers(),
+            content_security_policy: default_content_security_policy(),
+            session_secret: default_session_secret(),
+            resume_token_ttl_secs: default_resume_token_ttl_secs(),
+            idempotency_ttl_secs: default_idempotency_ttl_secs(),
+            idempotency_max_body_bytes: default_idempotency_max_body_bytes(),
+            database_path: None,
+            event_retention_secs: default_event_retention_secs(),
+            prune_interval_secs: default_prune_interval_secs(),
         }
     }
 }
@@ -0,0 +1,123 @@
+//! Key-value database for server state that outlives a single process
+//!
+//! Recorded idempotent responses must survive a restart and be seen by every
+//! server instance behind a load balancer, so they are kept in the
+//! [`Database`] named by the `database_path` config key. A [`FileDatabase`]
+//! directory can be shared by several instances; without a configured path
+//! the server falls back to a [`MemoryDatabase`] private to the process.
+
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use causality_core::{Hasher, Sha256Hasher};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::types::ApiError;
+
+/// Byte values stored under string keys
+#[async_trait]
+pub trait Database: std::fmt::Debug + Send + Sync {
+    /// Value stored under `key`, if any
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ApiError>;
+
+    /// Store `value` under `key`, replacing any earlier value
+    async fn put(&self, key: &str, value: Vec<u8>) -> Result<(), ApiError>;
+
+    /// Remove `key`; removing a key that is not stored is not an error
+    async fn delete(&self, key: &str) -> Result<(), ApiError>;
+}
+
+/// Open the database configured at `path`, or an in-memory one without it
+pub fn open(path: Option<&Path>) -> Arc<dyn Database> {
+    match path {
+        Some(path) => Arc::new(FileDatabase::new(path)),
+        None => Arc::new(MemoryDatabase::new()),
+    }
+}
+
+/// Database held in process memory and lost on restart
+#[derive(Debug, Clone, Default)]
+pub struct MemoryDatabase {
+    values: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+impl MemoryDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Database for MemoryDatabase {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ApiError> {
+        Ok(self.values.lock().unwrap().get(key).cloned())
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> Result<(), ApiError> {
+        self.values.lock().unwrap().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), ApiError> {
+        self.values.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
+
+/// Database storing one file per key in a directory
+///
+/// File names are the SHA-256 of the key, so any key is a valid name.
+/// Values are written to a temporary file and renamed into place, so
+/// concurrent readers, including other processes, never see a partial value.
+#[derive(Debug, Clone)]
+pub struct FileDatabase {
+    dir: PathBuf,
+}
+
+impl FileDatabase {
+    /// Use `dir`, which is created on the first write if it does not exist
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(hex::encode(Sha256Hasher::hash(key.as_bytes())))
+    }
+}
+
+#[async_trait]
+impl Database for FileDatabase {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ApiError> {
+        match tokio::fs::read(self.path(key)).await {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(storage_error("read", e)),
+        }
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> Result<(), ApiError> {
+        tokio::fs::create_dir_all(&self.dir).await.map_err(|e| storage_error("create", e))?;
+        let path = self.path(key);
+        let staged = path.with_extension(format!("{}.tmp", uuid::Uuid::new_v4()));
+        tokio::fs::write(&staged, value).await.map_err(|e| storage_error("write", e))?;
+        tokio::fs::rename(&staged, &path).await.map_err(|e| storage_error("write", e))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), ApiError> {
+        match tokio::fs::remove_file(self.path(key)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(storage_error("delete", e)),
+        }
+    }
+}
+
+fn storage_error(action: &str, err: std::io::Error) -> ApiError {
+    ApiError::new(
+        "STORAGE_ERROR",
+        format!("Failed to {} database entry: {}", action, err),
+        StatusCode::SERVICE_UNAVAILABLE,
+    )
+}
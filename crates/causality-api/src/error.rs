@@ -0,0 +1,178 @@
+//! Conversion of engine-layer errors into API errors
+//!
+//! Handlers can `?`-propagate errors from the runtime, the simulation engine,
+//! the compiler's structured error handling, and the core system straight
+//! into an [`ApiError`]. Every variant maps to a
+//! stable error code and the HTTP status it should be reported with.
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use causality_compiler::error_handling::CausalityError;
+use causality_core::system::Error as CoreError;
+use causality_runtime::RuntimeError;
+use causality_simulation::SimulationError;
+use std::collections::HashMap;
+
+use crate::types::ApiError;
+
+//-----------------------------------------------------------------------------
+// ApiError Construction
+//-----------------------------------------------------------------------------
+
+impl ApiError {
+    /// Create a new API error with the given code, message, and status
+    pub fn new(code: impl Into<String>, message: impl Into<String>, status: StatusCode) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            status: status.as_u16(),
+            details: HashMap::new(),
+        }
+    }
+    
+    /// Attach an additional detail to the error
+    pub fn with_detail(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.details.insert(key.into(), value.into());
+        self
+    }
+    
+    /// HTTP status for this error, falling back to 500 for invalid codes
+    pub fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status_code(), Json(self)).into_response()
+    }
+}
+
+//-----------------------------------------------------------------------------
+// Engine Error Conversions
+//-----------------------------------------------------------------------------
+
+impl From<CoreError> for ApiError {
+    fn from(err: CoreError) -> Self {
+        let (code, status) = match &err {
+            // Report the underlying error's code, carrying the context along
+            CoreError::Context { error, context } => {
+                let inner = ApiError::from((**error).clone());
+                let chain = serde_json::to_string(context).unwrap_or_default();
+                return ApiError::new(inner.code, err.to_string(), inner.status_code())
+                    .with_detail("source", "core")
+                    .with_detail("context", chain);
+            }
+            CoreError::Type(_) => ("TYPE_ERROR", StatusCode::UNPROCESSABLE_ENTITY),
+            CoreError::Resource { .. } => ("RESOURCE_ERROR", StatusCode::CONFLICT),
+            CoreError::Linearity(_) => ("LINEARITY_VIOLATION", StatusCode::CONFLICT),
+            CoreError::ContentAddressing { .. } => ("CONTENT_ADDRESSING_ERROR", StatusCode::UNPROCESSABLE_ENTITY),
+            CoreError::Serialization { .. } => ("SERIALIZATION_ERROR", StatusCode::BAD_REQUEST),
+            CoreError::Storage { .. } => ("STORAGE_ERROR", StatusCode::SERVICE_UNAVAILABLE),
+            CoreError::Network { .. } => ("NETWORK_ERROR", StatusCode::BAD_GATEWAY),
+            CoreError::Validation { .. } => ("VALIDATION_ERROR", StatusCode::BAD_REQUEST),
+            CoreError::System { .. } => ("SYSTEM_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+        };
+        ApiError::new(code, err.to_string(), status).with_detail("source", "core")
+    }
+}
+
+impl From<RuntimeError> for ApiError {
+    fn from(err: RuntimeError) -> Self {
+        let (code, status) = match &err {
+            // Preserve the machine error's own code and status
+            RuntimeError::MachineError(inner) => {
+                return ApiError::from(inner.clone()).with_detail("source", "runtime");
+            }
+            RuntimeError::ExecutionFailed { .. } => ("EXECUTION_FAILED", StatusCode::UNPROCESSABLE_ENTITY),
+            RuntimeError::HandlerError { .. } => ("HANDLER_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            RuntimeError::ResourceError { .. } => ("RESOURCE_ERROR", StatusCode::CONFLICT),
+            RuntimeError::TypeMismatch(_) => ("TYPE_MISMATCH", StatusCode::UNPROCESSABLE_ENTITY),
+            RuntimeError::LinearityViolation { .. } => ("LINEARITY_VIOLATION", StatusCode::CONFLICT),
+            RuntimeError::UnhandledEffect { .. } => ("UNHANDLED_EFFECT", StatusCode::NOT_IMPLEMENTED),
+            RuntimeError::RegisterError(_) => ("REGISTER_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            RuntimeError::MemoryError(_) => ("MEMORY_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            RuntimeError::Internal { .. } => ("INTERNAL_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+        };
+        let api_error = ApiError::new(code, err.to_string(), status).with_detail("source", "runtime");
+        match err {
+            RuntimeError::UnhandledEffect { effect_type } => api_error.with_detail("effect_type", effect_type),
+            _ => api_error,
+        }
+    }
+}
+
+impl From<SimulationError> for ApiError {
+    fn from(err: SimulationError) -> Self {
+        let (code, status) = match &err {
+            // Preserve the machine error's own code and status
+            SimulationError::MachineError(inner) => {
+                return ApiError::from(inner.clone()).with_detail("source", "simulation");
+            }
+            SimulationError::Configuration(_) => ("CONFIGURATION_ERROR", StatusCode::BAD_REQUEST),
+            SimulationError::EngineState(_) => ("INVALID_ENGINE_STATE", StatusCode::CONFLICT),
+            SimulationError::EffectExecutionError(_) => ("EFFECT_EXECUTION_FAILED", StatusCode::UNPROCESSABLE_ENTITY),
+            SimulationError::NetworkError(_) => ("NETWORK_ERROR", StatusCode::BAD_GATEWAY),
+            SimulationError::CrossChainError(_) => ("CROSS_CHAIN_ERROR", StatusCode::BAD_GATEWAY),
+            SimulationError::InvalidState(_) => ("INVALID_STATE", StatusCode::CONFLICT),
+            SimulationError::SnapshotError(_) => ("SNAPSHOT_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            SimulationError::ResourceUnavailable { .. } => ("RESOURCE_UNAVAILABLE", StatusCode::CONFLICT),
+            SimulationError::ConstraintViolation { .. } => ("CONSTRAINT_VIOLATION", StatusCode::UNPROCESSABLE_ENTITY),
+            SimulationError::SnapshotOperationFailed(_) => ("SNAPSHOT_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            SimulationError::FaultInjectionError(_) => ("FAULT_INJECTION_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            SimulationError::TegExecutionError(_) => ("TEG_EXECUTION_FAILED", StatusCode::UNPROCESSABLE_ENTITY),
+            SimulationError::IntentProcessingError(_) => ("INTENT_PROCESSING_FAILED", StatusCode::UNPROCESSABLE_ENTITY),
+            SimulationError::VisualizationError(_) => ("VISUALIZATION_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            SimulationError::EngineError(_) => ("ENGINE_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            SimulationError::BranchNotFound(_) => ("BRANCH_NOT_FOUND", StatusCode::NOT_FOUND),
+            SimulationError::OptimizationError(_) => ("OPTIMIZATION_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            SimulationError::RegisterError { .. } => ("REGISTER_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            SimulationError::ResourceError(_) => ("RESOURCE_ERROR", StatusCode::CONFLICT),
+            SimulationError::ParseError(_) => ("PARSE_ERROR", StatusCode::BAD_REQUEST),
+            SimulationError::CompilationError(_) => ("COMPILATION_ERROR", StatusCode::UNPROCESSABLE_ENTITY),
+            SimulationError::InvalidInput(_) => ("INVALID_INPUT", StatusCode::BAD_REQUEST),
+            SimulationError::SessionProtocolViolation { .. } => ("SESSION_PROTOCOL_VIOLATION", StatusCode::CONFLICT),
+        };
+        let api_error = ApiError::new(code, err.to_string(), status).with_detail("source", "simulation");
+        match err {
+            SimulationError::ResourceUnavailable { resource_id } => api_error.with_detail("resource_id", resource_id),
+            SimulationError::RegisterError { register_id, .. } => api_error.with_detail("register_id", register_id),
+            SimulationError::SessionProtocolViolation { participant, .. } => api_error.with_detail("participant", participant),
+            _ => api_error,
+        }
+    }
+}
+
+impl From<CausalityError> for ApiError {
+    fn from(err: CausalityError) -> Self {
+        let (code, status) = match &err {
+            CausalityError::Storage { .. } => ("STORAGE_ERROR", StatusCode::SERVICE_UNAVAILABLE),
+            CausalityError::Network { .. } => ("NETWORK_ERROR", StatusCode::BAD_GATEWAY),
+            CausalityError::Compilation { .. } => ("COMPILATION_ERROR", StatusCode::UNPROCESSABLE_ENTITY),
+            CausalityError::Serialization { .. } => ("SERIALIZATION_ERROR", StatusCode::BAD_REQUEST),
+            CausalityError::Validation { .. } => ("VALIDATION_ERROR", StatusCode::UNPROCESSABLE_ENTITY),
+            CausalityError::Configuration { .. } => ("CONFIGURATION_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+            CausalityError::ResourceExhaustion { .. } => ("RESOURCE_EXHAUSTED", StatusCode::TOO_MANY_REQUESTS),
+            CausalityError::Permission { .. } => ("PERMISSION_DENIED", StatusCode::FORBIDDEN),
+            CausalityError::Timeout { .. } => ("TIMEOUT", StatusCode::GATEWAY_TIMEOUT),
+            // A generic error may name its own code
+            CausalityError::Generic { error_code, .. } => {
+                (error_code.as_deref().unwrap_or("GENERIC_ERROR"), StatusCode::INTERNAL_SERVER_ERROR)
+            }
+        };
+        let api_error = ApiError::new(code, err.to_string(), status)
+            .with_detail("source", "compiler")
+            .with_detail("error_type", err.error_type())
+            .with_detail("retryable", err.is_retryable().to_string());
+        match err {
+            CausalityError::Validation { field: Some(field), .. } => api_error.with_detail("field", field),
+            CausalityError::Permission { required_permission: Some(permission), .. } => {
+                api_error.with_detail("required_permission", permission)
+            }
+            CausalityError::Timeout { operation, .. } => api_error.with_detail("operation", operation),
+            _ => api_error,
+        }
+    }
+}
+
@@ -1,6 +1,19 @@
 //! HTTP request handlers for the Causality API
 
 use anyhow::Result;
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use futures::{Stream, StreamExt};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use crate::client::ConnectionStatus;
+use crate::coalesce::accepts_event_stream;
+use crate::metrics::ApiMetrics;
+use crate::server::AppState;
+use crate::session::{ExecutionSession, SessionEvents, SessionLog, SessionStore};
 use crate::types::*;
 
 pub struct ApiHandlers {
@@ -33,3 +46,168 @@ impl ApiHandlers {
         })
     }
 }
+
+//-----------------------------------------------------------------------------
+// Health Routes
+//-----------------------------------------------------------------------------
+
+/// `GET /healthz`: liveness probe, answering as long as the process serves
+/// requests
+pub async fn healthz() -> Json<Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+/// `GET /readyz`: readiness probe, checking every configured chain
+///
+/// Responds 503 with each chain's connection status, keyed by domain, when
+/// any chain is unreachable or failing; a server without chains is always
+/// ready.
+pub async fn readyz(State(state): State<AppState>) -> (StatusCode, Json<Value>) {
+    let statuses = match &state.chains {
+        Some(chains) => chains.connection_statuses().await,
+        None => BTreeMap::new(),
+    };
+    let ready = statuses.values().all(|status| status.is_connected());
+    // JSON object keys must be strings
+    let chains: BTreeMap<String, ConnectionStatus> = statuses
+        .into_iter()
+        .map(|(domain, status)| (domain.to_string(), status))
+        .collect();
+    
+    let status = if ready { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (status, Json(json!({
+        "status": if ready { "ready" } else { "not_ready" },
+        "chains": chains,
+    })))
+}
+
+//-----------------------------------------------------------------------------
+// Session Routes
+//-----------------------------------------------------------------------------
+
+/// `POST /sessions`: run a program in a new session
+///
+/// The session runs on its own task, reporting to its event log as it goes.
+/// A client accepting `text/event-stream` gets those events streamed live,
+/// with the new session's location in the `Location` header; any other
+/// client gets the finished session once it has run.
+pub async fn create_session(
+    State(sessions): State<SessionStore>,
+    State(metrics): State<ApiMetrics>,
+    headers: HeaderMap,
+    Json(request): Json<CreateSessionRequest>,
+) -> Result<Response, ApiError> {
+    let mut session = ExecutionSession::new(uuid::Uuid::new_v4().to_string());
+    let id = session.id.clone();
+    let events = sessions.open_events(&id).await;
+    
+    let run = tokio::spawn({
+        let events = events.clone();
+        async move {
+            session.execute_with_events(request.seed.unwrap_or_else(rand::random), request.program, &events).await?;
+            metrics.record_execution(&session);
+            sessions.insert(session.clone()).await?;
+            metrics.record_stored(sessions.len().await);
+            Ok::<_, ApiError>(session)
+        }
+    });
+    
+    if accepts_event_stream(&headers) {
+        let location = format!("/sessions/{}", id);
+        tokio::spawn(async move {
+            match run.await {
+                Ok(Err(e)) => log::warn!("Session {} was not stored: {}", id, e.message),
+                Err(e) => log::error!("Session {} task failed: {}", id, e),
+                Ok(Ok(_)) => {}
+            }
+        });
+        return Ok((
+            StatusCode::CREATED,
+            [(header::LOCATION, location)],
+            event_stream(&events, 0),
+        ).into_response());
+    }
+    
+    let session = run.await.map_err(|e| ApiError::new(
+        "SESSION_TASK_FAILED",
+        format!("Session task failed: {}", e),
+        StatusCode::INTERNAL_SERVER_ERROR,
+    ))??;
+    Ok((StatusCode::CREATED, Json(session)).into_response())
+}
+
+/// `GET /sessions/{id}/log`: export a session's effect log
+///
+/// The log is returned as JSON by default, or as its SSZ encoding with
+/// `?format=ssz`.
+pub async fn export_session_log(
+    State(sessions): State<SessionStore>,
+    Path(id): Path<String>,
+    Query(query): Query<LogExportQuery>,
+) -> Result<Response, ApiError> {
+    let format = query.format.as_deref().unwrap_or("json");
+    if !matches!(format, "json" | "ssz") {
+        return Err(ApiError::new(
+            "UNSUPPORTED_FORMAT",
+            format!("Unsupported log format: {}", format),
+            StatusCode::BAD_REQUEST,
+        ).with_detail("supported", "json, ssz"));
+    }
+    
+    let log = sessions.get(&id).await?.export_log();
+    Ok(match format {
+        "ssz" => ([(header::CONTENT_TYPE, "application/octet-stream")], log.to_ssz_bytes()).into_response(),
+        _ => Json(log).into_response(),
+    })
+}
+
+/// `GET /sessions/{id}/events`: stream a session's progress as server-sent
+/// events
+///
+/// Each event's SSE id is its index in the session's event log, so a client
+/// reconnecting with `Last-Event-ID` receives only the events after it. The
+/// stream ends once the session completes or fails.
+pub async fn stream_session_events(
+    State(sessions): State<SessionStore>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, ApiError> {
+    let from = match headers.get("last-event-id") {
+        None => 0,
+        Some(value) => value.to_str().ok()
+            .and_then(|last| last.parse::<usize>().ok())
+            .map(|last| last + 1)
+            .ok_or_else(|| ApiError::new(
+                "INVALID_LAST_EVENT_ID",
+                "Last-Event-ID must be the id of a previously received event",
+                StatusCode::BAD_REQUEST,
+            ))?,
+    };
+    
+    let events = sessions.events(&id).await?;
+    Ok(event_stream(&events, from))
+}
+
+/// Server-sent event stream of `events`, starting at id `from`
+fn event_stream(events: &SessionEvents, from: usize) -> Sse<impl Stream<Item = Result<Event, axum::Error>>> {
+    let stream = events.stream_from(from).map(|(index, event)| {
+        Event::default()
+            .id(index.to_string())
+            .event(event.name())
+            .json_data(&event)
+    });
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+/// `POST /sessions/import`: reconstruct a session by replaying a log
+pub async fn import_session_log(
+    State(sessions): State<SessionStore>,
+    State(metrics): State<ApiMetrics>,
+    Json(log): Json<SessionLog>,
+) -> Result<(StatusCode, Json<ExecutionSession>), ApiError> {
+    let session = ExecutionSession::from_log(uuid::Uuid::new_v4().to_string(), log).await?;
+    metrics.record_execution(&session);
+    sessions.insert(session.clone()).await?;
+    metrics.record_stored(sessions.len().await);
+    Ok((StatusCode::CREATED, Json(session)))
+}
@@ -0,0 +1,299 @@
+//! Idempotency keys for write requests
+//!
+//! A client that times out waiting for a `POST` cannot tell whether it ran,
+//! and retrying blindly can execute it twice. Clients that send an
+//! `Idempotency-Key` header get the first response for that key replayed to
+//! every retry within the TTL window instead of the request running again.
+//! Responses are recorded in the configured [`Database`], so retries are
+//! recognised across restarts and by every instance sharing it.
+//! [`idempotent_writes`] applies this to whole HTTP responses.
+
+use axum::body::{Body, HttpBody};
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use causality_core::{Hasher, Sha256Hasher};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::coalesce::{SharedResponse, SingleFlight};
+use crate::database::Database;
+use crate::types::ApiError;
+
+/// Header carrying the client's idempotency key
+pub static IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Header set on responses replayed for a repeated key
+pub static IDEMPOTENT_REPLAYED: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+/// Identity of an idempotent request: the key scoped to its route and
+/// credentials, so keys never collide across endpoints or callers
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdempotencyKey {
+    pub method: Method,
+    pub path: String,
+    pub key: Vec<u8>,
+    pub authorization: Option<Vec<u8>>,
+}
+
+impl IdempotencyKey {
+    /// Database key the recorded response is stored under
+    ///
+    /// Each component is length-prefixed before hashing, so distinct keys
+    /// never hash the same input.
+    fn storage_key(&self) -> String {
+        let mut bytes = Vec::new();
+        let components = [
+            Some(self.method.as_str().as_bytes()),
+            Some(self.path.as_bytes()),
+            Some(self.key.as_slice()),
+            self.authorization.as_deref(),
+        ];
+        for component in components {
+            match component {
+                Some(component) => {
+                    bytes.push(1);
+                    bytes.extend_from_slice(&(component.len() as u32).to_le_bytes());
+                    bytes.extend_from_slice(component);
+                }
+                None => bytes.push(0),
+            }
+        }
+        format!("idempotency/{}", hex::encode(Sha256Hasher::hash(&bytes)))
+    }
+}
+
+/// First response recorded for a key, with the body that produced it
+#[derive(Debug, Clone)]
+struct RecordedResponse {
+    request_hash: [u8; 32],
+    response: SharedResponse,
+}
+
+/// Database form of a [`RecordedResponse`]; binary fields are hex-encoded
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredResponse {
+    /// When the response was recorded, in milliseconds since the Unix epoch
+    recorded_at_ms: u64,
+    request_hash: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl StoredResponse {
+    fn new(recorded: &RecordedResponse) -> Self {
+        Self {
+            recorded_at_ms: now_ms(),
+            request_hash: hex::encode(recorded.request_hash),
+            status: recorded.response.status.as_u16(),
+            headers: recorded.response.headers.iter()
+                .map(|(name, value)| (name.to_string(), hex::encode(value.as_bytes())))
+                .collect(),
+            body: hex::encode(&recorded.response.body),
+        }
+    }
+
+    /// Decode the recorded response, or `None` if the record is malformed
+    fn decode(&self) -> Option<RecordedResponse> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes()).ok()?;
+            let value = HeaderValue::from_bytes(&hex::decode(value).ok()?).ok()?;
+            headers.append(name, value);
+        }
+        Some(RecordedResponse {
+            request_hash: hex::decode(&self.request_hash).ok()?.try_into().ok()?,
+            response: SharedResponse {
+                status: StatusCode::from_u16(self.status).ok()?,
+                headers,
+                body: hex::decode(&self.body).ok()?.into(),
+            },
+        })
+    }
+}
+
+/// Outcome of the request that ran the handler for a key: the recorded
+/// response, or `None` if its response could not be recorded
+type Flight = Result<Option<RecordedResponse>, ApiError>;
+
+/// Responses recorded for idempotency keys, kept in the server's
+/// [`Database`] for a TTL window
+///
+/// Because records live in the database rather than in this value, they
+/// survive restarts and are shared by every server using the same database.
+#[derive(Debug, Clone)]
+pub struct IdempotencyStore {
+    database: Arc<dyn Database>,
+    in_flight: SingleFlight<IdempotencyKey, Flight>,
+    ttl: Duration,
+    max_body_bytes: usize,
+}
+
+impl IdempotencyStore {
+    /// Record responses in `database` for `ttl`, buffering request and
+    /// response bodies of at most `max_body_bytes`
+    pub fn new(database: Arc<dyn Database>, ttl: Duration, max_body_bytes: usize) -> Self {
+        Self {
+            database,
+            in_flight: SingleFlight::new(),
+            ttl,
+            max_body_bytes,
+        }
+    }
+
+    /// Response recorded for `key`, unless it has expired
+    ///
+    /// Expired and malformed records are deleted when found.
+    async fn lookup(&self, key: &IdempotencyKey) -> Result<Option<RecordedResponse>, ApiError> {
+        let storage_key = key.storage_key();
+        let Some(bytes) = self.database.get(&storage_key).await? else {
+            return Ok(None);
+        };
+        let stored = serde_json::from_slice::<StoredResponse>(&bytes).ok();
+        let expired = stored.as_ref().map_or(true, |stored| {
+            now_ms().saturating_sub(stored.recorded_at_ms) >= self.ttl.as_millis() as u64
+        });
+        match stored.as_ref().and_then(StoredResponse::decode) {
+            Some(recorded) if !expired => Ok(Some(recorded)),
+            _ => {
+                self.database.delete(&storage_key).await?;
+                Ok(None)
+            }
+        }
+    }
+
+    async fn record(&self, key: &IdempotencyKey, recorded: &RecordedResponse) -> Result<(), ApiError> {
+        let value = serde_json::to_vec(&StoredResponse::new(recorded)).map_err(|e| ApiError::new(
+            "SERIALIZATION_ERROR",
+            format!("Failed to encode idempotent response: {}", e),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ))?;
+        self.database.put(&key.storage_key(), value).await
+    }
+
+    /// Whether `response` can be buffered and replayed
+    ///
+    /// Event streams never finish, and bodies without a known size within
+    /// `max_body_bytes` are not held in memory.
+    fn is_recordable(&self, response: &Response) -> bool {
+        let streaming = response.headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| content_type.starts_with("text/event-stream"));
+        let within_limit = response.body()
+            .size_hint()
+            .upper()
+            .is_some_and(|len| len <= self.max_body_bytes as u64);
+        !streaming && within_limit
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+/// Middleware replaying the recorded response for repeated `POST`s that
+/// carry an `Idempotency-Key`
+///
+/// The first request for a key runs the handler and its buffered response
+/// is recorded; retries with the same key and body get that response back,
+/// marked with `Idempotent-Replayed: true`, without reaching the handler.
+/// Reusing a key with a different body is rejected. Request bodies larger
+/// than the configured limit are rejected before reaching the handler.
+/// Event streams and responses over the limit are passed through without
+/// being recorded, so a retry runs the request again. Requests without the
+/// header, and other methods, pass through untouched.
+pub async fn idempotent_writes(
+    State(store): State<IdempotencyStore>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if request.method() != Method::POST {
+        return next.run(request).await;
+    }
+    let Some(key) = request.headers().get(&IDEMPOTENCY_KEY) else {
+        return next.run(request).await;
+    };
+
+    let key = IdempotencyKey {
+        method: request.method().clone(),
+        path: request.uri().path().to_string(),
+        key: key.as_bytes().to_vec(),
+        authorization: request.headers()
+            .get(header::AUTHORIZATION)
+            .map(|value| value.as_bytes().to_vec()),
+    };
+
+    let (parts, body) = request.into_parts();
+    let body = match axum::body::to_bytes(body, store.max_body_bytes).await {
+        Ok(body) => body,
+        Err(err) => return ApiError::new(
+            "REQUEST_TOO_LARGE",
+            format!("Failed to buffer request within {} bytes: {}", store.max_body_bytes, err),
+            StatusCode::PAYLOAD_TOO_LARGE,
+        ).into_response(),
+    };
+    let request_hash = Sha256Hasher::hash(&body);
+    let request = Request::from_parts(parts, Body::from(body));
+
+    match store.lookup(&key).await {
+        Ok(Some(recorded)) => return replay(recorded, request_hash),
+        Ok(None) => {}
+        Err(err) => return err.into_response(),
+    }
+
+    // Concurrent retries wait for the first attempt instead of racing it.
+    // The attempt that runs the handler keeps its own response, since one
+    // that cannot be recorded cannot be shared either.
+    let own_response = Arc::new(Mutex::new(None));
+    let (slot, recorder, recorded_key) = (own_response.clone(), store.clone(), key.clone());
+    let flight = store.in_flight.run(key, move || async move {
+        let response = next.run(request).await;
+        if !recorder.is_recordable(&response) {
+            log::warn!("Not recording streaming or oversized response for idempotent {}", recorded_key.path);
+            *slot.lock().unwrap() = Some(response);
+            return Ok(None);
+        }
+
+        let recorded = RecordedResponse {
+            request_hash,
+            response: SharedResponse::buffer_limited(response, recorder.max_body_bytes).await?,
+        };
+        if let Err(err) = recorder.record(&recorded_key, &recorded).await {
+            log::warn!("Failed to record idempotent response: {}", err.message);
+        }
+        *slot.lock().unwrap() = Some(recorded.response.clone().into_response());
+        Ok(Some(recorded))
+    }).await;
+
+    if let Some(response) = own_response.lock().unwrap().take() {
+        return response;
+    }
+    match flight {
+        // Another request with this key ran the handler; replay its record
+        Ok(Some(recorded)) => replay(recorded, request_hash),
+        Ok(None) => ApiError::new(
+            "IDEMPOTENT_RESPONSE_NOT_RECORDED",
+            "A concurrent request with this idempotency key returned a response that cannot be replayed",
+            StatusCode::CONFLICT,
+        ).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+/// Replay `recorded` to a request whose body hashes to `request_hash`
+fn replay(recorded: RecordedResponse, request_hash: [u8; 32]) -> Response {
+    if recorded.request_hash != request_hash {
+        return ApiError::new(
+            "IDEMPOTENCY_KEY_REUSED",
+            "Idempotency key was already used for a different request",
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ).into_response();
+    }
+    let mut response = recorded.response.into_response();
+    response.headers_mut().insert(IDEMPOTENT_REPLAYED.clone(), HeaderValue::from_static("true"));
+    response
+}
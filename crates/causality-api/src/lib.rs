@@ -3,16 +3,29 @@
 //! This crate provides HTTP API server and client functionality for the Causality system,
 //! including session management, transaction submission, and multi-chain interaction.
 
+pub mod coalesce;
 pub mod config;
+pub mod database;
+pub mod error;
 pub mod handlers;
+pub mod idempotency;
+pub mod metrics;
 pub mod server;
 pub mod session;
 pub mod types;
 pub mod client;
 
 // Re-export commonly used types
-pub use config::ApiConfig;
-pub use session::ExecutionSession;
-pub use server::Server;
+pub use coalesce::{ReadCoalescer, SingleFlight};
+pub use config::{ApiConfig, ConfigError};
+pub use database::{Database, FileDatabase, MemoryDatabase};
+pub use idempotency::IdempotencyStore;
+pub use session::{
+    ExecutionSession, ResumeToken, SessionEvent, SessionEvents, SessionLog, SessionStore,
+};
+pub use metrics::ApiMetrics;
+pub use server::{AppState, Server};
 pub use types::*;
-pub use client::{ChainClient, TransactionResult};
+pub use client::{
+    ChainClient, ClientError, ConnectionStatus, DomainId, MultiChainClient, TransactionReceipt, TransactionResult,
+};
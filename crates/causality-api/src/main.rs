@@ -1,14 +1,22 @@
 //! Causality API Server
 //!
 //! HTTP API server for the Causality system
+//!
+//! Configuration is read from the file given by `--config <path>` (or the
+//! `CAUSALITY_API_CONFIG` environment variable), overlaid with
+//! `CAUSALITY_API_*` environment variables. The server refuses to start if
+//! the configuration cannot be loaded.
 
-use anyhow::Result;
-use causality_api::{config::ApiConfig, server::Server};
+use anyhow::{bail, Context, Result};
+use causality_api::{config::{ApiConfig, CONFIG_PATH_ENV}, server::Server};
+use std::path::PathBuf;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // Load configuration
-    let config = ApiConfig::default();
+    let path = config_path()?;
+    let config = ApiConfig::from_env_and_file(path.as_deref())
+        .context("Invalid API server configuration")?;
     
     // Create and start server
     let server = Server::new(config);
@@ -16,3 +24,21 @@ async fn main() -> Result<()> {
     
     Ok(())
 }
+
+/// Config file path from `--config <path>`, falling back to the environment
+fn config_path() -> Result<Option<PathBuf>> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--config" {
+            return match args.next() {
+                Some(path) => Ok(Some(PathBuf::from(path))),
+                None => bail!("--config requires a path"),
+            };
+        }
+        if let Some(path) = arg.strip_prefix("--config=") {
+            return Ok(Some(PathBuf::from(path)));
+        }
+        bail!("Unknown argument: {}", arg);
+    }
+    Ok(std::env::var_os(CONFIG_PATH_ENV).map(PathBuf::from))
+}
@@ -0,0 +1,117 @@
+//! Aggregated metrics for the Causality API
+//!
+//! Metrics are collected per subsystem and exposed together on `/metrics` in
+//! the Prometheus text exposition format:
+//!
+//! - **boundary**: requests crossing the HTTP boundary, by method and status
+//! - **storage**: sessions written to and held by the session store
+//! - **engine**: work done by the simulation engine executing sessions
+
+use axum::extract::{Request, State};
+use axum::http::header;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use causality_compiler::observability::{MetricsCollector, MetricsSnapshot};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::session::ExecutionSession;
+
+/// Content type of the Prometheus text exposition format
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Metric collectors for each subsystem served by the API
+#[derive(Debug, Clone)]
+pub struct ApiMetrics {
+    pub boundary: MetricsCollector,
+    pub storage: MetricsCollector,
+    pub engine: MetricsCollector,
+}
+
+impl Default for ApiMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApiMetrics {
+    pub fn new() -> Self {
+        Self {
+            boundary: MetricsCollector::new("causality_boundary"),
+            storage: MetricsCollector::new("causality_storage"),
+            engine: MetricsCollector::new("causality_engine"),
+        }
+    }
+
+    /// Record a request that crossed the HTTP boundary
+    pub fn record_request(&self, method: &str, status: u16) {
+        let labels = BTreeMap::from([
+            ("method".to_string(), method.to_string()),
+            ("status".to_string(), status.to_string()),
+        ]);
+        self.boundary.increment_counter("requests_total", Some(labels));
+    }
+
+    /// Record a session written to the store, which now holds `stored` sessions
+    pub fn record_stored(&self, stored: usize) {
+        self.storage.increment_counter("sessions_stored_total", None);
+        self.storage.set_gauge("sessions", stored as f64, None);
+    }
+
+    /// Record the engine work done to execute a session
+    pub fn record_execution(&self, session: &ExecutionSession) {
+        let gas: u64 = session.steps.iter().map(|step| step.gas_consumed).sum();
+
+        self.engine.increment_counter("sessions_executed_total", None);
+        self.engine.add_to_counter("steps_executed_total", session.steps.len() as u64, None);
+        self.engine.add_to_counter("gas_consumed_total", gas, None);
+        self.engine.add_to_counter("effects_executed_total", session.effects.len() as u64, None);
+    }
+
+    /// Render all subsystems in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for collector in [&self.boundary, &self.storage, &self.engine] {
+            write_snapshot(&mut out, &collector.get_metrics_snapshot());
+        }
+        out
+    }
+}
+
+/// Append one subsystem's counters and gauges, grouped into metric families
+fn write_snapshot(out: &mut String, snapshot: &MetricsSnapshot) {
+    let counters = snapshot.counters.iter().map(|(name, value)| (name, value.to_string()));
+    write_families(out, "counter", counters);
+
+    let gauges = snapshot.gauges.iter().map(|(name, value)| (name, value.to_string()));
+    write_families(out, "gauge", gauges);
+}
+
+fn write_families<'a>(out: &mut String, kind: &str, samples: impl Iterator<Item = (&'a String, String)>) {
+    // Samples are keyed by name plus labels; group them under their family name
+    let mut families: BTreeMap<&str, Vec<(&String, String)>> = BTreeMap::new();
+    for (name, value) in samples {
+        let family = name.split('{').next().unwrap_or(name);
+        families.entry(family).or_default().push((name, value));
+    }
+
+    for (family, samples) in families {
+        let _ = writeln!(out, "# TYPE {} {}", family, kind);
+        for (name, value) in samples {
+            let _ = writeln!(out, "{} {}", name, value);
+        }
+    }
+}
+
+/// Middleware counting every request that passes through the router
+pub async fn track_requests(State(metrics): State<ApiMetrics>, request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let response = next.run(request).await;
+    metrics.record_request(&method, response.status().as_u16());
+    response
+}
+
+/// `GET /metrics`: Prometheus scrape target for all subsystems
+pub async fn metrics_handler(State(metrics): State<ApiMetrics>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], metrics.render())
+}
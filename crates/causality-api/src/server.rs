@@ -1,20 +1,217 @@
 //! HTTP server for the Causality API
 
 use anyhow::Result;
+use axum::extract::FromRef;
+use axum::http::{header, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware;
+use axum::routing::{get, post};
+use axum::Router;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower_http::set_header::SetResponseHeaderLayer;
+use crate::coalesce::{self, ReadCoalescer};
 use crate::config::ApiConfig;
+use crate::client::MultiChainClient;
+use crate::database;
+use crate::handlers;
+use crate::idempotency::{self, IdempotencyStore};
+use crate::metrics::{self, ApiMetrics};
+use crate::session::{ExecutionSession, ResumeToken, SessionStore};
+use crate::types::ApiError;
+
+/// State shared by all routes
+#[derive(Debug, Clone)]
+pub struct AppState {
+    pub sessions: SessionStore,
+    pub metrics: ApiMetrics,
+    
+    /// Chains whose connectivity gates readiness, if any are configured
+    pub chains: Option<Arc<MultiChainClient>>,
+}
+
+impl FromRef<AppState> for SessionStore {
+    fn from_ref(state: &AppState) -> Self {
+        state.sessions.clone()
+    }
+}
+
+impl FromRef<AppState> for ApiMetrics {
+    fn from_ref(state: &AppState) -> Self {
+        state.metrics.clone()
+    }
+}
 
 pub struct Server {
     config: ApiConfig,
+    sessions: SessionStore,
+    metrics: ApiMetrics,
+    reads: ReadCoalescer,
+    idempotency: IdempotencyStore,
+    chains: Option<Arc<MultiChainClient>>,
 }
 
 impl Server {
     pub fn new(config: ApiConfig) -> Self {
-        Self { config }
+        let sessions = SessionStore::new(config.max_sessions);
+        let idempotency = IdempotencyStore::new(
+            database::open(config.database_path.as_deref()),
+            Duration::from_secs(config.idempotency_ttl_secs),
+            config.idempotency_max_body_bytes,
+        );
+        Self {
+            config,
+            sessions,
+            metrics: ApiMetrics::new(),
+            reads: ReadCoalescer::new(),
+            idempotency,
+            chains: None,
+        }
+    }
+    
+    /// Report these chains' connectivity from `GET /readyz`
+    pub fn with_chains(mut self, chains: MultiChainClient) -> Self {
+        self.chains = Some(Arc::new(chains));
+        self
+    }
+    
+    /// Sessions held by this server
+    pub fn sessions(&self) -> &SessionStore {
+        &self.sessions
+    }
+    
+    /// Issue a token that resumes `session` with this server's secret
+    pub fn resume_token(&self, session: &ExecutionSession) -> std::result::Result<ResumeToken, ApiError> {
+        session.to_resume_token(
+            self.config.session_secret.as_bytes(),
+            std::time::Duration::from_secs(self.config.resume_token_ttl_secs),
+        )
+    }
+    
+    /// Resume the session a token issued by this server refers to
+    ///
+    /// A token whose session has since changed in this server's store is
+    /// rejected as stale rather than rolling the session back.
+    pub async fn resume(&self, token: &ResumeToken) -> std::result::Result<ExecutionSession, ApiError> {
+        let session = ExecutionSession::resume(token, self.config.session_secret.as_bytes()).await?;
+        if let Ok(stored) = self.sessions.get(&session.id).await {
+            if stored.snapshot_id() != session.snapshot_id() {
+                return Err(ApiError::new(
+                    "RESUME_TOKEN_STALE",
+                    format!("Session {} has changed since the resume token was issued", session.id),
+                    StatusCode::CONFLICT,
+                ));
+            }
+        }
+        Ok(session)
+    }
+    
+    /// Metrics collected by this server
+    pub fn metrics(&self) -> &ApiMetrics {
+        &self.metrics
+    }
+    
+    /// Build the HTTP router serving this server's sessions
+    pub fn router(&self) -> Router {
+        let content_security_policy = HeaderValue::from_str(&self.config.content_security_policy)
+            .unwrap_or_else(|_| {
+                log::warn!("Invalid Content-Security-Policy in config, falling back to default-src 'none'");
+                HeaderValue::from_static("default-src 'none'")
+            });
+        
+        Router::new()
+            .route("/healthz", get(handlers::healthz))
+            .route("/readyz", get(handlers::readyz))
+            .route("/sessions", post(handlers::create_session))
+            .route("/sessions/import", post(handlers::import_session_log))
+            .route("/sessions/:id/log", get(handlers::export_session_log))
+            .route("/sessions/:id/events", get(handlers::stream_session_events))
+            .route("/metrics", get(metrics::metrics_handler))
+            .with_state(AppState {
+                sessions: self.sessions.clone(),
+                metrics: self.metrics.clone(),
+                chains: self.chains.clone(),
+            })
+            .layer(middleware::from_fn_with_state(self.reads.clone(), coalesce::coalesce_reads))
+            .layer(middleware::from_fn_with_state(self.idempotency.clone(), idempotency::idempotent_writes))
+            .layer(middleware::from_fn_with_state(self.metrics.clone(), metrics::track_requests))
+            .layer(self.cors_layer())
+            .layer(SetResponseHeaderLayer::if_not_present(
+                header::X_CONTENT_TYPE_OPTIONS,
+                HeaderValue::from_static("nosniff"),
+            ))
+            .layer(SetResponseHeaderLayer::if_not_present(
+                header::CONTENT_SECURITY_POLICY,
+                content_security_policy,
+            ))
+    }
+    
+    /// CORS policy built from the configured origins, methods, and headers
+    ///
+    /// Requests from origins outside the allow-list get no
+    /// `Access-Control-Allow-Origin` header, so browsers refuse the response.
+    fn cors_layer(&self) -> CorsLayer {
+        let origins = if self.config.cors_allowed_origins.iter().any(|origin| origin == "*") {
+            AllowOrigin::any()
+        } else {
+            AllowOrigin::list(parse_all(&self.config.cors_allowed_origins, "origin", |o| HeaderValue::from_str(o).ok()))
+        };
+        let methods = parse_all(&self.config.cors_allowed_methods, "method", |m| Method::from_bytes(m.as_bytes()).ok());
+        let headers = parse_all(&self.config.cors_allowed_headers, "header", |h| HeaderName::from_bytes(h.as_bytes()).ok());
+        
+        CorsLayer::new()
+            .allow_origin(origins)
+            .allow_methods(methods)
+            .allow_headers(headers)
+    }
+    
+    /// Spawn the background task that periodically prunes expired session
+    /// event logs
+    ///
+    /// The task runs every `prune_interval_secs` and drops the logs of
+    /// sessions that finished more than `event_retention_secs` ago.
+    pub fn spawn_pruning(&self) -> JoinHandle<()> {
+        let sessions = self.sessions.clone();
+        let retention = Duration::from_secs(self.config.event_retention_secs);
+        let every = Duration::from_secs(self.config.prune_interval_secs.max(1));
+        tokio::spawn(async move {
+            let mut ticks = tokio::time::interval(every);
+            ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticks.tick().await;
+                let pruned = sessions.prune_events(retention).await;
+                if pruned > 0 {
+                    log::debug!("Pruned {} expired session event logs", pruned);
+                }
+            }
+        })
     }
     
     pub async fn start(&self) -> Result<()> {
         println!("Starting Causality API server on {}:{}", self.config.host, self.config.port);
-        // Minimal implementation for now
+        let pruning = self.spawn_pruning();
+        let listener = tokio::net::TcpListener::bind((self.config.host.as_str(), self.config.port)).await;
+        let served = match listener {
+            Ok(listener) => axum::serve(listener, self.router()).await,
+            Err(e) => Err(e),
+        };
+        pruning.abort();
+        served?;
         Ok(())
     }
 }
+
+/// Parse configured CORS values, skipping (and logging) any that are invalid
+fn parse_all<T>(values: &[String], kind: &str, parse: impl Fn(&str) -> Option<T>) -> Vec<T> {
+    values.iter()
+        .filter_map(|value| {
+            let parsed = parse(value);
+            if parsed.is_none() {
+                log::warn!("Ignoring invalid CORS {} in config: {}", kind, value);
+            }
+            parsed
+        })
+        .collect()
+}
@@ -1,13 +1,314 @@
 //! Session management for the Causality API
 
+use axum::http::StatusCode;
+use causality_core::machine::Instruction;
+use causality_core::system::serialization::{DecodeError, SszDecode, SszEncode};
+use causality_core::system::{decode_fixed_bytes, decode_slice, decode_with_length, encode_with_length};
+use causality_core::{Hasher, Sha256Hasher};
+use causality_simulation::{ExecutionStep, SimulatedTimestamp, SimulationEngine, SimulationState};
+use futures::Stream;
+use jsonwebtoken::errors::ErrorKind as JwtErrorKind;
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{watch, RwLock};
+use tokio::time::Instant;
+
+use crate::types::ApiError;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionSession {
     pub id: String,
     pub created_at: u64,
     pub metadata: HashMap<String, String>,
+
+    /// Seed the session's simulation engine was created with
+    #[serde(default)]
+    pub seed: u64,
+
+    /// Program executed by the session
+    #[serde(default)]
+    pub program: Vec<Instruction>,
+
+    /// Steps recorded while executing the program
+    #[serde(default)]
+    pub steps: Vec<ExecutionStep>,
+
+    /// Effects emitted by the engine while executing the program
+    #[serde(default)]
+    pub effects: Vec<String>,
+}
+
+/// Portable effect log of a session, sufficient to reconstruct it by replay
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionLog {
+    /// Session the log was exported from
+    pub session_id: String,
+    pub seed: u64,
+    pub program: Vec<Instruction>,
+    pub steps: Vec<ExecutionStep>,
+    pub effects: Vec<String>,
+}
+
+impl SessionLog {
+    /// SSZ encoding of the log
+    ///
+    /// Fields are written in declaration order. Strings, instructions and
+    /// lists carry a `u32` length prefix; instructions use their own SSZ
+    /// encoding and an absent step instruction is a single zero byte.
+    pub fn to_ssz_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        encode_with_length(self.session_id.as_bytes(), &mut buf);
+        self.seed.ssz_append(&mut buf);
+
+        (self.program.len() as u32).ssz_append(&mut buf);
+        for instruction in &self.program {
+            encode_with_length(&instruction.as_ssz_bytes(), &mut buf);
+        }
+
+        (self.steps.len() as u32).ssz_append(&mut buf);
+        for step in &self.steps {
+            (step.step_number as u64).ssz_append(&mut buf);
+            step.timestamp.as_millis().ssz_append(&mut buf);
+            match &step.instruction {
+                Some(instruction) => {
+                    buf.push(1);
+                    encode_with_length(instruction.as_bytes(), &mut buf);
+                }
+                None => buf.push(0),
+            }
+            encode_strings(&step.resources_allocated, &mut buf);
+            encode_strings(&step.resources_consumed, &mut buf);
+            step.gas_consumed.ssz_append(&mut buf);
+        }
+
+        encode_strings(&self.effects, &mut buf);
+        buf
+    }
+
+    /// Decode a log from its SSZ encoding
+    pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = SszReader(bytes);
+        let session_id = reader.string()?;
+        let seed = reader.u64()?;
+
+        let program = (0..reader.u32()?)
+            .map(|_| Instruction::from_ssz_bytes(reader.bytes()?))
+            .collect::<Result<_, _>>()?;
+
+        let steps = (0..reader.u32()?)
+            .map(|_| {
+                Ok(ExecutionStep {
+                    step_number: reader.u64()? as usize,
+                    timestamp: SimulatedTimestamp::from_millis(reader.u64()?),
+                    instruction: match reader.u8()? {
+                        0 => None,
+                        1 => Some(reader.string()?),
+                        flag => return Err(DecodeError::BytesInvalid(
+                            format!("Invalid instruction flag: {}", flag)
+                        )),
+                    },
+                    resources_allocated: reader.strings()?,
+                    resources_consumed: reader.strings()?,
+                    gas_consumed: reader.u64()?,
+                })
+            })
+            .collect::<Result<_, DecodeError>>()?;
+
+        let effects = reader.strings()?;
+        if !reader.0.is_empty() {
+            return Err(DecodeError::BytesInvalid(
+                format!("{} trailing bytes after session log", reader.0.len())
+            ));
+        }
+
+        Ok(SessionLog { session_id, seed, program, steps, effects })
+    }
+}
+
+fn encode_strings(items: &[String], buf: &mut Vec<u8>) {
+    (items.len() as u32).ssz_append(buf);
+    for item in items {
+        encode_with_length(item.as_bytes(), buf);
+    }
+}
+
+/// Cursor over the fields of an SSZ-encoded session log
+struct SszReader<'a>(&'a [u8]);
+
+impl<'a> SszReader<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let field = decode_slice(self.0, 0, N)?;
+        let value = decode_fixed_bytes(field)?;
+        self.0 = &self.0[N..];
+        Ok(value)
+    }
+
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.take()?))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], DecodeError> {
+        let (data, rest) = decode_with_length(self.0)?;
+        self.0 = rest;
+        Ok(data)
+    }
+
+    fn string(&mut self) -> Result<String, DecodeError> {
+        String::from_utf8(self.bytes()?.to_vec())
+            .map_err(|e| DecodeError::BytesInvalid(format!("Invalid UTF-8 string: {}", e)))
+    }
+
+    fn strings(&mut self) -> Result<Vec<String>, DecodeError> {
+        (0..self.u32()?).map(|_| self.string()).collect()
+    }
+}
+
+/// Progress of a running session, as streamed to API clients
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SessionEvent {
+    /// The engine is about to execute program step `step`
+    StepStarted { step: usize },
+    
+    /// An effect was emitted while executing step `step`
+    EffectExecuted { step: usize, effect: String },
+    
+    /// Step `step` finished and the session state advanced
+    StateAdvanced {
+        step: usize,
+        instruction: Option<String>,
+        gas_consumed: u64,
+    },
+    
+    /// The program ran to completion after `steps` steps
+    Completed { steps: usize },
+    
+    /// Execution stopped with an error
+    Failed { error: String },
+}
+
+impl SessionEvent {
+    /// Event name used on the wire, matching the serialized `type` tag
+    pub fn name(&self) -> &'static str {
+        match self {
+            SessionEvent::StepStarted { .. } => "step_started",
+            SessionEvent::EffectExecuted { .. } => "effect_executed",
+            SessionEvent::StateAdvanced { .. } => "state_advanced",
+            SessionEvent::Completed { .. } => "completed",
+            SessionEvent::Failed { .. } => "failed",
+        }
+    }
+    
+    /// Whether no further events follow this one
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, SessionEvent::Completed { .. } | SessionEvent::Failed { .. })
+    }
+}
+
+/// Ordered log of a session's progress events, shared with readers that
+/// follow it while the session runs
+///
+/// An event's index in the log is its id, so a reader can pick the log back
+/// up after the last event it saw.
+#[derive(Debug, Clone)]
+pub struct SessionEvents {
+    log: Arc<watch::Sender<Vec<SessionEvent>>>,
+    
+    /// When the terminal event was recorded
+    finished_at: Arc<OnceLock<Instant>>,
+}
+
+impl Default for SessionEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionEvents {
+    pub fn new() -> Self {
+        Self {
+            log: Arc::new(watch::channel(Vec::new()).0),
+            finished_at: Arc::new(OnceLock::new()),
+        }
+    }
+    
+    /// Append an event and wake any readers waiting for it
+    pub fn push(&self, event: SessionEvent) {
+        if event.is_terminal() {
+            let _ = self.finished_at.set(Instant::now());
+        }
+        self.log.send_modify(|events| events.push(event));
+    }
+    
+    /// When the session's terminal event was recorded, if it has finished
+    pub fn finished_at(&self) -> Option<Instant> {
+        self.finished_at.get().copied()
+    }
+    
+    /// Number of events recorded so far
+    pub fn len(&self) -> usize {
+        self.log.borrow().len()
+    }
+    
+    /// Whether no events have been recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.log.borrow().is_empty()
+    }
+    
+    /// Stream events with their ids, starting at id `from`
+    ///
+    /// Recorded events are yielded immediately, after which the stream waits
+    /// for new ones. It ends after the session's terminal event.
+    pub fn stream_from(&self, from: usize) -> impl Stream<Item = (usize, SessionEvent)> {
+        futures::stream::unfold((self.log.subscribe(), from), |(mut receiver, next)| async move {
+            loop {
+                let (event, finished) = {
+                    let events = receiver.borrow_and_update();
+                    (events.get(next).cloned(), events.last().is_some_and(SessionEvent::is_terminal))
+                };
+                if let Some(event) = event {
+                    return Some(((next, event), (receiver, next + 1)));
+                }
+                if finished || receiver.changed().await.is_err() {
+                    return None;
+                }
+            }
+        })
+    }
+}
+
+/// Signed token a client can present to pick a session back up
+///
+/// The token carries the session's log and the content id of its state when
+/// the token was issued, so it reconstructs exactly that snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ResumeToken(pub String);
+
+/// Claims carried by a resume token
+#[derive(Debug, Serialize, Deserialize)]
+struct ResumeClaims {
+    /// Session the token resumes
+    sub: String,
+    /// Content id of the session's log when the token was issued
+    snapshot: String,
+    /// The session's log, replayed to reconstruct it
+    log: SessionLog,
+    /// Issue time, in seconds since the Unix epoch
+    iat: u64,
+    /// Expiry time, in seconds since the Unix epoch
+    exp: u64,
 }
 
 impl ExecutionSession {
@@ -19,6 +320,268 @@ impl ExecutionSession {
                 .unwrap()
                 .as_secs(),
             metadata: HashMap::new(),
+            seed: 0,
+            program: Vec::new(),
+            steps: Vec::new(),
+            effects: Vec::new(),
+        }
+    }
+
+    /// Run `program` on a fresh engine seeded with `seed` and record its log
+    pub async fn execute(&mut self, seed: u64, program: Vec<Instruction>) -> Result<(), ApiError> {
+        self.execute_with_events(seed, program, &SessionEvents::new()).await
+    }
+    
+    /// Run `program` like [`execute`](Self::execute), reporting progress to
+    /// `events` one step at a time
+    pub async fn execute_with_events(
+        &mut self,
+        seed: u64,
+        program: Vec<Instruction>,
+        events: &SessionEvents,
+    ) -> Result<(), ApiError> {
+        let mut engine = SimulationEngine::with_seed(seed);
+        if let Err(e) = Self::run_reporting(&mut engine, program.clone(), events).await {
+            events.push(SessionEvent::Failed { error: e.message.clone() });
+            return Err(e);
+        }
+        events.push(SessionEvent::Completed {
+            steps: engine.state_progression().executed_steps(),
+        });
+
+        self.seed = seed;
+        self.program = program;
+        self.steps = engine.state_progression().steps.clone();
+        self.effects = engine.effects_log().clone();
+        Ok(())
+    }
+
+    /// Step `engine` through `program`, pushing an event around each step
+    async fn run_reporting(
+        engine: &mut SimulationEngine,
+        program: Vec<Instruction>,
+        events: &SessionEvents,
+    ) -> Result<(), ApiError> {
+        let program_len = program.len();
+        engine.load_program(program)?;
+        engine.set_state(SimulationState::Running);
+
+        while engine.program_counter() < program_len {
+            let step = engine.state_progression().executed_steps();
+            let effects_before = engine.effects_log().len();
+            events.push(SessionEvent::StepStarted { step });
+
+            let more = engine.step().await?;
+
+            for effect in &engine.effects_log()[effects_before..] {
+                events.push(SessionEvent::EffectExecuted { step, effect: effect.clone() });
+            }
+            if let Some(recorded) = engine.state_progression().steps.last() {
+                events.push(SessionEvent::StateAdvanced {
+                    step,
+                    instruction: recorded.instruction.clone(),
+                    gas_consumed: recorded.gas_consumed,
+                });
+            }
+            if !more {
+                break;
+            }
+        }
+
+        engine.set_state(SimulationState::Completed);
+        Ok(())
+    }
+    
+    /// Export the session's effect log
+    pub fn export_log(&self) -> SessionLog {
+        SessionLog {
+            session_id: self.id.clone(),
+            seed: self.seed,
+            program: self.program.clone(),
+            steps: self.steps.clone(),
+            effects: self.effects.clone(),
+        }
+    }
+
+    /// Content id of the session's state, hashed over its exported log
+    pub fn snapshot_id(&self) -> String {
+        let log = serde_json::to_vec(&self.export_log()).unwrap_or_default();
+        hex::encode(Sha256Hasher::hash(&log))
+    }
+
+    /// Issue a token, signed with `secret`, that resumes this session's
+    /// current snapshot until `ttl` has passed
+    pub fn to_resume_token(&self, secret: &[u8], ttl: Duration) -> Result<ResumeToken, ApiError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let claims = ResumeClaims {
+            sub: self.id.clone(),
+            snapshot: self.snapshot_id(),
+            log: self.export_log(),
+            iat: now,
+            exp: now + ttl.as_secs(),
+        };
+        jsonwebtoken::encode(&Header::default(), &claims, &EncodingKey::from_secret(secret))
+            .map(ResumeToken)
+            .map_err(|e| ApiError::new(
+                "RESUME_TOKEN_ERROR",
+                format!("Failed to sign resume token: {}", e),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ))
+    }
+
+    /// Reconstruct the session a token signed with `secret` was issued for
+    ///
+    /// The token must carry a valid signature and must not have expired. The
+    /// session is rebuilt by replaying the log in the token, and the result
+    /// must have the content id the token was issued for.
+    pub async fn resume(token: &ResumeToken, secret: &[u8]) -> Result<Self, ApiError> {
+        let invalid = |reason: String| ApiError::new(
+            "RESUME_TOKEN_INVALID",
+            format!("Invalid resume token: {}", reason),
+            StatusCode::UNAUTHORIZED,
+        );
+        let mut validation = Validation::default();
+        validation.leeway = 0;
+        let claims = jsonwebtoken::decode::<ResumeClaims>(&token.0, &DecodingKey::from_secret(secret), &validation)
+            .map_err(|e| match e.kind() {
+                JwtErrorKind::ExpiredSignature => ApiError::new(
+                    "RESUME_TOKEN_EXPIRED",
+                    "Resume token has expired",
+                    StatusCode::UNAUTHORIZED,
+                ),
+                _ => invalid(e.to_string()),
+            })?
+            .claims;
+
+        let session = Self::from_log(claims.sub, claims.log).await
+            .map_err(|e| invalid(e.message))?;
+        if session.snapshot_id() != claims.snapshot {
+            return Err(invalid("log does not match the snapshot".to_string()));
+        }
+        Ok(session)
+    }
+
+    /// Reconstruct a session by replaying an exported log
+    ///
+    /// The log's program is re-executed with its seed and the resulting steps
+    /// and effects must match the log exactly; a log that does not replay
+    /// faithfully is rejected rather than imported with divergent state.
+    pub async fn from_log(id: String, log: SessionLog) -> Result<Self, ApiError> {
+        let mut session = Self::new(id);
+        session.execute(log.seed, log.program.clone()).await?;
+
+        if let Some(index) = first_mismatch(&session.steps, &log.steps) {
+            return Err(ApiError::new(
+                "LOG_REPLAY_MISMATCH",
+                format!("Replayed step {} does not match the imported log", index),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ).with_detail("step", index.to_string()));
         }
+        if session.effects != log.effects {
+            return Err(ApiError::new(
+                "LOG_REPLAY_MISMATCH",
+                "Replayed effects do not match the imported log",
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ));
+        }
+
+        session.metadata.insert("imported_from".to_string(), log.session_id);
+        Ok(session)
+    }
+}
+
+/// Index of the first position where two step sequences differ
+fn first_mismatch(replayed: &[ExecutionStep], logged: &[ExecutionStep]) -> Option<usize> {
+    replayed.iter()
+        .zip(logged)
+        .position(|(a, b)| a != b)
+        .or_else(|| (replayed.len() != logged.len()).then_some(replayed.len().min(logged.len())))
+}
+
+/// Shared, bounded store of execution sessions
+#[derive(Debug, Clone)]
+pub struct SessionStore {
+    sessions: Arc<RwLock<HashMap<String, ExecutionSession>>>,
+    events: Arc<RwLock<HashMap<String, SessionEvents>>>,
+    max_sessions: usize,
+}
+
+impl SessionStore {
+    pub fn new(max_sessions: usize) -> Self {
+        Self {
+            sessions: Arc::new(RwLock::new(HashMap::new())),
+            events: Arc::new(RwLock::new(HashMap::new())),
+            max_sessions,
+        }
+    }
+
+    /// Store a session, failing once `max_sessions` is reached
+    pub async fn insert(&self, session: ExecutionSession) -> Result<(), ApiError> {
+        let mut sessions = self.sessions.write().await;
+        if sessions.len() >= self.max_sessions && !sessions.contains_key(&session.id) {
+            return Err(ApiError::new(
+                "SESSION_LIMIT_REACHED",
+                format!("Session limit of {} reached", self.max_sessions),
+                StatusCode::SERVICE_UNAVAILABLE,
+            ));
+        }
+        sessions.insert(session.id.clone(), session);
+        Ok(())
+    }
+
+    /// Look up a session by ID
+    pub async fn get(&self, id: &str) -> Result<ExecutionSession, ApiError> {
+        self.sessions.read().await
+            .get(id)
+            .cloned()
+            .ok_or_else(|| ApiError::new(
+                "SESSION_NOT_FOUND",
+                format!("Session not found: {}", id),
+                StatusCode::NOT_FOUND,
+            ))
+    }
+
+    /// Start a fresh event log for session `id`, replacing any earlier one
+    pub async fn open_events(&self, id: &str) -> SessionEvents {
+        let events = SessionEvents::new();
+        self.events.write().await.insert(id.to_string(), events.clone());
+        events
+    }
+    
+    /// Event log of session `id`, if its execution was reported to this store
+    pub async fn events(&self, id: &str) -> Result<SessionEvents, ApiError> {
+        self.events.read().await
+            .get(id)
+            .cloned()
+            .ok_or_else(|| ApiError::new(
+                "SESSION_NOT_FOUND",
+                format!("No events recorded for session: {}", id),
+                StatusCode::NOT_FOUND,
+            ))
+    }
+    
+    /// Drop the event logs of sessions that finished more than `retention`
+    /// ago
+    ///
+    /// Logs of sessions still running are always kept, so live streams are
+    /// never cut short. Returns the number of logs removed.
+    pub async fn prune_events(&self, retention: Duration) -> usize {
+        let now = Instant::now();
+        let mut events = self.events.write().await;
+        let before = events.len();
+        events.retain(|_, log| {
+            log.finished_at().map_or(true, |finished| now.duration_since(finished) <= retention)
+        });
+        before - events.len()
+    }
+    
+    /// Number of stored sessions
+    pub async fn len(&self) -> usize {
+        self.sessions.read().await.len()
+    }
+
+    /// Whether the store holds no sessions
+    pub async fn is_empty(&self) -> bool {
+        self.sessions.read().await.is_empty()
     }
 }
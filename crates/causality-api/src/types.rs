@@ -5,6 +5,7 @@
 
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
+use std::time::Duration;
 
 //-----------------------------------------------------------------------------
 // Transaction Types
@@ -158,6 +159,22 @@ pub struct RetryConfig {
     pub max_delay_ms: u64,
 }
 
+/// How long and how often to poll for a transaction's confirmation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfirmOptions {
+    /// Give up once the transaction has been pending this long
+    pub timeout: Duration,
+    
+    /// Delay before the second poll
+    pub initial_interval: Duration,
+    
+    /// Multiplier applied to the delay after each poll
+    pub backoff_multiplier: f64,
+    
+    /// Upper bound on the delay between polls
+    pub max_interval: Duration,
+}
+
 //-----------------------------------------------------------------------------
 // Session Types for API Communication
 //-----------------------------------------------------------------------------
@@ -213,6 +230,23 @@ pub struct ApiResponse<T> {
     pub request_id: String,
 }
 
+/// Request to run a program in a new execution session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSessionRequest {
+    /// Program to execute
+    pub program: Vec<causality_core::machine::Instruction>,
+    
+    /// Engine seed; a random seed is chosen when omitted
+    pub seed: Option<u64>,
+}
+
+/// Query parameters for exporting a session log
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogExportQuery {
+    /// Export format, `json` (the default) or `ssz`
+    pub format: Option<String>,
+}
+
 /// API error information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiError {
@@ -222,6 +256,9 @@ pub struct ApiError {
     /// Human-readable error message
     pub message: String,
     
+    /// HTTP status code this error should be reported with
+    pub status: u16,
+    
     /// Additional error details
     pub details: HashMap<String, String>,
 }
@@ -241,6 +278,17 @@ impl Default for RetryConfig {
     }
 }
 
+impl Default for ConfirmOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(300),
+            initial_interval: Duration::from_secs(2),
+            backoff_multiplier: 1.5,
+            max_interval: Duration::from_secs(15),
+        }
+    }
+}
+
 impl Default for GlobalSettings {
     fn default() -> Self {
         Self {
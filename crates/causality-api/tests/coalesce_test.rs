@@ -0,0 +1,133 @@
+//! Request Coalescing Tests
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::extract::{Path, State};
+use axum::http::{Request, StatusCode};
+use axum::middleware;
+use axum::routing::get;
+use axum::Router;
+use causality_api::coalesce::coalesce_reads;
+use causality_api::{ReadCoalescer, SingleFlight};
+use tokio::task::JoinSet;
+use tower::ServiceExt;
+
+const CONCURRENT_REQUESTS: usize = 16;
+
+/// Router whose proof status handler counts its invocations
+fn proof_status_router(calls: Arc<AtomicUsize>) -> Router {
+    async fn proof_status(State(calls): State<Arc<AtomicUsize>>, Path(id): Path<String>) -> String {
+        calls.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        format!("proof {} verified", id)
+    }
+
+    Router::new()
+        .route("/proofs/:id/status", get(proof_status))
+        .with_state(calls)
+        .layer(middleware::from_fn_with_state(ReadCoalescer::new(), coalesce_reads))
+}
+
+async fn fire_concurrently(router: Router, uri: &'static str) -> Vec<(StatusCode, String)> {
+    fire_concurrently_with(router, uri, &[]).await
+}
+
+/// Fire identical requests carrying `headers` at the same time
+async fn fire_concurrently_with(
+    router: Router,
+    uri: &'static str,
+    headers: &[(&'static str, &'static str)],
+) -> Vec<(StatusCode, String)> {
+    let mut requests = JoinSet::new();
+    for i in 0..CONCURRENT_REQUESTS {
+        let router = router.clone();
+        // Alternate between the given headers and none
+        let headers = if i % 2 == 0 { headers.to_vec() } else { Vec::new() };
+        requests.spawn(async move {
+            let mut request = Request::builder().uri(uri);
+            for (name, value) in headers {
+                request = request.header(name, value);
+            }
+            let request = request.body(Body::empty()).unwrap();
+            let response = router.oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        });
+    }
+
+    let mut responses = Vec::new();
+    while let Some(response) = requests.join_next().await {
+        responses.push(response.unwrap());
+    }
+    responses
+}
+
+#[tokio::test]
+async fn test_concurrent_identical_reads_invoke_backend_once() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let router = proof_status_router(calls.clone());
+
+    let responses = fire_concurrently(router, "/proofs/abc/status").await;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(responses.len(), CONCURRENT_REQUESTS);
+    for (status, body) in responses {
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "proof abc verified");
+    }
+}
+
+#[tokio::test]
+async fn test_sequential_and_distinct_reads_are_not_coalesced() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let router = proof_status_router(calls.clone());
+
+    fire_concurrently(router.clone(), "/proofs/abc/status").await;
+    fire_concurrently(router.clone(), "/proofs/abc/status").await;
+    fire_concurrently(router, "/proofs/xyz/status").await;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_reads_with_different_cookies_or_accept_are_not_shared() {
+    for header in [("cookie", "session=alice"), ("accept", "application/json")] {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let router = proof_status_router(calls.clone());
+
+        fire_concurrently_with(router, "/proofs/abc/status", &[header]).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2, "{} must split the key", header.0);
+    }
+}
+
+#[tokio::test]
+async fn test_cancelled_callers_do_not_leak_entries() {
+    let flight = SingleFlight::<&str, u32>::new();
+
+    let mut callers = JoinSet::new();
+    for _ in 0..4 {
+        let flight = flight.clone();
+        callers.spawn(async move {
+            flight.run("proof", || async {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                1
+            }).await
+        });
+    }
+    while flight.in_flight() == 0 {
+        tokio::task::yield_now().await;
+    }
+
+    callers.abort_all();
+    while callers.join_next().await.is_some() {}
+    assert_eq!(flight.in_flight(), 0);
+
+    // The next caller starts a fresh call
+    assert_eq!(flight.run("proof", || async { 2 }).await, 2);
+    assert_eq!(flight.in_flight(), 0);
+}
@@ -0,0 +1,113 @@
+//! API Config Loading Tests
+//!
+//! Loads `ApiConfig` from a TOML file, from `CAUSALITY_API_*` variables, and
+//! from both, checking that variables take precedence over the file and that
+//! missing or malformed values are reported against their key.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+
+use causality_api::{ApiConfig, ConfigError};
+use tempfile::NamedTempFile;
+
+fn config_file(contents: &str) -> NamedTempFile {
+    let mut file = tempfile::Builder::new().suffix(".toml").tempfile().unwrap();
+    file.write_all(contents.as_bytes()).unwrap();
+    file
+}
+
+fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+const FILE: &str = r#"
+host = "0.0.0.0"
+port = 9000
+max_sessions = 10
+cors_allowed_origins = ["https://app.example"]
+resume_token_ttl_secs = 120
+"#;
+
+#[test]
+fn test_load_from_file_only() {
+    let file = config_file(FILE);
+    let config = ApiConfig::from_file_and_vars(Some(file.path()), HashMap::new()).unwrap();
+
+    assert_eq!(config.host, "0.0.0.0");
+    assert_eq!(config.port, 9000);
+    assert_eq!(config.max_sessions, 10);
+    assert_eq!(config.cors_allowed_origins, ["https://app.example"]);
+    assert_eq!(config.resume_token_ttl_secs, 120);
+    assert_eq!(config.idempotency_ttl_secs, ApiConfig::default().idempotency_ttl_secs);
+}
+
+#[test]
+fn test_load_from_env_only() {
+    let config = ApiConfig::from_file_and_vars(None, vars(&[
+        ("CAUSALITY_API_HOST", "10.0.0.1"),
+        ("CAUSALITY_API_PORT", "8443"),
+        ("CAUSALITY_API_MAX_SESSIONS", "50"),
+        ("CAUSALITY_API_CORS_ALLOWED_ORIGINS", "https://a.example,https://b.example"),
+        ("UNRELATED_PORT", "1"),
+    ])).unwrap();
+
+    assert_eq!(config.host, "10.0.0.1");
+    assert_eq!(config.port, 8443);
+    assert_eq!(config.max_sessions, 50);
+    assert_eq!(config.cors_allowed_origins, ["https://a.example", "https://b.example"]);
+}
+
+#[test]
+fn test_env_overrides_file() {
+    let file = config_file(FILE);
+    let config = ApiConfig::from_file_and_vars(Some(file.path()), vars(&[
+        ("CAUSALITY_API_PORT", "9100"),
+        ("CAUSALITY_API_RESUME_TOKEN_TTL_SECS", "30"),
+    ])).unwrap();
+
+    assert_eq!(config.port, 9100);
+    assert_eq!(config.resume_token_ttl_secs, 30);
+    assert_eq!(config.host, "0.0.0.0");
+    assert_eq!(config.max_sessions, 10);
+}
+
+#[test]
+fn test_from_env_and_file_reads_process_environment() {
+    let file = config_file(FILE);
+    std::env::set_var("CAUSALITY_API_MAX_SESSIONS", "7");
+    let config = ApiConfig::from_env_and_file(Some(file.path()));
+    std::env::remove_var("CAUSALITY_API_MAX_SESSIONS");
+
+    assert_eq!(config.unwrap().max_sessions, 7);
+}
+
+#[test]
+fn test_reports_missing_and_malformed_values() {
+    let missing = ApiConfig::from_file_and_vars(None, vars(&[("CAUSALITY_API_HOST", "localhost")]));
+    assert!(matches!(missing, Err(ConfigError::Load(_))));
+
+    let file = config_file(FILE);
+    let malformed = ApiConfig::from_file_and_vars(Some(file.path()), vars(&[("CAUSALITY_API_PORT", "eighty")]))
+        .unwrap_err();
+    assert!(matches!(malformed, ConfigError::Load(_)));
+    assert!(malformed.to_string().contains("port"), "{}", malformed);
+
+    let invalid = ApiConfig::from_file_and_vars(Some(file.path()), vars(&[("CAUSALITY_API_MAX_SESSIONS", "0")]));
+    assert!(matches!(invalid, Err(ConfigError::Invalid { key: "max_sessions", .. })));
+
+    let absent = ApiConfig::from_env_and_file(Some(Path::new("/nonexistent/causality-api.toml")));
+    assert!(matches!(absent, Err(ConfigError::FileNotFound(_))));
+}
+
+#[test]
+fn test_session_secret_is_not_exposed() {
+    let config = ApiConfig {
+        session_secret: "do-not-leak".to_string(),
+        ..ApiConfig::default()
+    };
+
+    assert!(!format!("{:?}", config).contains("do-not-leak"));
+    let serialized = serde_json::to_string(&config).unwrap();
+    assert!(!serialized.contains("session_secret"), "{}", serialized);
+}
@@ -0,0 +1,97 @@
+//! Transaction Confirmation Tests
+//!
+//! Points a ChainClient at a mock JSON-RPC endpoint that reports a
+//! transaction as pending for a few polls before returning its receipt.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::State;
+use axum::routing::post;
+use axum::{Json, Router};
+use causality_api::{ChainClient, ChainConfig, ClientError, ConfirmOptions};
+use serde_json::{json, Value};
+
+/// Mock chain that answers receipt queries with `null` for the first
+/// `pending_polls` calls and then with a receipt carrying `status`
+#[derive(Clone)]
+struct MockChain {
+    polls: Arc<AtomicUsize>,
+    pending_polls: usize,
+    status: &'static str,
+}
+
+async fn rpc(State(chain): State<MockChain>, Json(request): Json<Value>) -> Json<Value> {
+    assert_eq!(request["method"], "eth_getTransactionReceipt");
+    let poll = chain.polls.fetch_add(1, Ordering::SeqCst);
+    let result = if poll < chain.pending_polls {
+        Value::Null
+    } else {
+        json!({ "blockNumber": "0x10", "gasUsed": "0x5208", "status": chain.status })
+    };
+    Json(json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }))
+}
+
+/// Serve `chain` on a local port and return a client pointed at it
+async fn client_for(chain: MockChain) -> ChainClient {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let router = Router::new().route("/", post(rpc)).with_state(chain);
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    ChainClient::new(ChainConfig {
+        name: "mock".to_string(),
+        chain_id: 31337,
+        rpc_url: format!("http://{}/", addr),
+        explorer_url: String::new(),
+        gas_price_multiplier: 1.0,
+        confirmation_blocks: 1,
+    }).await.unwrap()
+}
+
+fn fast_polling(timeout: Duration) -> ConfirmOptions {
+    ConfirmOptions {
+        timeout,
+        initial_interval: Duration::from_millis(5),
+        backoff_multiplier: 2.0,
+        max_interval: Duration::from_millis(20),
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_confirmation_polls_until_mined() {
+    let polls = Arc::new(AtomicUsize::new(0));
+    let client = client_for(MockChain { polls: polls.clone(), pending_polls: 2, status: "0x1" }).await;
+
+    let receipt = client
+        .wait_for_confirmation("0xabc", fast_polling(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert_eq!(receipt.transaction_hash, "0xabc");
+    assert_eq!(receipt.block_number, 16);
+    assert_eq!(receipt.gas_used, 21000);
+    assert_eq!(polls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_wait_for_confirmation_reports_reverted_transaction() {
+    let polls = Arc::new(AtomicUsize::new(0));
+    let client = client_for(MockChain { polls, pending_polls: 1, status: "0x0" }).await;
+
+    let result = client.wait_for_confirmation("0xabc", fast_polling(Duration::from_secs(5))).await;
+    match result {
+        Err(ClientError::TransactionFailed(receipt)) => assert_eq!(receipt.block_number, 16),
+        other => panic!("expected a failed transaction, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_confirmation_times_out_while_pending() {
+    let polls = Arc::new(AtomicUsize::new(0));
+    let client = client_for(MockChain { polls: polls.clone(), pending_polls: usize::MAX, status: "0x1" }).await;
+
+    let result = client.wait_for_confirmation("0xabc", fast_polling(Duration::from_millis(50))).await;
+    assert!(matches!(result, Err(ClientError::ConfirmationTimeout { ref tx_hash, .. }) if tx_hash == "0xabc"));
+    assert!(polls.load(Ordering::SeqCst) > 1);
+}
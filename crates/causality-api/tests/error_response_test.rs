@@ -0,0 +1,250 @@
+//! Engine Error Response Tests
+//!
+//! Serves engine-layer errors from a route added to the server's router and
+//! checks the HTTP status, error code, and details each variant is reported
+//! with once it has gone through axum's response handling.
+
+use std::collections::HashMap;
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::routing::get;
+use causality_api::{ApiConfig, ApiError, Server};
+use causality_compiler::error_handling::CausalityError;
+use causality_core::system::{ContextChain, Error as CoreError, ResultExt};
+use causality_runtime::RuntimeError;
+use causality_simulation::SimulationError;
+use tower::ServiceExt;
+
+/// Serve `err` as a handler's error response and decode what the client sees
+async fn respond(err: impl Into<ApiError>) -> (StatusCode, ApiError) {
+    let error: ApiError = err.into();
+    let router = Server::new(ApiConfig::default())
+        .router()
+        .route("/fail", get(move || async move { Err::<(), _>(error) }));
+
+    let response = router
+        .oneshot(Request::builder().uri("/fail").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+async fn assert_responds(err: impl Into<ApiError>, code: &str, status: StatusCode) {
+    let (actual_status, body) = respond(err).await;
+    assert_eq!(actual_status, status, "{}", body.message);
+    assert_eq!(body.code, code);
+    assert_eq!(body.status, status.as_u16());
+}
+
+fn detail<'a>(error: &'a ApiError, key: &str) -> Option<&'a str> {
+    error.details.get(key).map(String::as_str)
+}
+
+#[tokio::test]
+async fn test_core_error_responses() {
+    assert_responds(CoreError::resource("r"), "RESOURCE_ERROR", StatusCode::CONFLICT).await;
+    assert_responds(CoreError::content_addressing("c"), "CONTENT_ADDRESSING_ERROR", StatusCode::UNPROCESSABLE_ENTITY).await;
+    assert_responds(CoreError::serialization("s"), "SERIALIZATION_ERROR", StatusCode::BAD_REQUEST).await;
+    assert_responds(CoreError::storage("s"), "STORAGE_ERROR", StatusCode::SERVICE_UNAVAILABLE).await;
+    assert_responds(CoreError::network("n"), "NETWORK_ERROR", StatusCode::BAD_GATEWAY).await;
+    assert_responds(CoreError::validation("v"), "VALIDATION_ERROR", StatusCode::BAD_REQUEST).await;
+    assert_responds(CoreError::system("s"), "SYSTEM_ERROR", StatusCode::INTERNAL_SERVER_ERROR).await;
+}
+
+#[tokio::test]
+async fn test_core_error_context_chain_is_serialized() {
+    let err = Err::<(), _>(CoreError::validation("bad id"))
+        .context("while loading resource X")
+        .context("while handling request 7")
+        .unwrap_err();
+    let (status, body) = respond(err).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body.code, "VALIDATION_ERROR");
+    assert_eq!(
+        body.message,
+        "while handling request 7: while loading resource X: Validation error: bad id"
+    );
+
+    let chain: Vec<String> = serde_json::from_str(&body.details["context"]).unwrap();
+    assert_eq!(chain, ["while loading resource X", "while handling request 7"]);
+}
+
+#[tokio::test]
+async fn test_context_chain_round_trips_through_serialization() {
+    let err = Err::<(), _>(CoreError::storage("disk full"))
+        .context("while writing block 12")
+        .context("while committing transaction")
+        .unwrap_err();
+    let sent = err.context_chain().cloned().unwrap();
+    let (_, body) = respond(err).await;
+
+    let received: ContextChain = serde_json::from_str(&body.details["context"]).unwrap();
+    assert_eq!(received, sent);
+    assert_eq!(received.to_string(), "while committing transaction: while writing block 12");
+
+    // Forwarding the error again keeps its details intact
+    let forwarded: ApiError = serde_json::from_str(&serde_json::to_string(&body).unwrap()).unwrap();
+    assert_eq!(forwarded.code, "STORAGE_ERROR");
+    assert_eq!(forwarded.details, body.details);
+}
+
+#[tokio::test]
+async fn test_runtime_error_responses() {
+    assert_responds(RuntimeError::execution_failed("e"), "EXECUTION_FAILED", StatusCode::UNPROCESSABLE_ENTITY).await;
+    assert_responds(RuntimeError::handler_error("h"), "HANDLER_ERROR", StatusCode::INTERNAL_SERVER_ERROR).await;
+    assert_responds(RuntimeError::resource_error("r"), "RESOURCE_ERROR", StatusCode::CONFLICT).await;
+    assert_responds(RuntimeError::type_mismatch("t"), "TYPE_MISMATCH", StatusCode::UNPROCESSABLE_ENTITY).await;
+    assert_responds(RuntimeError::linearity_violation("l"), "LINEARITY_VIOLATION", StatusCode::CONFLICT).await;
+    assert_responds(RuntimeError::unhandled_effect("e"), "UNHANDLED_EFFECT", StatusCode::NOT_IMPLEMENTED).await;
+    assert_responds(RuntimeError::RegisterError("r".into()), "REGISTER_ERROR", StatusCode::INTERNAL_SERVER_ERROR).await;
+    assert_responds(RuntimeError::MemoryError("m".into()), "MEMORY_ERROR", StatusCode::INTERNAL_SERVER_ERROR).await;
+    assert_responds(RuntimeError::internal("i"), "INTERNAL_ERROR", StatusCode::INTERNAL_SERVER_ERROR).await;
+}
+
+#[tokio::test]
+async fn test_machine_error_code_is_preserved() {
+    let (status, via_runtime) = respond(RuntimeError::MachineError(CoreError::storage("disk full"))).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(via_runtime.code, "STORAGE_ERROR");
+    assert_eq!(detail(&via_runtime, "source"), Some("runtime"));
+
+    let (status, via_simulation) = respond(SimulationError::MachineError(CoreError::network("down"))).await;
+    assert_eq!(status, StatusCode::BAD_GATEWAY);
+    assert_eq!(via_simulation.code, "NETWORK_ERROR");
+}
+
+#[tokio::test]
+async fn test_simulation_error_responses() {
+    let cases = [
+        (SimulationError::Configuration("c".into()), "CONFIGURATION_ERROR", StatusCode::BAD_REQUEST),
+        (SimulationError::EngineState("s".into()), "INVALID_ENGINE_STATE", StatusCode::CONFLICT),
+        (SimulationError::EffectExecutionError("e".into()), "EFFECT_EXECUTION_FAILED", StatusCode::UNPROCESSABLE_ENTITY),
+        (SimulationError::NetworkError("n".into()), "NETWORK_ERROR", StatusCode::BAD_GATEWAY),
+        (SimulationError::CrossChainError("x".into()), "CROSS_CHAIN_ERROR", StatusCode::BAD_GATEWAY),
+        (SimulationError::InvalidState("s".into()), "INVALID_STATE", StatusCode::CONFLICT),
+        (SimulationError::SnapshotError("s".into()), "SNAPSHOT_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+        (SimulationError::ConstraintViolation { constraint: "c".into() }, "CONSTRAINT_VIOLATION", StatusCode::UNPROCESSABLE_ENTITY),
+        (SimulationError::SnapshotOperationFailed("s".into()), "SNAPSHOT_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+        (SimulationError::FaultInjectionError("f".into()), "FAULT_INJECTION_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+        (SimulationError::TegExecutionError("t".into()), "TEG_EXECUTION_FAILED", StatusCode::UNPROCESSABLE_ENTITY),
+        (SimulationError::IntentProcessingError("i".into()), "INTENT_PROCESSING_FAILED", StatusCode::UNPROCESSABLE_ENTITY),
+        (SimulationError::VisualizationError("v".into()), "VISUALIZATION_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+        (SimulationError::EngineError("e".into()), "ENGINE_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+        (SimulationError::BranchNotFound("b".into()), "BRANCH_NOT_FOUND", StatusCode::NOT_FOUND),
+        (SimulationError::OptimizationError("o".into()), "OPTIMIZATION_ERROR", StatusCode::INTERNAL_SERVER_ERROR),
+        (SimulationError::ResourceError("r".into()), "RESOURCE_ERROR", StatusCode::CONFLICT),
+        (SimulationError::ParseError("p".into()), "PARSE_ERROR", StatusCode::BAD_REQUEST),
+        (SimulationError::CompilationError("c".into()), "COMPILATION_ERROR", StatusCode::UNPROCESSABLE_ENTITY),
+        (SimulationError::InvalidInput("i".into()), "INVALID_INPUT", StatusCode::BAD_REQUEST),
+    ];
+    for (err, code, status) in cases {
+        assert_responds(err, code, status).await;
+    }
+}
+
+#[tokio::test]
+async fn test_simulation_error_details_are_preserved() {
+    let (status, unavailable) = respond(SimulationError::ResourceUnavailable { resource_id: "res-1".into() }).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(unavailable.code, "RESOURCE_UNAVAILABLE");
+    assert_eq!(detail(&unavailable, "resource_id"), Some("res-1"));
+
+    let (_, register) = respond(SimulationError::RegisterError { message: "m".into(), register_id: "r7".into() }).await;
+    assert_eq!(detail(&register, "register_id"), Some("r7"));
+
+    let (status, violation) = respond(SimulationError::SessionProtocolViolation {
+        participant: "alice".into(),
+        operation: "send".into(),
+        expected: "receive".into(),
+    }).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(violation.code, "SESSION_PROTOCOL_VIOLATION");
+    assert_eq!(detail(&violation, "participant"), Some("alice"));
+}
+
+#[tokio::test]
+async fn test_compiler_error_responses() {
+    assert_responds(
+        CausalityError::Permission {
+            message: "p".into(),
+            required_permission: None,
+            current_role: None,
+        },
+        "PERMISSION_DENIED",
+        StatusCode::FORBIDDEN,
+    ).await;
+    assert_responds(
+        CausalityError::Validation {
+            message: "v".into(),
+            field: None,
+            expected: None,
+            actual: None,
+        },
+        "VALIDATION_ERROR",
+        StatusCode::UNPROCESSABLE_ENTITY,
+    ).await;
+    assert_responds(
+        CausalityError::Timeout {
+            message: "t".into(),
+            operation: "compile".into(),
+            duration_ms: 20,
+            timeout_ms: 10,
+        },
+        "TIMEOUT",
+        StatusCode::GATEWAY_TIMEOUT,
+    ).await;
+    assert_responds(
+        CausalityError::Storage {
+            message: "s".into(),
+            details: None,
+            recoverable: true,
+            error_code: None,
+        },
+        "STORAGE_ERROR",
+        StatusCode::SERVICE_UNAVAILABLE,
+    ).await;
+    assert_responds(
+        CausalityError::Compilation {
+            message: "c".into(),
+            line: Some(3),
+            column: None,
+            source_context: None,
+        },
+        "COMPILATION_ERROR",
+        StatusCode::UNPROCESSABLE_ENTITY,
+    ).await;
+}
+
+#[tokio::test]
+async fn test_compiler_error_details_are_preserved() {
+    let (_, permission) = respond(CausalityError::Permission {
+        message: "no access".into(),
+        required_permission: Some("admin".into()),
+        current_role: Some("viewer".into()),
+    }).await;
+    assert_eq!(detail(&permission, "source"), Some("compiler"));
+    assert_eq!(detail(&permission, "error_type"), Some("permission"));
+    assert_eq!(detail(&permission, "retryable"), Some("false"));
+    assert_eq!(detail(&permission, "required_permission"), Some("admin"));
+
+    let (_, timeout) = respond(CausalityError::Timeout {
+        message: "slow".into(),
+        operation: "compile".into(),
+        duration_ms: 20,
+        timeout_ms: 10,
+    }).await;
+    assert_eq!(detail(&timeout, "retryable"), Some("true"));
+    assert_eq!(detail(&timeout, "operation"), Some("compile"));
+
+    let (status, generic) = respond(CausalityError::Generic {
+        message: "g".into(),
+        error_code: Some("QUOTA_EXCEEDED".into()),
+        context: HashMap::new(),
+    }).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(generic.code, "QUOTA_EXCEEDED");
+}
@@ -0,0 +1,85 @@
+//! Health and Readiness Probe Tests
+//!
+//! Serves mock JSON-RPC chains, one healthy and one failing, and checks that
+//! `GET /readyz` reports each chain's connection status, keyed by domain, and
+//! answers 503 while any chain is not connected.
+
+use std::collections::HashMap;
+
+use axum::body::{to_bytes, Body};
+use axum::extract::State;
+use axum::http::{Request, StatusCode};
+use axum::routing::post;
+use axum::{Json, Router};
+use causality_api::{ApiConfig, ChainConfig, GlobalSettings, MultiChainClient, MultiChainConfig, Server};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+/// Mock chain answering block number queries, or failing them if `fail`
+async fn rpc(State(fail): State<bool>, Json(request): Json<Value>) -> Json<Value> {
+    assert_eq!(request["method"], "eth_blockNumber");
+    let id = request["id"].clone();
+    if fail {
+        Json(json!({ "jsonrpc": "2.0", "id": id, "error": { "code": -32603, "message": "node is syncing" } }))
+    } else {
+        Json(json!({ "jsonrpc": "2.0", "id": id, "result": "0x2a" }))
+    }
+}
+
+async fn mock_chain(name: &str, fail: bool) -> ChainConfig {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let router = Router::new().route("/", post(rpc)).with_state(fail);
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    ChainConfig {
+        name: name.to_string(),
+        chain_id: 31337,
+        rpc_url: format!("http://{}/", addr),
+        explorer_url: String::new(),
+        gas_price_multiplier: 1.0,
+        confirmation_blocks: 1,
+    }
+}
+
+async fn get(server: &Server, uri: &str) -> (StatusCode, Value) {
+    let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+    let response = server.router().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_readyz_reports_failed_chain() {
+    let mut chains = HashMap::new();
+    chains.insert("ethereum".to_string(), mock_chain("ethereum", false).await);
+    chains.insert("polygon".to_string(), mock_chain("polygon", true).await);
+    let client = MultiChainClient::new(MultiChainConfig {
+        chains,
+        default_gas_limits: HashMap::new(),
+        global_settings: GlobalSettings::default(),
+    }).await.unwrap();
+    let server = Server::new(ApiConfig::default()).with_chains(client);
+
+    let (status, body) = get(&server, "/readyz").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["status"], "not_ready");
+    assert_eq!(body["chains"]["domain:ethereum"], json!({ "status": "connected", "block_number": 42 }));
+    assert_eq!(body["chains"]["domain:polygon"]["status"], "failed");
+    assert!(body["chains"]["domain:polygon"]["error"].as_str().unwrap().contains("node is syncing"));
+
+    // Liveness does not depend on the chains
+    let (status, body) = get(&server, "/healthz").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "ok");
+}
+
+#[tokio::test]
+async fn test_readyz_without_chains_is_ready() {
+    let server = Server::new(ApiConfig::default());
+
+    let (status, body) = get(&server, "/readyz").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!({ "status": "ready", "chains": {} }));
+}
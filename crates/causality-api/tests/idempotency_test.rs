@@ -0,0 +1,130 @@
+//! Idempotency Key Tests
+//!
+//! Repeats session-creating requests with the same `Idempotency-Key` and
+//! checks that the first response is replayed instead of a second session
+//! being created, and that reusing a key for a different body is rejected.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use causality_api::{ApiConfig, CreateSessionRequest, ExecutionSession, Server};
+use causality_core::machine::{Instruction, RegisterId};
+use tower::ServiceExt;
+
+fn create_session(key: Option<&str>, seed: u64) -> Request<Body> {
+    let body = CreateSessionRequest {
+        program: vec![Instruction::Transform {
+            morph_reg: RegisterId::new(0),
+            input_reg: RegisterId::new(1),
+            output_reg: RegisterId::new(2),
+        }],
+        seed: Some(seed),
+    };
+    let mut request = Request::builder()
+        .method("POST")
+        .uri("/sessions")
+        .header("content-type", "application/json");
+    if let Some(key) = key {
+        request = request.header("idempotency-key", key);
+    }
+    request.body(Body::from(serde_json::to_vec(&body).unwrap())).unwrap()
+}
+
+#[tokio::test]
+async fn test_repeated_key_replays_first_response() {
+    let server = Server::new(ApiConfig::default());
+
+    let first = server.router().oneshot(create_session(Some("abc"), 7)).await.unwrap();
+    assert_eq!(first.status(), StatusCode::CREATED);
+    assert!(first.headers().get("idempotent-replayed").is_none());
+    let first = to_bytes(first.into_body(), usize::MAX).await.unwrap();
+
+    let retry = server.router().oneshot(create_session(Some("abc"), 7)).await.unwrap();
+    assert_eq!(retry.status(), StatusCode::CREATED);
+    assert_eq!(retry.headers()["idempotent-replayed"], "true");
+    let retry = to_bytes(retry.into_body(), usize::MAX).await.unwrap();
+
+    assert_eq!(first, retry);
+    let session: ExecutionSession = serde_json::from_slice(&retry).unwrap();
+    assert!(server.sessions().get(&session.id).await.is_ok());
+    assert_eq!(server.sessions().len().await, 1);
+}
+
+#[tokio::test]
+async fn test_requests_without_key_are_not_deduplicated() {
+    let server = Server::new(ApiConfig::default());
+
+    for _ in 0..2 {
+        let response = server.router().oneshot(create_session(None, 7)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+    assert_eq!(server.sessions().len().await, 2);
+}
+
+#[tokio::test]
+async fn test_reused_key_with_different_body_is_rejected() {
+    let server = Server::new(ApiConfig::default());
+
+    let first = server.router().oneshot(create_session(Some("abc"), 7)).await.unwrap();
+    assert_eq!(first.status(), StatusCode::CREATED);
+
+    let reused = server.router().oneshot(create_session(Some("abc"), 8)).await.unwrap();
+    assert_eq!(reused.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(server.sessions().len().await, 1);
+}
+
+#[tokio::test]
+async fn test_recorded_response_survives_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = ApiConfig {
+        database_path: Some(dir.path().to_path_buf()),
+        ..ApiConfig::default()
+    };
+
+    let first = Server::new(config.clone());
+    let response = first.router().oneshot(create_session(Some("abc"), 7)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+    // A restarted server, or another instance, sharing the database
+    let restarted = Server::new(config);
+    let retry = restarted.router().oneshot(create_session(Some("abc"), 7)).await.unwrap();
+    assert_eq!(retry.status(), StatusCode::CREATED);
+    assert_eq!(retry.headers()["idempotent-replayed"], "true");
+    assert_eq!(to_bytes(retry.into_body(), usize::MAX).await.unwrap(), body);
+    assert_eq!(restarted.sessions().len().await, 0);
+}
+
+#[tokio::test]
+async fn test_oversized_request_is_rejected() {
+    let server = Server::new(ApiConfig {
+        idempotency_max_body_bytes: 16,
+        ..ApiConfig::default()
+    });
+
+    let response = server.router().oneshot(create_session(Some("abc"), 7)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(server.sessions().len().await, 0);
+
+    // Without a key the body is not buffered by the idempotency layer
+    let response = server.router().oneshot(create_session(None, 7)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_event_stream_response_is_not_recorded() {
+    let server = Server::new(ApiConfig::default());
+    let streaming = || {
+        let mut request = create_session(Some("abc"), 7);
+        request.headers_mut().insert("accept", "text/event-stream".parse().unwrap());
+        request
+    };
+
+    for _ in 0..2 {
+        let response = server.router().oneshot(streaming()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+        assert!(response.headers().get("idempotent-replayed").is_none());
+        let events = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(String::from_utf8(events.to_vec()).unwrap().contains("event: completed"));
+    }
+}
@@ -0,0 +1,57 @@
+//! Metrics Endpoint Tests
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Request, StatusCode};
+use causality_api::{ApiConfig, CreateSessionRequest, Server};
+use causality_core::machine::{Instruction, RegisterId};
+use tower::ServiceExt;
+
+/// Value of the first sample whose name starts with `prefix`
+fn sample(exposition: &str, prefix: &str) -> f64 {
+    exposition.lines()
+        .filter(|line| !line.starts_with('#'))
+        .find(|line| line.starts_with(prefix))
+        .and_then(|line| line.rsplit(' ').next())
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_else(|| panic!("no sample for {} in:\n{}", prefix, exposition))
+}
+
+#[tokio::test]
+async fn test_metrics_aggregates_all_subsystems() {
+    let server = Server::new(ApiConfig::default());
+    let program: Vec<Instruction> = (0..3)
+        .map(|i| Instruction::Alloc {
+            type_reg: RegisterId::new(i),
+            init_reg: RegisterId::new(i),
+            output_reg: RegisterId::new(i + 1),
+        })
+        .collect();
+    
+    for seed in 0..2 {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/sessions")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&CreateSessionRequest {
+                program: program.clone(),
+                seed: Some(seed),
+            }).unwrap()))
+            .unwrap();
+        let response = server.router().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+    
+    let request = Request::builder().uri("/metrics").body(Body::empty()).unwrap();
+    let response = server.router().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()[header::CONTENT_TYPE].to_str().unwrap().starts_with("text/plain"));
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let exposition = String::from_utf8(body.to_vec()).unwrap();
+    
+    assert!(exposition.contains("# TYPE causality_boundary_requests_total counter"));
+    assert_eq!(sample(&exposition, "causality_boundary_requests_total{method=\"POST\",status=\"201\"}"), 2.0);
+    assert_eq!(sample(&exposition, "causality_storage_sessions_stored_total"), 2.0);
+    assert_eq!(sample(&exposition, "causality_storage_sessions "), 2.0);
+    assert_eq!(sample(&exposition, "causality_engine_steps_executed_total"), 6.0);
+    assert!(sample(&exposition, "causality_engine_gas_consumed_total") > 0.0);
+}
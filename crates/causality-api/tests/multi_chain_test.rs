@@ -0,0 +1,104 @@
+//! Multi-Chain Submission Tests
+//!
+//! Submits the same transaction to several mock JSON-RPC chains at once and
+//! checks that every chain's outcome is reported, including failures.
+
+use std::collections::HashMap;
+
+use axum::extract::State;
+use axum::routing::post;
+use axum::{Json, Router};
+use causality_api::{
+    ChainConfig, ClientError, DomainId, GlobalSettings, MultiChainClient, MultiChainConfig,
+    ProofData, TransactionRequest, TransactionResult,
+};
+use serde_json::{json, Value};
+
+/// Mock chain that accepts transactions unless `reject` is set
+async fn rpc(State(reject): State<bool>, Json(request): Json<Value>) -> Json<Value> {
+    let id = request["id"].clone();
+    match request["method"].as_str() {
+        Some("eth_sendRawTransaction") if reject => Json(json!({
+            "jsonrpc": "2.0", "id": id, "error": { "code": -32000, "message": "nonce too low" }
+        })),
+        Some("eth_sendRawTransaction") => Json(json!({ "jsonrpc": "2.0", "id": id, "result": "0xfeed" })),
+        Some("eth_getTransactionReceipt") => Json(json!({
+            "jsonrpc": "2.0", "id": id,
+            "result": { "blockNumber": "0x2a", "gasUsed": "0x5208", "status": "0x1" }
+        })),
+        other => panic!("unexpected RPC method {:?}", other),
+    }
+}
+
+/// Serve a mock chain on a local port and return its configuration
+async fn mock_chain(name: &str, reject: bool) -> ChainConfig {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let router = Router::new().route("/", post(rpc)).with_state(reject);
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    ChainConfig {
+        name: name.to_string(),
+        chain_id: 31337,
+        rpc_url: format!("http://{}/", addr),
+        explorer_url: String::new(),
+        gas_price_multiplier: 1.0,
+        confirmation_blocks: 1,
+    }
+}
+
+fn transfer() -> TransactionRequest {
+    TransactionRequest {
+        proof_data: ProofData {
+            proof: "0x01".to_string(),
+            public_inputs: vec!["0x02".to_string()],
+            verification_key: "vk".to_string(),
+            circuit_id: "transfer".to_string(),
+            metadata: HashMap::new(),
+        },
+        gas_price: Some(1_000_000_000),
+        gas_limit: Some(100_000),
+        dry_run: false,
+    }
+}
+
+#[tokio::test]
+async fn test_submit_multi_reports_every_chain() {
+    let mut chains = HashMap::new();
+    chains.insert("ethereum".to_string(), mock_chain("ethereum", false).await);
+    chains.insert("polygon".to_string(), mock_chain("polygon", true).await);
+    let client = MultiChainClient::new(MultiChainConfig {
+        chains,
+        default_gas_limits: HashMap::new(),
+        global_settings: GlobalSettings::default(),
+    }).await.unwrap();
+
+    let results = client.submit_multi(vec![
+        (DomainId::domain("polygon"), transfer()),
+        (DomainId::domain("ethereum"), transfer()),
+        (DomainId::domain("solana"), transfer()),
+    ]).await;
+
+    let domains: Vec<DomainId> = results.iter().map(|(domain, _)| domain.clone()).collect();
+    assert_eq!(domains, [
+        DomainId::domain("polygon"),
+        DomainId::domain("ethereum"),
+        DomainId::domain("solana"),
+    ]);
+
+    match &results[0].1 {
+        Err(ClientError::Submission(message)) => assert!(message.contains("nonce too low")),
+        other => panic!("expected polygon to fail, got {:?}", other),
+    }
+    match &results[1].1 {
+        Ok(TransactionResult::Success { tx_hash, block_number, .. }) => {
+            assert_eq!(tx_hash, "0xfeed");
+            assert_eq!(*block_number, 42);
+        }
+        other => panic!("expected ethereum to succeed, got {:?}", other),
+    }
+    assert!(matches!(
+        &results[2].1,
+        Err(ClientError::UnknownDomain(domain)) if *domain == DomainId::domain("solana")
+    ));
+}
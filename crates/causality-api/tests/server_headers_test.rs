@@ -0,0 +1,64 @@
+//! Server CORS and Security Header Tests
+
+use axum::body::Body;
+use axum::http::{header, Request, Response};
+use causality_api::{ApiConfig, Server};
+use tower::ServiceExt;
+
+fn server_allowing(origin: &str) -> Server {
+    Server::new(ApiConfig {
+        cors_allowed_origins: vec![origin.to_string()],
+        ..ApiConfig::default()
+    })
+}
+
+async fn preflight(server: &Server, origin: &str) -> Response<Body> {
+    let request = Request::builder()
+        .method("OPTIONS")
+        .uri("/sessions")
+        .header(header::ORIGIN, origin)
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "content-type")
+        .body(Body::empty())
+        .unwrap();
+    server.router().oneshot(request).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_preflight_from_allowed_origin() {
+    let server = server_allowing("https://app.example.com");
+    let response = preflight(&server, "https://app.example.com").await;
+    let headers = response.headers();
+    
+    assert!(response.status().is_success());
+    assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], "https://app.example.com");
+    let methods = headers[header::ACCESS_CONTROL_ALLOW_METHODS].to_str().unwrap();
+    assert!(methods.contains("POST"));
+    let allowed_headers = headers[header::ACCESS_CONTROL_ALLOW_HEADERS].to_str().unwrap();
+    assert!(allowed_headers.contains("content-type"));
+}
+
+#[tokio::test]
+async fn test_preflight_from_disallowed_origin_is_rejected() {
+    let server = server_allowing("https://app.example.com");
+    let response = preflight(&server, "https://evil.example.com").await;
+    
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[tokio::test]
+async fn test_security_headers_are_set() {
+    let server = Server::new(ApiConfig::default());
+    let request = Request::builder()
+        .uri("/sessions/missing/log")
+        .body(Body::empty())
+        .unwrap();
+    let response = server.router().oneshot(request).await.unwrap();
+    let headers = response.headers();
+    
+    assert_eq!(headers[header::X_CONTENT_TYPE_OPTIONS], "nosniff");
+    assert_eq!(
+        headers[header::CONTENT_SECURITY_POLICY],
+        "default-src 'none'; frame-ancestors 'none'"
+    );
+}
@@ -0,0 +1,246 @@
+//! Session Event Stream Tests
+//!
+//! Drives sessions and reads `GET /sessions/{id}/events` to check that
+//! progress events arrive in order, that a stream follows a session while it
+//! runs, and that reconnecting with `Last-Event-ID` resumes after that event.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use causality_api::{ApiConfig, CreateSessionRequest, ExecutionSession, Server, SessionEvent};
+use causality_core::machine::{Instruction, RegisterId};
+use tower::ServiceExt;
+
+fn program() -> Vec<Instruction> {
+    (0..2)
+        .map(|i| Instruction::Transform {
+            morph_reg: RegisterId::new(i),
+            input_reg: RegisterId::new(i),
+            output_reg: RegisterId::new(i),
+        })
+        .collect()
+}
+
+fn expected_events() -> Vec<SessionEvent> {
+    let mut events = Vec::new();
+    for step in 0..2 {
+        events.push(SessionEvent::StepStarted { step });
+        events.push(SessionEvent::StateAdvanced {
+            step,
+            instruction: Some("Transform".to_string()),
+            gas_consumed: 3,
+        });
+    }
+    events.push(SessionEvent::Completed { steps: 2 });
+    events
+}
+
+fn events_request(id: &str, last_event_id: Option<&str>) -> Request<Body> {
+    let mut request = Request::builder()
+        .uri(format!("/sessions/{}/events", id))
+        .header("accept", "text/event-stream");
+    if let Some(last) = last_event_id {
+        request = request.header("last-event-id", last);
+    }
+    request.body(Body::empty()).unwrap()
+}
+
+/// Read an event stream to its end and return `(id, event)` pairs
+async fn read_events(server: &Server, request: Request<Body>) -> Vec<(usize, SessionEvent)> {
+    let response = server.router().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "text/event-stream");
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+    String::from_utf8(body.to_vec()).unwrap()
+        .split("\n\n")
+        .filter_map(|frame| {
+            let mut id = None;
+            let mut name = None;
+            let mut data = None;
+            for line in frame.lines() {
+                if let Some(value) = line.strip_prefix("id:") {
+                    id = Some(value.trim().parse::<usize>().unwrap());
+                } else if let Some(value) = line.strip_prefix("event:") {
+                    name = Some(value.trim().to_string());
+                } else if let Some(value) = line.strip_prefix("data:") {
+                    data = Some(serde_json::from_str::<SessionEvent>(value.trim()).unwrap());
+                }
+            }
+            let event = data?;
+            assert_eq!(name.as_deref(), Some(event.name()));
+            Some((id.expect("every event carries an id"), event))
+        })
+        .collect()
+}
+
+async fn create_session(server: &Server) -> ExecutionSession {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sessions")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(&CreateSessionRequest {
+            program: program(),
+            seed: Some(3),
+        }).unwrap()))
+        .unwrap();
+    let response = server.router().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_event_stream_reports_session_progress_in_order() {
+    let server = Server::new(ApiConfig::default());
+    let session = create_session(&server).await;
+
+    let events = read_events(&server, events_request(&session.id, None)).await;
+    let ids: Vec<usize> = events.iter().map(|(id, _)| *id).collect();
+    assert_eq!(ids, (0..5).collect::<Vec<_>>());
+    let events: Vec<SessionEvent> = events.into_iter().map(|(_, event)| event).collect();
+    assert_eq!(events, expected_events());
+}
+
+#[tokio::test]
+async fn test_event_stream_follows_running_session() {
+    let server = Server::new(ApiConfig::default());
+    let events = server.sessions().open_events("live").await;
+
+    // Read the stream concurrently while the session runs
+    let stream = tokio::spawn({
+        let router = server.router();
+        async move {
+            let response = router.oneshot(events_request("live", None)).await.unwrap();
+            to_bytes(response.into_body(), usize::MAX).await.unwrap()
+        }
+    });
+    tokio::task::yield_now().await;
+    let mut session = ExecutionSession::new("live".to_string());
+    session.execute_with_events(3, program(), &events).await.unwrap();
+
+    let body = String::from_utf8(stream.await.unwrap().to_vec()).unwrap();
+    let names: Vec<&str> = body.lines().filter_map(|line| line.strip_prefix("event:")).map(str::trim).collect();
+    assert_eq!(names, ["step_started", "state_advanced", "step_started", "state_advanced", "completed"]);
+}
+
+#[tokio::test]
+async fn test_event_stream_resumes_after_last_event_id() {
+    let server = Server::new(ApiConfig::default());
+    let session = create_session(&server).await;
+
+    let resumed = read_events(&server, events_request(&session.id, Some("2"))).await;
+    let ids: Vec<usize> = resumed.iter().map(|(id, _)| *id).collect();
+    assert_eq!(ids, [3, 4]);
+    assert_eq!(resumed[0].1, expected_events()[3]);
+    assert_eq!(resumed[1].1, SessionEvent::Completed { steps: 2 });
+
+    let done = read_events(&server, events_request(&session.id, Some("4"))).await;
+    assert!(done.is_empty());
+}
+
+#[tokio::test]
+async fn test_event_stream_rejects_unknown_session_and_bad_last_event_id() {
+    let server = Server::new(ApiConfig::default());
+    let response = server.router().oneshot(events_request("missing", None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let session = create_session(&server).await;
+    let response = server.router().oneshot(events_request(&session.id, Some("abc"))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_prune_events_keeps_running_and_recent_sessions() {
+    let server = Server::new(ApiConfig::default());
+    let finished = create_session(&server).await;
+    let running = server.sessions().open_events("running").await;
+    running.push(SessionEvent::StepStarted { step: 0 });
+    let retention = std::time::Duration::from_secs(60);
+
+    assert_eq!(server.sessions().prune_events(retention).await, 0);
+
+    tokio::time::advance(retention * 2).await;
+    assert_eq!(server.sessions().prune_events(retention).await, 1);
+    assert!(server.sessions().events(&finished.id).await.is_err());
+    assert!(server.sessions().events("running").await.is_ok());
+
+    // The stored session itself is untouched
+    assert!(server.sessions().get(&finished.id).await.is_ok());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_background_task_prunes_expired_event_logs() {
+    let server = Server::new(ApiConfig {
+        event_retention_secs: 60,
+        prune_interval_secs: 10,
+        ..ApiConfig::default()
+    });
+    let session = create_session(&server).await;
+    let pruning = server.spawn_pruning();
+
+    tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+    assert!(server.sessions().events(&session.id).await.is_ok());
+
+    tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+    let response = server.router().oneshot(events_request(&session.id, None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    pruning.abort();
+}
+
+#[tokio::test]
+async fn test_create_session_streams_events_while_running() {
+    let server = Server::new(ApiConfig::default());
+    let request = Request::builder()
+        .method("POST")
+        .uri("/sessions")
+        .header("content-type", "application/json")
+        .header("accept", "text/event-stream")
+        .body(Body::from(serde_json::to_vec(&CreateSessionRequest {
+            program: program(),
+            seed: Some(3),
+        }).unwrap()))
+        .unwrap();
+
+    let response = server.router().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.headers()["content-type"], "text/event-stream");
+    let location = response.headers()["location"].to_str().unwrap().to_string();
+    let id = location.strip_prefix("/sessions/").unwrap().to_string();
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let names: Vec<&str> = std::str::from_utf8(&body).unwrap()
+        .lines()
+        .filter_map(|line| line.strip_prefix("event:"))
+        .map(str::trim)
+        .collect();
+    assert_eq!(names, ["step_started", "state_advanced", "step_started", "state_advanced", "completed"]);
+
+    // The same log can be replayed from the events route
+    let replayed = read_events(&server, events_request(&id, None)).await;
+    assert_eq!(replayed.into_iter().map(|(_, event)| event).collect::<Vec<_>>(), expected_events());
+}
+
+#[tokio::test]
+async fn test_event_route_is_not_coalesced_without_accept_header() {
+    let server = Server::new(ApiConfig::default());
+    let events = server.sessions().open_events("live").await;
+    events.push(SessionEvent::StepStarted { step: 0 });
+
+    // A buffered response would wait for the session to finish
+    let request = Request::builder()
+        .uri("/sessions/live/events")
+        .body(Body::empty())
+        .unwrap();
+    let response = tokio::time::timeout(std::time::Duration::from_secs(5), server.router().oneshot(request))
+        .await
+        .expect("event stream was buffered")
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "text/event-stream");
+
+    events.push(SessionEvent::Completed { steps: 0 });
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(std::str::from_utf8(&body).unwrap()
+        .lines()
+        .any(|line| line.strip_prefix("event:").map(str::trim) == Some("completed")));
+}
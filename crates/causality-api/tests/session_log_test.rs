@@ -0,0 +1,167 @@
+//! Session Log Export/Import Tests
+//!
+//! Runs a session through the HTTP router, exports its effect log, and imports
+//! the log into a fresh server to check the reconstructed session matches.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::Router;
+use causality_api::{ApiConfig, CreateSessionRequest, ExecutionSession, Server, SessionLog};
+use causality_core::machine::{Instruction, RegisterId};
+use serde::de::DeserializeOwned;
+use tower::ServiceExt;
+
+async fn send<T: DeserializeOwned>(router: Router, request: Request<Body>) -> (StatusCode, T) {
+    let response = router.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+fn post_json(uri: &str, body: &impl serde::Serialize) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(body).unwrap()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_export_and_import_reconstructs_session() {
+    let original = Server::new(ApiConfig::default());
+    let program = (0..4)
+        .map(|i| Instruction::Transform {
+            morph_reg: RegisterId::new(i),
+            input_reg: RegisterId::new(i),
+            output_reg: RegisterId::new(i),
+        })
+        .collect();
+    
+    let (status, session): (_, ExecutionSession) = send(
+        original.router(),
+        post_json("/sessions", &CreateSessionRequest { program, seed: Some(11) }),
+    ).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(session.steps.len(), 4);
+    
+    let (status, log): (_, SessionLog) = send(
+        original.router(),
+        Request::builder()
+            .uri(format!("/sessions/{}/log?format=json", session.id))
+            .body(Body::empty())
+            .unwrap(),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(log.session_id, session.id);
+    
+    let fresh = Server::new(ApiConfig::default());
+    let (status, imported): (_, ExecutionSession) = send(
+        fresh.router(),
+        post_json("/sessions/import", &log),
+    ).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_ne!(imported.id, session.id);
+    assert_eq!(imported.seed, session.seed);
+    assert_eq!(imported.program, session.program);
+    assert_eq!(imported.steps, session.steps);
+    assert_eq!(imported.effects, session.effects);
+    assert_eq!(imported.metadata.get("imported_from"), Some(&session.id));
+    assert_eq!(fresh.sessions().len().await, 1);
+}
+
+#[tokio::test]
+async fn test_import_rejects_tampered_log() {
+    let server = Server::new(ApiConfig::default());
+    let mut session = ExecutionSession::new("source".to_string());
+    session.execute(5, vec![Instruction::Transform {
+        morph_reg: RegisterId::new(0),
+        input_reg: RegisterId::new(0),
+        output_reg: RegisterId::new(0),
+    }]).await.unwrap();
+    
+    let mut log = session.export_log();
+    log.steps[0].gas_consumed += 1;
+    
+    let (status, error): (_, causality_api::ApiError) = send(
+        server.router(),
+        post_json("/sessions/import", &log),
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(error.code, "LOG_REPLAY_MISMATCH");
+    assert!(server.sessions().is_empty().await);
+}
+
+#[tokio::test]
+async fn test_export_unknown_session_is_not_found() {
+    let server = Server::new(ApiConfig::default());
+    
+    let (status, error): (_, causality_api::ApiError) = send(
+        server.router(),
+        Request::builder()
+            .uri("/sessions/missing/log")
+            .body(Body::empty())
+            .unwrap(),
+    ).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(error.code, "SESSION_NOT_FOUND");
+    assert_eq!(error.status, 404);
+}
+
+#[tokio::test]
+async fn test_export_ssz_round_trips() {
+    let server = Server::new(ApiConfig::default());
+    let program = (0..3)
+        .map(|i| Instruction::Transform {
+            morph_reg: RegisterId::new(i),
+            input_reg: RegisterId::new(i),
+            output_reg: RegisterId::new(i),
+        })
+        .collect();
+    
+    let (_, session): (_, ExecutionSession) = send(
+        server.router(),
+        post_json("/sessions", &CreateSessionRequest { program, seed: Some(3) }),
+    ).await;
+    
+    let response = server.router()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/sessions/{}/log?format=ssz", session.id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/octet-stream");
+    
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(body.as_ref(), session.export_log().to_ssz_bytes().as_slice());
+    assert_eq!(SessionLog::from_ssz_bytes(&body).unwrap(), session.export_log());
+    
+    // Truncated encodings are rejected rather than partially decoded
+    assert!(SessionLog::from_ssz_bytes(&body[..body.len() - 1]).is_err());
+}
+
+#[tokio::test]
+async fn test_export_rejects_unknown_format() {
+    let server = Server::new(ApiConfig::default());
+    let mut session = ExecutionSession::new("source".to_string());
+    session.execute(1, vec![Instruction::Transform {
+        morph_reg: RegisterId::new(0),
+        input_reg: RegisterId::new(0),
+        output_reg: RegisterId::new(0),
+    }]).await.unwrap();
+    server.sessions().insert(session).await.unwrap();
+    
+    let (status, error): (_, causality_api::ApiError) = send(
+        server.router(),
+        Request::builder()
+            .uri("/sessions/source/log?format=cbor")
+            .body(Body::empty())
+            .unwrap(),
+    ).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error.code, "UNSUPPORTED_FORMAT");
+}
@@ -0,0 +1,105 @@
+//! Session Resume Token Tests
+//!
+//! Issues resume tokens for stored sessions and checks that they resume an
+//! equivalent session, while tampered, foreign, expired, and stale tokens
+//! are rejected.
+
+use std::time::Duration;
+
+use causality_api::{ApiConfig, ApiError, ExecutionSession, ResumeToken, Server};
+use causality_core::machine::{Instruction, RegisterId};
+
+async fn stored_session(server: &Server, id: &str, seed: u64) -> ExecutionSession {
+    let mut session = ExecutionSession::new(id.to_string());
+    session.execute(seed, vec![Instruction::Transform {
+        morph_reg: RegisterId::new(0),
+        input_reg: RegisterId::new(1),
+        output_reg: RegisterId::new(2),
+    }]).await.unwrap();
+    server.sessions().insert(session.clone()).await.unwrap();
+    session
+}
+
+fn rejection_code(result: Result<ExecutionSession, ApiError>) -> String {
+    result.expect_err("token should be rejected").code
+}
+
+#[tokio::test]
+async fn test_resume_token_round_trip() {
+    let server = Server::new(ApiConfig::default());
+    let session = stored_session(&server, "resumable", 3).await;
+
+    let token = server.resume_token(&session).unwrap();
+    let serialized = serde_json::to_string(&token).unwrap();
+    let token: ResumeToken = serde_json::from_str(&serialized).unwrap();
+
+    let resumed = server.resume(&token).await.unwrap();
+    assert_eq!(resumed.id, session.id);
+    assert_eq!(resumed.export_log(), session.export_log());
+    assert_eq!(resumed.snapshot_id(), session.snapshot_id());
+}
+
+#[tokio::test]
+async fn test_session_resumes_from_token_alone() {
+    let secret = b"resume-secret";
+    let mut session = ExecutionSession::new("standalone".to_string());
+    session.execute(9, vec![Instruction::Transform {
+        morph_reg: RegisterId::new(0),
+        input_reg: RegisterId::new(1),
+        output_reg: RegisterId::new(2),
+    }]).await.unwrap();
+
+    let token = session.to_resume_token(secret, Duration::from_secs(60)).unwrap();
+    let token: ResumeToken = serde_json::from_str(&serde_json::to_string(&token).unwrap()).unwrap();
+
+    let resumed = ExecutionSession::resume(&token, secret).await.unwrap();
+    assert_eq!(resumed.id, session.id);
+    assert_eq!(resumed.export_log(), session.export_log());
+    assert_eq!(
+        rejection_code(ExecutionSession::resume(&token, b"other-secret").await),
+        "RESUME_TOKEN_INVALID"
+    );
+}
+
+#[tokio::test]
+async fn test_resume_rejects_tampered_and_foreign_tokens() {
+    let server = Server::new(ApiConfig::default());
+    let a = stored_session(&server, "a", 1).await;
+    let b = stored_session(&server, "b", 2).await;
+
+    // Splice b's claims under a's signature
+    let token_a = server.resume_token(&a).unwrap().0;
+    let token_b = server.resume_token(&b).unwrap().0;
+    let parts_a: Vec<&str> = token_a.split('.').collect();
+    let parts_b: Vec<&str> = token_b.split('.').collect();
+    let spliced = ResumeToken(format!("{}.{}.{}", parts_a[0], parts_b[1], parts_a[2]));
+    assert_eq!(rejection_code(server.resume(&spliced).await), "RESUME_TOKEN_INVALID");
+
+    let other_server = Server::new(ApiConfig::default());
+    let foreign = other_server.resume_token(&a).unwrap();
+    assert_eq!(rejection_code(server.resume(&foreign).await), "RESUME_TOKEN_INVALID");
+}
+
+#[tokio::test]
+async fn test_resume_rejects_expired_token() {
+    let config = ApiConfig::default();
+    let secret = config.session_secret.clone();
+    let server = Server::new(config);
+    let session = stored_session(&server, "short-lived", 4).await;
+
+    let token = session.to_resume_token(secret.as_bytes(), Duration::ZERO).unwrap();
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert_eq!(rejection_code(server.resume(&token).await), "RESUME_TOKEN_EXPIRED");
+}
+
+#[tokio::test]
+async fn test_resume_rejects_stale_snapshot() {
+    let server = Server::new(ApiConfig::default());
+    let mut session = stored_session(&server, "changing", 5).await;
+    let token = server.resume_token(&session).unwrap();
+
+    session.execute(6, session.program.clone()).await.unwrap();
+    server.sessions().insert(session).await.unwrap();
+
+    assert_eq!(rejection_code(server.resume(&token).await), "RESUME_TOKEN_STALE");
+}
@@ -1,11 +1,24 @@
 //! Compile command for transforming Lisp S-expression code into bytecode.
 
+use crate::error::describe_compile_error;
 use anyhow::Result;
 use causality_compiler::{compile, CompiledArtifact};
-use clap::Parser;
+use causality_core::system::serialization::SszEncode;
+use clap::{Parser, ValueEnum};
 use std::fs;
 use std::path::PathBuf;
 
+/// Machine-readable outputs for tooling that does not link the Rust crates
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmitFormat {
+    /// The instruction stream as a JSON array, with register ids as numbers
+    InstructionsJson,
+    /// The SSZ encoding of the instruction stream
+    Ssz,
+    /// The parsed S-expression, pretty-printed
+    Sexpr,
+}
+
 #[derive(Parser, Debug, Clone)]
 pub struct CompileCommand {
     /// Input file containing the Lisp S-expression source code (.sx)
@@ -24,6 +37,10 @@ pub struct CompileCommand {
     )]
     pub format: String,
 
+    /// Write a machine-readable form of the output instead of bytecode
+    #[arg(long, value_enum)]
+    pub emit: Option<EmitFormat>,
+
     /// Enable verbose output
     #[arg(short, long)]
     pub verbose: bool,
@@ -45,7 +62,9 @@ impl CompileCommand {
         if self.input.extension().and_then(|s| s.to_str()) != Some("sx") {
             println!("Warning: Input file does not have a .sx extension. Assuming S-expression format.");
         }
-        if self.output.extension().and_then(|s| s.to_str()) != Some("bc") {
+        if self.emit.is_none()
+            && self.output.extension().and_then(|s| s.to_str()) != Some("bc")
+        {
             println!("Warning: Output file does not have a .bc extension. It will contain raw bytecode.");
         }
 
@@ -75,7 +94,9 @@ impl CompileCommand {
         }
 
         // Compile S-expression to intermediate representation
-        let compiled_artifact = compile(&source_code)?;
+        let compiled_artifact = compile(&source_code).map_err(|e| {
+            anyhow::anyhow!("{}", describe_compile_error(&e, &source_code))
+        })?;
 
         if self.verbose {
             println!("    Lisp → IR compilation complete");
@@ -85,18 +106,18 @@ impl CompileCommand {
             );
         }
 
-        // Serialize the artifact to bytecode
-        let bytecode = self.serialize_bytecode(&compiled_artifact)?;
+        // Serialize the artifact to bytecode, or to the requested emit format
+        let bytes = match self.emit {
+            Some(emit) => Self::emit(&compiled_artifact, emit)?,
+            None => self.serialize_bytecode(&compiled_artifact)?,
+        };
 
         if self.verbose {
-            println!(
-                "    Bytecode serialization complete ({} bytes)",
-                bytecode.len()
-            );
+            println!("    Serialization complete ({} bytes)", bytes.len());
         }
 
         // Write the output
-        fs::write(&self.output, bytecode).map_err(|e| {
+        fs::write(&self.output, bytes).map_err(|e| {
             anyhow::anyhow!(
                 "Failed to write output file {}: {}",
                 self.output.display(),
@@ -112,6 +133,22 @@ impl CompileCommand {
         Ok(())
     }
 
+    /// Serialize the compiled artifact in a machine-readable emit format
+    pub fn emit(artifact: &CompiledArtifact, format: EmitFormat) -> Result<Vec<u8>> {
+        match format {
+            EmitFormat::InstructionsJson => {
+                serde_json::to_vec_pretty(&artifact.instructions).map_err(|e| {
+                    anyhow::anyhow!(
+                        "Failed to serialize instructions to JSON: {}",
+                        e
+                    )
+                })
+            }
+            EmitFormat::Ssz => Ok(artifact.instructions.as_ssz_bytes()),
+            EmitFormat::Sexpr => Ok(format!("{}\n", artifact.sexpr).into_bytes()),
+        }
+    }
+
     fn serialize_bytecode(&self, artifact: &CompiledArtifact) -> Result<Vec<u8>> {
         // Use bincode for a compact binary representation
         bincode::serialize(artifact).map_err(|e| {
@@ -1,13 +1,17 @@
 //! Interactive REPL for Causality Lisp
 //!
 //! Provides an interactive Read-Eval-Print Loop for evaluating Causality Lisp expressions
-//! with support for resource inspection and step-through execution.
+//! with support for resource inspection and step-through execution. On a
+//! terminal, input is read with line editing and arrow-key history recall.
 
-use crate::error::CliErrorHandler;
+use crate::error::{describe_compile_error, CliErrorHandler};
 use std::sync::Arc;
-use std::io::{self, Write};
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use colored::Colorize;
 use anyhow::{Result, anyhow};
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
 
 /// REPL commands and configuration
 #[derive(Debug, Clone)]
@@ -33,16 +37,203 @@ impl Default for ReplCommand {
     }
 }
 
+/// File name of the persisted REPL history in the home directory
+pub const HISTORY_FILE_NAME: &str = ".causality_history";
+
+/// Default location of the history file, `~/.causality_history`
+pub fn default_history_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(HISTORY_FILE_NAME))
+}
+
+/// `entry` with its whitespace, including newlines, collapsed to single spaces
+fn single_line(entry: &str) -> String {
+    entry.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Net number of open parentheses in `source`
+///
+/// Parentheses inside string literals and `;` comments are ignored. A
+/// positive result means the expression continues on the next line.
+pub fn paren_depth(source: &str) -> i64 {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut in_comment = false;
+    
+    for c in source.chars() {
+        if in_comment {
+            in_comment = c != '\n';
+        } else if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                '"' => in_string = true,
+                ';' => in_comment = true,
+                _ => {}
+            }
+        }
+    }
+    
+    depth
+}
+
 /// REPL state management
 pub struct ReplState {
     /// Configuration
     config: ReplCommand,
+    
+    /// Line editor, whose history holds every entry read so far
+    editor: DefaultEditor,
+    
+    /// File the history is appended to, if any
+    history_path: Option<PathBuf>,
+    
+    /// Set once `:quit` has been entered
+    quit: bool,
 }
 
 impl ReplState {
-    /// Create a new REPL state
-    pub fn new(config: ReplCommand) -> Self {
-        Self { config }
+    /// Create a new REPL state whose history is kept in memory only
+    pub fn new(config: ReplCommand) -> Result<Self> {
+        Ok(Self {
+            config,
+            editor: DefaultEditor::new()?,
+            history_path: None,
+            quit: false,
+        })
+    }
+    
+    /// Create a REPL state whose history is loaded from and appended to `path`
+    ///
+    /// The file is created on the first entry if it does not exist.
+    pub fn with_history_file(config: ReplCommand, path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let mut state = Self::new(config)?;
+        if path.exists() {
+            state.editor.load_history(&path)?;
+        }
+        state.history_path = Some(path);
+        Ok(state)
+    }
+    
+    /// Entries read so far, oldest first
+    pub fn history(&self) -> Vec<&str> {
+        self.editor.history().iter().map(String::as_str).collect()
+    }
+    
+    /// File the history is persisted to
+    pub fn history_path(&self) -> Option<&Path> {
+        self.history_path.as_deref()
+    }
+    
+    /// Read entries from `input` until it is exhausted, writing results to `output`
+    ///
+    /// Lines are accumulated while the entry has unclosed parentheses, so
+    /// an expression spanning several lines is evaluated once as a whole.
+    pub fn run<R: BufRead, W: Write>(&mut self, mut input: R, output: &mut W) -> io::Result<()> {
+        let mut entry = String::new();
+        
+        while !self.quit {
+            let prompt = if entry.is_empty() { ">".green().bold() } else { ".".dimmed() };
+            write!(output, "{} ", prompt)?;
+            output.flush()?;
+            
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                // Evaluate whatever is pending so an unclosed entry is reported
+                if !entry.trim().is_empty() {
+                    writeln!(output)?;
+                    self.submit(&entry, output)?;
+                }
+                break;
+            }
+            
+            entry.push_str(&line);
+            if paren_depth(&entry) > 0 {
+                continue;
+            }
+            
+            self.submit(&entry, output)?;
+            entry.clear();
+        }
+        
+        Ok(())
+    }
+    
+    /// Read entries from the terminal until end of input, with line editing
+    ///
+    /// Arrow keys move within the line and recall earlier entries, starting
+    /// from those in the persisted history. Ctrl-C discards the entry being
+    /// typed and Ctrl-D ends the session.
+    pub fn run_interactive(&mut self) -> Result<()> {
+        let mut output = io::stdout();
+        let mut entry = String::new();
+        while !self.quit {
+            let prompt = if entry.is_empty() { ">".green().bold() } else { ".".dimmed() };
+            match self.editor.readline(&format!("{} ", prompt)) {
+                Ok(line) => {
+                    entry.push_str(&line);
+                    entry.push('\n');
+                    if paren_depth(&entry) > 0 {
+                        continue;
+                    }
+                    
+                    self.submit(&entry, &mut output)?;
+                    entry.clear();
+                }
+                Err(ReadlineError::Interrupted) => entry.clear(),
+                Err(ReadlineError::Eof) => {
+                    // Evaluate whatever is pending so an unclosed entry is reported
+                    if !entry.trim().is_empty() {
+                        self.submit(&entry, &mut output)?;
+                    }
+                    break;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Record and evaluate one complete entry
+    fn submit<W: Write>(&mut self, entry: &str, output: &mut W) -> io::Result<()> {
+        let entry = entry.trim();
+        if let Err(e) = self.record(entry) {
+            writeln!(output, "{}: {}", "History error".yellow().bold(), e)?;
+        }
+        
+        match self.evaluate(entry) {
+            Ok(result) => {
+                if !result.is_empty() {
+                    writeln!(output, "{}", result)?;
+                }
+            }
+            Err(e) => {
+                writeln!(output, "{}: {}", "Error".red().bold(), e)?;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Add a complete entry to the history and append it to the history file
+    ///
+    /// Multi-line entries are stored on a single line so every line of the
+    /// history file is one entry. Repeating the previous entry records nothing.
+    fn record(&mut self, entry: &str) -> Result<()> {
+        if self.editor.add_history_entry(single_line(entry))? {
+            if let Some(path) = &self.history_path {
+                self.editor.append_history(path)?;
+            }
+        }
+        Ok(())
     }
     
     /// Evaluate a Lisp expression
@@ -58,12 +249,13 @@ impl ReplState {
         
         // Compile the input to machine instructions using unified pipeline
         let compiled_artifact = causality_compiler::compile(input)
-            .map_err(|e| anyhow!("Compilation failed: {:?}", e))?;
+            .map_err(|e| anyhow!("Compilation failed: {}", describe_compile_error(&e, input)))?;
         
+        let mut report = String::new();
         if self.config.debug {
-            println!("{}", "Compiled instructions:".cyan());
+            report.push_str(&format!("{}\n", "Compiled instructions:".cyan()));
             for (i, instr) in compiled_artifact.instructions.iter().enumerate() {
-                println!("  {}: {:?}", i, instr);
+                report.push_str(&format!("  {}: {:?}\n", i, instr));
             }
         }
         
@@ -73,10 +265,11 @@ impl ReplState {
             .map_err(|e| anyhow!("Execution failed: {:?}", e))?;
         
         if self.config.show_state {
-            self.print_execution_result(&result);
+            report.push_str(&self.format_execution_result(&result));
         }
         
-        Ok(format!("{:?}", result))
+        report.push_str(&format!("{:?}", result));
+        Ok(report)
     }
     
     /// Handle special REPL commands
@@ -94,13 +287,19 @@ impl ReplState {
                 Ok(format!("Show state: {}", if self.config.show_state { "on" } else { "off" }))
             }
             Some(&"reset") => {
-                // Reset state by creating new REPL state
-                *self = ReplState::new(self.config.clone());
+                // Evaluations share no state, so only the history carries over
                 Ok("REPL state reset".to_string())
             }
+            Some(&"history") => Ok(self
+                .history()
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| format!("{:>4}  {}", i + 1, entry))
+                .collect::<Vec<_>>()
+                .join("\n")),
             Some(&"quit") | Some(&"exit") | Some(&"q") => {
-                println!("{}", "Goodbye!".green());
-                std::process::exit(0);
+                self.quit = true;
+                Ok("Goodbye!".green().to_string())
             }
             Some(cmd) => Err(anyhow!("Unknown command: {}", cmd)),
             None => Err(anyhow!("Empty command")),
@@ -119,6 +318,7 @@ impl ReplState {
               :help, :h         - Show this help\n  \
               :debug            - Toggle debug mode\n  \
               :state            - Toggle state display\n  \
+              :history          - Show entered expressions\n  \
               :reset            - Reset REPL state\n  \
               :quit, :exit, :q  - Exit REPL",
             "Causality Lisp REPL".cyan().bold(),
@@ -127,24 +327,41 @@ impl ReplState {
         )
     }
     
-    /// Print the execution result information
-    fn print_execution_result(&self, result: &causality_core::machine::ExecutionResult) {
-        println!("{}", "Execution Result:".cyan());
-        match result {
-            causality_core::machine::ExecutionResult::Success { steps_executed, .. } => {
-                println!("    Status: Success");
-                println!("    Steps executed: {}", steps_executed);
+    /// Describe the execution result and the final machine state
+    fn format_execution_result(&self, result: &causality_core::machine::ExecutionResult) -> String {
+        let mut report = format!("{}\n", "Execution Result:".cyan());
+        let trace = match result {
+            causality_core::machine::ExecutionResult::Success { steps_executed, trace } => {
+                report.push_str("    Status: Success\n");
+                report.push_str(&format!("    Steps executed: {}\n", steps_executed));
+                trace
             }
-            causality_core::machine::ExecutionResult::Error { message, steps_executed, .. } => {
-                println!("    Status: Error");
-                println!("    Message: {}", message);
-                println!("    Steps executed: {}", steps_executed);
+            causality_core::machine::ExecutionResult::Error { message, steps_executed, trace } => {
+                report.push_str("    Status: Error\n");
+                report.push_str(&format!("    Message: {}\n", message));
+                report.push_str(&format!("    Steps executed: {}\n", steps_executed));
+                trace
             }
-            causality_core::machine::ExecutionResult::Timeout { steps_executed, .. } => {
-                println!("    Status: Timeout");
-                println!("    Steps executed: {}", steps_executed);
+            causality_core::machine::ExecutionResult::Timeout { steps_executed, trace } => {
+                report.push_str("    Status: Timeout\n");
+                report.push_str(&format!("    Steps executed: {}\n", steps_executed));
+                trace
             }
+        };
+        
+        let state = &trace.final_state;
+        report.push_str(&format!("{}\n", "Machine State:".cyan()));
+        report.push_str(&format!("    Instruction pointer: {}\n", state.instruction_pointer));
+        report.push_str(&format!("    Lamport clock: {}\n", state.lamport_clock));
+        report.push_str("    Registers:\n");
+        for (register, value) in &state.registers {
+            report.push_str(&format!("      {:?} = {:?}\n", register, value));
+        }
+        report.push_str("    Resources:\n");
+        for (resource, value) in &state.resources {
+            report.push_str(&format!("      {:?} = {:?}\n", resource, value));
         }
+        report
     }
 }
 
@@ -157,34 +374,26 @@ pub async fn handle_repl_command(
     println!("{}", "Type :help for commands or :quit to exit".dimmed());
     println!("{}", "Note: This REPL uses the unified 5-instruction machine system".dimmed());
     
-    let mut repl_state = ReplState::new(config);
-    
-    loop {
-        // Print prompt
-        print!("{} ", ">".green().bold());
-        io::stdout().flush().unwrap();
-        
-        // Read input
-        let mut input = String::new();
-        match io::stdin().read_line(&mut input) {
-            Ok(0) => break, // EOF
-            Ok(_) => {
-                match repl_state.evaluate(input.trim()) {
-                    Ok(result) => {
-                        if !result.is_empty() {
-                            println!("{}", result);
-                        }
-                    }
-                    Err(e) => {
-                        println!("{}: {}", "Error".red().bold(), e);
-                    }
-                }
-            }
+    // A missing or unreadable history file should not prevent the REPL from starting
+    let mut repl_state = match default_history_path() {
+        Some(path) => match ReplState::with_history_file(config.clone(), path) {
+            Ok(state) => state,
             Err(e) => {
-                println!("{}: {}", "Input error".red().bold(), e);
-                break;
+                println!("{}: {}", "History unavailable".yellow().bold(), e);
+                ReplState::new(config)?
             }
-        }
+        },
+        None => ReplState::new(config)?,
+    };
+    let stdin = io::stdin();
+    let result = if stdin.is_terminal() {
+        repl_state.run_interactive()
+    } else {
+        // Piped input has no line editing to offer
+        repl_state.run(stdin.lock(), &mut io::stdout()).map_err(Into::into)
+    };
+    if let Err(e) = result {
+        println!("{}: {}", "Input error".red().bold(), e);
     }
     
     Ok(())
@@ -197,13 +406,13 @@ mod tests {
     #[test]
     fn test_repl_state_creation() {
         let config = ReplCommand::default();
-        let _repl_state = ReplState::new(config);
+        let _repl_state = ReplState::new(config).unwrap();
     }
     
     #[test]
     fn test_repl_commands() {
         let config = ReplCommand::default();
-        let mut repl_state = ReplState::new(config);
+        let mut repl_state = ReplState::new(config).unwrap();
         
         // Test help command
         let result = repl_state.handle_repl_command(":help").unwrap();
@@ -213,9 +422,32 @@ mod tests {
     #[tokio::test]
     async fn test_basic_evaluation() {
         let config = ReplCommand::default();
-        let mut repl_state = ReplState::new(config);
+        let mut repl_state = ReplState::new(config).unwrap();
         
         // Test simple evaluation (this will fail until we have proper Lisp parsing)
         let _result = repl_state.evaluate("42");
     }
+    
+    #[test]
+    fn test_paren_depth_ignores_strings_and_comments() {
+        assert_eq!(paren_depth("(lambda (x)"), 1);
+        assert_eq!(paren_depth("(lambda (x) x)"), 0);
+        assert_eq!(paren_depth("(f \")(\" ; )\n"), 1);
+        assert_eq!(paren_depth("(f \"\\\"(\")"), 0);
+    }
+    
+    #[test]
+    fn test_history_persists_entries() {
+        let path = std::env::temp_dir().join(format!("causality_history_{}", uuid::Uuid::new_v4()));
+        
+        let mut repl_state = ReplState::with_history_file(ReplCommand::default(), &path).unwrap();
+        repl_state.record("(pure\n  42)").unwrap();
+        repl_state.record("(pure 42)").unwrap();
+        repl_state.record("(alloc 1 2)").unwrap();
+        
+        let reloaded = ReplState::with_history_file(ReplCommand::default(), &path).unwrap();
+        assert_eq!(reloaded.history(), ["(pure 42)", "(alloc 1 2)"]);
+        
+        std::fs::remove_file(path).unwrap();
+    }
 }
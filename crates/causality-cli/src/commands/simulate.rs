@@ -4,7 +4,10 @@
 //! allowing users to run simulations, view execution traces, and analyze results.
 
 use anyhow::Result;
+use causality_compiler::compile;
+use causality_core::machine::{GasMeter, InstructionCosts};
 use clap::Parser;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::fs;
 
@@ -26,6 +29,10 @@ pub struct SimulateCommand {
     #[arg(long)]
     pub gas_price_gwei: Option<u32>,
     
+    /// JSON cost table mapping each instruction to its gas cost (repeatable)
+    #[arg(long = "cost-table")]
+    pub cost_tables: Vec<PathBuf>,
+    
     /// Enable verbose output
     #[arg(short, long)]
     pub verbose: bool,
@@ -71,10 +78,35 @@ impl SimulateCommand {
         println!("Bridge time estimate: {} seconds", bridge_time_seconds);
         println!("Vault APY estimate: {:.1}%", vault_apy);
 
+        if !self.cost_tables.is_empty() {
+            self.report_gas_by_cost_table(&ir_content)?;
+        }
+
         if self.verbose {
             println!("Simulation analysis completed successfully!");
         }
 
         Ok(())
     }
+
+    /// Compile the input and report its gas under each supplied cost table
+    fn report_gas_by_cost_table(&self, source: &str) -> Result<()> {
+        let artifact = compile(source)
+            .map_err(|e| anyhow::anyhow!("Failed to compile input for gas analysis: {:?}", e))?;
+
+        for path in &self.cost_tables {
+            let content = fs::read_to_string(path)
+                .map_err(|e| anyhow::anyhow!("Failed to read cost table {}: {}", path.display(), e))?;
+            let table: BTreeMap<String, u64> = serde_json::from_str(&content)
+                .map_err(|e| anyhow::anyhow!("Invalid cost table {}: {}", path.display(), e))?;
+            let costs = InstructionCosts::from_table(table)
+                .map_err(|e| anyhow::anyhow!("Invalid cost table {}: {}", path.display(), e))?;
+
+            let gas = GasMeter::with_costs(u64::MAX, costs).estimate_gas(&artifact.instructions);
+            let model = path.file_stem().map_or_else(|| path.display().to_string(), |stem| stem.to_string_lossy().into_owned());
+            println!("Gas under {} cost table: {} ({} instructions)", model, gas, artifact.instructions.len());
+        }
+
+        Ok(())
+    }
 }
@@ -11,6 +11,8 @@ use std::path::PathBuf;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{Result, anyhow};
+use causality_compiler::CompileError;
+use causality_lisp::ast::Span;
 use chrono::prelude::*;
 use serde_json::{self, json, Value};
 
@@ -95,6 +97,19 @@ impl CliErrorHandler {
     }
 }
 
+/// Format a compile error with a caret under the offending source position
+pub fn describe_compile_error(error: &CompileError, source: &str) -> String {
+    match error.location() {
+        Some(location) => {
+            // The compiler only records where an error starts, so the caret
+            // marks a single character
+            let span = Span::new(0, 0, location.line, location.column);
+            format!("{}\n{}", error, span.render(source))
+        }
+        None => error.to_string(),
+    }
+}
+
 /// CLI result type alias
 #[allow(dead_code)]
 pub type CliResult<T> = Result<T>;
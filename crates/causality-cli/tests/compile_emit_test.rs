@@ -0,0 +1,57 @@
+//! Integration tests for the machine-readable emit formats of the compile command
+
+use anyhow::Result;
+use causality_cli::commands::compile::{CompileCommand, EmitFormat};
+use causality_core::machine::instruction::Instruction;
+use causality_core::system::serialization::SszDecode;
+use std::fs;
+use std::path::PathBuf;
+
+const SOURCE: &str = "(tensor (alloc 1 2) (pure 42))";
+
+/// Compile `SOURCE` with the given emit format and return the written bytes
+async fn compile_with_emit(emit: EmitFormat, extension: &str) -> Result<Vec<u8>> {
+    let dir = std::env::temp_dir().join(format!("causality_emit_{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&dir)?;
+    let input = dir.join("program.sx");
+    fs::write(&input, SOURCE)?;
+    let output: PathBuf = dir.join(format!("program.{}", extension));
+    
+    let compile_cmd = CompileCommand {
+        input,
+        output: output.clone(),
+        format: "bytecode".to_string(),
+        emit: Some(emit),
+        verbose: false,
+        optimize: false,
+    };
+    compile_cmd.execute().await?;
+    
+    let bytes = fs::read(&output)?;
+    fs::remove_dir_all(&dir)?;
+    Ok(bytes)
+}
+
+#[tokio::test]
+async fn test_instructions_json_round_trips() -> Result<()> {
+    let expected = causality_compiler::compile(SOURCE)?.instructions;
+    
+    let json = compile_with_emit(EmitFormat::InstructionsJson, "json").await?;
+    let decoded: Vec<Instruction> = serde_json::from_slice(&json)?;
+    
+    assert!(!decoded.is_empty());
+    assert_eq!(decoded, expected);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ssz_emit_decodes_to_instructions() -> Result<()> {
+    let expected = causality_compiler::compile(SOURCE)?.instructions;
+    
+    let bytes = compile_with_emit(EmitFormat::Ssz, "ssz").await?;
+    let decoded = Vec::<Instruction>::from_ssz_bytes(&bytes)
+        .map_err(|e| anyhow::anyhow!("SSZ decode failed: {:?}", e))?;
+    
+    assert_eq!(decoded, expected);
+    Ok(())
+}
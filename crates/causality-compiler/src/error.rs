@@ -67,6 +67,22 @@ pub struct Location {
     pub column: usize,
 }
 
+impl CompileError {
+    /// Source location the error points at, if known
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            CompileError::ParseError { location, .. }
+            | CompileError::TypeError { location, .. }
+            | CompileError::Layer2Error { location, .. }
+            | CompileError::Layer1Error { location, .. }
+            | CompileError::UnknownSymbol { location, .. }
+            | CompileError::InvalidArity { location, .. }
+            | CompileError::CompilationError { location, .. }
+            | CompileError::ValidationError { location, .. } => location.as_ref(),
+        }
+    }
+}
+
 impl fmt::Display for CompileError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::error::Error as StdError;
+use crate::storage_backend::DbError;
 /// Enhanced error handling for Causality compiler operations
 ///
 /// This module provides comprehensive error handling and recovery mechanisms
@@ -15,6 +16,9 @@ pub enum CausalityError {
         message: String,
         details: Option<String>,
         recoverable: bool,
+        /// Stable code of the underlying [`DbError`], if any
+        #[serde(default)]
+        error_code: Option<String>,
     },
 
     /// Network-related errors
@@ -93,11 +97,15 @@ impl fmt::Display for CausalityError {
                 message,
                 details,
                 recoverable,
+                error_code,
             } => {
                 write!(f, "Storage error: {}", message)?;
                 if let Some(details) = details {
                     write!(f, " ({})", details)?;
                 }
+                if let Some(code) = error_code {
+                    write!(f, " (code: {})", code)?;
+                }
                 if *recoverable {
                     write!(f, " [recoverable]")?;
                 }
@@ -350,6 +358,17 @@ impl From<reqwest::Error> for CausalityError {
     }
 }
 
+impl From<DbError> for CausalityError {
+    fn from(error: DbError) -> Self {
+        CausalityError::Storage {
+            message: error.to_string(),
+            details: None,
+            recoverable: error.is_recoverable(),
+            error_code: Some(error.error_code().to_string()),
+        }
+    }
+}
+
 impl From<tokio::time::error::Elapsed> for CausalityError {
     fn from(error: tokio::time::error::Elapsed) -> Self {
         CausalityError::Timeout {
@@ -485,14 +504,21 @@ impl CausalityError {
         }
     }
 
-    pub fn is_retryable(&self) -> bool {
+    /// Stable machine-readable code, for errors that carry one
+    pub fn error_code(&self) -> Option<&str> {
         match self {
-            CausalityError::Storage { recoverable, .. } => *recoverable,
-            CausalityError::Network { .. } | CausalityError::Timeout { .. } => true,
-            _ => false,
+            CausalityError::Storage { error_code, .. } | CausalityError::Generic { error_code, .. } => {
+                error_code.as_deref()
+            }
+            _ => None,
         }
     }
 
+    /// Whether retrying the failed operation may succeed; see [`Retryable`]
+    pub fn is_retryable(&self) -> bool {
+        self.is_transient()
+    }
+
     /// Check if error should trigger circuit breaker
     pub fn should_break_circuit(&self) -> bool {
         matches!(
@@ -518,6 +544,114 @@ impl CausalityError {
     }
 }
 
+/// Classification of errors into transient and permanent failures
+pub trait Retryable {
+    /// Whether the failure may go away on its own, so the operation is
+    /// worth retrying
+    fn is_transient(&self) -> bool;
+}
+
+impl Retryable for CausalityError {
+    /// Timeouts, recoverable storage errors, and network errors without a
+    /// status or with a 408, 429 or 5xx status are transient. Client errors
+    /// and every other variant fail the same way on each attempt.
+    fn is_transient(&self) -> bool {
+        match self {
+            CausalityError::Storage { recoverable, .. } => *recoverable,
+            CausalityError::Network { status_code, .. } => match status_code {
+                None => true,
+                Some(code) => matches!(*code, 408 | 429 | 500..=599),
+            },
+            CausalityError::Timeout { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+impl Retryable for ContextualError {
+    fn is_transient(&self) -> bool {
+        self.error.is_transient()
+    }
+}
+
+/// How often and how patiently [`retry_transient`] retries an operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled before each further retry
+    pub base_delay: std::time::Duration,
+    /// Upper bound on the delay between two attempts
+    pub max_delay: std::time::Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, base_delay: std::time::Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_delay(mut self, max_delay: std::time::Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Delay before retrying after failed attempt number `attempt`, counting
+    /// from 1
+    pub fn delay_after(&self, attempt: u32) -> std::time::Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(100),
+            max_delay: std::time::Duration::from_secs(5),
+        }
+    }
+}
+
+/// Run `operation` until it succeeds, fails permanently, or has been
+/// attempted `policy.max_attempts` times
+///
+/// Only errors reporting [`Retryable::is_transient`] are retried, after an
+/// exponentially growing delay; a permanent error is returned immediately.
+/// When the attempts run out the last transient error is returned.
+pub async fn retry_transient<F, Fut, T, E>(policy: RetryPolicy, mut operation: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+    E: Retryable + fmt::Display,
+{
+    let mut attempt = 1;
+    loop {
+        match operation().await {
+            Ok(result) => return Ok(result),
+            Err(error) if !error.is_transient() || attempt >= policy.max_attempts => {
+                return Err(error);
+            }
+            Err(error) => {
+                let delay = policy.delay_after(attempt);
+                log::warn!(
+                    "Attempt {}/{} failed with a transient error: {}. Retrying in {}ms",
+                    attempt,
+                    policy.max_attempts,
+                    error,
+                    delay.as_millis()
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
 /// Retry logic with exponential backoff
 pub struct RetryConfig {
     pub max_attempts: u32,
@@ -724,6 +858,7 @@ mod tests {
             message: "Test storage error".to_string(),
             details: None,
             recoverable: false,
+            error_code: None,
         };
 
         assert_eq!(error.error_type(), "storage");
@@ -792,4 +927,169 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(*attempt_count.lock().unwrap(), 2);
     }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_on_permanent_error() {
+        let config = RetryConfig {
+            max_attempts: 3,
+            initial_delay_ms: 10,
+            max_delay_ms: 100,
+            backoff_multiplier: 2.0,
+        };
+
+        let error_handler = ErrorHandler::new("test").with_logging(false);
+        let attempt_count = std::sync::Arc::new(std::sync::Mutex::new(0));
+        let attempt_count_clone = attempt_count.clone();
+
+        let result: ContextualResult<()> = retry_with_backoff(
+            move || {
+                let attempt_count = attempt_count_clone.clone();
+                async move {
+                    *attempt_count.lock().unwrap() += 1;
+                    Err(CausalityError::Validation {
+                        message: "Malformed request".to_string(),
+                        field: None,
+                        expected: None,
+                        actual: None,
+                    })
+                }
+            },
+            config,
+            &error_handler,
+            "test_operation",
+        )
+        .await;
+
+        let error = result.unwrap_err();
+        assert_eq!(error.error.error_type(), "validation");
+        assert_eq!(*attempt_count.lock().unwrap(), 1);
+    }
+
+    fn network_error(status_code: Option<u16>) -> CausalityError {
+        CausalityError::Network {
+            message: "Request failed".to_string(),
+            endpoint: None,
+            status_code,
+            retry_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_transient_classification() {
+        assert!(network_error(None).is_transient());
+        assert!(network_error(Some(503)).is_transient());
+        assert!(network_error(Some(429)).is_transient());
+        assert!(!network_error(Some(404)).is_transient());
+        assert!(CausalityError::Timeout {
+            message: "Timed out".to_string(),
+            operation: "fetch".to_string(),
+            duration_ms: 200,
+            timeout_ms: 100,
+        }
+        .is_transient());
+        assert!(CausalityError::Storage {
+            message: "Locked".to_string(),
+            details: None,
+            recoverable: true,
+            error_code: None,
+        }
+        .is_transient());
+        assert!(!CausalityError::Permission {
+            message: "Denied".to_string(),
+            required_permission: None,
+            current_role: None,
+        }
+        .is_transient());
+    }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy::new(5, std::time::Duration::from_millis(10))
+            .with_max_delay(std::time::Duration::from_millis(30));
+
+        assert_eq!(policy.delay_after(1), std::time::Duration::from_millis(10));
+        assert_eq!(policy.delay_after(2), std::time::Duration::from_millis(20));
+        assert_eq!(policy.delay_after(3), std::time::Duration::from_millis(30));
+        assert_eq!(policy.delay_after(40), std::time::Duration::from_millis(30));
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_succeeds_after_transient_errors() {
+        let mut attempts = 0;
+        let result = retry_transient(RetryPolicy::new(3, std::time::Duration::from_millis(1)), || {
+            attempts += 1;
+            let attempt = attempts;
+            async move {
+                if attempt < 3 {
+                    Err(network_error(Some(503)))
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_fails_fast_on_permanent_error() {
+        let mut attempts = 0;
+        let result: CausalityResult<()> = retry_transient(RetryPolicy::new(3, std::time::Duration::from_millis(1)), || {
+            attempts += 1;
+            async { Err(network_error(Some(404))) }
+        })
+        .await;
+
+        assert_eq!(result.unwrap_err().error_type(), "network");
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_gives_up_after_max_attempts() {
+        let mut attempts = 0;
+        let result: CausalityResult<()> = retry_transient(RetryPolicy::new(2, std::time::Duration::from_millis(1)), || {
+            attempts += 1;
+            async { Err(network_error(None)) }
+        })
+        .await;
+
+        assert!(result.unwrap_err().is_transient());
+        assert_eq!(attempts, 2);
+    }
+
+    #[test]
+    fn test_db_errors_convert_to_storage_errors() {
+        let cases = [
+            (DbError::NotInitialized, "STORAGE_NOT_INITIALIZED", false),
+            (DbError::NotFound { key: "k".to_string() }, "STORAGE_NOT_FOUND", false),
+            (DbError::Read("disk".to_string()), "STORAGE_READ", true),
+            (DbError::Write("disk".to_string()), "STORAGE_WRITE", true),
+            (DbError::Connection("refused".to_string()), "STORAGE_CONNECTION", true),
+            (DbError::Migration("v2".to_string()), "STORAGE_MIGRATION", false),
+        ];
+
+        for (db_error, code, recoverable) in cases {
+            let message = db_error.to_string();
+            let error = CausalityError::from(db_error);
+            assert_eq!(error.error_type(), "storage");
+            assert_eq!(error.error_code(), Some(code));
+            assert_eq!(error.is_transient(), recoverable, "{}", code);
+            assert!(matches!(&error, CausalityError::Storage { message: m, .. } if *m == message));
+        }
+    }
+
+    #[test]
+    fn test_db_error_propagates_with_question_mark() {
+        fn lookup() -> Result<String, DbError> {
+            Err(DbError::NotFound { key: "contract".to_string() })
+        }
+
+        fn load() -> CausalityResult<String> {
+            Ok(lookup()?)
+        }
+
+        assert_eq!(load().unwrap_err().error_code(), Some("STORAGE_NOT_FOUND"));
+    }
 }
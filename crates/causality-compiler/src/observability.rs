@@ -30,9 +30,14 @@ impl MetricsCollector {
     
     /// Increment a counter metric
     pub fn increment_counter(&self, name: &str, labels: Option<BTreeMap<String, String>>) {
+        self.add_to_counter(name, 1, labels);
+    }
+    
+    /// Add an amount to a counter metric
+    pub fn add_to_counter(&self, name: &str, amount: u64, labels: Option<BTreeMap<String, String>>) {
         let metric_name = self.format_metric_name(name, labels);
         let mut counters = self.counters.lock().unwrap();
-        *counters.entry(metric_name).or_insert(0) += 1;
+        *counters.entry(metric_name).or_insert(0) += amount;
     }
     
     /// Set a gauge metric
@@ -671,6 +671,8 @@ fn compile_term(ctx: &mut CompileContext, term: &Term) -> CompileResult<Register
         TermKind::Let { var, value, body } => compile_let(ctx, var, value, body),
         TermKind::Alloc { value } => compile_alloc(ctx, value),
         TermKind::Consume { resource } => compile_consume(ctx, resource),
+        // Weakening compiles to a consume whose output is never read
+        TermKind::Drop { value } => compile_consume(ctx, value),
         TermKind::Tensor { left, right } => compile_tensor(ctx, left, right),
         _ => Err(CompileError::Layer1Error {
             message: format!("Compilation not yet implemented for {:?}", term.kind),
@@ -2,7 +2,7 @@
 // Purpose: Real database integration for Almanac storage backends
 
 use std::sync::Arc;
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
@@ -10,6 +10,48 @@ use std::collections::BTreeMap;
 #[cfg(feature = "almanac")]
 use indexer_storage::{Storage, StorageConfig, PostgresStorage, RocksDbStorage};
 
+/// Failure of a storage backend operation
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DbError {
+    #[error("Storage not initialized")]
+    NotInitialized,
+
+    #[error("Key not found: {key}")]
+    NotFound { key: String },
+
+    #[error("Storage read failed: {0}")]
+    Read(String),
+
+    #[error("Storage write failed: {0}")]
+    Write(String),
+
+    #[error("Storage connection failed: {0}")]
+    Connection(String),
+
+    #[error("Storage migration failed: {0}")]
+    Migration(String),
+}
+
+impl DbError {
+    /// Stable code identifying the variant, carried over when the error is
+    /// converted into a `CausalityError`
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            DbError::NotInitialized => "STORAGE_NOT_INITIALIZED",
+            DbError::NotFound { .. } => "STORAGE_NOT_FOUND",
+            DbError::Read(_) => "STORAGE_READ",
+            DbError::Write(_) => "STORAGE_WRITE",
+            DbError::Connection(_) => "STORAGE_CONNECTION",
+            DbError::Migration(_) => "STORAGE_MIGRATION",
+        }
+    }
+
+    /// Whether the operation may succeed if retried
+    pub fn is_recoverable(&self) -> bool {
+        matches!(self, DbError::Read(_) | DbError::Write(_) | DbError::Connection(_))
+    }
+}
+
 /// Storage backend configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StorageBackendConfig {
@@ -203,7 +245,7 @@ impl StorageBackendManager {
     #[cfg(feature = "almanac")]
     async fn initialize_postgres(&mut self) -> Result<()> {
         let postgres_config = self.config.postgres_config.as_ref()
-            .ok_or_else(|| anyhow!("PostgreSQL config required for PostgreSQL backend"))?;
+            .ok_or_else(|| anyhow::anyhow!("PostgreSQL config required for PostgreSQL backend"))?;
 
         let storage_config = StorageConfig::postgres(
             &postgres_config.host,
@@ -235,7 +277,7 @@ impl StorageBackendManager {
     #[cfg(feature = "almanac")]
     async fn initialize_rocksdb(&mut self) -> Result<()> {
         let rocksdb_config = self.config.rocksdb_config.as_ref()
-            .ok_or_else(|| anyhow!("RocksDB config required for RocksDB backend"))?;
+            .ok_or_else(|| anyhow::anyhow!("RocksDB config required for RocksDB backend"))?;
 
         let storage_config = StorageConfig::rocksdb(&rocksdb_config.path);
         let storage = RocksDbStorage::new(storage_config).await?;
@@ -284,7 +326,7 @@ impl StorageBackendManager {
         if let Some(_storage) = &self.storage {
             #[cfg(feature = "almanac")]
             {
-                _storage.health_check().await.map_err(|e| anyhow!("Storage health check failed: {}", e))
+                _storage.health_check().await.map_err(|e| anyhow::anyhow!("Storage health check failed: {}", e))
             }
             
             #[cfg(not(feature = "almanac"))]
@@ -292,7 +334,7 @@ impl StorageBackendManager {
                 _storage.health_check().await
             }
         } else {
-            Err(anyhow!("Storage not initialized"))
+            Err(DbError::NotInitialized.into())
         }
     }
 
@@ -316,7 +358,7 @@ impl StorageBackendManager {
                 Ok(StorageStatistics::mock())
             }
         } else {
-            Err(anyhow!("Storage not initialized"))
+            Err(DbError::NotInitialized.into())
         }
     }
 }
@@ -0,0 +1,50 @@
+//! SSZ encode/decode benchmarks for the hot core types
+//!
+//! Run with `cargo bench -p causality-core --features benchmarks --bench ssz_codec`.
+//!
+//! The decode throughput baseline is checked by the ignored
+//! `test_decode_throughput_above_baseline` test in `tests/ssz_corpus_test.rs`.
+
+use causality_core::effect::ExecutionTrace;
+use causality_core::machine::Instruction;
+use causality_core::Value;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ssz::{Decode, Encode};
+
+#[path = "../tests/ssz_corpus/mod.rs"]
+mod ssz_corpus;
+
+fn bench_codec<T: Encode + Decode>(c: &mut Criterion, name: &str, items: &[T]) {
+    let encoded = ssz_corpus::encode_all(items);
+    let total_bytes: usize = encoded.iter().map(Vec::len).sum();
+
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Bytes(total_bytes as u64));
+
+    group.bench_function("encode", |b| {
+        b.iter(|| {
+            for item in items {
+                black_box(item.as_ssz_bytes());
+            }
+        })
+    });
+
+    group.bench_function("decode", |b| {
+        b.iter(|| {
+            for bytes in &encoded {
+                black_box(T::from_ssz_bytes(black_box(bytes)).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn ssz_benchmarks(c: &mut Criterion) {
+    bench_codec::<Instruction>(c, "ssz_instruction", &ssz_corpus::instructions());
+    bench_codec::<Value>(c, "ssz_value", &ssz_corpus::values());
+    bench_codec::<ExecutionTrace>(c, "ssz_execution_trace", &ssz_corpus::traces());
+}
+
+criterion_group!(benches, ssz_benchmarks);
+criterion_main!(benches);
@@ -153,6 +153,15 @@ pub struct Position {
     pub offset: u32,
 }
 
+impl std::fmt::Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(f, "{}:{}", self.start.line, self.start.column)
+    }
+}
+
 //-----------------------------------------------------------------------------
 // Constructor Helpers
 //-----------------------------------------------------------------------------
@@ -38,6 +38,9 @@ pub mod synthesis;
 /// Temporal Effect Graph (TEG) for dynamic orchestration
 pub mod teg;
 
+/// Optimization pass pipeline for Temporal Effect Graphs
+pub mod teg_optimization;
+
 /// Execution tracing
 pub mod trace;
 
@@ -210,7 +213,7 @@ pub use transform_constraint::{
 // Transform system
 pub use transform::{
     Effect, EffectComposition, EffectParallel, EffectContext, EffectStats,
-    SyncRequirement, MergeStrategy,
+    SyncRequirement, MergeStrategy, TypedMetadata,
 };
 
 // Effect system error type
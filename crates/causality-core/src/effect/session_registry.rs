@@ -46,6 +46,15 @@ pub enum SessionError {
     MalformedSessionType {
         reason: String,
     },
+    
+    /// Channel operation not permitted by the current session type
+    ProtocolViolation {
+        expected: String,
+        attempted: String,
+    },
+    
+    /// Operation attempted on a channel that has already been consumed
+    ChannelConsumed,
 }
 
 impl std::fmt::Display for SessionError {
@@ -61,6 +70,12 @@ impl std::fmt::Display for SessionError {
             SessionError::MalformedSessionType { reason } => {
                 write!(f, "Malformed session type: {}", reason)
             }
+            SessionError::ProtocolViolation { expected, attempted } => {
+                write!(f, "Protocol violation: expected {}, attempted {}", expected, attempted)
+            }
+            SessionError::ChannelConsumed => {
+                write!(f, "Channel has already been consumed")
+            }
         }
     }
 }
@@ -147,35 +162,12 @@ impl SessionDeclaration {
 
 /// Verify that two session types are duals
 pub fn verify_duality(s1: &SessionType, s2: &SessionType) -> bool {
-    let dual_s1 = compute_dual(s1);
-    &dual_s1 == s2
+    s1.is_dual_to(s2)
 }
 
 /// Duality computation for session types
 pub fn compute_dual(session_type: &SessionType) -> SessionType {
-    match session_type {
-        SessionType::Send(t, s) => SessionType::Receive(t.clone(), Box::new(compute_dual(s))),
-        SessionType::Receive(t, s) => SessionType::Send(t.clone(), Box::new(compute_dual(s))),
-        SessionType::InternalChoice(branches) => {
-            SessionType::ExternalChoice(
-                branches.iter()
-                    .map(|(label, session)| (label.clone(), compute_dual(session)))
-                    .collect()
-            )
-        }
-        SessionType::ExternalChoice(branches) => {
-            SessionType::InternalChoice(
-                branches.iter()
-                    .map(|(label, session)| (label.clone(), compute_dual(session)))
-                    .collect()
-            )
-        }
-        SessionType::End => SessionType::End,
-        SessionType::Recursive(var, s) => {
-            SessionType::Recursive(var.clone(), Box::new(compute_dual(s)))
-        }
-        SessionType::Variable(var) => SessionType::Variable(var.clone()),
-    }
+    session_type.dual()
 }
 
 /// Global registry for session types and choreographies
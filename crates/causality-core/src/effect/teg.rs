@@ -177,6 +177,23 @@ pub enum TegError {
     ExecutionError(NodeId, String),
 }
 
+/// Structural problem found by [`TemporalEffectGraph::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TegValidationError {
+    /// Nodes forming a dependency cycle, in dependency order
+    Cycle(Vec<NodeId>),
+    
+    /// Edge or dependency whose endpoint is not a node in the graph
+    DanglingEdge { from: NodeId, to: NodeId },
+    
+    /// Resource produced by one node and consumed by several
+    ResourceConsumedTwice {
+        resource: String,
+        producer: NodeId,
+        consumers: Vec<NodeId>,
+    },
+}
+
 /// Helper function to create EntityId from effect
 fn effect_to_entity_id(effect: &EffectExpr) -> EntityId {
     use std::collections::hash_map::DefaultHasher;
@@ -305,6 +322,124 @@ impl TemporalEffectGraph {
         Ok(())
     }
     
+    /// Remove a node along with its edges and any dependencies on it
+    pub fn remove_node(&mut self, node_id: &NodeId) -> Option<EffectNode> {
+        let node = self.nodes.remove(node_id)?;
+        self.metadata.total_cost = self.metadata.total_cost.saturating_sub(node.cost);
+        
+        self.edges.retain(|edge| {
+            let (from, to) = match edge {
+                EffectEdge::CausalityLink { from, to, .. } => (from, to),
+                EffectEdge::ResourceLink { from, to, .. } => (from, to),
+                EffectEdge::ControlLink { from, to, .. } => (from, to),
+            };
+            from != node_id && to != node_id
+        });
+        
+        self.adjacency_list.remove(node_id);
+        self.reverse_adjacency_list.remove(node_id);
+        for adjacent in self.adjacency_list.values_mut().chain(self.reverse_adjacency_list.values_mut()) {
+            adjacent.retain(|id| id != node_id);
+        }
+        for other in self.nodes.values_mut() {
+            other.dependencies.retain(|id| id != node_id);
+        }
+        
+        Some(node)
+    }
+    
+    /// Check the graph's structure, reporting every problem found
+    ///
+    /// Detects dependency cycles, edges and dependencies pointing at nodes
+    /// that are not in the graph, and resources linked from one producer to
+    /// more than one consumer. `nodes` and `edges` are public, so a graph
+    /// built without [`add_edge`](Self::add_edge) may hold any of these.
+    pub fn validate(&self) -> Result<(), Vec<TegValidationError>> {
+        let mut errors = Vec::new();
+        
+        // Successors of each node, from both edges and declared dependencies
+        let mut successors: BTreeMap<NodeId, Vec<NodeId>> = BTreeMap::new();
+        let mut link = |from: NodeId, to: NodeId, errors: &mut Vec<TegValidationError>| {
+            if !self.nodes.contains_key(&from) || !self.nodes.contains_key(&to) {
+                let dangling = TegValidationError::DanglingEdge { from, to };
+                if !errors.contains(&dangling) {
+                    errors.push(dangling);
+                }
+                return;
+            }
+            let next = successors.entry(from).or_default();
+            if !next.contains(&to) {
+                next.push(to);
+            }
+        };
+        
+        for edge in &self.edges {
+            let (from, to) = match edge {
+                EffectEdge::CausalityLink { from, to, .. } => (*from, *to),
+                EffectEdge::ResourceLink { from, to, .. } => (*from, *to),
+                EffectEdge::ControlLink { from, to, .. } => (*from, *to),
+            };
+            link(from, to, &mut errors);
+        }
+        for (id, node) in &self.nodes {
+            for dependency in &node.dependencies {
+                link(*dependency, *id, &mut errors);
+            }
+        }
+        
+        // Depth-first search; an edge back onto the current path closes a cycle
+        let mut finished = std::collections::BTreeSet::new();
+        for start in self.nodes.keys() {
+            if finished.contains(start) {
+                continue;
+            }
+            let mut path = vec![*start];
+            let mut pending = vec![successors.get(start).cloned().unwrap_or_default()];
+            while let Some(next) = pending.last_mut() {
+                match next.pop() {
+                    Some(to) if finished.contains(&to) => {}
+                    Some(to) => match path.iter().position(|id| *id == to) {
+                        Some(index) => errors.push(TegValidationError::Cycle(path[index..].to_vec())),
+                        None => {
+                            path.push(to);
+                            pending.push(successors.get(&to).cloned().unwrap_or_default());
+                        }
+                    },
+                    None => {
+                        pending.pop();
+                        finished.insert(path.pop().unwrap());
+                    }
+                }
+            }
+        }
+        
+        // A produced resource may only be handed on to one consumer
+        let mut consumers: BTreeMap<(NodeId, &String), Vec<NodeId>> = BTreeMap::new();
+        for edge in &self.edges {
+            if let EffectEdge::ResourceLink { from, to, resource } = edge {
+                let entry = consumers.entry((*from, resource)).or_default();
+                if !entry.contains(to) {
+                    entry.push(*to);
+                }
+            }
+        }
+        for ((producer, resource), consumers) in consumers {
+            if consumers.len() > 1 {
+                errors.push(TegValidationError::ResourceConsumedTwice {
+                    resource: resource.clone(),
+                    producer,
+                    consumers,
+                });
+            }
+        }
+        
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+    
     /// Get nodes that are ready to execute
     pub fn get_ready_nodes(&self) -> Vec<NodeId> {
         self.nodes
@@ -401,6 +536,101 @@ impl TemporalEffectGraph {
         
         result
     }
+    
+    /// Generate DOT (Graphviz) diagram representation
+    ///
+    /// Effect nodes are drawn as boxes and the resources they require or
+    /// produce as ellipses. Causal edges are solid, resource edges are
+    /// labelled with the resource and control edges with their condition.
+    pub fn to_dot(&self) -> String {
+        let mut result = String::from("digraph TEG {\n");
+        result.push_str("    rankdir=TD;\n");
+        result.push_str("    node [fontname=\"Arial\"];\n");
+        result.push_str("    edge [fontname=\"Arial\"];\n\n");
+        
+        // Add effect nodes
+        for (id, node) in &self.nodes {
+            result.push_str(&format!(
+                "    \"e_{}\" [label=\"{}\" shape=box fillcolor=lightblue style=filled];\n",
+                hex::encode(&id.bytes[0..4]),
+                escape_dot(&node.effect.kind.to_string())
+            ));
+        }
+        
+        // Add resource nodes, one per resource name
+        let mut resources = std::collections::BTreeSet::new();
+        for node in self.nodes.values() {
+            resources.extend(node.resource_requirements.iter());
+            resources.extend(node.resource_productions.iter());
+        }
+        for resource in &resources {
+            result.push_str(&format!(
+                "    \"r_{}\" [label=\"{}\" shape=ellipse fillcolor=lightgreen style=filled];\n",
+                escape_dot(resource),
+                escape_dot(resource)
+            ));
+        }
+        
+        result.push('\n');
+        
+        // Connect effects to the resources they produce and consume
+        for (id, node) in &self.nodes {
+            let effect = hex::encode(&id.bytes[0..4]);
+            for resource in &node.resource_productions {
+                result.push_str(&format!(
+                    "    \"e_{}\" -> \"r_{}\" [label=\"produces\" color=darkgreen];\n",
+                    effect,
+                    escape_dot(resource)
+                ));
+            }
+            for resource in &node.resource_requirements {
+                result.push_str(&format!(
+                    "    \"r_{}\" -> \"e_{}\" [label=\"requires\" color=darkgreen];\n",
+                    escape_dot(resource),
+                    effect
+                ));
+            }
+        }
+        
+        // Add edges between effects
+        for edge in &self.edges {
+            let (from, to, attributes) = match edge {
+                EffectEdge::CausalityLink { from, to, constraint } => (
+                    from,
+                    to,
+                    match constraint {
+                        Some(constraint) => format!("label=\"{}\"", escape_dot(constraint)),
+                        None => String::new(),
+                    },
+                ),
+                EffectEdge::ResourceLink { from, to, resource } => (
+                    from,
+                    to,
+                    format!("label=\"{}\" style=bold color=red", escape_dot(resource)),
+                ),
+                EffectEdge::ControlLink { from, to, condition } => (
+                    from,
+                    to,
+                    format!("label=\"{}\" style=dashed color=gray", escape_dot(condition)),
+                ),
+            };
+            
+            result.push_str(&format!(
+                "    \"e_{}\" -> \"e_{}\" [{}];\n",
+                hex::encode(&from.bytes[0..4]),
+                hex::encode(&to.bytes[0..4]),
+                attributes
+            ));
+        }
+        
+        result.push_str("}\n");
+        result
+    }
+}
+
+/// Escape a string for use inside a quoted DOT identifier or label
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
 impl Default for TemporalEffectGraph {
@@ -428,6 +658,22 @@ impl std::fmt::Display for TegError {
 
 impl std::error::Error for TegError {}
 
+impl std::fmt::Display for TegValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TegValidationError::Cycle(nodes) => 
+                write!(f, "Dependency cycle through {} nodes: {:?}", nodes.len(), nodes),
+            TegValidationError::DanglingEdge { from, to } => 
+                write!(f, "Edge {:?} -> {:?} refers to a missing node", from, to),
+            TegValidationError::ResourceConsumedTwice { resource, producer, consumers } => 
+                write!(f, "Resource {} from {:?} consumed by {} nodes: {:?}",
+                    resource, producer, consumers.len(), consumers),
+        }
+    }
+}
+
+impl std::error::Error for TegValidationError {}
+
 impl std::fmt::Display for EffectExprKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -510,4 +756,140 @@ mod tests {
         assert_eq!(ready.len(), 1);
         assert_eq!(ready[0], node1_id);
     }
+    
+    fn perform_node(tag: &str, dependencies: Vec<NodeId>) -> EffectNode {
+        let effect = EffectExpr::new(EffectExprKind::Perform {
+            effect_tag: tag.to_string(),
+            args: vec![],
+        });
+        EffectNode {
+            id: effect_to_entity_id(&effect),
+            effect,
+            status: NodeStatus::Pending,
+            dependencies,
+            results: None,
+            cost: 1,
+            resource_requirements: vec![],
+            resource_productions: vec![],
+        }
+    }
+    
+    #[test]
+    fn test_validate_dag() {
+        let mut teg = TemporalEffectGraph::new();
+        let a = perform_node("a", vec![]);
+        let b = perform_node("b", vec![a.id]);
+        let c = perform_node("c", vec![a.id]);
+        let d = perform_node("d", vec![b.id, c.id]);
+        let (a_id, b_id, c_id, d_id) = (a.id, b.id, c.id, d.id);
+        for node in [a, b, c, d] {
+            teg.add_node(node).unwrap();
+        }
+        teg.add_edge(EffectEdge::ResourceLink { from: a_id, to: b_id, resource: "token".to_string() }).unwrap();
+        teg.add_edge(EffectEdge::CausalityLink { from: b_id, to: d_id, constraint: None }).unwrap();
+        teg.add_edge(EffectEdge::CausalityLink { from: c_id, to: d_id, constraint: None }).unwrap();
+        
+        assert_eq!(teg.validate(), Ok(()));
+    }
+    
+    #[test]
+    fn test_validate_reports_cycle() {
+        let mut teg = TemporalEffectGraph::new();
+        let a = perform_node("a", vec![]);
+        let b = perform_node("b", vec![a.id]);
+        let c = perform_node("c", vec![b.id]);
+        let (a_id, b_id, c_id) = (a.id, b.id, c.id);
+        for node in [a, b, c] {
+            teg.add_node(node).unwrap();
+        }
+        teg.add_edge(EffectEdge::ControlLink { from: c_id, to: a_id, condition: "retry".to_string() }).unwrap();
+        
+        let errors = teg.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            TegValidationError::Cycle(nodes) => {
+                let mut nodes = nodes.clone();
+                nodes.sort();
+                let mut expected = vec![a_id, b_id, c_id];
+                expected.sort();
+                assert_eq!(nodes, expected);
+            }
+            other => panic!("expected a cycle, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_validate_reports_all_problems() {
+        let mut teg = TemporalEffectGraph::new();
+        let missing = perform_node("missing", vec![]).id;
+        let a = perform_node("a", vec![]);
+        let b = perform_node("b", vec![a.id, missing]);
+        let c = perform_node("c", vec![]);
+        let (a_id, b_id, c_id) = (a.id, b.id, c.id);
+        for node in [a, b, c] {
+            teg.add_node(node).unwrap();
+        }
+        teg.edges.push(EffectEdge::CausalityLink { from: c_id, to: missing, constraint: None });
+        teg.add_edge(EffectEdge::ResourceLink { from: a_id, to: b_id, resource: "token".to_string() }).unwrap();
+        teg.add_edge(EffectEdge::ResourceLink { from: a_id, to: c_id, resource: "token".to_string() }).unwrap();
+        
+        let errors = teg.validate().unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(errors.contains(&TegValidationError::DanglingEdge { from: c_id, to: missing }));
+        assert!(errors.contains(&TegValidationError::DanglingEdge { from: missing, to: b_id }));
+        assert!(errors.contains(&TegValidationError::ResourceConsumedTwice {
+            resource: "token".to_string(),
+            producer: a_id,
+            consumers: vec![b_id, c_id],
+        }));
+    }
+    
+    #[test]
+    fn test_to_dot() {
+        let effects = vec![
+            EffectExpr::new(EffectExprKind::Perform {
+                effect_tag: "create_token".to_string(),
+                args: vec![],
+            }),
+            EffectExpr::new(EffectExprKind::Perform {
+                effect_tag: "read_balance".to_string(),
+                args: vec![],
+            }),
+        ];
+        
+        let mut teg = TemporalEffectGraph::from_effect_sequence(effects.clone()).unwrap();
+        let producer = hex::encode(&effect_to_entity_id(&effects[0]).bytes[0..4]);
+        let consumer = hex::encode(&effect_to_entity_id(&effects[1]).bytes[0..4]);
+        teg.add_edge(EffectEdge::ControlLink {
+            from: effect_to_entity_id(&effects[0]),
+            to: effect_to_entity_id(&effects[1]),
+            condition: "balance > 0".to_string(),
+        }).unwrap();
+        
+        let dot = teg.to_dot();
+        assert!(dot.starts_with("digraph TEG {\n"));
+        assert!(dot.ends_with("}\n"));
+        
+        // Effect and resource nodes are distinguished by shape
+        assert!(dot.contains(&format!(
+            "\"e_{}\" [label=\"Perform(create_token)\" shape=box", producer
+        )));
+        assert!(dot.contains(&format!(
+            "\"e_{}\" [label=\"Perform(read_balance)\" shape=box", consumer
+        )));
+        assert!(dot.contains("\"r_resource_create_token\" [label=\"resource_create_token\" shape=ellipse"));
+        assert!(dot.contains("\"r_resource_read_balance\" [label=\"resource_read_balance\" shape=ellipse"));
+        
+        // Resource and effect edges
+        assert!(dot.contains(&format!(
+            "\"e_{}\" -> \"r_resource_create_token\" [label=\"produces\"", producer
+        )));
+        assert!(dot.contains(&format!(
+            "\"r_resource_read_balance\" -> \"e_{}\" [label=\"requires\"", consumer
+        )));
+        assert!(dot.contains(&format!("\"e_{}\" -> \"e_{}\" [];", producer, consumer)));
+        assert!(dot.contains(&format!(
+            "\"e_{}\" -> \"e_{}\" [label=\"balance > 0\" style=dashed", producer, consumer
+        )));
+    }
 } 
\ No newline at end of file
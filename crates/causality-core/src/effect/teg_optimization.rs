@@ -0,0 +1,322 @@
+//! Optimization pass pipeline for Temporal Effect Graphs
+//!
+//! Passes rewrite a [`TemporalEffectGraph`] in place and are composed into
+//! an ordered [`OptimizationPipeline`], where each pass can be switched off
+//! by name. Running a pipeline produces an [`OptimizationReport`] recording
+//! how much each pass shrank the graph.
+
+use super::teg::{EffectEdge, NodeId, NodeStatus, TemporalEffectGraph};
+use std::collections::BTreeSet;
+
+/// A rewrite over a TEG that preserves its execution semantics
+pub trait OptimizationPass: std::fmt::Debug + Send + Sync {
+    /// Name used to enable, disable and report on the pass
+    fn name(&self) -> &str;
+
+    /// Rewrite the graph in place
+    fn run(&self, teg: &mut TemporalEffectGraph);
+}
+
+/// Drops edges that repeat an earlier edge of the same kind and endpoints
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeduplicateEdges;
+
+impl OptimizationPass for DeduplicateEdges {
+    fn name(&self) -> &str {
+        "deduplicate_edges"
+    }
+
+    fn run(&self, teg: &mut TemporalEffectGraph) {
+        let mut seen: Vec<EffectEdge> = Vec::new();
+        teg.edges.retain(|edge| {
+            if seen.contains(edge) {
+                false
+            } else {
+                seen.push(edge.clone());
+                true
+            }
+        });
+    }
+}
+
+/// Removes nodes that were cancelled and will never execute
+///
+/// A node that depends on a cancelled node can never run either, so the
+/// cancellation is first propagated to all transitive dependents. Removing
+/// only the cancelled node would strip the dependency and let its
+/// dependents become ready.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PruneCancelledNodes;
+
+impl OptimizationPass for PruneCancelledNodes {
+    fn name(&self) -> &str {
+        "prune_cancelled_nodes"
+    }
+
+    fn run(&self, teg: &mut TemporalEffectGraph) {
+        let mut cancelled: BTreeSet<NodeId> = teg.nodes
+            .iter()
+            .filter(|(_, node)| node.status == NodeStatus::Cancelled)
+            .map(|(id, _)| *id)
+            .collect();
+
+        loop {
+            let dependents: Vec<_> = teg.nodes
+                .iter()
+                .filter(|(id, node)| {
+                    !cancelled.contains(*id)
+                        && node.dependencies.iter().any(|dep| cancelled.contains(dep))
+                })
+                .map(|(id, _)| *id)
+                .collect();
+            if dependents.is_empty() {
+                break;
+            }
+            cancelled.extend(dependents);
+        }
+
+        for id in cancelled {
+            teg.remove_node(&id);
+        }
+    }
+}
+
+/// Effect of one pass on the graph's size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassReport {
+    /// Name of the pass
+    pub name: String,
+
+    /// Whether the pass ran; disabled passes leave the graph untouched
+    pub enabled: bool,
+
+    /// Node count before the pass
+    pub nodes_before: usize,
+
+    /// Node count after the pass
+    pub nodes_after: usize,
+
+    /// Edge count before the pass
+    pub edges_before: usize,
+
+    /// Edge count after the pass
+    pub edges_after: usize,
+}
+
+impl PassReport {
+    /// Change in node count; negative when the pass removed nodes
+    pub fn node_delta(&self) -> i64 {
+        self.nodes_after as i64 - self.nodes_before as i64
+    }
+
+    /// Change in edge count; negative when the pass removed edges
+    pub fn edge_delta(&self) -> i64 {
+        self.edges_after as i64 - self.edges_before as i64
+    }
+}
+
+/// Per-pass results of running a pipeline, in pass order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptimizationReport {
+    /// One entry per pass, including disabled ones
+    pub passes: Vec<PassReport>,
+}
+
+impl OptimizationReport {
+    /// Report for the pass with the given name
+    pub fn pass(&self, name: &str) -> Option<&PassReport> {
+        self.passes.iter().find(|pass| pass.name == name)
+    }
+
+    /// Total change in node count across all passes
+    pub fn node_delta(&self) -> i64 {
+        self.passes.iter().map(PassReport::node_delta).sum()
+    }
+}
+
+/// Ordered sequence of optimization passes
+#[derive(Debug)]
+pub struct OptimizationPipeline {
+    /// Passes with their enabled flag, run in order
+    passes: Vec<(Box<dyn OptimizationPass>, bool)>,
+}
+
+impl OptimizationPipeline {
+    /// Create a pipeline with no passes
+    pub fn new() -> Self {
+        Self { passes: Vec::new() }
+    }
+
+    /// Append an enabled pass
+    pub fn with_pass(mut self, pass: impl OptimizationPass + 'static) -> Self {
+        self.passes.push((Box::new(pass), true));
+        self
+    }
+
+    /// Enable or disable every pass with the given name, returning whether
+    /// any pass matched
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        let mut found = false;
+        for (pass, flag) in &mut self.passes {
+            if pass.name() == name {
+                *flag = enabled;
+                found = true;
+            }
+        }
+        found
+    }
+
+    /// Names of the passes in run order
+    pub fn pass_names(&self) -> Vec<&str> {
+        self.passes.iter().map(|(pass, _)| pass.name()).collect()
+    }
+
+    /// Run the enabled passes over the graph in order
+    pub fn run(&self, teg: &mut TemporalEffectGraph) -> OptimizationReport {
+        let mut report = OptimizationReport::default();
+
+        for (pass, enabled) in &self.passes {
+            let (nodes_before, edges_before) = (teg.nodes.len(), teg.edges.len());
+            if *enabled {
+                pass.run(teg);
+            }
+            report.passes.push(PassReport {
+                name: pass.name().to_string(),
+                enabled: *enabled,
+                nodes_before,
+                nodes_after: teg.nodes.len(),
+                edges_before,
+                edges_after: teg.edges.len(),
+            });
+        }
+
+        report
+    }
+}
+
+impl Default for OptimizationPipeline {
+    /// The standard passes: cancelled nodes are pruned before duplicate
+    /// edges are dropped
+    fn default() -> Self {
+        Self::new()
+            .with_pass(PruneCancelledNodes)
+            .with_pass(DeduplicateEdges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::effect::core::{EffectExpr, EffectExprKind};
+    use crate::effect::teg::EffectNode;
+    use crate::system::content_addressing::EntityId;
+
+    fn node(n: u8, status: NodeStatus) -> EffectNode {
+        let mut bytes = [0u8; 32];
+        bytes[0] = n;
+        EffectNode {
+            id: EntityId::from_bytes(bytes),
+            effect: EffectExpr::new(EffectExprKind::Perform {
+                effect_tag: format!("effect_{}", n),
+                args: vec![],
+            }),
+            status,
+            dependencies: vec![],
+            results: None,
+            cost: 10,
+            resource_requirements: vec![],
+            resource_productions: vec![],
+        }
+    }
+
+    fn sample_teg() -> TemporalEffectGraph {
+        let mut teg = TemporalEffectGraph::new();
+        let a = node(1, NodeStatus::Pending);
+        let b = node(2, NodeStatus::Cancelled);
+        let c = node(3, NodeStatus::Pending);
+        let (a_id, b_id, c_id) = (a.id, b.id, c.id);
+        for node in [a, b, c] {
+            teg.add_node(node).unwrap();
+        }
+
+        let link = |from, to| EffectEdge::CausalityLink { from, to, constraint: None };
+        teg.add_edge(link(a_id, b_id)).unwrap();
+        teg.add_edge(link(a_id, c_id)).unwrap();
+        teg.add_edge(link(a_id, c_id)).unwrap();
+        teg.add_edge(link(a_id, c_id)).unwrap();
+        teg
+    }
+
+    #[test]
+    fn test_pipeline_reports_each_pass() {
+        let mut teg = sample_teg();
+        let report = OptimizationPipeline::default().run(&mut teg);
+
+        assert_eq!(report.passes.len(), 2);
+        let prune = report.pass("prune_cancelled_nodes").unwrap();
+        assert_eq!((prune.nodes_before, prune.nodes_after), (3, 2));
+        assert_eq!(prune.node_delta(), -1);
+        assert_eq!(prune.edge_delta(), -1);
+
+        let dedup = report.pass("deduplicate_edges").unwrap();
+        assert_eq!(dedup.node_delta(), 0);
+        assert_eq!((dedup.edges_before, dedup.edges_after), (3, 1));
+
+        assert_eq!(report.node_delta(), -1);
+        assert_eq!(teg.nodes.len(), 2);
+        assert_eq!(teg.edges.len(), 1);
+        assert_eq!(teg.metadata.total_cost, 20);
+        assert_eq!(teg.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_prune_removes_dependents_of_cancelled_nodes() {
+        let mut teg = sample_teg();
+        let cancelled_id = node(2, NodeStatus::Cancelled).id;
+
+        let mut blocked = node(4, NodeStatus::Pending);
+        blocked.dependencies = vec![cancelled_id];
+        let mut transitive = node(5, NodeStatus::Pending);
+        transitive.dependencies = vec![blocked.id];
+        let (blocked_id, transitive_id) = (blocked.id, transitive.id);
+        teg.add_node(blocked).unwrap();
+        teg.add_node(transitive).unwrap();
+
+        PruneCancelledNodes.run(&mut teg);
+
+        assert!(!teg.nodes.contains_key(&cancelled_id));
+        assert!(!teg.nodes.contains_key(&blocked_id));
+        assert!(!teg.nodes.contains_key(&transitive_id));
+        assert_eq!(teg.nodes.len(), 2);
+        assert!(teg.get_ready_nodes().iter().all(|id| *id != blocked_id && *id != transitive_id));
+        assert_eq!(teg.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_disabled_pass_is_reported_but_skipped() {
+        let mut pipeline = OptimizationPipeline::default();
+        assert!(pipeline.set_enabled("prune_cancelled_nodes", false));
+        assert!(!pipeline.set_enabled("unknown", false));
+
+        let mut teg = sample_teg();
+        let report = pipeline.run(&mut teg);
+
+        let prune = report.pass("prune_cancelled_nodes").unwrap();
+        assert!(!prune.enabled);
+        assert_eq!(prune.node_delta(), 0);
+        assert_eq!(report.pass("deduplicate_edges").unwrap().edge_delta(), -2);
+        assert_eq!(teg.nodes.len(), 3);
+    }
+
+    #[test]
+    fn test_custom_pipeline_runs_in_order() {
+        let pipeline = OptimizationPipeline::new().with_pass(DeduplicateEdges);
+        assert_eq!(pipeline.pass_names(), ["deduplicate_edges"]);
+
+        let mut teg = sample_teg();
+        let report = pipeline.run(&mut teg);
+        assert_eq!(report.passes.len(), 1);
+        assert_eq!(teg.nodes.len(), 3);
+        assert_eq!(teg.edges.len(), 2);
+    }
+}
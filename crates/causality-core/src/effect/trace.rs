@@ -4,7 +4,9 @@
 //! of effects and computations through the Causality system.
 
 use crate::system::content_addressing::{EntityId, Timestamp};
+use crate::system::{encode_with_length, decode_with_length};
 use serde::{Serialize, Deserialize};
+use ssz::{Encode, Decode, DecodeError};
 
 /// Execution trace for tracking effect execution through the system
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -167,4 +169,222 @@ impl EffectStep {
         self.end_time = Some(Timestamp::now());
         self.error = Some(error);
     }
-} 
\ No newline at end of file
+}
+
+//-----------------------------------------------------------------------------
+// SSZ Serialization
+//-----------------------------------------------------------------------------
+
+crate::impl_ssz_for_unit_enum!(ExecutionStatus,
+    Running => 0,
+    Completed => 1,
+    Failed => 2,
+    Cancelled => 3,
+);
+
+crate::impl_ssz_for_unit_enum!(StepStatus,
+    Pending => 0,
+    Running => 1,
+    Completed => 2,
+    Failed => 3,
+    Skipped => 4,
+);
+
+// Optional fields encode as a presence byte followed by length-prefixed bytes
+fn optional_len(len: Option<usize>) -> usize {
+    1 + len.map_or(0, |len| 4 + len)
+}
+
+fn encode_optional(data: Option<&[u8]>, buf: &mut Vec<u8>) {
+    match data {
+        Some(data) => {
+            buf.push(1);
+            encode_with_length(data, buf);
+        }
+        None => buf.push(0),
+    }
+}
+
+fn decode_optional(bytes: &[u8]) -> Result<(Option<&[u8]>, &[u8]), DecodeError> {
+    let (present, rest) = split_fixed(bytes, 1)?;
+    match present[0] {
+        0 => Ok((None, rest)),
+        1 => {
+            let (data, rest) = decode_with_length(rest)?;
+            Ok((Some(data), rest))
+        }
+        flag => Err(DecodeError::BytesInvalid(format!("Invalid option flag: {}", flag))),
+    }
+}
+
+fn decode_optional_string(bytes: &[u8]) -> Result<(Option<String>, &[u8]), DecodeError> {
+    let (data, rest) = decode_optional(bytes)?;
+    let string = data.map(|data| String::from_utf8(data.to_vec())
+        .map_err(|_| DecodeError::BytesInvalid("Invalid UTF-8 in error message".into())))
+        .transpose()?;
+    Ok((string, rest))
+}
+
+fn decode_optional_timestamp(bytes: &[u8]) -> Result<(Option<Timestamp>, &[u8]), DecodeError> {
+    let (data, rest) = decode_optional(bytes)?;
+    Ok((data.map(Timestamp::from_ssz_bytes).transpose()?, rest))
+}
+
+fn split_fixed(bytes: &[u8], len: usize) -> Result<(&[u8], &[u8]), DecodeError> {
+    if bytes.len() < len {
+        return Err(DecodeError::InvalidByteLength {
+            len: bytes.len(),
+            expected: len,
+        });
+    }
+    Ok(bytes.split_at(len))
+}
+
+fn encode_entity_list(ids: &[EntityId], buf: &mut Vec<u8>) {
+    (ids.len() as u32).ssz_append(buf);
+    for id in ids {
+        id.ssz_append(buf);
+    }
+}
+
+fn decode_entity_list(bytes: &[u8]) -> Result<(Vec<EntityId>, &[u8]), DecodeError> {
+    let (count, mut rest) = split_fixed(bytes, 4)?;
+    let count = u32::from_ssz_bytes(count)? as usize;
+    let mut ids = Vec::with_capacity(count.min(rest.len() / 32));
+    for _ in 0..count {
+        let (id, remaining) = split_fixed(rest, 32)?;
+        ids.push(EntityId::from_ssz_bytes(id)?);
+        rest = remaining;
+    }
+    Ok((ids, rest))
+}
+
+fn expect_consumed(rest: &[u8]) -> Result<(), DecodeError> {
+    if rest.is_empty() {
+        Ok(())
+    } else {
+        Err(DecodeError::BytesInvalid(format!("{} trailing bytes", rest.len())))
+    }
+}
+
+impl Encode for EffectStep {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        32 + 8
+            + optional_len(self.end_time.map(|_| 8))
+            + 1
+            + 4 + self.inputs.len()
+            + optional_len(self.outputs.as_ref().map(Vec::len))
+            + optional_len(self.error.as_ref().map(String::len))
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        self.effect_id.ssz_append(buf);
+        self.start_time.ssz_append(buf);
+        encode_optional(self.end_time.map(|end| end.as_ssz_bytes()).as_deref(), buf);
+        self.status.ssz_append(buf);
+        encode_with_length(&self.inputs, buf);
+        encode_optional(self.outputs.as_deref(), buf);
+        encode_optional(self.error.as_ref().map(String::as_bytes), buf);
+    }
+}
+
+impl Decode for EffectStep {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (effect_id, rest) = split_fixed(bytes, 32)?;
+        let (start_time, rest) = split_fixed(rest, 8)?;
+        let (end_time, rest) = decode_optional_timestamp(rest)?;
+        let (status, rest) = split_fixed(rest, 1)?;
+        let (inputs, rest) = decode_with_length(rest)?;
+        let (outputs, rest) = decode_optional(rest)?;
+        let (error, rest) = decode_optional_string(rest)?;
+        expect_consumed(rest)?;
+        
+        Ok(EffectStep {
+            effect_id: EntityId::from_ssz_bytes(effect_id)?,
+            start_time: Timestamp::from_ssz_bytes(start_time)?,
+            end_time,
+            status: StepStatus::from_ssz_bytes(status)?,
+            inputs: inputs.to_vec(),
+            outputs: outputs.map(<[u8]>::to_vec),
+            error,
+        })
+    }
+}
+
+impl Encode for ExecutionTrace {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        32 + 8
+            + optional_len(self.end_time.map(|_| 8))
+            + 1
+            + optional_len(self.error.as_ref().map(String::len))
+            + 4 + self.effects.iter().map(|step| 4 + step.ssz_bytes_len()).sum::<usize>()
+            + 4 + 32 * self.resources_consumed.len()
+            + 4 + 32 * self.resources_created.len()
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        self.id.ssz_append(buf);
+        self.start_time.ssz_append(buf);
+        encode_optional(self.end_time.map(|end| end.as_ssz_bytes()).as_deref(), buf);
+        self.status.ssz_append(buf);
+        encode_optional(self.error.as_ref().map(String::as_bytes), buf);
+        
+        (self.effects.len() as u32).ssz_append(buf);
+        for step in &self.effects {
+            encode_with_length(&step.as_ssz_bytes(), buf);
+        }
+        
+        encode_entity_list(&self.resources_consumed, buf);
+        encode_entity_list(&self.resources_created, buf);
+    }
+}
+
+impl Decode for ExecutionTrace {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (id, rest) = split_fixed(bytes, 32)?;
+        let (start_time, rest) = split_fixed(rest, 8)?;
+        let (end_time, rest) = decode_optional_timestamp(rest)?;
+        let (status, rest) = split_fixed(rest, 1)?;
+        let (error, rest) = decode_optional_string(rest)?;
+        
+        let (count, mut rest) = split_fixed(rest, 4)?;
+        let count = u32::from_ssz_bytes(count)? as usize;
+        let mut effects = Vec::with_capacity(count.min(rest.len() / 4));
+        for _ in 0..count {
+            let (step, remaining) = decode_with_length(rest)?;
+            effects.push(EffectStep::from_ssz_bytes(step)?);
+            rest = remaining;
+        }
+        
+        let (resources_consumed, rest) = decode_entity_list(rest)?;
+        let (resources_created, rest) = decode_entity_list(rest)?;
+        expect_consumed(rest)?;
+        
+        Ok(ExecutionTrace {
+            id: EntityId::from_ssz_bytes(id)?,
+            start_time: Timestamp::from_ssz_bytes(start_time)?,
+            end_time,
+            effects,
+            resources_consumed,
+            resources_created,
+            status: ExecutionStatus::from_ssz_bytes(status)?,
+            error,
+        })
+    }
+}
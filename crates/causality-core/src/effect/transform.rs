@@ -20,7 +20,9 @@ use crate::{
         capability::Capability,
     },
 };
+use std::any::{Any, TypeId};
 use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
 
 /// Unified effect representation as location-indexed transformation
 /// 
@@ -156,6 +158,91 @@ pub struct EffectContext {
     
     /// Execution constraints
     pub constraints: Vec<TransformConstraint>,
+    
+    /// String metadata propagated to derived contexts
+    pub metadata: BTreeMap<String, String>,
+    
+    /// Typed metadata propagated to derived contexts
+    pub typed_metadata: TypedMetadata,
+}
+
+impl EffectContext {
+    /// Derive a child context for an effect executing at `location`
+    ///
+    /// The child inherits capabilities, constraints, and all metadata, but
+    /// starts with no active sessions or resource bindings of its own.
+    pub fn derive_context(&self, location: Location) -> Self {
+        Self {
+            current_location: location,
+            available_capabilities: self.available_capabilities.clone(),
+            active_sessions: BTreeMap::new(),
+            resource_bindings: BTreeMap::new(),
+            constraints: self.constraints.clone(),
+            metadata: self.metadata.clone(),
+            typed_metadata: self.typed_metadata.clone(),
+        }
+    }
+    
+    /// Add string metadata to the context
+    pub fn with_additional_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+    
+    /// Attach a typed metadata value, replacing any previous value of type `T`
+    pub fn set_typed<T: Any + Send + Sync>(&mut self, value: T) {
+        self.typed_metadata.insert(value);
+    }
+    
+    /// Get the typed metadata value of type `T`, if set
+    pub fn get_typed<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.typed_metadata.get::<T>()
+    }
+}
+
+/// Metadata map holding at most one value per type
+///
+/// Values are shared between a context and the contexts derived from it, so
+/// cloning the map never clones the values themselves.
+#[derive(Clone, Default)]
+pub struct TypedMetadata {
+    entries: BTreeMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl TypedMetadata {
+    /// Insert a value, replacing any previous value of the same type
+    pub fn insert<T: Any + Send + Sync>(&mut self, value: T) {
+        self.entries.insert(TypeId::of::<T>(), Arc::new(value));
+    }
+    
+    /// Get the value of type `T`, if present
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.entries.get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+    
+    /// Remove the value of type `T`, returning whether one was present
+    pub fn remove<T: Any + Send + Sync>(&mut self) -> bool {
+        self.entries.remove(&TypeId::of::<T>()).is_some()
+    }
+    
+    /// Number of typed values held
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    /// Check if no typed values are held
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl std::fmt::Debug for TypedMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedMetadata")
+            .field("entries", &self.entries.len())
+            .finish()
+    }
 }
 
 /// Effect execution result
@@ -689,6 +776,8 @@ impl Default for EffectContext {
             active_sessions: BTreeMap::new(),
             resource_bindings: BTreeMap::new(),
             constraints: Vec::new(),
+            metadata: BTreeMap::new(),
+            typed_metadata: TypedMetadata::default(),
         }
     }
 }
@@ -698,7 +787,7 @@ impl std::fmt::Display for EffectError {
         match self {
             EffectError::InvalidTransform(msg) => write!(f, "Invalid transform: {}", msg),
             EffectError::TypeMismatch { expected, actual } => {
-                write!(f, "Type mismatch: expected {:?}, got {:?}", expected, actual)
+                write!(f, "Type mismatch: expected {}, got {}", expected, actual)
             }
             EffectError::LocationNotAccessible { location, reason } => {
                 write!(f, "Location {:?} not accessible: {}", location, reason)
@@ -1085,6 +1174,8 @@ mod tests {
                     ))),
                 },
             ],
+            metadata: BTreeMap::new(),
+            typed_metadata: TypedMetadata::default(),
         };
         
         // Both effects should work within the same constraint context
@@ -1102,4 +1193,35 @@ mod tests {
             _ => panic!("Expected unified constraint types"),
         }
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_typed_metadata_survives_derivation() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Deadline(u64);
+        
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        struct TraceId(String);
+        
+        let mut parent = EffectContext::default()
+            .with_additional_metadata("origin", "test");
+        parent.set_typed(Deadline(1_700_000_000));
+        parent.set_typed(TraceId("trace-1".to_string()));
+        
+        let mut child = parent.derive_context(Location::remote("server"));
+        assert_eq!(child.current_location, Location::remote("server"));
+        assert_eq!(child.get_typed::<Deadline>(), Some(&Deadline(1_700_000_000)));
+        assert_eq!(child.get_typed::<TraceId>(), Some(&TraceId("trace-1".to_string())));
+        assert_eq!(child.metadata.get("origin").map(String::as_str), Some("test"));
+        
+        // Overriding in the child leaves the parent untouched
+        child.set_typed(Deadline(1_600_000_000));
+        let grandchild = child.derive_context(Location::Local)
+            .with_additional_metadata("hop", "2");
+        assert_eq!(grandchild.get_typed::<Deadline>(), Some(&Deadline(1_600_000_000)));
+        assert_eq!(parent.get_typed::<Deadline>(), Some(&Deadline(1_700_000_000)));
+        assert_eq!(grandchild.metadata.len(), 2);
+        
+        // Values are keyed by type, not by underlying representation
+        assert_eq!(grandchild.get_typed::<u64>(), None);
+    }
+}
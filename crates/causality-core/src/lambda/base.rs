@@ -358,22 +358,24 @@ impl Decode for Value {
                 })
             }
             7 => {
-                let field_count = u32::from_ssz_bytes(&data[0..4])? as usize;
+                use crate::system::{decode_rest, decode_slice, decode_u32_at};
+                
+                let field_count = decode_u32_at(data, 0)? as usize;
                 let mut offset = 4;
                 let mut fields = std::collections::BTreeMap::new();
                 
                 for _ in 0..field_count {
                     // Decode key length
-                    let key_len = u32::from_ssz_bytes(&data[offset..offset + 4])? as usize;
+                    let key_len = decode_u32_at(data, offset)? as usize;
                     offset += 4;
                     
                     // Decode key
-                    let key = String::from_utf8(data[offset..offset + key_len].to_vec())
+                    let key = String::from_utf8(decode_slice(data, offset, key_len)?.to_vec())
                         .map_err(|_| DecodeError::BytesInvalid("Invalid UTF-8 in field name".into()))?;
                     offset += key_len;
                     
                     // Decode value
-                    let (value, remaining_after_value) = Value::decode_with_remainder(&data[offset..])?;
+                    let (value, remaining_after_value) = Value::decode_with_remainder(decode_rest(data, offset)?)?;
                     offset = data.len() - remaining_after_value.len();
                     
                     fields.insert(key, value);
@@ -417,15 +419,17 @@ impl DecodeWithRemainder for Value {
             }
             3 => {
                 let s = crate::system::Str::from_ssz_bytes(data)?;
-                Ok((Value::Symbol(s), data))
+                let s_len = s.ssz_bytes_len();
+                Ok((Value::Symbol(s), crate::system::decode_rest(data, s_len)?))
             }
             4 => {
                 let s = crate::system::Str::from_ssz_bytes(data)?;
-                Ok((Value::String(s), data))
+                let s_len = s.ssz_bytes_len();
+                Ok((Value::String(s), crate::system::decode_rest(data, s_len)?))
             }
             5 => {
                 let (left, remaining) = Value::decode_with_remainder(data)?;
-                let (right, _) = Value::decode_with_remainder(remaining)?;
+                let (right, remaining) = Value::decode_with_remainder(remaining)?;
                 Ok((Value::Product(Box::new(left), Box::new(right)), remaining))
             }
             6 => {
@@ -436,29 +440,31 @@ impl DecodeWithRemainder for Value {
                     });
                 }
                 let tag = data[0];
-                let (value, _) = Value::decode_with_remainder(&data[1..])?;
+                let (value, remaining) = Value::decode_with_remainder(&data[1..])?;
                 Ok((Value::Sum {
                     tag,
                     value: Box::new(value),
-                }, &data[1..]))
+                }, remaining))
             }
             7 => {
-                let field_count = u32::from_ssz_bytes(&data[0..4])? as usize;
+                use crate::system::{decode_rest, decode_slice, decode_u32_at};
+                
+                let field_count = decode_u32_at(data, 0)? as usize;
                 let mut offset = 4;
                 let mut fields = std::collections::BTreeMap::new();
                 
                 for _ in 0..field_count {
                     // Decode key length
-                    let key_len = u32::from_ssz_bytes(&data[offset..offset + 4])? as usize;
+                    let key_len = decode_u32_at(data, offset)? as usize;
                     offset += 4;
                     
                     // Decode key
-                    let key = String::from_utf8(data[offset..offset + key_len].to_vec())
+                    let key = String::from_utf8(decode_slice(data, offset, key_len)?.to_vec())
                         .map_err(|_| DecodeError::BytesInvalid("Invalid UTF-8 in field name".into()))?;
                     offset += key_len;
                     
                     // Decode value
-                    let (value, remaining_after_value) = Value::decode_with_remainder(&data[offset..])?;
+                    let (value, remaining_after_value) = Value::decode_with_remainder(decode_rest(data, offset)?)?;
                     offset = data.len() - remaining_after_value.len();
                     
                     fields.insert(key, value);
@@ -783,6 +789,67 @@ impl std::error::Error for SessionEnvironmentError {}
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_type_pretty_printing() {
+        let int = || Box::new(TypeInner::Base(BaseType::Int));
+        let boolean = || Box::new(TypeInner::Base(BaseType::Bool));
+    
+        let product = TypeInner::Product(int(), Box::new(TypeInner::Sum(boolean(), int())));
+        assert_eq!(product.to_string(), "Int ⊗ (Bool ⊕ Int)");
+    
+        let sum = TypeInner::Sum(Box::new(TypeInner::Product(int(), boolean())), int());
+        assert_eq!(sum.to_string(), "Int ⊗ Bool ⊕ Int");
+    
+        let curried = TypeInner::LinearFunction(
+            Box::new(TypeInner::LinearFunction(int(), boolean())),
+            Box::new(TypeInner::LinearFunction(int(), boolean())),
+        );
+        assert_eq!(curried.to_string(), "(Int ⊸ Bool) ⊸ Int ⊸ Bool");
+    
+        let typed: Type<Linear> = Type::new(product);
+        assert_eq!(typed.to_string(), "Int ⊗ (Bool ⊕ Int)");
+    }
+    
+    #[test]
+    fn test_session_type_pretty_printing() {
+        let protocol = SessionType::Send(
+            Box::new(TypeInner::Product(
+                Box::new(TypeInner::Base(BaseType::Int)),
+                Box::new(TypeInner::Base(BaseType::Symbol)),
+            )),
+            Box::new(SessionType::Receive(
+                Box::new(TypeInner::Base(BaseType::Bool)),
+                Box::new(SessionType::ExternalChoice(vec![
+                    ("ok".to_string(), SessionType::End),
+                    ("retry".to_string(), SessionType::Variable("X".to_string())),
+                ])),
+            )),
+        );
+        assert_eq!(protocol.to_string(), "!(Int ⊗ Symbol).?Bool.&{ok: end, retry: X}");
+    
+        let looping = SessionType::Recursive("X".to_string(), Box::new(protocol.dual()));
+        assert_eq!(looping.to_string(), "μX.?(Int ⊗ Symbol).!Bool.⊕{ok: end, retry: X}");
+    
+        let channel = TypeInner::Product(
+            Box::new(TypeInner::Session(Box::new(SessionType::End))),
+            Box::new(TypeInner::Base(BaseType::Unit)),
+        );
+        assert_eq!(channel.to_string(), "end ⊗ Unit");
+    
+        let send_int = TypeInner::Session(Box::new(SessionType::Send(
+            Box::new(TypeInner::Base(BaseType::Int)),
+            Box::new(SessionType::End),
+        )));
+        let pair = TypeInner::Product(
+            Box::new(send_int.clone()),
+            Box::new(TypeInner::Base(BaseType::Unit)),
+        );
+        assert_eq!(pair.to_string(), "(!Int.end) ⊗ Unit");
+    
+        let handler = TypeInner::LinearFunction(Box::new(send_int.clone()), Box::new(send_int));
+        assert_eq!(handler.to_string(), "(!Int.end) ⊸ !Int.end");
+    }
+    
     #[test]
     fn test_type_content_addressing() {
         let int_type1 = Type::int();
@@ -883,6 +950,52 @@ mod tests {
         assert!(internal_choice.is_dual_to(&external_choice));
     }
     
+    #[test]
+    fn test_multi_step_protocol_duality() {
+        let int = || Box::new(TypeInner::Base(BaseType::Int));
+        let boolean = || Box::new(TypeInner::Base(BaseType::Bool));
+        
+        // !Int.?Bool.⊕{quote: ?Int.&{accept: end, reject: !Bool.end}, cancel: end}
+        let buyer = SessionType::Send(int(), Box::new(SessionType::Receive(boolean(), Box::new(
+            SessionType::InternalChoice(vec![
+                ("quote".to_string(), SessionType::Receive(int(), Box::new(
+                    SessionType::ExternalChoice(vec![
+                        ("accept".to_string(), SessionType::End),
+                        ("reject".to_string(), SessionType::Send(boolean(), Box::new(SessionType::End))),
+                    ])
+                ))),
+                ("cancel".to_string(), SessionType::End),
+            ])
+        ))));
+        
+        let seller = SessionType::Receive(int(), Box::new(SessionType::Send(boolean(), Box::new(
+            SessionType::ExternalChoice(vec![
+                ("quote".to_string(), SessionType::Send(int(), Box::new(
+                    SessionType::InternalChoice(vec![
+                        ("accept".to_string(), SessionType::End),
+                        ("reject".to_string(), SessionType::Receive(boolean(), Box::new(SessionType::End))),
+                    ])
+                ))),
+                ("cancel".to_string(), SessionType::End),
+            ])
+        ))));
+        
+        assert_eq!(buyer.dual(), seller);
+        assert_eq!(seller.dual().dual(), seller);
+        assert!(buyer.is_dual_to(&seller));
+        assert!(seller.is_dual_to(&buyer));
+        
+        // The same side twice is never dual, nor is a protocol with a mismatched branch
+        assert!(!buyer.is_dual_to(&buyer));
+        let mut wrong_branch = seller.clone();
+        if let SessionType::Receive(_, next) = &mut wrong_branch {
+            if let SessionType::Send(_, choice) = next.as_mut() {
+                **choice = SessionType::ExternalChoice(vec![("cancel".to_string(), SessionType::End)]);
+            }
+        }
+        assert!(!buyer.is_dual_to(&wrong_branch));
+    }
+    
     #[test]
     fn test_session_type_substitution() {
         // Test basic variable substitution
@@ -1626,6 +1739,146 @@ impl SessionType {
     }
 }
 
+//-----------------------------------------------------------------------------
+// Pretty Printing
+//-----------------------------------------------------------------------------
+
+impl std::fmt::Display for BaseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaseType::Unit => write!(f, "Unit"),
+            BaseType::Bool => write!(f, "Bool"),
+            BaseType::Int => write!(f, "Int"),
+            BaseType::Symbol => write!(f, "Symbol"),
+        }
+    }
+}
+
+impl TypeInner {
+    /// Binding strength of the outermost type constructor
+    ///
+    /// Operands binding more loosely than their operator are parenthesized,
+    /// so `⊗` binds tighter than `⊕`, which binds tighter than `⊸`. Session
+    /// types other than `end` or a variable extend as far right as possible,
+    /// so they bind as loosely as a function.
+    fn precedence(&self) -> u8 {
+        match self {
+            TypeInner::Session(session) => match **session {
+                SessionType::End | SessionType::Variable(_) => 4,
+                _ => 1,
+            },
+            TypeInner::LinearFunction(_, _)
+            | TypeInner::Transform { .. }
+            | TypeInner::Located(_, _) => 1,
+            TypeInner::Sum(_, _) => 2,
+            TypeInner::Product(_, _) => 3,
+            TypeInner::Base(_) | TypeInner::Record(_) => 4,
+        }
+    }
+
+    /// Render this type as an operand that must bind tighter than `precedence`
+    fn fmt_operand(&self, f: &mut std::fmt::Formatter<'_>, precedence: u8) -> std::fmt::Result {
+        if self.precedence() > precedence {
+            write!(f, "{}", self)
+        } else {
+            write!(f, "({})", self)
+        }
+    }
+}
+
+impl std::fmt::Display for TypeInner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeInner::Base(base) => write!(f, "{}", base),
+            TypeInner::Product(left, right) => {
+                left.fmt_operand(f, 3)?;
+                write!(f, " ⊗ ")?;
+                right.fmt_operand(f, 3)
+            }
+            TypeInner::Sum(left, right) => {
+                left.fmt_operand(f, 2)?;
+                write!(f, " ⊕ ")?;
+                right.fmt_operand(f, 2)
+            }
+            TypeInner::LinearFunction(input, output) => {
+                // Right-associative: A ⊸ B ⊸ C is A ⊸ (B ⊸ C)
+                input.fmt_operand(f, 1)?;
+                write!(f, " ⊸ ")?;
+                output.fmt_operand(f, 0)
+            }
+            TypeInner::Record(record) => {
+                write!(f, "{{")?;
+                for (i, (name, field)) in record.row.fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", name, field.ty)?;
+                }
+                if let Some(extension) = &record.row.extension {
+                    write!(f, " | {}", extension.name)?;
+                }
+                write!(f, "}}")
+            }
+            TypeInner::Session(session) => write!(f, "{}", session),
+            TypeInner::Transform { input, output, location } => {
+                input.fmt_operand(f, 1)?;
+                write!(f, " → ")?;
+                output.fmt_operand(f, 1)?;
+                write!(f, " @ {}", location)
+            }
+            TypeInner::Located(inner, location) => {
+                inner.fmt_operand(f, 1)?;
+                write!(f, " @ {}", location)
+            }
+        }
+    }
+}
+
+impl<L> std::fmt::Display for Type<L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl std::fmt::Display for SessionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionType::Send(ty, continuation) => {
+                write!(f, "!")?;
+                ty.fmt_operand(f, 3)?;
+                write!(f, ".{}", continuation)
+            }
+            SessionType::Receive(ty, continuation) => {
+                write!(f, "?")?;
+                ty.fmt_operand(f, 3)?;
+                write!(f, ".{}", continuation)
+            }
+            SessionType::InternalChoice(branches) => {
+                write!(f, "⊕")?;
+                fmt_branches(f, branches)
+            }
+            SessionType::ExternalChoice(branches) => {
+                write!(f, "&")?;
+                fmt_branches(f, branches)
+            }
+            SessionType::End => write!(f, "end"),
+            SessionType::Recursive(var, body) => write!(f, "μ{}.{}", var, body),
+            SessionType::Variable(var) => write!(f, "{}", var),
+        }
+    }
+}
+
+fn fmt_branches(f: &mut std::fmt::Formatter<'_>, branches: &[(String, SessionType)]) -> std::fmt::Result {
+    write!(f, "{{")?;
+    for (i, (label, session)) in branches.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}: {}", label, session)?;
+    }
+    write!(f, "}}")
+}
+
 // SSZ implementation for SessionType
 impl Encode for SessionType {
     fn is_ssz_fixed_len() -> bool {
@@ -1704,7 +1957,7 @@ impl Decode for SessionType {
     }
 
     fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
-        use crate::system::decode_enum_variant;
+        use crate::system::{decode_enum_variant, decode_rest, decode_slice, decode_u32_at};
         
         let (variant, data) = decode_enum_variant(bytes)?;
         
@@ -1720,19 +1973,19 @@ impl Decode for SessionType {
                 Ok(SessionType::Receive(Box::new(t), Box::new(s)))
             }
             2 | 3 => {
-                let branch_count = u32::from_ssz_bytes(&data[..4])? as usize;
+                let branch_count = decode_u32_at(data, 0)? as usize;
                 let mut offset = 4;
                 let mut branches = Vec::new();
                 
                 for _ in 0..branch_count {
-                    let label_len = u32::from_ssz_bytes(&data[offset..offset+4])? as usize;
+                    let label_len = decode_u32_at(data, offset)? as usize;
                     offset += 4;
                     
-                    let label = String::from_utf8(data[offset..offset+label_len].to_vec())
+                    let label = String::from_utf8(decode_slice(data, offset, label_len)?.to_vec())
                         .map_err(|_| DecodeError::BytesInvalid("Invalid UTF-8".into()))?;
                     offset += label_len;
                     
-                    let session = SessionType::from_ssz_bytes(&data[offset..])?;
+                    let session = SessionType::from_ssz_bytes(decode_rest(data, offset)?)?;
                     offset += session.ssz_bytes_len();
                     
                     branches.push((label, session));
@@ -1746,15 +1999,15 @@ impl Decode for SessionType {
             }
             4 => Ok(SessionType::End),
             5 => {
-                let var_len = u32::from_ssz_bytes(&data[..4])? as usize;
-                let var = String::from_utf8(data[4..4+var_len].to_vec())
+                let var_len = decode_u32_at(data, 0)? as usize;
+                let var = String::from_utf8(decode_slice(data, 4, var_len)?.to_vec())
                     .map_err(|_| DecodeError::BytesInvalid("Invalid UTF-8".into()))?;
-                let body = SessionType::from_ssz_bytes(&data[4+var_len..])?;
+                let body = SessionType::from_ssz_bytes(decode_rest(data, 4 + var_len)?)?;
                 Ok(SessionType::Recursive(var, Box::new(body)))
             }
             6 => {
-                let var_len = u32::from_ssz_bytes(&data[..4])? as usize;
-                let var = String::from_utf8(data[4..4+var_len].to_vec())
+                let var_len = decode_u32_at(data, 0)? as usize;
+                let var = String::from_utf8(decode_slice(data, 4, var_len)?.to_vec())
                     .map_err(|_| DecodeError::BytesInvalid("Invalid UTF-8".into()))?;
                 Ok(SessionType::Variable(var))
             }
@@ -1957,7 +2210,7 @@ impl DecodeWithRemainder for TypeInner {
             4 => {
                 let record = RecordType::from_ssz_bytes(data)?;
                 let record_len = record.ssz_bytes_len();
-                Ok((TypeInner::Record(record), &data[record_len..]))
+                Ok((TypeInner::Record(record), crate::system::decode_rest(data, record_len)?))
             }
             5 => {
                 Ok((TypeInner::Session(Box::new(SessionType::End)), data))
@@ -1965,7 +2218,7 @@ impl DecodeWithRemainder for TypeInner {
             6 => {
                 let (input, remaining) = Self::decode_with_remainder(data)?;
                 let (output, remaining) = Self::decode_with_remainder(remaining)?;
-                let (location, _) = Location::decode_with_remainder(remaining)?;
+                let (location, remaining) = Location::decode_with_remainder(remaining)?;
                 Ok((TypeInner::Transform {
                     input: Box::new(input),
                     output: Box::new(output),
@@ -1974,7 +2227,7 @@ impl DecodeWithRemainder for TypeInner {
             }
             7 => {
                 let (inner, remaining) = Self::decode_with_remainder(data)?;
-                let (location, _) = Location::decode_with_remainder(remaining)?;
+                let (location, remaining) = Location::decode_with_remainder(remaining)?;
                 Ok((TypeInner::Located(Box::new(inner), location), remaining))
             }
             _ => Err(DecodeError::BytesInvalid(
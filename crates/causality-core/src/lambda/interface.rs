@@ -234,6 +234,19 @@ fn compile_term_to_register(
             Ok(result_reg)
         }
         
+        // Weakening - consume the value and leave the output unused
+        TermKind::Drop { value } => {
+            let resource_reg = compile_term_to_register(value, ctx)?;
+            let result_reg = ctx.fresh_register();
+            
+            ctx.emit(Instruction::Consume {
+                resource_reg,
+                output_reg: result_reg,
+            });
+            
+            Ok(result_reg)
+        }
+        
         // Resource consumption - direct Consume instruction
         TermKind::Consume { resource } => {
             let resource_reg = compile_term_to_register(resource, ctx)?;
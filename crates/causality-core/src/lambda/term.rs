@@ -3,6 +3,7 @@
 //! This module defines the term language for the linear lambda calculus.
 //! These are the semantic terms that compile down to Layer 0 instructions.
 
+use crate::effect::core::Span;
 use crate::lambda::base::TypeInner;
 use crate::lambda::symbol::Symbol;
 use crate::machine::MachineValue;
@@ -13,15 +14,30 @@ use serde::{Deserialize, Serialize};
 //-----------------------------------------------------------------------------
 
 /// A term in the linear lambda calculus
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Equality ignores `span`: the same term parsed from different places in
+/// the source compares equal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Term {
     /// The term kind
     pub kind: TermKind,
 
     /// Optional type annotation
     pub ty: Option<TypeInner>,
+
+    /// Source location, if the term came from surface syntax
+    #[serde(skip)]
+    pub span: Option<Span>,
+}
+
+impl PartialEq for Term {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.ty == other.ty
+    }
 }
 
+impl Eq for Term {}
+
 /// Different kinds of terms in Layer 1
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TermKind {
@@ -88,6 +104,9 @@ pub enum TermKind {
     /// Resource consumption: consume t
     Consume { resource: Box<Term> },
 
+    /// Explicit weakening of a droppable value: drop t
+    Drop { value: Box<Term> },
+
     /// Let binding: let x = t1 in t2
     Let {
         var: String,
@@ -182,7 +201,7 @@ pub enum Literal {
 impl Term {
     /// Create a new term with the given kind
     pub fn new(kind: TermKind) -> Self {
-        Self { kind, ty: None }
+        Self { kind, ty: None, span: None }
     }
 
     /// Create a term with a type annotation
@@ -191,6 +210,12 @@ impl Term {
         self
     }
 
+    /// Attach the source location the term was parsed from
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
     /// Create a variable term
     pub fn var(name: impl Into<String>) -> Self {
         Self::new(TermKind::Var(name.into()))
@@ -258,6 +283,13 @@ impl Term {
         })
     }
 
+    /// Create a drop term discarding an affine or unrestricted value
+    pub fn drop_value(value: Term) -> Self {
+        Self::new(TermKind::Drop {
+            value: Box::new(value),
+        })
+    }
+
     /// Create a let binding
     pub fn let_bind(var: impl Into<String>, value: Term, body: Term) -> Self {
         Self::new(TermKind::Let {
@@ -403,6 +435,21 @@ mod tests {
         assert_eq!(term.ty, None);
     }
 
+    #[test]
+    fn test_term_equality_ignores_span() {
+        use crate::effect::core::{Position, Span};
+
+        let at = |line| Span {
+            start: Position { line, column: 1, offset: 0 },
+            end: Position { line, column: 2, offset: 1 },
+            file: None,
+        };
+
+        assert_eq!(Term::var("x").with_span(at(1)), Term::var("x").with_span(at(2)));
+        assert_eq!(Term::var("x").with_span(at(1)), Term::var("x"));
+        assert_ne!(Term::var("x").with_span(at(1)), Term::var("y").with_span(at(1)));
+    }
+
     #[test]
     fn test_term_literal() {
         // --- Bool literal
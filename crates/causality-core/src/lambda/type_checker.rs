@@ -3,6 +3,7 @@
 //! This module implements type checking for the linear lambda calculus
 //! with session types, ensuring both type safety and linear resource usage.
 
+use crate::effect::core::Span;
 use crate::lambda::{
     base::{
         BaseType, SessionEnvironment, SessionEnvironmentError, SessionType,
@@ -10,6 +11,7 @@ use crate::lambda::{
     },
     term::{Literal, Term, TermKind},
 };
+use crate::system::error::LinearityError;
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -17,22 +19,22 @@ use thiserror::Error;
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum TypeCheckError {
     /// Type mismatch between expected and actual types
-    #[error("Type mismatch: expected {expected:?}, got {actual:?}")]
+    #[error("Type mismatch: expected {expected}, got {actual}")]
     TypeMismatch {
         expected: Box<TypeInner>,
         actual: Box<TypeInner>,
     },
 
     /// Cannot apply function to argument
-    #[error("Cannot apply function to argument: {0:?}")]
+    #[error("Cannot apply function to argument: {0}")]
     CannotApply(Box<TypeInner>),
 
     /// Invalid tensor elimination
-    #[error("Invalid tensor elimination: {0:?}")]
+    #[error("Invalid tensor elimination: {0}")]
     InvalidTensorElimination(Box<TypeInner>),
 
     /// Invalid case analysis
-    #[error("Invalid case analysis: {0:?}")]
+    #[error("Invalid case analysis: {0}")]
     InvalidCase(Box<TypeInner>),
 
     /// Variable not found in context
@@ -40,7 +42,7 @@ pub enum TypeCheckError {
     VariableNotFound(String),
 
     /// Invalid session operation
-    #[error("Invalid session operation: {0:?}")]
+    #[error("Invalid session operation: {0}")]
     InvalidSessionOperation(Box<TypeInner>),
 
     /// Linearity violation
@@ -59,14 +61,14 @@ pub enum TypeCheckError {
     SessionError(#[from] SessionEnvironmentError),
 
     #[error(
-        "Session protocol mismatch: cannot perform {operation} on {session_type:?}"
+        "Session protocol mismatch: cannot perform {operation} on {session_type}"
     )]
     SessionProtocolMismatch {
         operation: String,
         session_type: SessionType,
     },
 
-    #[error("Choice label '{label}' not found in {session_type:?}")]
+    #[error("Choice label '{label}' not found in {session_type}")]
     ChoiceLabelNotFound {
         label: String,
         session_type: SessionType,
@@ -78,8 +80,49 @@ pub enum TypeCheckError {
     #[error("Linear variable '{0}' not used")]
     LinearVariableUnused(String),
 
-    #[error("Invalid branch: expected external choice, got {0:?}")]
+    #[error("Invalid branch: expected external choice, got {0}")]
     InvalidBranch(SessionType),
+
+    /// Binding dropped without meeting its usage requirement
+    #[error(transparent)]
+    Linearity(#[from] LinearityError),
+
+    /// Parameter type could not be inferred and needs an annotation
+    #[error("Cannot infer type of parameter '{param}': {reason}")]
+    CannotInfer { param: String, reason: String },
+}
+
+/// How often a tracked binding must be used before it goes out of scope
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageRequirement {
+    /// Exactly once
+    Linear,
+
+    /// At most once
+    Affine,
+
+    /// At least once
+    Relevant,
+}
+
+impl UsageRequirement {
+    /// Whether a binding with this requirement may be discarded unused
+    pub fn can_drop(self) -> bool {
+        matches!(self, UsageRequirement::Affine)
+    }
+
+    /// Whether a binding with this requirement may be used more than once
+    pub fn can_copy(self) -> bool {
+        matches!(self, UsageRequirement::Relevant)
+    }
+}
+
+/// Usage of a binding with a usage requirement
+#[derive(Debug, Clone)]
+struct UsageRecord {
+    requirement: UsageRequirement,
+    uses: usize,
+    binding: Option<Span>,
 }
 
 /// Type checking context for variables
@@ -88,11 +131,14 @@ pub struct TypeContext {
     /// Variable type bindings
     variables: HashMap<String, TypeInner>,
 
-    /// Linear variable usage tracking
-    linear_usage: HashMap<String, bool>,
+    /// Usage tracking for linear and relevant variables
+    usage: HashMap<String, UsageRecord>,
 
     /// Session environment for tracking channels
     session_env: SessionEnvironment,
+
+    /// Infer parameter types of let-bound lambdas from their applications
+    infer_lambdas: bool,
 }
 
 impl TypeContext {
@@ -100,27 +146,89 @@ impl TypeContext {
     pub fn new() -> Self {
         Self {
             variables: HashMap::new(),
-            linear_usage: HashMap::new(),
+            usage: HashMap::new(),
             session_env: SessionEnvironment::new(),
+            infer_lambdas: false,
         }
     }
 
+    /// Enable inference of unannotated lambda parameters
+    ///
+    /// A let-bound lambda takes its parameter types from the arguments it is
+    /// applied to in the let body. Any other unannotated parameter, or one
+    /// whose applications disagree, is rejected with `CannotInfer` rather
+    /// than defaulting to unit.
+    pub fn with_inference(mut self) -> Self {
+        self.infer_lambdas = true;
+        self
+    }
+
     /// Bind a variable with a type
     pub fn bind_variable(
         &mut self,
         name: String,
         ty: TypeInner,
     ) -> Result<(), TypeCheckError> {
-        self.variables.insert(name.clone(), ty.clone());
+        self.bind_variable_at(name, ty, None)
+    }
 
+    /// Bind a variable, recording where it was bound for diagnostics
+    pub fn bind_variable_at(
+        &mut self,
+        name: String,
+        ty: TypeInner,
+        binding: Option<Span>,
+    ) -> Result<(), TypeCheckError> {
         // Track linear variables
         if self.is_linear_type(&ty) {
-            self.linear_usage.insert(name, false);
+            self.track_usage(name.clone(), UsageRequirement::Linear, binding);
         }
 
+        self.variables.insert(name, ty);
         Ok(())
     }
 
+    /// Bind a relevant variable, which may be copied but must be used
+    pub fn bind_relevant_variable(
+        &mut self,
+        name: String,
+        ty: TypeInner,
+        binding: Option<Span>,
+    ) -> Result<(), TypeCheckError> {
+        self.bind_variable_with_usage(name, ty, UsageRequirement::Relevant, binding)
+    }
+
+    /// Bind an affine variable, which may be used at most once or dropped
+    pub fn bind_affine_variable(
+        &mut self,
+        name: String,
+        ty: TypeInner,
+        binding: Option<Span>,
+    ) -> Result<(), TypeCheckError> {
+        self.bind_variable_with_usage(name, ty, UsageRequirement::Affine, binding)
+    }
+
+    fn bind_variable_with_usage(
+        &mut self,
+        name: String,
+        ty: TypeInner,
+        requirement: UsageRequirement,
+        binding: Option<Span>,
+    ) -> Result<(), TypeCheckError> {
+        self.track_usage(name.clone(), requirement, binding);
+        self.variables.insert(name, ty);
+        Ok(())
+    }
+
+    /// Usage requirement of a tracked variable, if any
+    pub fn usage_requirement(&self, name: &str) -> Option<UsageRequirement> {
+        self.usage.get(name).map(|record| record.requirement)
+    }
+
+    fn track_usage(&mut self, name: String, requirement: UsageRequirement, binding: Option<Span>) {
+        self.usage.insert(name, UsageRecord { requirement, uses: 0, binding });
+    }
+
     /// Look up a variable's type
     pub fn lookup_variable(&self, name: &str) -> Result<&TypeInner, TypeCheckError> {
         self.variables
@@ -132,20 +240,49 @@ impl TypeContext {
     pub fn use_variable(&mut self, name: &str) -> Result<TypeInner, TypeCheckError> {
         let ty = self.lookup_variable(name)?.clone();
 
-        if self.is_linear_type(&ty) {
-            if let Some(used) = self.linear_usage.get_mut(name) {
-                if *used {
-                    return Err(TypeCheckError::LinearVariableReused(
-                        name.to_string(),
-                    ));
-                }
-                *used = true;
+        if let Some(record) = self.usage.get_mut(name) {
+            if !record.requirement.can_copy() && record.uses > 0 {
+                return Err(TypeCheckError::LinearVariableReused(
+                    name.to_string(),
+                ));
             }
+            record.uses += 1;
         }
 
         Ok(ty)
     }
 
+    /// End the scope of a variable, checking its usage requirement
+    ///
+    /// An unused linear binding reports `NeverConsumed` and an unused
+    /// relevant binding reports `MustUse`, both pointing at the binding site.
+    pub fn drop_variable(&mut self, name: &str) -> Result<(), TypeCheckError> {
+        self.variables.remove(name);
+        match self.usage.remove(name) {
+            Some(record) if record.uses == 0 && !record.requirement.can_drop() => {
+                Err(unused_error(name, record).into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Remove a binding that an inner binder is about to shadow, returning
+    /// it for `restore_binding`
+    fn take_binding(&mut self, name: &str) -> Option<(TypeInner, Option<UsageRecord>)> {
+        let ty = self.variables.remove(name)?;
+        Some((ty, self.usage.remove(name)))
+    }
+
+    /// Reinstate a binding removed by `take_binding` once the inner scope ends
+    fn restore_binding(&mut self, name: &str, binding: Option<(TypeInner, Option<UsageRecord>)>) {
+        if let Some((ty, usage)) = binding {
+            self.variables.insert(name.to_string(), ty);
+            if let Some(usage) = usage {
+                self.usage.insert(name.to_string(), usage);
+            }
+        }
+    }
+
     /// Check if a type is linear (requires exactly-once usage)
     fn is_linear_type(&self, ty: &TypeInner) -> bool {
         matches!(
@@ -205,14 +342,22 @@ impl TypeContext {
         Ok(())
     }
 
-    /// Check for unused linear variables
+    /// Check for unused linear and relevant variables
     pub fn check_linear_usage(&self) -> Result<(), TypeCheckError> {
-        for (name, used) in &self.linear_usage {
-            if !used {
-                return Err(TypeCheckError::LinearVariableUnused(name.clone()));
-            }
+        match self.usage.iter().find(|(_, record)| record.uses == 0 && !record.requirement.can_drop()) {
+            Some((name, record)) => Err(unused_error(name, record.clone()).into()),
+            None => Ok(()),
         }
-        Ok(())
+    }
+}
+
+fn unused_error(name: &str, record: UsageRecord) -> LinearityError {
+    let resource = name.to_string();
+    match record.requirement {
+        UsageRequirement::Linear | UsageRequirement::Affine => {
+            LinearityError::NeverConsumed { resource, binding: record.binding }
+        }
+        UsageRequirement::Relevant => LinearityError::MustUse { resource, binding: record.binding },
     }
 }
 
@@ -372,6 +517,13 @@ pub fn type_check(
             param_type,
             body,
         } => {
+            if param_type.is_none() && ctx.infer_lambdas {
+                return Err(TypeCheckError::CannotInfer {
+                    param: param.clone(),
+                    reason: "annotation required outside an applied let binding".to_string(),
+                });
+            }
+
             ctx.enter_scope();
 
             let param_ty = param_type
@@ -421,10 +573,46 @@ pub fn type_check(
             Ok(resource_ty)
         }
 
+        TermKind::Drop { value } => {
+            // Only affine and unrestricted values may be weakened away
+            let requirement = match &value.kind {
+                TermKind::Var(name) => ctx.usage_requirement(name),
+                _ => None,
+            };
+            let value_ty = type_check(ctx, value)?;
+            let requirement = requirement
+                .or_else(|| ctx.is_linear_type(&value_ty).then_some(UsageRequirement::Linear));
+
+            match requirement {
+                Some(requirement) if !requirement.can_drop() => {
+                    Err(TypeCheckError::LinearityViolation(format!(
+                        "cannot drop {:?} value of type {}",
+                        requirement, value_ty
+                    )))
+                }
+                _ => Ok(TypeInner::Base(BaseType::Unit)),
+            }
+        }
+
         TermKind::Let { var, value, body } => {
+            let inferred;
+            let value = match &value.kind {
+                TermKind::Lambda { param_type: None, .. } if ctx.infer_lambdas => {
+                    inferred = infer_lambda_params(ctx, var, value, body)?;
+                    &inferred
+                }
+                _ => value.as_ref(),
+            };
             let value_ty = type_check(ctx, value)?;
-            ctx.bind_variable(var.clone(), value_ty)?;
-            type_check(ctx, body)
+            let shadowed = ctx.take_binding(var);
+            ctx.bind_variable_at(var.clone(), value_ty, term.span.clone())?;
+            let body_ty = type_check(ctx, body)?;
+            // The binding's scope ends with the body, so its usage is checked
+            // here, while the let's span is at hand, rather than left to
+            // `check_linear_usage`, which cannot tell which let it came from
+            ctx.drop_variable(var)?;
+            ctx.restore_binding(var, shadowed);
+            Ok(body_ty)
         }
 
         // Session type constructors
@@ -733,6 +921,254 @@ pub fn type_check(
     }
 }
 
+/// Annotate the unannotated parameters of a let-bound lambda chain
+///
+/// The i-th parameter takes the type of the i-th argument at every
+/// application of `var` in `body`. Partial applications only constrain the
+/// parameters they supply. Each argument is checked in the context of its
+/// application, so it may refer to binders introduced inside `body`.
+fn infer_lambda_params(
+    ctx: &TypeContext,
+    var: &str,
+    lambda: &Term,
+    body: &Term,
+) -> Result<Term, TypeCheckError> {
+    let mut applications = Vec::new();
+    collect_applications(body, var, &mut Vec::new(), &mut applications);
+
+    let mut annotated = lambda.clone();
+    let mut current = &mut annotated;
+    let mut position = 0;
+    while let TermKind::Lambda { param, param_type, body } = &mut current.kind {
+        if param_type.is_none() {
+            let mut inferred: Option<TypeInner> = None;
+            for application in &applications {
+                let Some(arg) = application.args.get(position) else {
+                    continue;
+                };
+                // Synthesize on a scratch context so no usage is recorded
+                let arg_ty = type_check(&mut application_context(ctx, &application.scope), arg)?;
+                match &inferred {
+                    Some(ty) if *ty != arg_ty => {
+                        return Err(TypeCheckError::CannotInfer {
+                            param: param.clone(),
+                            reason: format!("applied to both {} and {}", ty, arg_ty),
+                        });
+                    }
+                    _ => inferred = Some(arg_ty),
+                }
+            }
+            *param_type = Some(inferred.ok_or_else(|| TypeCheckError::CannotInfer {
+                param: param.clone(),
+                reason: format!("no application of '{}' supplies this argument", var),
+            })?);
+        }
+        position += 1;
+        current = body.as_mut();
+    }
+    Ok(annotated)
+}
+
+/// An application spine `var a1 a2 ...` with the binders in scope at it
+struct Application<'a> {
+    /// Binders between the let and the application, outermost first
+    scope: Vec<Binder<'a>>,
+
+    /// Arguments in application order
+    args: Vec<&'a Term>,
+}
+
+/// A variable bound between a let-bound lambda and one of its applications
+#[derive(Clone)]
+enum Binder<'a> {
+    /// `let name = value`, typed by checking the value
+    Let(&'a str, &'a Term),
+
+    /// A variable of known type, such as an annotated parameter
+    Typed(&'a str, TypeInner),
+
+    /// A variable whose type is not known, such as an unannotated parameter
+    Unknown(&'a str),
+
+    /// `let (left, right) = tensor`
+    Tensor(&'a str, &'a str, &'a Term),
+
+    /// A case arm binding the left (`true`) or right side of the scrutinee
+    Arm(&'a str, &'a Term, bool),
+}
+
+/// Scratch context for checking an argument at its application site
+///
+/// Binders whose type cannot be determined are removed, so an argument that
+/// refers to one fails instead of seeing a shadowed outer binding.
+fn application_context(ctx: &TypeContext, scope: &[Binder]) -> TypeContext {
+    fn rebind(ctx: &mut TypeContext, name: &str, ty: Option<TypeInner>) {
+        match ty {
+            Some(ty) => ctx.variables.insert(name.to_string(), ty),
+            None => ctx.variables.remove(name),
+        };
+    }
+
+    let mut scratch = ctx.clone();
+    scratch.usage.clear();
+    for binder in scope {
+        match binder {
+            Binder::Let(name, value) => {
+                let ty = type_check(&mut scratch, value).ok();
+                rebind(&mut scratch, name, ty);
+            }
+            Binder::Typed(name, ty) => rebind(&mut scratch, name, Some(ty.clone())),
+            Binder::Unknown(name) => rebind(&mut scratch, name, None),
+            Binder::Tensor(left, right, tensor) => match type_check(&mut scratch, tensor) {
+                Ok(TypeInner::Product(left_ty, right_ty)) => {
+                    rebind(&mut scratch, left, Some(*left_ty));
+                    rebind(&mut scratch, right, Some(*right_ty));
+                }
+                _ => {
+                    rebind(&mut scratch, left, None);
+                    rebind(&mut scratch, right, None);
+                }
+            },
+            Binder::Arm(name, scrutinee, is_left) => {
+                let ty = match type_check(&mut scratch, scrutinee) {
+                    Ok(TypeInner::Sum(left_ty, right_ty)) => {
+                        Some(if *is_left { *left_ty } else { *right_ty })
+                    }
+                    _ => None,
+                };
+                rebind(&mut scratch, name, ty);
+            }
+        }
+    }
+    scratch
+}
+
+/// Collect every application spine `var a1 a2 ...` in `term`, with the
+/// binders in scope at each one
+fn collect_applications<'a>(
+    term: &'a Term,
+    var: &str,
+    scope: &mut Vec<Binder<'a>>,
+    applications: &mut Vec<Application<'a>>,
+) {
+    // Visit `child` with `binders` added to the scope
+    fn visit_under<'a>(
+        child: &'a Term,
+        var: &str,
+        binders: Vec<Binder<'a>>,
+        scope: &mut Vec<Binder<'a>>,
+        applications: &mut Vec<Application<'a>>,
+    ) {
+        let depth = scope.len();
+        scope.extend(binders);
+        collect_applications(child, var, scope, applications);
+        scope.truncate(depth);
+    }
+
+    let mut visit = |child: &'a Term| collect_applications(child, var, scope, applications);
+    match &term.kind {
+        TermKind::Apply { .. } => {
+            let mut args = Vec::new();
+            let mut head = term;
+            while let TermKind::Apply { func, arg } = &head.kind {
+                args.push(arg.as_ref());
+                head = func.as_ref();
+            }
+            args.reverse();
+
+            if matches!(&head.kind, TermKind::Var(name) if name == var) {
+                for arg in &args {
+                    collect_applications(arg, var, scope, applications);
+                }
+                applications.push(Application { scope: scope.clone(), args });
+            } else {
+                collect_applications(head, var, scope, applications);
+                for arg in args {
+                    collect_applications(arg, var, scope, applications);
+                }
+            }
+        }
+        TermKind::Var(_) | TermKind::Literal(_) | TermKind::Unit | TermKind::NewChannel { .. } => {}
+        TermKind::LetUnit { unit_term, body } => {
+            visit(unit_term);
+            visit(body);
+        }
+        TermKind::Tensor { left, right } => {
+            visit(left);
+            visit(right);
+        }
+        TermKind::LetTensor { tensor_term, left_var, right_var, body } => {
+            visit(tensor_term);
+            if left_var != var && right_var != var {
+                let binder = Binder::Tensor(left_var, right_var, tensor_term);
+                visit_under(body, var, vec![binder], scope, applications);
+            }
+        }
+        TermKind::Inl { value, .. }
+        | TermKind::Inr { value, .. }
+        | TermKind::Alloc { value }
+        | TermKind::Drop { value } => visit(value),
+        TermKind::Case { scrutinee, left_var, left_body, right_var, right_body } => {
+            visit(scrutinee);
+            if left_var != var {
+                let binder = Binder::Arm(left_var, scrutinee, true);
+                visit_under(left_body, var, vec![binder], scope, applications);
+            }
+            if right_var != var {
+                let binder = Binder::Arm(right_var, scrutinee, false);
+                visit_under(right_body, var, vec![binder], scope, applications);
+            }
+        }
+        TermKind::Lambda { param, param_type, body } => {
+            if param != var {
+                let binder = match param_type {
+                    Some(ty) => Binder::Typed(param, ty.clone()),
+                    None => Binder::Unknown(param),
+                };
+                visit_under(body, var, vec![binder], scope, applications);
+            }
+        }
+        TermKind::Let { var: bound, value, body } => {
+            visit(value);
+            if bound != var {
+                visit_under(body, var, vec![Binder::Let(bound, value)], scope, applications);
+            }
+        }
+        TermKind::Consume { resource } => visit(resource),
+        TermKind::Send { channel, value } => {
+            visit(channel);
+            visit(value);
+        }
+        TermKind::Receive { channel }
+        | TermKind::Select { channel, .. }
+        | TermKind::Close { channel } => visit(channel),
+        TermKind::Branch { channel, branches } => {
+            visit(channel);
+            for (_, branch) in branches {
+                visit(branch);
+            }
+        }
+        TermKind::Fork { session_type, client_var, server_var, body } => {
+            if client_var != var && server_var != var {
+                let binders = vec![
+                    Binder::Typed(client_var, TypeInner::Session(Box::new(session_type.clone()))),
+                    Binder::Typed(server_var, TypeInner::Session(Box::new(session_type.dual()))),
+                ];
+                visit_under(body, var, binders, scope, applications);
+            }
+        }
+        TermKind::Wait { channel, body } => {
+            visit(channel);
+            visit(body);
+        }
+        TermKind::Transform { body, .. } | TermKind::At { body, .. } => visit(body),
+        TermKind::ApplyTransform { transform, arg } => {
+            visit(transform);
+            visit(arg);
+        }
+    }
+}
+
 /// Get the type of a literal
 fn literal_type(lit: &Literal) -> TypeInner {
     match lit {
@@ -1065,6 +1501,244 @@ mod tests {
         );
     }
 
+    fn span_at(line: u32, column: u32) -> Span {
+        let position = |column| crate::effect::core::Position { line, column, offset: 0 };
+        Span { start: position(column), end: position(column + 10), file: None }
+    }
+
+    #[test]
+    fn test_unused_linear_let_reports_binding_span() {
+        let mut ctx = TypeContext::new();
+        let let_span = span_at(3, 5);
+
+        // let chan = new_channel (!Int.end) in ()
+        let term = Term::let_bind(
+            "chan",
+            Term::new_channel(SessionType::Send(
+                Box::new(TypeInner::Base(BaseType::Int)),
+                Box::new(SessionType::End),
+            )),
+            Term::unit(),
+        )
+        .with_span(let_span.clone());
+
+        let err = type_check(&mut ctx, &term).unwrap_err();
+        assert_eq!(
+            err,
+            TypeCheckError::Linearity(LinearityError::NeverConsumed {
+                resource: "chan".to_string(),
+                binding: Some(let_span),
+            })
+        );
+        assert_eq!(err.to_string(), "Linear resource never consumed: chan (bound at 3:5)");
+
+        // Using the binding satisfies the drop check
+        let term = Term::let_bind("f", Term::lambda("x", Term::var("x")), Term::var("f"));
+        assert!(type_check(&mut TypeContext::new(), &term).is_ok());
+    }
+
+    #[test]
+    fn test_let_checks_usage_when_its_scope_ends() {
+        let session = || SessionType::Send(
+            Box::new(TypeInner::Base(BaseType::Int)),
+            Box::new(SessionType::End),
+        );
+        let (outer_span, inner_span) = (span_at(1, 1), span_at(2, 3));
+
+        // let outer = new_channel in (let inner = new_channel in outer)
+        let term = Term::let_bind(
+            "outer",
+            Term::new_channel(session()),
+            Term::let_bind("inner", Term::new_channel(session()), Term::var("outer"))
+                .with_span(inner_span.clone()),
+        )
+        .with_span(outer_span);
+
+        // The inner let is reported, not whichever binding is found last
+        let err = type_check(&mut TypeContext::new(), &term).unwrap_err();
+        assert_eq!(
+            err,
+            TypeCheckError::Linearity(LinearityError::NeverConsumed {
+                resource: "inner".to_string(),
+                binding: Some(inner_span),
+            })
+        );
+
+        // A consumed binding goes out of scope and leaves nothing to check
+        let mut ctx = TypeContext::new();
+        let term = Term::let_bind("chan", Term::new_channel(session()), Term::var("chan"));
+        type_check(&mut ctx, &term).unwrap();
+        assert!(ctx.lookup_variable("chan").is_err());
+        assert!(ctx.check_linear_usage().is_ok());
+    }
+
+    #[test]
+    fn test_dropped_relevant_value_must_be_used() {
+        let mut ctx = TypeContext::new();
+        ctx.bind_relevant_variable("audit".to_string(), TypeInner::Base(BaseType::Int), Some(span_at(1, 1)))
+            .unwrap();
+
+        // Relevant values may be copied
+        type_check(&mut ctx, &Term::var("audit")).unwrap();
+        type_check(&mut ctx, &Term::var("audit")).unwrap();
+        assert!(ctx.drop_variable("audit").is_ok());
+
+        ctx.bind_relevant_variable("unused".to_string(), TypeInner::Base(BaseType::Int), Some(span_at(2, 7)))
+            .unwrap();
+        assert_eq!(
+            ctx.drop_variable("unused"),
+            Err(TypeCheckError::Linearity(LinearityError::MustUse {
+                resource: "unused".to_string(),
+                binding: Some(span_at(2, 7)),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_drop_accepts_only_droppable_values() {
+        // Dropping a linear channel is a type error
+        let channel = Term::new_channel(SessionType::End);
+        let err = type_check(&mut TypeContext::new(), &Term::drop_value(channel)).unwrap_err();
+        assert!(matches!(err, TypeCheckError::LinearityViolation(_)));
+
+        // Dropping an affine value is accepted and compiles to a Consume
+        let mut ctx = TypeContext::new();
+        ctx.bind_affine_variable("token".to_string(), TypeInner::Base(BaseType::Int), None)
+            .unwrap();
+        let drop = Term::drop_value(Term::var("token"));
+        assert_eq!(type_check(&mut ctx, &drop).unwrap(), TypeInner::Base(BaseType::Unit));
+        assert!(ctx.drop_variable("token").is_ok());
+
+        let instructions = crate::lambda::compile_term(&Term::drop_value(Term::literal(Literal::Int(1))))
+            .unwrap();
+        assert!(matches!(instructions.last(), Some(crate::machine::Instruction::Consume { .. })));
+
+        // A dropped affine binding may not be used again
+        let mut ctx = TypeContext::new();
+        ctx.bind_affine_variable("token".to_string(), TypeInner::Base(BaseType::Int), None)
+            .unwrap();
+        type_check(&mut ctx, &drop).unwrap();
+        assert!(type_check(&mut ctx, &Term::var("token")).is_err());
+    }
+
+    #[test]
+    fn test_infers_let_bound_identity_on_int() {
+        let mut ctx = TypeContext::new().with_inference();
+
+        // let id = \x. x in id 42
+        let term = Term::let_bind(
+            "id",
+            Term::lambda("x", Term::var("x")),
+            Term::apply(Term::var("id"), Term::literal(Literal::Int(42))),
+        );
+
+        assert_eq!(type_check(&mut ctx, &term).unwrap(), TypeInner::Base(BaseType::Int));
+    }
+
+    #[test]
+    fn test_infers_argument_bound_inside_let_body() {
+        let mut ctx = TypeContext::new().with_inference();
+
+        // let f = \x. x in let y = 1 in f y
+        let term = Term::let_bind(
+            "f",
+            Term::lambda("x", Term::var("x")),
+            Term::let_bind(
+                "y",
+                Term::literal(Literal::Int(1)),
+                Term::apply(Term::var("f"), Term::var("y")),
+            ),
+        );
+
+        assert_eq!(type_check(&mut ctx, &term).unwrap(), TypeInner::Base(BaseType::Int));
+    }
+
+    #[test]
+    fn test_inference_sees_shadowing_binder() {
+        let mut ctx = TypeContext::new().with_inference();
+        ctx.bind_variable("y".to_string(), TypeInner::Base(BaseType::Bool)).unwrap();
+
+        // y is Bool outside, but the application sees the inner Int binding
+        let term = Term::let_bind(
+            "f",
+            Term::lambda("x", Term::var("x")),
+            Term::let_bind(
+                "y",
+                Term::literal(Literal::Int(1)),
+                Term::apply(Term::var("f"), Term::var("y")),
+            ),
+        );
+
+        assert_eq!(type_check(&mut ctx, &term).unwrap(), TypeInner::Base(BaseType::Int));
+    }
+
+    #[test]
+    fn test_let_restores_shadowed_binding() {
+        let mut ctx = TypeContext::new();
+        ctx.bind_variable("y".to_string(), TypeInner::Base(BaseType::Bool)).unwrap();
+
+        // (let y = 1 in y) ⊗ y: the outer y is visible again after the let
+        let term = Term::tensor(
+            Term::let_bind("y", Term::literal(Literal::Int(1)), Term::var("y")),
+            Term::var("y"),
+        );
+
+        assert_eq!(
+            type_check(&mut ctx, &term).unwrap(),
+            TypeInner::Product(
+                Box::new(TypeInner::Base(BaseType::Int)),
+                Box::new(TypeInner::Base(BaseType::Bool)),
+            )
+        );
+        assert_eq!(ctx.lookup_variable("y").unwrap(), &TypeInner::Base(BaseType::Bool));
+    }
+
+    #[test]
+    fn test_ambiguous_lambda_requires_annotation() {
+        // Never applied: nothing to infer from
+        let unapplied = Term::let_bind("id", Term::lambda("x", Term::var("x")), Term::var("id"));
+        let err = type_check(&mut TypeContext::new().with_inference(), &unapplied).unwrap_err();
+        assert!(matches!(err, TypeCheckError::CannotInfer { ref param, .. } if param == "x"));
+
+        // Applied at two different types
+        let conflicting = Term::let_bind(
+            "id",
+            Term::lambda("x", Term::var("x")),
+            Term::tensor(
+                Term::apply(Term::var("id"), Term::literal(Literal::Int(1))),
+                Term::apply(Term::var("id"), Term::literal(Literal::Bool(true))),
+            ),
+        );
+        let err = type_check(&mut TypeContext::new().with_inference(), &conflicting).unwrap_err();
+        assert!(matches!(err, TypeCheckError::CannotInfer { ref param, .. } if param == "x"));
+
+        // Partial application leaves the second parameter unconstrained
+        let partial = Term::let_bind(
+            "const",
+            Term::lambda("x", Term::lambda("y", Term::var("x"))),
+            Term::apply(Term::var("const"), Term::literal(Literal::Int(1))),
+        );
+        let err = type_check(&mut TypeContext::new().with_inference(), &partial).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cannot infer type of parameter 'y': no application of 'const' supplies this argument"
+        );
+
+        // Full application of the same function infers both parameters
+        let full = Term::let_bind(
+            "const",
+            Term::lambda("x", Term::lambda("y", Term::var("x"))),
+            Term::apply(
+                Term::apply(Term::var("const"), Term::literal(Literal::Int(1))),
+                Term::literal(Literal::Bool(false)),
+            ),
+        );
+        assert_eq!(
+            type_check(&mut TypeContext::new().with_inference(), &full).unwrap(),
+            TypeInner::Base(BaseType::Int)
+        );
+    }
+
     #[test]
     fn test_tensor_types() {
         let mut ctx = TypeContext::new();
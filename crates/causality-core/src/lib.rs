@@ -129,13 +129,13 @@ pub use lambda::{
 // Layer 0: Register Machine components
 pub use machine::{
     instruction::{Instruction, RegisterId, Label},
-    value::{MachineValue, SessionChannel, ChannelState},
+    value::{MachineValue, SessionChannel, ChannelState, ChannelOp},
     reduction::{MachineState, ExecutionTrace, TraceStep, MachineStateSnapshot},
-    register_file::{RegisterFile, RegisterFileError, RegisterFileSnapshot},
+    register_file::{RegisterFile, RegisterFileError, RegisterFileSnapshot, RegisterFileDelta, RegisterChange},
     bounded_execution::{BoundedExecutor, BoundedExecutionError, ExecutionResult, ExecutionState},
     resource::{
-        Resource, ResourceManager, ResourceError, Nullifier, NullifierSet, ConsumptionResult,
-        DependencyType, ResourceDependency,
+        Resource, ResourceManager, ResourceError, Nullifier, NullifierSet, ConsumptionResult, ConsumptionSite,
+        DependencyType, ResourceDependency, Lease,
     },
     metering::{GasMeter, GasError, InstructionCosts},
 };
@@ -18,9 +18,11 @@ use crate::machine::{
     register_file::{RegisterFile, RegisterFileError, MAX_REGISTERS},
     resource::ResourceStore,
     reduction::{ExecutionTrace, TraceStep},
+    value::MachineValue,
 };
 use crate::system::deterministic::DeterministicSystem;
 use serde::{Serialize, Deserialize};
+use std::collections::BTreeSet;
 
 //-----------------------------------------------------------------------------
 // Execution Bounds Configuration
@@ -166,8 +168,50 @@ impl BoundedExecutor {
         }
     }
     
+    /// Execute the next instruction and return its trace step
+    ///
+    /// The step records the values of the registers the instruction read and
+    /// the registers it wrote, so the register file delta can be replayed
+    /// without snapshotting the whole `RegisterFile`. A register cleared by
+    /// the instruction (e.g. the source of a `Consume`) is recorded as written
+    /// with `MachineValue::Unit`. The executor state after the step is stored
+    /// in `resulting_state`.
+    pub fn step_with_trace(&mut self) -> Result<TraceStep, BoundedExecutionError> {
+        if self.has_error || self.is_complete {
+            return Err(BoundedExecutionError::ExecutionHalted);
+        }
+        
+        if self.execution_steps >= MAX_EXECUTION_STEPS {
+            return Err(BoundedExecutionError::ExecutionLimitExceeded);
+        }
+        
+        if self.program_counter >= self.program.len() {
+            self.is_complete = true;
+            return Err(BoundedExecutionError::ExecutionHalted);
+        }
+        
+        let instruction = self.program[self.program_counter].clone();
+        let mut step = match self.execute_instruction(instruction) {
+            Ok(step) => step,
+            Err(e) => {
+                self.has_error = true;
+                self.error_message = Some(e.to_string());
+                return Err(e);
+            }
+        };
+        
+        self.program_counter += 1;
+        self.execution_steps += 1;
+        if self.program_counter >= self.program.len() {
+            self.is_complete = true;
+        }
+        
+        step.resulting_state = Some(self.execution_state());
+        Ok(step)
+    }
+    
     /// Execute a single instruction with bounds checking
-    fn execute_instruction(&mut self, instruction: Instruction) -> Result<(), BoundedExecutionError> {
+    fn execute_instruction(&mut self, instruction: Instruction) -> Result<TraceStep, BoundedExecutionError> {
         // Capture current state for validation
         let prev_state = self.execution_state();
         
@@ -175,12 +219,21 @@ impl BoundedExecutor {
         self.validate_transition(&prev_state, &instruction)?;
         
         // Record the instruction execution in the trace
-        let step = TraceStep::new(
+        let mut step = TraceStep::new(
             self.execution_steps as u64,
             self.deterministic_system.current_time(),
             instruction.clone(),
         );
         
+        // Capture every register the instruction touches so the delta can be recorded
+        let reads = instruction.reads();
+        let writes = instruction.writes();
+        let touched: BTreeSet<RegisterId> = reads.iter().chain(writes.iter()).copied().collect();
+        let mut before = Vec::with_capacity(touched.len());
+        for reg in &touched {
+            before.push((*reg, self.register_file.read_register(*reg)?));
+        }
+        
         // Execute the instruction based on its type (immutable - creates new state)
         match instruction {
             Instruction::Transform { morph_reg, input_reg, output_reg } => {
@@ -203,10 +256,23 @@ impl BoundedExecutor {
         // Verify state consistency after execution
         self.verify_state_consistency()?;
         
+        // Record the values read and the registers written or cleared
+        for (reg, old_value) in before {
+            if let Some(resource_id) = old_value.filter(|_| reads.contains(&reg)) {
+                step.registers_read.push((reg, MachineValue::ResourceRef(resource_id)));
+            }
+            
+            let new_value = self.register_file.read_register(reg)?;
+            if writes.contains(&reg) || new_value != old_value {
+                let value = new_value.map_or(MachineValue::Unit, MachineValue::ResourceRef);
+                step.registers_written.push((reg, value));
+            }
+        }
+        
         // Add the completed step to the trace
-        self.execution_trace.add_step(step);
+        self.execution_trace.add_step(step.clone());
         
-        Ok(())
+        Ok(step)
     }
     
     /// Execute a Transform instruction
@@ -328,9 +394,10 @@ impl BoundedExecutor {
             return Err(BoundedExecutionError::EmptyRegister(resource_reg));
         }
         
-        // Consume the resource and generate nullifier
+        // Consume the resource and generate nullifier, reporting any earlier
+        // consumption of the same resource against this instruction
         if let Some(resource_id) = resource {
-            self.resource_store.consume_resource(resource_id);
+            self.resource_store.consume_at(resource_id, Some(self.program_counter))?;
             
             // Clear the source register
             self.register_file.write_register(resource_reg, None)?;
@@ -662,6 +729,9 @@ pub enum BoundedExecutionError {
     
     /// Execution step limit exceeded
     ExecutionLimitExceeded,
+    
+    /// Execution already completed or failed
+    ExecutionHalted,
 }
 
 impl From<RegisterFileError> for BoundedExecutionError {
@@ -700,6 +770,9 @@ impl std::fmt::Display for BoundedExecutionError {
             BoundedExecutionError::ExecutionLimitExceeded => {
                 write!(f, "Execution limit exceeded (max: {} steps)", MAX_EXECUTION_STEPS)
             }
+            BoundedExecutionError::ExecutionHalted => {
+                write!(f, "Execution has already halted")
+            }
         }
     }
 }
@@ -756,4 +829,62 @@ mod tests {
         let result = BoundedExecutor::new(program);
         assert!(matches!(result, Err(BoundedExecutionError::InvalidInstruction(_, _))));
     }
+    
+    #[test]
+    fn test_step_with_trace_records_register_delta() {
+        let program = vec![
+            Instruction::Alloc {
+                type_reg: RegisterId::new(0),
+                init_reg: RegisterId::new(1),
+                output_reg: RegisterId::new(2),
+            },
+            Instruction::Consume {
+                resource_reg: RegisterId::new(2),
+                output_reg: RegisterId::new(3),
+            },
+        ];
+        
+        let mut executor = BoundedExecutor::new(program).unwrap();
+        for _ in 0..4 {
+            executor.register_file.allocate_register(&mut executor.deterministic_system).unwrap();
+        }
+        let type_id = executor.resource_store.create_resource();
+        let init_id = executor.resource_store.create_resource();
+        executor.register_file.write_register(RegisterId::new(0), Some(type_id)).unwrap();
+        executor.register_file.write_register(RegisterId::new(1), Some(init_id)).unwrap();
+        
+        // Mirror of the register file, maintained only from the recorded deltas
+        let mut registers = executor.register_file.snapshot().register_contents;
+        
+        let alloc = executor.step_with_trace().unwrap();
+        assert_eq!(alloc.registers_read, vec![
+            (RegisterId::new(0), MachineValue::ResourceRef(type_id)),
+            (RegisterId::new(1), MachineValue::ResourceRef(init_id)),
+        ]);
+        assert_eq!(alloc.registers_written.len(), 1);
+        assert_eq!(alloc.registers_written[0].0, RegisterId::new(2));
+        let state = alloc.resulting_state.as_ref().unwrap();
+        assert_eq!(state.program_counter, 1);
+        assert!(!state.is_complete);
+        
+        let consume = executor.step_with_trace().unwrap();
+        assert_eq!(consume.registers_read, vec![(RegisterId::new(2), alloc.registers_written[0].1.clone())]);
+        assert_eq!(consume.registers_written[0], (RegisterId::new(2), MachineValue::Unit));
+        assert_eq!(consume.registers_written[1].0, RegisterId::new(3));
+        let state = consume.resulting_state.as_ref().unwrap();
+        assert_eq!(state.execution_steps, 2);
+        assert!(state.is_complete);
+        
+        for step in [&alloc, &consume] {
+            for (reg, value) in &step.registers_written {
+                registers[reg.id() as usize] = match value {
+                    MachineValue::ResourceRef(id) => Some(*id),
+                    _ => None,
+                };
+            }
+        }
+        assert_eq!(registers, executor.register_file.snapshot().register_contents);
+        
+        assert_eq!(executor.step_with_trace().unwrap_err(), BoundedExecutionError::ExecutionHalted);
+    }
 } 
\ No newline at end of file
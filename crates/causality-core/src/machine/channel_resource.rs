@@ -9,7 +9,7 @@ use crate::{
     machine::{
         instruction::{Instruction, RegisterId},
         resource::{ResourceId, ResourceManager, ResourceError},
-        value::{MachineValue, SessionChannel},
+        value::{MachineValue, SessionChannel, ChannelOp},
         register_file::{RegisterFile, RegisterFileError},
     },
     system::deterministic::DeterministicSystem,
@@ -137,11 +137,15 @@ impl ChannelResourceManager {
         let channel_resource = self.resource_manager.peek(&channel_resource_id)
             .map_err(ChannelResourceError::ResourceError)?;
         
-        if !matches!(channel_resource, MachineValue::Channel(_)) {
+        let MachineValue::Channel(channel) = channel_resource else {
             return Err(ChannelResourceError::SessionTypeMismatch(
                 "Resource is not a channel".to_string()
             ));
-        }
+        };
+        
+        // Reject protocol violations before the transform mutates the channel
+        channel.check_transition(ChannelOp::Send)
+            .map_err(|err| ChannelResourceError::SessionTypeMismatch(err.to_string()))?;
         
         // Allocate a register for the result (updated channel)
         let result_register = self.register_file.allocate_register(det_sys)
@@ -181,11 +185,15 @@ impl ChannelResourceManager {
         let channel_resource = self.resource_manager.peek(&channel_resource_id)
             .map_err(ChannelResourceError::ResourceError)?;
         
-        if !matches!(channel_resource, MachineValue::Channel(_)) {
+        let MachineValue::Channel(channel) = channel_resource else {
             return Err(ChannelResourceError::SessionTypeMismatch(
                 "Resource is not a channel".to_string()
             ));
-        }
+        };
+        
+        // Reject protocol violations before the transform mutates the channel
+        channel.check_transition(ChannelOp::Receive)
+            .map_err(|err| ChannelResourceError::SessionTypeMismatch(err.to_string()))?;
         
         // Allocate registers for the received value and updated channel
         let value_register = self.register_file.allocate_register(det_sys)
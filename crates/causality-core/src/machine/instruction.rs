@@ -25,6 +25,7 @@
 //! - Symmetry between computation and communication
 
 use serde::{Serialize, Deserialize};
+use ssz::{Encode, Decode, DecodeError};
 
 //-----------------------------------------------------------------------------
 // Register Identifiers
@@ -182,4 +183,83 @@ impl Instruction {
             Instruction::Tensor { output_reg, .. } => vec![*output_reg],
         }
     }
-} 
\ No newline at end of file
+}
+
+//-----------------------------------------------------------------------------
+// SSZ Serialization
+//-----------------------------------------------------------------------------
+
+// Each instruction encodes as its variant byte followed by its registers in
+// field order, as little-endian u32s
+impl Instruction {
+    /// Variant byte and registers in field order, without allocating
+    fn ssz_parts(&self) -> (u8, [RegisterId; 3], usize) {
+        match self {
+            Instruction::Transform { morph_reg, input_reg, output_reg } => (0, [*morph_reg, *input_reg, *output_reg], 3),
+            Instruction::Alloc { type_reg, init_reg, output_reg } => (1, [*type_reg, *init_reg, *output_reg], 3),
+            Instruction::Consume { resource_reg, output_reg } => (2, [*resource_reg, *output_reg, RegisterId(0)], 2),
+            Instruction::Compose { first_reg, second_reg, output_reg } => (3, [*first_reg, *second_reg, *output_reg], 3),
+            Instruction::Tensor { left_reg, right_reg, output_reg } => (4, [*left_reg, *right_reg, *output_reg], 3),
+        }
+    }
+}
+
+impl Encode for Instruction {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        let (_, _, register_count) = self.ssz_parts();
+        1 + 4 * register_count
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        use crate::system::encode_enum_variant;
+        
+        let (variant, regs, register_count) = self.ssz_parts();
+        encode_enum_variant(variant, buf);
+        for reg in &regs[..register_count] {
+            buf.extend_from_slice(&reg.0.to_le_bytes());
+        }
+    }
+}
+
+impl Decode for Instruction {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        use crate::system::{decode_enum_variant, decode_fixed_bytes};
+        
+        let (variant, data) = decode_enum_variant(bytes)?;
+        let register_count = match variant {
+            2 => 2,
+            0 | 1 | 3 | 4 => 3,
+            _ => return Err(DecodeError::BytesInvalid(
+                format!("Invalid Instruction variant: {}", variant)
+            )),
+        };
+        
+        if data.len() != 4 * register_count {
+            return Err(DecodeError::InvalidByteLength {
+                len: data.len(),
+                expected: 4 * register_count,
+            });
+        }
+        
+        let mut regs = [RegisterId(0); 3];
+        for (reg, chunk) in regs.iter_mut().zip(data.chunks_exact(4)) {
+            *reg = RegisterId(u32::from_le_bytes(decode_fixed_bytes(chunk)?));
+        }
+        
+        Ok(match variant {
+            0 => Instruction::Transform { morph_reg: regs[0], input_reg: regs[1], output_reg: regs[2] },
+            1 => Instruction::Alloc { type_reg: regs[0], init_reg: regs[1], output_reg: regs[2] },
+            2 => Instruction::Consume { resource_reg: regs[0], output_reg: regs[1] },
+            3 => Instruction::Compose { first_reg: regs[0], second_reg: regs[1], output_reg: regs[2] },
+            _ => Instruction::Tensor { left_reg: regs[0], right_reg: regs[1], output_reg: regs[2] },
+        })
+    }
+}
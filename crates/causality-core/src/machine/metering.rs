@@ -50,6 +50,50 @@ impl Default for InstructionCosts {
     }
 }
 
+impl InstructionCosts {
+    /// Instruction names accepted in a cost table
+    pub const INSTRUCTION_NAMES: [&'static str; 5] = ["Transform", "Alloc", "Consume", "Compose", "Tensor"];
+    
+    /// Build costs from a table mapping instruction names to costs
+    ///
+    /// Every one of the five instructions must be priced; names are matched
+    /// case-insensitively. This lets each target chain supply its own pricing.
+    pub fn from_table<K: AsRef<str>>(table: impl IntoIterator<Item = (K, u64)>) -> Result<Self, GasError> {
+        let mut costs: [Option<u64>; 5] = [None; 5];
+        
+        for (name, cost) in table {
+            let name = name.as_ref();
+            let index = Self::INSTRUCTION_NAMES.iter()
+                .position(|known| known.eq_ignore_ascii_case(name))
+                .ok_or_else(|| GasError::InvalidCostTable(format!("unknown instruction '{}'", name)))?;
+            costs[index] = Some(cost);
+        }
+        
+        let cost = |index: usize| costs[index].ok_or_else(|| GasError::InvalidCostTable(
+            format!("missing cost for '{}'", Self::INSTRUCTION_NAMES[index])
+        ));
+        
+        Ok(Self {
+            transform_cost: cost(0)?,
+            alloc_cost: cost(1)?,
+            consume_cost: cost(2)?,
+            compose_cost: cost(3)?,
+            tensor_cost: cost(4)?,
+        })
+    }
+    
+    /// Cost of a single instruction under this table
+    pub fn cost_of(&self, instruction: &Instruction) -> u64 {
+        match instruction {
+            Instruction::Transform { .. } => self.transform_cost,
+            Instruction::Alloc { .. } => self.alloc_cost,
+            Instruction::Consume { .. } => self.consume_cost,
+            Instruction::Compose { .. } => self.compose_cost,
+            Instruction::Tensor { .. } => self.tensor_cost,
+        }
+    }
+}
+
 impl GasMeter {
     /// Create a new gas meter with the given limit
     pub fn new(gas_limit: u64) -> Self {
@@ -92,13 +136,7 @@ impl GasMeter {
     
     /// Get the gas cost for an instruction
     pub fn instruction_cost(&self, instruction: &Instruction) -> u64 {
-        match instruction {
-            Instruction::Transform { .. } => self.instruction_costs.transform_cost,
-            Instruction::Alloc { .. } => self.instruction_costs.alloc_cost,
-            Instruction::Consume { .. } => self.instruction_costs.consume_cost,
-            Instruction::Compose { .. } => self.instruction_costs.compose_cost,
-            Instruction::Tensor { .. } => self.instruction_costs.tensor_cost,
-        }
+        self.instruction_costs.cost_of(instruction)
     }
     
     /// Get remaining gas
@@ -142,6 +180,9 @@ pub enum GasError {
         limit: u64,
         used: u64,
     },
+    
+    /// Cost table is missing an instruction or names an unknown one
+    InvalidCostTable(String),
 }
 
 impl std::fmt::Display for GasError {
@@ -153,6 +194,9 @@ impl std::fmt::Display for GasError {
             GasError::GasLimitExceeded { limit, used } => {
                 write!(f, "Gas limit exceeded: limit {}, used {}", limit, used)
             }
+            GasError::InvalidCostTable(reason) => {
+                write!(f, "Invalid cost table: {}", reason)
+            }
         }
     }
 }
@@ -324,4 +368,47 @@ mod tests {
         // Consume is cheaper
         assert_eq!(meter.resource_operation_cost(ResourceOperation::Consume, 100), 3); // 2 + 1
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_cost_tables_price_same_stream_differently() {
+        let instructions = vec![
+            Instruction::Alloc {
+                type_reg: RegisterId::new(1),
+                init_reg: RegisterId::new(2),
+                output_reg: RegisterId::new(3),
+            },
+            Instruction::Transform {
+                morph_reg: RegisterId::new(3),
+                input_reg: RegisterId::new(4),
+                output_reg: RegisterId::new(5),
+            },
+            Instruction::Consume {
+                resource_reg: RegisterId::new(5),
+                output_reg: RegisterId::new(6),
+            },
+        ];
+        
+        let storage_heavy = InstructionCosts::from_table([
+            ("Transform", 3), ("Alloc", 20_000), ("Consume", 5_000), ("Compose", 3), ("Tensor", 3),
+        ]).unwrap();
+        let compute_heavy = InstructionCosts::from_table([
+            ("transform", 150), ("alloc", 50), ("consume", 50), ("compose", 100), ("tensor", 100),
+        ]).unwrap();
+        
+        let storage_total = GasMeter::with_costs(u64::MAX, storage_heavy).estimate_gas(&instructions);
+        let compute_total = GasMeter::with_costs(u64::MAX, compute_heavy).estimate_gas(&instructions);
+        assert_eq!(storage_total, 25_003);
+        assert_eq!(compute_total, 250);
+    }
+    
+    #[test]
+    fn test_cost_table_must_price_every_instruction() {
+        let missing = InstructionCosts::from_table([("Transform", 1), ("Alloc", 1)]);
+        assert!(matches!(missing, Err(GasError::InvalidCostTable(_))));
+        
+        let unknown = InstructionCosts::from_table([
+            ("Transform", 1), ("Alloc", 1), ("Consume", 1), ("Compose", 1), ("Tensor", 1), ("Jump", 1),
+        ]);
+        assert!(matches!(unknown, Err(GasError::InvalidCostTable(_))));
+    }
+}
@@ -16,9 +16,9 @@ pub mod pattern;
 // Re-export key types
 pub use instruction::{Instruction, Label, RegisterId};
 pub use reduction::MachineState;
-pub use value::{MachineValue, SessionChannel, ChannelState};
+pub use value::{MachineValue, SessionChannel, ChannelState, ChannelOp};
 pub use resource::Resource;
-pub use register_file::{RegisterFile, RegisterFileError};
+pub use register_file::{RegisterFile, RegisterFileError, RegisterFileDelta, RegisterChange};
 pub use bounded_execution::{BoundedExecutor, BoundedExecutionError, ExecutionResult};
 pub use metering::{GasMeter, GasError, InstructionCosts};
 pub use pattern::{Pattern, LiteralValue};
@@ -8,11 +8,13 @@ use crate::{
     lambda::base::{TypeInner, Location},
     machine::{
         instruction::{Instruction, RegisterId, Label},
-        value::{MachineValue, SessionChannel, ChannelState},
+        value::{MachineValue, SessionChannel, ChannelState, append_prefixed, prefixed_len},
         resource::{ResourceId, Nullifier},
+        bounded_execution::ExecutionState,
     },
 };
 use serde::{Serialize, Deserialize};
+use ssz::Encode;
 use std::collections::{BTreeMap, BTreeSet};
 use sha2::{Sha256, Digest};
 
@@ -39,6 +41,10 @@ pub struct TraceStep {
     
     /// Resources consumed during this step
     pub resources_consumed: Vec<(ResourceId, MachineValue)>,
+    
+    /// Executor state after this step, when recorded by a stepping debugger
+    #[serde(default)]
+    pub resulting_state: Option<ExecutionState>,
 }
 
 impl TraceStep {
@@ -52,6 +58,7 @@ impl TraceStep {
             registers_written: Vec::new(),
             resources_allocated: Vec::new(),
             resources_consumed: Vec::new(),
+            resulting_state: None,
         }
     }
 }
@@ -135,7 +142,7 @@ impl Default for ExecutionTrace {
 }
 
 /// Snapshot of machine state at a point in time
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MachineStateSnapshot {
     /// Register file contents
     pub registers: BTreeMap<RegisterId, MachineValue>,
@@ -150,6 +157,36 @@ pub struct MachineStateSnapshot {
     pub lamport_clock: u64,
 }
 
+// Encoding only, so a snapshot can be content-addressed with
+// `EntityId::from_content`; entries are written in map order
+impl Encode for MachineStateSnapshot {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        4 + self.registers.values().map(|value| 4 + prefixed_len(value)).sum::<usize>()
+            + 4 + self.resources.values().map(|value| 32 + prefixed_len(value)).sum::<usize>()
+            + 8
+            + 8
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        (self.registers.len() as u32).ssz_append(buf);
+        for (register, value) in &self.registers {
+            buf.extend_from_slice(&register.0.to_le_bytes());
+            append_prefixed(value, buf);
+        }
+        (self.resources.len() as u32).ssz_append(buf);
+        for (resource, value) in &self.resources {
+            resource.0.ssz_append(buf);
+            append_prefixed(value, buf);
+        }
+        (self.instruction_pointer as u64).ssz_append(buf);
+        self.lamport_clock.ssz_append(buf);
+    }
+}
+
 /// Machine state for executing the minimal instruction set
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MachineState {
@@ -309,6 +346,7 @@ impl MachineState {
             registers_written: Vec::new(),
             resources_allocated: Vec::new(),
             resources_consumed: Vec::new(),
+            resulting_state: None,
         };
         
         // Execute the instruction and record operations
@@ -468,6 +506,10 @@ impl MachineState {
     }
     
     /// Execute function with given parameters and body
+    ///
+    /// A self tail call (see [`Self::take_self_tail_call`]) does not nest a
+    /// new frame: the parameter is rebound and the body restarts in the
+    /// current one, so tail-recursive loops run in constant space.
     fn execute_function(&mut self, params: Vec<RegisterId>, body: Vec<Instruction>, 
                        captured_env: BTreeMap<RegisterId, MachineValue>, input: MachineValue) -> Result<MachineValue, String> {
         // Save current state
@@ -475,28 +517,42 @@ impl MachineState {
         let saved_ip = self.instruction_pointer;
         let saved_instructions = self.instructions.clone();
         
-        // Set up function environment
-        // Bind captured environment
-        for (reg_id, value) in captured_env {
-            self.registers.insert(reg_id, value);
-        }
-        
-        // Bind parameters to input value
-        if let Some(param_reg) = params.first() {
-            self.registers.insert(*param_reg, input);
-        }
-        
-        // Execute function body
+        let function = MachineValue::Function {
+            params: params.clone(),
+            body: body.clone(),
+            captured_env: captured_env.clone(),
+        };
         self.instructions = body;
-        self.instruction_pointer = 0;
-        self.finished = false;
         
-        // Run until completion or error
-        while !self.finished && self.error.is_none() {
-            if let Err(e) = self.step() {
-                self.error = Some(e);
-                break;
+        let mut input = input;
+        'frame: loop {
+            // Set up function environment
+            // Bind captured environment
+            for (reg_id, value) in &captured_env {
+                self.registers.insert(*reg_id, value.clone());
             }
+            
+            // Bind parameters to input value
+            if let Some(param_reg) = params.first() {
+                self.registers.insert(*param_reg, input);
+            }
+            
+            // Execute function body
+            self.instruction_pointer = 0;
+            self.finished = false;
+            
+            // Run until completion or error
+            while !self.finished && self.error.is_none() {
+                if let Some(argument) = self.take_self_tail_call(&function) {
+                    input = argument;
+                    continue 'frame;
+                }
+                if let Err(e) = self.step() {
+                    self.error = Some(e);
+                    break;
+                }
+            }
+            break;
         }
         
         // Get result (assume it's in the last register used)
@@ -516,6 +572,51 @@ impl MachineState {
         Ok(result)
     }
     
+    /// Take the argument of a self tail call about to be executed, if any
+    ///
+    /// A self tail call is a `Transform` that is the last instruction of the
+    /// running function's body and whose morphism resolves, for its input, to
+    /// that same function. Its input register is consumed as for any
+    /// `Transform`.
+    fn take_self_tail_call(&mut self, function: &MachineValue) -> Option<MachineValue> {
+        if self.instruction_pointer + 1 != self.instructions.len() {
+            return None;
+        }
+        let Instruction::Transform { morph_reg, input_reg, .. } = self.instructions[self.instruction_pointer] else {
+            return None;
+        };
+        
+        let morphism = self.registers.get(&morph_reg)?;
+        let input = self.registers.get(&input_reg)?;
+        let (target, argument) = self.resolve_morphism(morphism, input)?;
+        if target != function {
+            return None;
+        }
+        
+        let argument = argument.clone();
+        self.registers.remove(&input_reg);
+        self.lamport_clock += 1;
+        Some(argument)
+    }
+    
+    /// The morphism `morphism` applies to `input` and the value it receives,
+    /// following morphism references and copairings
+    fn resolve_morphism<'a>(&'a self, morphism: &'a MachineValue, input: &'a MachineValue) -> Option<(&'a MachineValue, &'a MachineValue)> {
+        match (morphism, input) {
+            (MachineValue::MorphismRef(reg_id), _) => {
+                self.resolve_morphism(self.registers.get(reg_id)?, input)
+            }
+            (MachineValue::Product(left, right), MachineValue::Sum { tag, value }) => {
+                match tag.as_str() {
+                    "0" => self.resolve_morphism(left, value),
+                    "1" => self.resolve_morphism(right, value),
+                    _ => None,
+                }
+            }
+            _ => Some((morphism, input)),
+        }
+    }
+    
     /// Apply a morphism to an input value
     fn apply_morphism(&mut self, morphism: MachineValue, input: MachineValue) -> Result<MachineValue, String> {
         match morphism {
@@ -531,6 +632,16 @@ impl MachineState {
                     Err(format!("Morphism not found in register {:?}", reg_id))
                 }
             }
+            MachineValue::Product(left, right) => match input {
+                // Copairing [left, right]: case analysis on the sum's tag
+                MachineValue::Sum { tag, value } => match tag.as_str() {
+                    "0" => self.apply_morphism(*left, *value),
+                    "1" => self.apply_morphism(*right, *value),
+                    _ => Err(format!("Copairing requires a sum tagged 0 or 1, got {}", tag)),
+                },
+                // Like other non-morphism values, a pair is the identity elsewhere
+                other => Ok(other),
+            },
             MachineValue::Symbol(name) => {
                 // Built-in morphisms by name
                 match name.as_str() {
@@ -543,6 +654,18 @@ impl MachineState {
                         MachineValue::Int(i) => Ok(MachineValue::Int(i + 1)),
                         _ => Err("Increment morphism requires integer input".to_string()),
                     },
+                    // Predecessor as a case split: 0 is the left unit, n the right n - 1
+                    "pred" => match input {
+                        MachineValue::Int(0) => Ok(MachineValue::Sum {
+                            tag: "0".into(),
+                            value: Box::new(MachineValue::Unit),
+                        }),
+                        MachineValue::Int(i) => Ok(MachineValue::Sum {
+                            tag: "1".into(),
+                            value: Box::new(MachineValue::Int(i - 1)),
+                        }),
+                        _ => Err("Pred morphism requires integer input".to_string()),
+                    },
                     _ => Err(format!("Unknown built-in morphism: {}", name)),
                 }
             }
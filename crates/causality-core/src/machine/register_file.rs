@@ -13,6 +13,7 @@
 use crate::{
     machine::instruction::RegisterId,
     machine::resource::ResourceId,
+    machine::value::MachineValue,
     system::deterministic::DeterministicSystem,
 };
 use serde::{Serialize, Deserialize};
@@ -178,6 +179,32 @@ impl RegisterFile {
         }
     }
     
+    /// Compute the changes made since `prior` was taken
+    ///
+    /// Only registers whose contents or allocation state differ are listed,
+    /// so the delta stays small however large the register file is.
+    pub fn diff(&self, prior: &RegisterFileSnapshot) -> RegisterFileDelta {
+        let changes = (0..MAX_REGISTERS)
+            .filter(|&i| self.registers[i] != prior.register_contents[i])
+            .map(|i| RegisterChange {
+                register: RegisterId::new(i as u32),
+                old_value: prior.register_contents[i].map(MachineValue::ResourceRef),
+                new_value: self.registers[i].map(MachineValue::ResourceRef),
+            })
+            .collect();
+        
+        RegisterFileDelta {
+            changes,
+            allocated: self.allocated_registers.difference(&prior.allocated_registers)
+                .map(|&id| RegisterId::new(id))
+                .collect(),
+            freed: prior.allocated_registers.difference(&self.allocated_registers)
+                .map(|&id| RegisterId::new(id))
+                .collect(),
+            next_register_id: self.next_register_id,
+        }
+    }
+    
     /// Restore register file from a snapshot
     pub fn restore_from_snapshot(&mut self, snapshot: RegisterFileSnapshot) {
         self.registers = snapshot.register_contents;
@@ -397,6 +424,62 @@ pub struct RegisterFileSnapshot {
     pub next_register_id: u32,
 }
 
+//-----------------------------------------------------------------------------
+// Register File Delta
+//-----------------------------------------------------------------------------
+
+/// Change to a single register between two register file states
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegisterChange {
+    /// Register that changed
+    pub register: RegisterId,
+    
+    /// Contents before the change (`None` if empty)
+    pub old_value: Option<MachineValue>,
+    
+    /// Contents after the change (`None` if empty)
+    pub new_value: Option<MachineValue>,
+}
+
+/// Compact difference between two register file states
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegisterFileDelta {
+    /// Registers whose contents changed, in register order
+    pub changes: Vec<RegisterChange>,
+    
+    /// Registers allocated since the prior state
+    pub allocated: Vec<RegisterId>,
+    
+    /// Registers freed since the prior state
+    pub freed: Vec<RegisterId>,
+    
+    /// Next register ID counter after the change
+    pub next_register_id: u32,
+}
+
+impl RegisterFileDelta {
+    /// Check if the delta changes no register
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.allocated.is_empty() && self.freed.is_empty()
+    }
+    
+    /// Apply the delta to the snapshot it was computed against
+    pub fn apply(&self, snapshot: &mut RegisterFileSnapshot) {
+        for change in &self.changes {
+            snapshot.register_contents[change.register.id() as usize] = match &change.new_value {
+                Some(MachineValue::ResourceRef(resource_id)) => Some(*resource_id),
+                _ => None,
+            };
+        }
+        
+        for register in &self.freed {
+            snapshot.allocated_registers.remove(&register.id());
+        }
+        snapshot.allocated_registers.extend(self.allocated.iter().map(RegisterId::id));
+        snapshot.next_register_id = self.next_register_id;
+    }
+}
+
 //-----------------------------------------------------------------------------
 // Error Types
 //-----------------------------------------------------------------------------
@@ -518,6 +601,42 @@ mod tests {
             Err(RegisterFileError::RegisterNotAllocated(_))
         ));
     }
+    
+    #[test]
+    fn test_diff_applies_to_prior_snapshot() {
+        let mut register_file = RegisterFile::new();
+        let mut det_sys = DeterministicSystem::new();
+        
+        let kept = register_file.allocate_register(&mut det_sys).unwrap();
+        let overwritten = register_file.allocate_register(&mut det_sys).unwrap();
+        let freed = register_file.allocate_register(&mut det_sys).unwrap();
+        register_file.write_register(kept, Some(ResourceId::new(1))).unwrap();
+        register_file.write_register(overwritten, Some(ResourceId::new(2))).unwrap();
+        register_file.write_register(freed, Some(ResourceId::new(3))).unwrap();
+        
+        let prior = register_file.snapshot();
+        assert!(register_file.diff(&prior).is_empty());
+        
+        register_file.write_register(overwritten, Some(ResourceId::new(4))).unwrap();
+        let added = register_file.allocate_register(&mut det_sys).unwrap();
+        register_file.write_register(added, Some(ResourceId::new(5))).unwrap();
+        register_file.free_register(freed).unwrap();
+        
+        let delta = register_file.diff(&prior);
+        let changed: Vec<_> = delta.changes.iter().map(|change| change.register).collect();
+        assert_eq!(changed, vec![overwritten, freed, added]);
+        assert_eq!(delta.allocated, vec![added]);
+        assert_eq!(delta.freed, vec![freed]);
+        assert_eq!(delta.changes.iter().find(|change| change.register == overwritten).unwrap().old_value,
+            Some(MachineValue::ResourceRef(ResourceId::new(2))));
+        
+        let mut replayed = prior.clone();
+        delta.apply(&mut replayed);
+        let current = register_file.snapshot();
+        assert_eq!(replayed.register_contents, current.register_contents);
+        assert_eq!(replayed.allocated_registers, current.allocated_registers);
+        assert_eq!(replayed.next_register_id, current.next_register_id);
+    }
 }
 
 /// Register usage statistics for optimization
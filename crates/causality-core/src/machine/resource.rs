@@ -19,6 +19,8 @@ use crate::{
 };
 use serde::{Serialize, Deserialize};
 use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use ssz::{Encode, Decode};
 use sha2::{Sha256, Digest};
 
@@ -94,6 +96,16 @@ pub struct ConsumptionResult {
     pub consumed_at: u64,
 }
 
+/// Where a resource was consumed, kept to explain later double-spends
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsumptionSite {
+    /// Nullifier produced by the consumption
+    pub nullifier: Nullifier,
+    
+    /// Index of the consuming instruction, if known
+    pub instruction_index: Option<usize>,
+}
+
 impl Resource {
     /// Create a new immutable resource with nullifier capability
     pub fn new(resource_type: MachineValue, init_value: MachineValue, allocation_counter: u64) -> Self {
@@ -376,6 +388,10 @@ pub struct ResourceManager {
     /// Nullifier set for consumed resources
     nullifiers: NullifierSet,
     
+    /// Where each consumed resource was consumed
+    #[serde(default)]
+    consumptions: BTreeMap<ResourceId, ConsumptionSite>,
+    
     /// Resource allocation counter
     allocation_counter: u64,
     
@@ -387,6 +403,10 @@ pub struct ResourceManager {
     
     /// Reverse dependency lookup (what depends on this resource)
     reverse_dependencies: BTreeMap<ResourceId, BTreeSet<ResourceId>>,
+    
+    /// Outstanding leases (runtime-only, not serialized)
+    #[serde(skip)]
+    leases: LeaseTable,
 }
 
 /// Resource store (alias for ResourceManager for compatibility)
@@ -398,10 +418,12 @@ impl ResourceManager {
         Self {
             resources: BTreeMap::new(),
             nullifiers: NullifierSet::new(),
+            consumptions: BTreeMap::new(),
             allocation_counter: 0,
             total_memory: 0,
             dependencies: BTreeMap::new(),
             reverse_dependencies: BTreeMap::new(),
+            leases: LeaseTable::default(),
         }
     }
     
@@ -428,6 +450,25 @@ impl ResourceManager {
     
     /// Consume a resource with nullifier generation and dependency validation
     pub fn consume(&mut self, id: ResourceId) -> Result<ConsumptionResult, ResourceError> {
+        self.consume_at(id, None)
+    }
+    
+    /// Consume a resource on behalf of the instruction at `instruction_index`
+    ///
+    /// Consuming a resource a second time fails with
+    /// `ResourceError::DoubleConsumption`, which names the nullifier and
+    /// instruction of the first consumption alongside the second.
+    pub fn consume_at(&mut self, id: ResourceId, instruction_index: Option<usize>) -> Result<ConsumptionResult, ResourceError> {
+        // Report double-spends against the site of the first consumption
+        if let Some(first) = self.consumptions.get(&id) {
+            return Err(ResourceError::DoubleConsumption {
+                resource: id,
+                nullifier: first.nullifier.clone(),
+                first_instruction: first.instruction_index,
+                second_instruction: instruction_index,
+            });
+        }
+        
         // Validate that consumption is allowed based on dependencies
         if !self.can_consume(&id)? {
             return Err(ResourceError::OperationFailed(
@@ -454,6 +495,11 @@ impl ResourceManager {
         // Clean up dependencies involving this resource
         self.cleanup_dependencies(&id);
         
+        self.consumptions.insert(id, ConsumptionSite {
+            nullifier: nullifier.clone(),
+            instruction_index,
+        });
+        
         Ok(ConsumptionResult {
             value: consumed_resource.value,
             nullifier,
@@ -499,6 +545,11 @@ impl ResourceManager {
         &self.nullifiers
     }
     
+    /// Get where a consumed resource was consumed
+    pub fn consumption_site(&self, id: &ResourceId) -> Option<&ConsumptionSite> {
+        self.consumptions.get(id)
+    }
+    
     /// Get mutable access to nullifiers (for verification)
     pub fn nullifiers_mut(&mut self) -> &mut NullifierSet {
         &mut self.nullifiers
@@ -691,6 +742,47 @@ impl ResourceManager {
         }
     }
 
+    /// Acquire an exclusive lease on a resource for `ttl`
+    ///
+    /// The lease is released when it expires, when [`Lease::release`] is
+    /// called, or when the returned [`Lease`] is dropped. Fails with
+    /// [`ResourceError::LeaseBusy`] while another unexpired lease is held.
+    pub fn acquire_lease(&self, resource_id: ResourceId, ttl: Duration) -> Result<Lease, ResourceError> {
+        if !self.resources.contains_key(&resource_id) {
+            return Err(ResourceError::NotFound(resource_id));
+        }
+        
+        let now = Instant::now();
+        let mut state = self.leases.inner.lock().unwrap();
+        
+        if let Some(entry) = state.entries.get(&resource_id) {
+            if entry.expires_at > now {
+                return Err(ResourceError::LeaseBusy(resource_id));
+            }
+        }
+        
+        state.next_token += 1;
+        let token = state.next_token;
+        let expires_at = now + ttl;
+        state.entries.insert(resource_id, LeaseEntry { token, expires_at });
+        
+        Ok(Lease {
+            resource_id,
+            token,
+            ttl,
+            expires_at,
+            table: Arc::clone(&self.leases.inner),
+        })
+    }
+    
+    /// Check whether a resource is currently held by an unexpired lease
+    pub fn is_leased(&self, resource_id: &ResourceId) -> bool {
+        let state = self.leases.inner.lock().unwrap();
+        state.entries.get(resource_id)
+            .map(|entry| entry.expires_at > Instant::now())
+            .unwrap_or(false)
+    }
+
     /// Create a simple resource (for bounded execution)
     pub fn create_resource(&mut self) -> ResourceId {
         let placeholder_type = MachineValue::Unit;
@@ -711,6 +803,99 @@ impl Default for ResourceManager {
     }
 }
 
+/// Exclusive, time-limited claim on a resource
+///
+/// Obtained from [`ResourceManager::acquire_lease`]. Dropping the lease
+/// releases it, so a session that panics or returns early never leaves the
+/// resource locked beyond its TTL.
+#[derive(Debug)]
+pub struct Lease {
+    resource_id: ResourceId,
+    token: u64,
+    ttl: Duration,
+    expires_at: Instant,
+    table: Arc<Mutex<LeaseState>>,
+}
+
+impl Lease {
+    /// Resource this lease grants access to
+    pub fn resource_id(&self) -> ResourceId {
+        self.resource_id
+    }
+    
+    /// Instant at which the lease lapses unless renewed
+    pub fn expires_at(&self) -> Instant {
+        self.expires_at
+    }
+    
+    /// Whether the lease has passed its expiry
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+    
+    /// Extend the lease by its original TTL, measured from now
+    ///
+    /// Fails with [`ResourceError::LeaseExpired`] once the lease has lapsed,
+    /// since another holder may already have acquired the resource.
+    pub fn renew(&mut self) -> Result<(), ResourceError> {
+        let now = Instant::now();
+        let mut state = self.table.lock().unwrap();
+        
+        match state.entries.get_mut(&self.resource_id) {
+            Some(entry) if entry.token == self.token && entry.expires_at > now => {
+                entry.expires_at = now + self.ttl;
+                self.expires_at = entry.expires_at;
+                Ok(())
+            }
+            _ => Err(ResourceError::LeaseExpired(self.resource_id)),
+        }
+    }
+    
+    /// Release the lease before it expires
+    pub fn release(self) {
+        // Dropping performs the release
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.table.lock() {
+            if state.entries.get(&self.resource_id).map(|entry| entry.token) == Some(self.token) {
+                state.entries.remove(&self.resource_id);
+            }
+        }
+    }
+}
+
+/// Bookkeeping for a single outstanding lease
+#[derive(Debug, Clone)]
+struct LeaseEntry {
+    token: u64,
+    expires_at: Instant,
+}
+
+/// Shared lease state between a manager and the leases it hands out
+#[derive(Debug, Default)]
+struct LeaseState {
+    entries: BTreeMap<ResourceId, LeaseEntry>,
+    next_token: u64,
+}
+
+/// Lease table owned by a [`ResourceManager`]
+///
+/// Leases belong to the live manager that granted them, so cloning a
+/// manager starts the clone with an empty table rather than sharing locks.
+#[derive(Debug, Default)]
+struct LeaseTable {
+    inner: Arc<Mutex<LeaseState>>,
+}
+
+impl Clone for LeaseTable {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
 /// Snapshot of resource store state for execution tracing
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResourceStoreSnapshot {
@@ -741,6 +926,15 @@ pub enum ResourceError {
     /// Double-spending detected (nullifier already exists)
     DoubleSpending([u8; 32]),
     
+    /// Resource consumed again after an earlier consumption
+    DoubleConsumption {
+        resource: ResourceId,
+        /// Nullifier produced by the first consumption
+        nullifier: Nullifier,
+        first_instruction: Option<usize>,
+        second_instruction: Option<usize>,
+    },
+    
     /// Resource type mismatch
     TypeMismatch {
         expected: String,
@@ -752,6 +946,12 @@ pub enum ResourceError {
     
     /// ZK proof verification failed
     ProofVerificationFailed,
+    
+    /// Resource is held by another unexpired lease
+    LeaseBusy(ResourceId),
+    
+    /// Lease lapsed before it could be renewed
+    LeaseExpired(ResourceId),
 }
 
 impl std::fmt::Display for ResourceError {
@@ -760,11 +960,27 @@ impl std::fmt::Display for ResourceError {
             ResourceError::NotFound(id) => write!(f, "Resource not found: {:?}", id),
             ResourceError::AlreadyConsumed(id) => write!(f, "Resource already consumed: {:?}", id),
             ResourceError::DoubleSpending(hash) => write!(f, "Double-spending detected: {:?}", hash),
+            ResourceError::DoubleConsumption { resource, nullifier, first_instruction, second_instruction } => {
+                let site = |index: &Option<usize>| match index {
+                    Some(index) => format!("instruction {}", index),
+                    None => "an unknown instruction".to_string(),
+                };
+                write!(
+                    f,
+                    "{} consumed twice: first at {} (nullifier {}), again at {}",
+                    resource,
+                    site(first_instruction),
+                    hex::encode(nullifier.nullifier_hash),
+                    site(second_instruction),
+                )
+            }
             ResourceError::TypeMismatch { expected, found } => {
                 write!(f, "Resource type mismatch: expected {}, found {}", expected, found)
             }
             ResourceError::OperationFailed(msg) => write!(f, "Resource operation failed: {}", msg),
             ResourceError::ProofVerificationFailed => write!(f, "ZK proof verification failed"),
+            ResourceError::LeaseBusy(id) => write!(f, "Resource is leased: {:?}", id),
+            ResourceError::LeaseExpired(id) => write!(f, "Lease expired: {:?}", id),
         }
     }
 }
@@ -921,7 +1137,7 @@ mod tests {
         
         // Try to consume again (should fail)
         let result = manager.consume(id);
-        assert!(matches!(result, Err(ResourceError::NotFound(_))));
+        assert!(matches!(result, Err(ResourceError::DoubleConsumption { .. })));
     }
 
     #[test]
@@ -997,4 +1213,91 @@ mod tests {
         
         assert!(matches!(double_spend_result, Err(ResourceError::DoubleSpending(_))));
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_double_consumption_names_both_sites() {
+        let mut manager = ResourceManager::new();
+        let id = manager.allocate(MachineValue::Unit, MachineValue::Int(7));
+        
+        let first = manager.consume_at(id, Some(3)).unwrap();
+        assert_eq!(manager.consumption_site(&id).unwrap().instruction_index, Some(3));
+        
+        let err = manager.consume_at(id, Some(9)).unwrap_err();
+        assert_eq!(err, ResourceError::DoubleConsumption {
+            resource: id,
+            nullifier: first.nullifier.clone(),
+            first_instruction: Some(3),
+            second_instruction: Some(9),
+        });
+        
+        let message = err.to_string();
+        assert!(message.contains("instruction 3"));
+        assert!(message.contains("instruction 9"));
+        assert!(message.contains(&hex::encode(first.nullifier.nullifier_hash)));
+        
+        // The failed attempt leaves the nullifier set untouched
+        assert_eq!(manager.nullifiers().len(), 1);
+    }
+    
+    #[test]
+    fn test_lease_acquire_and_busy() {
+        let mut manager = ResourceManager::new();
+        let id = manager.allocate(MachineValue::Unit, MachineValue::Int(1));
+        
+        let lease = manager.acquire_lease(id, Duration::from_secs(60)).unwrap();
+        assert_eq!(lease.resource_id(), id);
+        assert!(manager.is_leased(&id));
+        
+        let second = manager.acquire_lease(id, Duration::from_secs(60));
+        assert!(matches!(second, Err(ResourceError::LeaseBusy(busy)) if busy == id));
+        
+        lease.release();
+        assert!(!manager.is_leased(&id));
+        assert!(manager.acquire_lease(id, Duration::from_secs(60)).is_ok());
+    }
+    
+    #[test]
+    fn test_lease_expiry_allows_reacquisition() {
+        let mut manager = ResourceManager::new();
+        let id = manager.allocate(MachineValue::Unit, MachineValue::Int(1));
+        
+        let mut stale = manager.acquire_lease(id, Duration::from_millis(10)).unwrap();
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(stale.is_expired());
+        
+        let fresh = manager.acquire_lease(id, Duration::from_secs(60)).unwrap();
+        assert!(matches!(stale.renew(), Err(ResourceError::LeaseExpired(_))));
+        
+        // Dropping the stale lease must not release the fresh holder
+        drop(stale);
+        assert!(manager.is_leased(&id));
+        drop(fresh);
+        assert!(!manager.is_leased(&id));
+    }
+    
+    #[test]
+    fn test_lease_drop_releases_and_renew_extends() {
+        let mut manager = ResourceManager::new();
+        let id = manager.allocate(MachineValue::Unit, MachineValue::Int(1));
+        
+        {
+            let mut lease = manager.acquire_lease(id, Duration::from_secs(60)).unwrap();
+            let before = lease.expires_at();
+            lease.renew().unwrap();
+            assert!(lease.expires_at() >= before);
+        }
+        
+        assert!(!manager.is_leased(&id));
+        assert!(manager.acquire_lease(id, Duration::from_secs(60)).is_ok());
+    }
+    
+    #[test]
+    fn test_lease_requires_existing_resource() {
+        let manager = ResourceManager::new();
+        let missing = ResourceId::new(999);
+        assert!(matches!(
+            manager.acquire_lease(missing, Duration::from_secs(1)),
+            Err(ResourceError::NotFound(_))
+        ));
+    }
+}
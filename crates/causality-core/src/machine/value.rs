@@ -6,9 +6,13 @@
 use super::instruction::RegisterId;
 use crate::system::content_addressing::ResourceId;
 use crate::lambda::{TypeInner, Symbol, BaseType};
+use crate::lambda::base::SessionType;
+use crate::effect::session_registry::SessionError;
+use crate::system::error::TypeError;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::collections::BTreeMap;
 use serde::{Serialize, Deserialize};
+use ssz::Encode;
 
 /// Values that can be stored in registers for the minimal instruction set
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -89,6 +93,37 @@ pub enum ChannelState {
     Consumed,
 }
 
+/// Operation applied to a session channel
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChannelOp {
+    /// Send a value
+    Send,
+    
+    /// Receive a value
+    Receive,
+    
+    /// Select a branch of an internal choice
+    Select(String),
+    
+    /// Follow a branch of an external choice chosen by the other party
+    Branch(String),
+    
+    /// Close the channel
+    Close,
+}
+
+impl std::fmt::Display for ChannelOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChannelOp::Send => write!(f, "send"),
+            ChannelOp::Receive => write!(f, "receive"),
+            ChannelOp::Select(label) => write!(f, "select '{}'", label),
+            ChannelOp::Branch(label) => write!(f, "branch '{}'", label),
+            ChannelOp::Close => write!(f, "close"),
+        }
+    }
+}
+
 impl MachineValue {
     /// Create a new session channel
     pub fn new_channel(
@@ -216,6 +251,98 @@ impl MachineValue {
             _ => None,
         }
     }
+    
+    /// Name of this value's variant, used in type mismatch errors
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            MachineValue::Unit => "Unit",
+            MachineValue::Bool(_) => "Bool",
+            MachineValue::Int(_) => "Int",
+            MachineValue::Symbol(_) => "Symbol",
+            MachineValue::Product(_, _) => "Product",
+            MachineValue::Sum { .. } => "Sum",
+            MachineValue::ResourceRef(_) => "ResourceRef",
+            MachineValue::MorphismRef(_) => "MorphismRef",
+            MachineValue::Tensor(_, _) => "Tensor",
+            MachineValue::Type(_) => "Type",
+            MachineValue::Channel(_) => "Channel",
+            MachineValue::Function { .. } => "Function",
+        }
+    }
+    
+    /// Get the integer if this is an `Int`
+    ///
+    /// Returned as `i64`, which holds every `Int` value, so callers need no
+    /// changes if `Int` is widened.
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            MachineValue::Int(n) => Some(i64::from(*n)),
+            _ => None,
+        }
+    }
+    
+    /// Get the boolean if this is a `Bool`
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            MachineValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+    
+    /// Get the symbol if this is a `Symbol`
+    pub fn as_symbol(&self) -> Option<&Symbol> {
+        match self {
+            MachineValue::Symbol(sym) => Some(sym),
+            _ => None,
+        }
+    }
+    
+    /// Get the session channel if this is a `Channel`
+    pub fn as_channel(&self) -> Option<&SessionChannel> {
+        match self {
+            MachineValue::Channel(channel) => Some(channel),
+            _ => None,
+        }
+    }
+    
+    /// Get the session channel mutably if this is a `Channel`
+    pub fn as_channel_mut(&mut self) -> Option<&mut SessionChannel> {
+        match self {
+            MachineValue::Channel(channel) => Some(channel),
+            _ => None,
+        }
+    }
+    
+    /// Take the resource ID out of a `ResourceRef`
+    pub fn into_resource(self) -> Result<crate::machine::resource::ResourceId, TypeError> {
+        match self {
+            MachineValue::ResourceRef(id) => Ok(id),
+            other => Err(other.mismatch("ResourceRef")),
+        }
+    }
+    
+    /// Take the session channel out of a `Channel`
+    pub fn into_channel(self) -> Result<SessionChannel, TypeError> {
+        match self {
+            MachineValue::Channel(channel) => Ok(channel),
+            other => Err(other.mismatch("Channel")),
+        }
+    }
+    
+    /// Take both components out of a `Product`
+    pub fn into_product(self) -> Result<(MachineValue, MachineValue), TypeError> {
+        match self {
+            MachineValue::Product(l, r) => Ok((*l, *r)),
+            other => Err(other.mismatch("Product")),
+        }
+    }
+    
+    fn mismatch(&self, expected: &str) -> TypeError {
+        TypeError::Mismatch {
+            expected: expected.to_string(),
+            actual: self.variant_name().to_string(),
+        }
+    }
 }
 
 impl SessionChannel {
@@ -292,6 +419,213 @@ impl SessionChannel {
     pub fn dual_session_type(&self) -> crate::lambda::base::SessionType {
         self.session_type.dual()
     }
+    
+    /// Check that `op` is permitted by the channel's current session type
+    ///
+    /// Called before a `Transform` mutates the channel so that protocol
+    /// violations are rejected instead of silently advancing the channel.
+    pub fn check_transition(&self, op: ChannelOp) -> Result<(), SessionError> {
+        if self.is_consumed() {
+            return Err(SessionError::ChannelConsumed);
+        }
+        
+        // Recursive types permit whatever their body permits
+        let mut session = &self.session_type;
+        while let SessionType::Recursive(_, body) = session {
+            session = body;
+        }
+        
+        let permitted = match (session, &op) {
+            (SessionType::Send(_, _), ChannelOp::Send) => true,
+            (SessionType::Receive(_, _), ChannelOp::Receive) => true,
+            (SessionType::InternalChoice(branches), ChannelOp::Select(label))
+            | (SessionType::ExternalChoice(branches), ChannelOp::Branch(label)) => {
+                branches.iter().any(|(name, _)| name == label)
+            }
+            (SessionType::End, ChannelOp::Close) => true,
+            (SessionType::Variable(name), _) => {
+                return Err(SessionError::MalformedSessionType {
+                    reason: format!("unbound session variable '{}'", name),
+                });
+            }
+            _ => false,
+        };
+        
+        if permitted {
+            Ok(())
+        } else {
+            Err(SessionError::ProtocolViolation {
+                expected: expected_operation(session),
+                attempted: op.to_string(),
+            })
+        }
+    }
+}
+
+/// Describe the operations a session type permits next
+fn expected_operation(session: &SessionType) -> String {
+    let labels = |branches: &[(String, SessionType)]| {
+        branches.iter().map(|(name, _)| format!("'{}'", name)).collect::<Vec<_>>().join(", ")
+    };
+    match session {
+        SessionType::Send(_, _) => "send".to_string(),
+        SessionType::Receive(_, _) => "receive".to_string(),
+        SessionType::InternalChoice(branches) => format!("select one of {}", labels(branches)),
+        SessionType::ExternalChoice(branches) => format!("branch on one of {}", labels(branches)),
+        SessionType::End => "close".to_string(),
+        SessionType::Recursive(_, body) => expected_operation(body),
+        SessionType::Variable(name) => format!("session variable '{}'", name),
+    }
+}
+
+//-----------------------------------------------------------------------------
+// SSZ Encoding
+//-----------------------------------------------------------------------------
+
+/// Length of a value's encoding behind a 4-byte length prefix
+pub(crate) fn prefixed_len<T: Encode>(value: &T) -> usize {
+    4 + value.ssz_bytes_len()
+}
+
+/// Append a value's encoding behind a 4-byte length prefix, so a
+/// variable-length value followed by further fields decodes unambiguously
+pub(crate) fn append_prefixed<T: Encode>(value: &T, buf: &mut Vec<u8>) {
+    (value.ssz_bytes_len() as u32).ssz_append(buf);
+    value.ssz_append(buf);
+}
+
+// Encoding only: machine values are content-addressed, never decoded from SSZ
+impl Encode for MachineValue {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        1 + match self {
+            MachineValue::Unit => 0,
+            MachineValue::Bool(_) => 1,
+            MachineValue::Int(_) => 4,
+            MachineValue::Symbol(symbol) => symbol.ssz_bytes_len(),
+            MachineValue::Product(left, right) | MachineValue::Tensor(left, right) => {
+                prefixed_len(left.as_ref()) + right.ssz_bytes_len()
+            }
+            MachineValue::Sum { tag, value } => tag.ssz_bytes_len() + value.ssz_bytes_len(),
+            MachineValue::ResourceRef(id) => id.0.ssz_bytes_len(),
+            MachineValue::MorphismRef(_) => 4,
+            MachineValue::Type(ty) => ty.ssz_bytes_len(),
+            MachineValue::Channel(channel) => channel.ssz_bytes_len(),
+            MachineValue::Function { params, body, captured_env } => {
+                4 + 4 * params.len()
+                    + 4 + body.iter().map(prefixed_len).sum::<usize>()
+                    + 4 + captured_env.values().map(|value| 4 + prefixed_len(value)).sum::<usize>()
+            }
+        }
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        use crate::system::encode_enum_variant;
+        
+        match self {
+            MachineValue::Unit => encode_enum_variant(0, buf),
+            MachineValue::Bool(b) => {
+                encode_enum_variant(1, buf);
+                buf.push(*b as u8);
+            }
+            MachineValue::Int(i) => {
+                encode_enum_variant(2, buf);
+                buf.extend_from_slice(&i.to_le_bytes());
+            }
+            MachineValue::Symbol(symbol) => {
+                encode_enum_variant(3, buf);
+                symbol.ssz_append(buf);
+            }
+            MachineValue::Product(left, right) => {
+                encode_enum_variant(4, buf);
+                append_prefixed(left.as_ref(), buf);
+                right.ssz_append(buf);
+            }
+            MachineValue::Sum { tag, value } => {
+                encode_enum_variant(5, buf);
+                tag.ssz_append(buf);
+                value.ssz_append(buf);
+            }
+            MachineValue::ResourceRef(id) => {
+                encode_enum_variant(6, buf);
+                id.0.ssz_append(buf);
+            }
+            MachineValue::MorphismRef(register) => {
+                encode_enum_variant(7, buf);
+                buf.extend_from_slice(&register.0.to_le_bytes());
+            }
+            MachineValue::Tensor(left, right) => {
+                encode_enum_variant(8, buf);
+                append_prefixed(left.as_ref(), buf);
+                right.ssz_append(buf);
+            }
+            MachineValue::Type(ty) => {
+                encode_enum_variant(9, buf);
+                ty.ssz_append(buf);
+            }
+            MachineValue::Channel(channel) => {
+                encode_enum_variant(10, buf);
+                channel.ssz_append(buf);
+            }
+            MachineValue::Function { params, body, captured_env } => {
+                encode_enum_variant(11, buf);
+                (params.len() as u32).ssz_append(buf);
+                for param in params {
+                    buf.extend_from_slice(&param.0.to_le_bytes());
+                }
+                (body.len() as u32).ssz_append(buf);
+                for instruction in body {
+                    append_prefixed(instruction, buf);
+                }
+                (captured_env.len() as u32).ssz_append(buf);
+                for (register, value) in captured_env {
+                    buf.extend_from_slice(&register.0.to_le_bytes());
+                    append_prefixed(value, buf);
+                }
+            }
+        }
+    }
+}
+
+impl Encode for SessionChannel {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        let state_len = match self.state {
+            ChannelState::ChoiceSelected(_) => 5,
+            ChannelState::Open | ChannelState::Consumed => 1,
+        };
+        self.channel_id.ssz_bytes_len()
+            + prefixed_len(&self.session_type)
+            + state_len
+            + 4 + self.message_queue.iter().map(prefixed_len).sum::<usize>()
+            + self.location.ssz_bytes_len()
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        use crate::system::encode_enum_variant;
+        
+        self.channel_id.ssz_append(buf);
+        append_prefixed(&self.session_type, buf);
+        match self.state {
+            ChannelState::Open => encode_enum_variant(0, buf),
+            ChannelState::ChoiceSelected(index) => {
+                encode_enum_variant(1, buf);
+                buf.extend_from_slice(&index.to_le_bytes());
+            }
+            ChannelState::Consumed => encode_enum_variant(2, buf),
+        }
+        (self.message_queue.len() as u32).ssz_append(buf);
+        for message in &self.message_queue {
+            append_prefixed(message, buf);
+        }
+        self.location.ssz_append(buf);
+    }
 }
 
 #[cfg(test)]
@@ -423,6 +757,87 @@ mod tests {
         assert!(result.is_err());
     }
     
+    #[test]
+    fn test_typed_accessors() {
+        assert_eq!(MachineValue::Int(7).as_int(), Some(7));
+        assert_eq!(MachineValue::Int(u32::MAX).as_int(), Some(4_294_967_295));
+        assert_eq!(MachineValue::Bool(true).as_bool(), Some(true));
+        assert_eq!(MachineValue::Unit.as_int(), None);
+        assert!(MachineValue::Int(7).as_channel().is_none());
+        
+        let channel = MachineValue::new_channel(SessionType::End, Location::Local);
+        assert_eq!(channel.as_channel().map(|c| &c.session_type), Some(&SessionType::End));
+        assert!(channel.into_channel().is_ok());
+        
+        let id = crate::machine::resource::ResourceId::new(7);
+        assert_eq!(MachineValue::ResourceRef(id).into_resource(), Ok(id));
+        
+        let pair = MachineValue::Product(Box::new(MachineValue::Int(1)), Box::new(MachineValue::Unit));
+        assert_eq!(pair.into_product(), Ok((MachineValue::Int(1), MachineValue::Unit)));
+    }
+    
+    #[test]
+    fn test_typed_accessor_mismatch_names_variants() {
+        assert_eq!(
+            MachineValue::Int(7).into_resource(),
+            Err(TypeError::Mismatch {
+                expected: "ResourceRef".to_string(),
+                actual: "Int".to_string(),
+            })
+        );
+        
+        let err = MachineValue::Bool(false).into_channel().unwrap_err();
+        assert_eq!(err.to_string(), "Type mismatch: expected Channel, found Bool");
+    }
+    
+    #[test]
+    fn test_check_transition_follows_protocol() {
+        let session_type = SessionType::Send(
+            Box::new(TypeInner::Base(BaseType::Int)),
+            Box::new(SessionType::Receive(
+                Box::new(TypeInner::Base(BaseType::Bool)),
+                Box::new(SessionType::End)
+            ))
+        );
+        let mut channel = SessionChannel::new(session_type, Location::Local);
+        
+        assert_eq!(channel.check_transition(ChannelOp::Send), Ok(()));
+        channel.progress_session(SessionType::Receive(
+            Box::new(TypeInner::Base(BaseType::Bool)),
+            Box::new(SessionType::End)
+        ));
+        
+        assert_eq!(channel.check_transition(ChannelOp::Receive), Ok(()));
+        channel.progress_session(SessionType::End);
+        
+        // Reaching End consumes the channel, so nothing further is allowed
+        assert_eq!(channel.check_transition(ChannelOp::Close), Err(SessionError::ChannelConsumed));
+    }
+    
+    #[test]
+    fn test_check_transition_rejects_out_of_order_operation() {
+        let session_type = SessionType::Send(
+            Box::new(TypeInner::Base(BaseType::Int)),
+            Box::new(SessionType::End)
+        );
+        let channel = SessionChannel::new(session_type, Location::Local);
+        
+        assert_eq!(
+            channel.check_transition(ChannelOp::Receive),
+            Err(SessionError::ProtocolViolation {
+                expected: "send".to_string(),
+                attempted: "receive".to_string(),
+            })
+        );
+        
+        let choice = SessionChannel::new(
+            SessionType::InternalChoice(vec![("left".to_string(), SessionType::End)]),
+            Location::Local,
+        );
+        assert_eq!(choice.check_transition(ChannelOp::Select("left".to_string())), Ok(()));
+        assert!(choice.check_transition(ChannelOp::Select("right".to_string())).is_err());
+    }
+    
     #[test]
     fn test_channel_choice_selection() {
         let session_type = SessionType::InternalChoice(vec![
@@ -567,4 +982,33 @@ mod tests {
         assert!(matches!(consumed_result.value, MachineValue::Channel(_)));
         assert!(heap.is_consumed(&resource_id));
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_ssz_encoding_length_and_nesting() {
+        use crate::machine::instruction::Instruction;
+        
+        let int = |n| Box::new(MachineValue::Int(n));
+        let values = [
+            MachineValue::Product(Box::new(MachineValue::Product(int(1), int(2))), int(3)),
+            MachineValue::Product(int(1), Box::new(MachineValue::Product(int(2), int(3)))),
+            MachineValue::Tensor(int(1), Box::new(MachineValue::Product(int(2), int(3)))),
+            MachineValue::Channel(SessionChannel::new(SessionType::End, Location::Local)),
+            MachineValue::Function {
+                params: vec![RegisterId(0)],
+                body: vec![Instruction::Consume {
+                    resource_reg: RegisterId(0),
+                    output_reg: RegisterId(1),
+                }],
+                captured_env: BTreeMap::from([(RegisterId(2), MachineValue::Bool(true))]),
+            },
+        ];
+        
+        let encodings: Vec<Vec<u8>> = values.iter().map(|value| value.as_ssz_bytes()).collect();
+        for (value, bytes) in values.iter().zip(&encodings) {
+            assert_eq!(value.ssz_bytes_len(), bytes.len(), "{:?}", value);
+        }
+        // Differently nested pairs must not share an encoding
+        assert_ne!(encodings[0], encodings[1]);
+        assert_ne!(encodings[1], encodings[2]);
+    }
+}
@@ -5,8 +5,13 @@
 
 #![allow(clippy::result_large_err)]
 
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::effect::core::Span;
+
 /// Core system error type
 ///
 /// This error type encompasses all possible failures in the Causality system,
@@ -48,6 +53,55 @@ pub enum Error {
     /// Generic system error
     #[error("System error: {message}")]
     System { message: String },
+
+    /// Error annotated with the context it propagated through
+    #[error("{context}: {error}")]
+    Context {
+        #[source]
+        error: Box<Error>,
+        context: ContextChain,
+    },
+}
+
+/// Context attached to an error as it propagates, in the order it was attached
+///
+/// The innermost context comes first; `Display` prints the outermost first,
+/// so a chain reads like a call path from the top down to the failure.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextChain(Vec<String>);
+
+impl ContextChain {
+    /// Add the next, outer, piece of context
+    pub fn push(&mut self, context: impl Into<String>) {
+        self.0.push(context.into());
+    }
+
+    /// Iterate over the context, innermost first
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    /// Number of context entries
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether no context has been attached
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for ContextChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, context) in self.0.iter().rev().enumerate() {
+            if i > 0 {
+                write!(f, ": ")?;
+            }
+            write!(f, "{}", context)?;
+        }
+        Ok(())
+    }
 }
 
 /// Type system error variants
@@ -109,7 +163,7 @@ pub enum TypeError {
 }
 
 /// Linear resource management errors
-#[derive(Error, Debug, Clone, PartialEq)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum LinearityError {
     /// Resource used more than once
     #[error("Resource used multiple times: {resource}")]
@@ -123,11 +177,23 @@ pub enum LinearityError {
     #[error("Resource used after consumption: {resource}")]
     UseAfterConsumption { resource: String },
 
+    /// Linear resource went out of scope without being consumed
+    #[error("Linear resource never consumed: {resource}{}", binding_site(.binding))]
+    NeverConsumed { resource: String, binding: Option<Span> },
+
+    /// Relevant value dropped without ever being used
+    #[error("Relevant value must be used: {resource}{}", binding_site(.binding))]
+    MustUse { resource: String, binding: Option<Span> },
+
     /// Generic linearity error
     #[error("Linearity error: {message}")]
     Generic { message: String },
 }
 
+fn binding_site(binding: &Option<Span>) -> String {
+    binding.as_ref().map(|span| format!(" (bound at {})", span)).unwrap_or_default()
+}
+
 /// Result type for Causality operations
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -164,6 +230,44 @@ impl Error {
             Error::Network { .. } => ErrorKind::Recoverable,
             Error::Validation { .. } => ErrorKind::Validation,
             Error::System { .. } => ErrorKind::Fatal,
+            Error::Context { error, .. } => error.kind(),
+        }
+    }
+
+    /// Attach context to this error, keeping the underlying error and its kind
+    ///
+    /// Context accumulates in a single chain rather than nesting, so the
+    /// underlying error is always one level down.
+    pub fn with_context(self, context: impl fmt::Display) -> Self {
+        match self {
+            Error::Context { error, context: mut chain } => {
+                chain.push(context.to_string());
+                Error::Context { error, context: chain }
+            }
+            error => {
+                let mut chain = ContextChain::default();
+                chain.push(context.to_string());
+                Error::Context {
+                    error: Box::new(error),
+                    context: chain,
+                }
+            }
+        }
+    }
+
+    /// The underlying error, without any attached context
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context { error, .. } => error.root(),
+            error => error,
+        }
+    }
+
+    /// Context attached through `with_context`, if any
+    pub fn context_chain(&self) -> Option<&ContextChain> {
+        match self {
+            Error::Context { context, .. } => Some(context),
+            _ => None,
         }
     }
 
@@ -372,10 +476,7 @@ where
     where
         C: std::fmt::Display + Send + Sync + 'static,
     {
-        self.map_err(|e| {
-            let error = e.into();
-            Error::system(format!("{}: {}", context, error))
-        })
+        self.map_err(|e| e.into().with_context(context))
     }
     
     fn with_context<C, F>(self, f: F) -> Result<T>
@@ -383,10 +484,7 @@ where
         C: std::fmt::Display + Send + Sync + 'static,
         F: FnOnce() -> C,
     {
-        self.map_err(|e| {
-            let error = e.into();
-            Error::system(format!("{}: {}", f(), error))
-        })
+        self.map_err(|e| e.into().with_context(f()))
     }
 }
 
@@ -426,4 +524,31 @@ macro_rules! ensure {
             return Err($crate::system::Error::Other(anyhow::anyhow!($fmt, $($arg)*)))
         }
     };
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_resource() -> Result<()> {
+        Err(Error::storage("disk full"))
+    }
+
+    #[test]
+    fn test_context_chain_preserves_kind() {
+        let err = load_resource()
+            .context("while loading resource X")
+            .context("while handling request 7")
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Recoverable);
+        assert_eq!(err.root(), &Error::storage("disk full"));
+
+        let chain: Vec<&str> = err.context_chain().unwrap().iter().collect();
+        assert_eq!(chain, ["while loading resource X", "while handling request 7"]);
+        assert_eq!(
+            err.to_string(),
+            "while handling request 7: while loading resource X: Storage error: disk full"
+        );
+    }
+}
@@ -15,14 +15,15 @@ pub mod utils;
 pub mod storage;
 
 // Re-export common types
-pub use error::{Error, Result, ErrorKind, ResultExt};
+pub use error::{Error, Result, ErrorKind, ResultExt, ContextChain};
 pub use content_addressing::{
     EntityId, ResourceId, ExprId, RowTypeId, HandlerId, TransactionId, IntentId, NullifierId,
     ContentAddressable, Timestamp, Str,
 };
 pub use serialization::{
     encode_fixed_bytes, decode_fixed_bytes, DecodeWithRemainder,
-    encode_with_length, decode_with_length, encode_enum_variant, decode_enum_variant
+    encode_with_length, decode_with_length, encode_enum_variant, decode_enum_variant,
+    decode_slice, decode_rest, decode_u32_at, try_decode_value,
 };
 pub use provenance::CausalProof;
 pub use domain::{Domain, UnifiedRouter, RoutingInfo, RoutingPath, RoutingStrategy, RoutingStats};
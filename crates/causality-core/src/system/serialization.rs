@@ -44,9 +44,9 @@ impl<T: Encode> ToBytes for T {
 }
 
 /// Blanket implementation for SSZ decodable types
-impl<T: Decode> FromBytes for T {
+impl<T: Decode + Encode> FromBytes for T {
     fn from_bytes(bytes: &[u8]) -> Result<Self> {
-        T::from_ssz_bytes(bytes)
+        try_decode_value(bytes)
             .map_err(|e| CausalityError::SerializationError(format!("SSZ decode error: {:?}", e)))
     }
 }
@@ -55,6 +55,25 @@ impl<T: Decode> FromBytes for T {
 // Core Helper Functions
 //-----------------------------------------------------------------------------
 
+/// Decode a stored value, rejecting truncated and overlong input
+///
+/// Values written under an older schema may be shorter or longer than the
+/// current type expects. Decoders report short input as a `DecodeError`,
+/// and bytes left over after the value are rejected here.
+pub fn try_decode_value<T: Decode + Encode>(bytes: &[u8]) -> std::result::Result<T, DecodeError> {
+    let value = T::from_ssz_bytes(bytes)?;
+    
+    let expected = value.as_ssz_bytes().len();
+    if expected != bytes.len() {
+        return Err(DecodeError::InvalidByteLength {
+            len: bytes.len(),
+            expected,
+        });
+    }
+    
+    Ok(value)
+}
+
 /// Helper to encode a value and compute its hash
 pub fn hash_encode<T: Encode>(value: &T) -> [u8; 32] {
     use crate::{Sha256Hasher, Hasher};
@@ -124,6 +143,30 @@ pub fn decode_fixed_bytes<const N: usize>(bytes: &[u8]) -> std::result::Result<[
     Ok(array)
 }
 
+/// Helper for taking `len` bytes at `start`, failing instead of panicking
+/// when the input is too short
+pub fn decode_slice(bytes: &[u8], start: usize, len: usize) -> std::result::Result<&[u8], ssz::DecodeError> {
+    start
+        .checked_add(len)
+        .and_then(|end| bytes.get(start..end))
+        .ok_or(ssz::DecodeError::InvalidByteLength {
+            len: bytes.len(),
+            expected: start.saturating_add(len),
+        })
+}
+
+/// Helper for taking the bytes from `start` onwards, failing instead of
+/// panicking when `start` is past the end
+pub fn decode_rest(bytes: &[u8], start: usize) -> std::result::Result<&[u8], ssz::DecodeError> {
+    decode_slice(bytes, start, bytes.len().saturating_sub(start))
+}
+
+/// Helper for decoding a little-endian u32 at `start`
+pub fn decode_u32_at(bytes: &[u8], start: usize) -> std::result::Result<u32, ssz::DecodeError> {
+    let slice = decode_slice(bytes, start, 4)?;
+    Ok(u32::from_le_bytes([slice[0], slice[1], slice[2], slice[3]]))
+}
+
 /// Helper for encoding enum variants with a discriminator byte
 pub fn encode_enum_variant(variant: u8, buf: &mut Vec<u8>) {
     buf.push(variant);
@@ -321,6 +364,66 @@ mod tests {
         assert!(remaining.is_empty());
     }
     
+    #[test]
+    fn test_try_decode_value_roundtrip() {
+        let value = crate::lambda::base::Value::Int(7);
+        let decoded: crate::lambda::base::Value = try_decode_value(&value.as_ssz_bytes()).unwrap();
+        assert_eq!(decoded, value);
+    }
+    
+    #[test]
+    fn test_try_decode_value_truncated() {
+        use crate::lambda::base::Value;
+        
+        let record = Value::Record {
+            fields: [("balance".to_string(), Value::Int(100))].into_iter().collect(),
+        };
+        let encoded = record.as_ssz_bytes();
+        
+        // The record decoder reports these as errors rather than panicking
+        assert!(try_decode_value::<Value>(&encoded[..3]).is_err());
+        assert!(try_decode_value::<Value>(&encoded[..encoded.len() - 1]).is_err());
+        assert!(Value::from_bytes(&encoded[..3]).is_err());
+    }
+    
+    #[test]
+    fn test_try_decode_session_type_truncated() {
+        use crate::lambda::base::SessionType;
+        
+        let session = SessionType::InternalChoice(vec![("left".to_string(), SessionType::End)]);
+        let encoded = session.as_ssz_bytes();
+        
+        for len in 0..encoded.len() {
+            assert!(try_decode_value::<SessionType>(&encoded[..len]).is_err());
+        }
+    }
+    
+    #[test]
+    fn test_decode_slice_bounds() {
+        let bytes = [1u8, 2, 3, 4, 5];
+        
+        assert_eq!(decode_slice(&bytes, 1, 3), Ok(&bytes[1..4]));
+        assert!(decode_slice(&bytes, 3, 3).is_err());
+        assert!(decode_slice(&bytes, usize::MAX, 2).is_err());
+        assert_eq!(decode_rest(&bytes, 5), Ok(&[][..]));
+        assert!(decode_rest(&bytes, 6).is_err());
+        assert_eq!(decode_u32_at(&bytes, 1), Ok(u32::from_le_bytes([2, 3, 4, 5])));
+        assert!(decode_u32_at(&bytes, 2).is_err());
+    }
+    
+    #[test]
+    fn test_try_decode_value_overlong() {
+        use crate::lambda::base::Value;
+        
+        let mut encoded = Value::Int(7).as_ssz_bytes();
+        encoded.extend_from_slice(&[0xff, 0xff]);
+        
+        assert!(matches!(
+            try_decode_value::<Value>(&encoded),
+            Err(DecodeError::InvalidByteLength { expected: 5, .. })
+        ));
+    }
+    
     #[test]
     fn test_with_length() {
         let data = b"hello world";
@@ -0,0 +1,84 @@
+//! Fixed SSZ corpus shared by the codec benchmarks and throughput tests
+//!
+//! The corpus is deterministic so throughput numbers are comparable across
+//! runs and against the committed baseline.
+
+#![allow(dead_code)]
+
+use causality_core::effect::{EffectStep, ExecutionStatus, ExecutionTrace, StepStatus};
+use causality_core::machine::{Instruction, RegisterId};
+use causality_core::{EntityId, Timestamp, Value};
+use ssz::Encode;
+
+pub const INSTRUCTION_COUNT: usize = 1024;
+pub const VALUE_COUNT: usize = 256;
+pub const TRACE_COUNT: usize = 32;
+pub const STEPS_PER_TRACE: usize = 16;
+
+pub fn instructions() -> Vec<Instruction> {
+    (0..INSTRUCTION_COUNT as u32)
+        .map(|i| {
+            let (a, b, c) = (RegisterId::new(i % 64), RegisterId::new((i + 1) % 64), RegisterId::new((i + 2) % 64));
+            match i % 5 {
+                0 => Instruction::Transform { morph_reg: a, input_reg: b, output_reg: c },
+                1 => Instruction::Alloc { type_reg: a, init_reg: b, output_reg: c },
+                2 => Instruction::Consume { resource_reg: a, output_reg: b },
+                3 => Instruction::Compose { first_reg: a, second_reg: b, output_reg: c },
+                _ => Instruction::Tensor { left_reg: a, right_reg: b, output_reg: c },
+            }
+        })
+        .collect()
+}
+
+pub fn values() -> Vec<Value> {
+    (0..VALUE_COUNT as u32)
+        .map(|i| match i % 4 {
+            0 => Value::Int(i),
+            1 => Value::Product(Box::new(Value::Int(i)), Box::new(Value::Bool(i % 2 == 0))),
+            2 => Value::Sum { tag: (i % 3) as u8, value: Box::new(Value::Int(i)) },
+            _ => Value::Record {
+                fields: (0..8).map(|f| (format!("field_{}", f), Value::Int(i + f))).collect(),
+            },
+        })
+        .collect()
+}
+
+pub fn traces() -> Vec<ExecutionTrace> {
+    (0..TRACE_COUNT as u64)
+        .map(|t| {
+            let effects = (0..STEPS_PER_TRACE as u64)
+                .map(|s| EffectStep {
+                    effect_id: entity(t * 1000 + s),
+                    start_time: Timestamp { millis: 1_000 * t + s },
+                    end_time: Some(Timestamp { millis: 1_000 * t + s + 1 }),
+                    status: StepStatus::Completed,
+                    inputs: vec![s as u8; 64],
+                    outputs: Some(vec![t as u8; 32]),
+                    error: None,
+                })
+                .collect();
+
+            ExecutionTrace {
+                id: entity(t),
+                start_time: Timestamp { millis: 1_000 * t },
+                end_time: Some(Timestamp { millis: 1_000 * t + STEPS_PER_TRACE as u64 }),
+                effects,
+                resources_consumed: (0..4).map(|r| entity(t * 100 + r)).collect(),
+                resources_created: (4..8).map(|r| entity(t * 100 + r)).collect(),
+                status: ExecutionStatus::Completed,
+                error: None,
+            }
+        })
+        .collect()
+}
+
+/// Encode every item of a corpus separately
+pub fn encode_all<T: Encode>(items: &[T]) -> Vec<Vec<u8>> {
+    items.iter().map(Encode::as_ssz_bytes).collect()
+}
+
+fn entity(seed: u64) -> EntityId {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    EntityId::from_bytes(bytes)
+}
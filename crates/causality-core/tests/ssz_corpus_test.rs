@@ -0,0 +1,134 @@
+//! SSZ corpus round-trip and throughput tests
+//!
+//! Checks that every item in the fixed corpus shared with the `ssz_codec`
+//! benchmark decodes back to itself, that values nested inside products,
+//! sums and records decode at the right offsets, and that decode throughput
+//! stays above the committed baseline.
+//!
+//! The throughput test is ignored by default since unoptimized timings are
+//! not meaningful; run it with
+//! `cargo test -p causality-core --release --test ssz_corpus_test -- --ignored`.
+
+use std::time::{Duration, Instant};
+
+use causality_core::effect::ExecutionTrace;
+use causality_core::machine::Instruction;
+use causality_core::{BaseType, Location, Str, TypeInner, Value};
+use ssz::{Decode, Encode};
+
+mod ssz_corpus;
+
+fn assert_round_trips<T: Encode + Decode + PartialEq + std::fmt::Debug>(items: &[T]) {
+    let encoded = ssz_corpus::encode_all(items);
+    assert_eq!(encoded.len(), items.len());
+    for (item, bytes) in items.iter().zip(&encoded) {
+        assert_eq!(&T::from_ssz_bytes(bytes).unwrap(), item);
+    }
+}
+
+#[test]
+fn test_instruction_corpus_round_trips() {
+    assert_round_trips::<Instruction>(&ssz_corpus::instructions());
+}
+
+#[test]
+fn test_value_corpus_round_trips() {
+    assert_round_trips::<Value>(&ssz_corpus::values());
+}
+
+#[test]
+fn test_execution_trace_corpus_round_trips() {
+    assert_round_trips::<ExecutionTrace>(&ssz_corpus::traces());
+}
+
+fn record(fields: Vec<(&str, Value)>) -> Value {
+    Value::Record {
+        fields: fields.into_iter().map(|(key, value)| (key.to_string(), value)).collect(),
+    }
+}
+
+fn product(left: Value, right: Value) -> Value {
+    Value::Product(Box::new(left), Box::new(right))
+}
+
+fn sum(tag: u8, value: Value) -> Value {
+    Value::Sum { tag, value: Box::new(value) }
+}
+
+#[test]
+fn test_nested_values_round_trip() {
+    // Each nested value is followed by a sibling, so a decoder returning the
+    // wrong remainder misreads the sibling instead of silently succeeding
+    let nested = vec![
+        product(product(Value::Int(1), Value::Bool(true)), Value::Int(2)),
+        product(sum(1, Value::Int(3)), Value::Int(4)),
+        product(Value::Symbol(Str::new("left")), Value::String(Str::new("right"))),
+        sum(2, product(Value::Int(5), sum(0, Value::Unit))),
+        record(vec![
+            ("a", product(Value::Int(6), product(Value::Bool(false), Value::Int(7)))),
+            ("b", sum(1, product(Value::Int(8), Value::Int(9)))),
+            ("c", Value::Symbol(Str::new("tail"))),
+            ("d", Value::Int(10)),
+        ]),
+        product(
+            record(vec![("x", sum(0, Value::Int(11))), ("y", Value::Int(12))]),
+            record(vec![("z", product(Value::Unit, Value::Int(13)))]),
+        ),
+    ];
+    assert_round_trips::<Value>(&nested);
+}
+
+#[test]
+fn test_nested_types_round_trip() {
+    let int = || Box::new(TypeInner::Base(BaseType::Int));
+    let nested = vec![
+        TypeInner::Product(
+            Box::new(TypeInner::Transform { input: int(), output: int(), location: Location::Local }),
+            int(),
+        ),
+        TypeInner::Sum(
+            Box::new(TypeInner::Located(int(), Location::Local)),
+            Box::new(TypeInner::Product(int(), int())),
+        ),
+    ];
+    assert_round_trips::<TypeInner>(&nested);
+}
+
+/// Minimum decode throughput, in bytes per second, for every corpus
+const DECODE_BASELINE_BYTES_PER_SEC: f64 = 1024.0 * 1024.0;
+
+/// Number of timed passes; the fastest one is compared against the baseline
+const BASELINE_PASSES: usize = 5;
+
+/// Panic if decoding `items` is slower than the committed baseline
+fn assert_decode_above_baseline<T: Encode + Decode>(name: &str, items: &[T]) {
+    let encoded = ssz_corpus::encode_all(items);
+    let total_bytes: usize = encoded.iter().map(Vec::len).sum();
+
+    let fastest = (0..BASELINE_PASSES)
+        .map(|_| {
+            let start = Instant::now();
+            for bytes in &encoded {
+                std::hint::black_box(T::from_ssz_bytes(bytes).unwrap());
+            }
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or(Duration::ZERO)
+        .max(Duration::from_nanos(1));
+
+    let throughput = total_bytes as f64 / fastest.as_secs_f64();
+    assert!(
+        throughput >= DECODE_BASELINE_BYTES_PER_SEC,
+        "{} decode throughput {:.0} B/s is below the baseline of {:.0} B/s",
+        name, throughput, DECODE_BASELINE_BYTES_PER_SEC,
+    );
+}
+
+#[test]
+#[ignore = "timing-sensitive; run in release with --ignored"]
+fn test_decode_throughput_above_baseline() {
+    assert_decode_above_baseline::<Instruction>("Instruction", &ssz_corpus::instructions());
+    assert_decode_above_baseline::<Value>("Value", &ssz_corpus::values());
+    assert_decode_above_baseline::<ExecutionTrace>("ExecutionTrace", &ssz_corpus::traces());
+}
@@ -8,8 +8,10 @@
 #![warn(missing_docs)]
 
 use causality_compiler::CompiledArtifact;
+use causality_core::machine::RegisterId;
 use causality_runtime::Executor;
-use std::ffi::c_void;
+use std::cell::Cell;
+use std::ffi::{c_char, c_void};
 use std::slice;
 
 //-----------------------------------------------------------------------------
@@ -41,11 +43,67 @@ pub enum CResult {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CPointerResult {
     /// Success result with pointer
-    Ok(*const c_void), // Placeholder for a result pointer
+    Ok(*const c_void),
     /// Error result with error code
     Err(i32),
 }
 
+//-----------------------------------------------------------------------------
+// Error Codes
+//-----------------------------------------------------------------------------
+
+/// Error code: the executor could not produce the requested value
+pub const ERR_GENERIC: i32 = -1;
+
+/// Error code: a required pointer argument, such as the bytecode, was null
+pub const ERR_NULL_INPUT: i32 = -2;
+
+/// Error code: a value could not be serialized for the caller
+pub const ERR_SERIALIZE: i32 = -3;
+
+/// Error code: the requested register holds no value
+pub const ERR_EMPTY_REGISTER: i32 = -4;
+
+/// Error code: the caller's buffer is too small for the value
+pub const ERR_BUFFER_TOO_SMALL: i32 = -5;
+
+/// Load error code: the bytecode is not a valid `CompiledArtifact`
+pub const ERR_DESERIALIZE: i32 = -6;
+
+/// Load error code: priming the executor with the program failed
+pub const ERR_EXECUTION: i32 = -7;
+
+thread_local! {
+    /// NUL-terminated message for the last load failure on this thread
+    static LAST_ERROR: Cell<Option<&'static [u8]>> = const { Cell::new(None) };
+}
+
+/// Record a load failure and return the null state pointer to report it
+unsafe fn load_failed(
+    error_out: *mut i32,
+    code: i32,
+    message: &'static [u8],
+) -> *mut SimulationState {
+    LAST_ERROR.with(|last| last.set(Some(message)));
+    if !error_out.is_null() {
+        *error_out = code;
+    }
+    std::ptr::null_mut()
+}
+
+/// Message describing the last failed `causality_load_bytecode` call on the
+/// calling thread, or null if the last call succeeded.
+///
+/// The returned string is static and NUL-terminated; the caller must not
+/// free it.
+#[no_mangle]
+pub extern "C" fn causality_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| match last.get() {
+        Some(message) => message.as_ptr() as *const c_char,
+        None => std::ptr::null(),
+    })
+}
+
 //-----------------------------------------------------------------------------
 // FFI Interface Functions
 //-----------------------------------------------------------------------------
@@ -56,29 +114,55 @@ pub enum CPointerResult {
 /// Returns a pointer to an opaque `SimulationState` struct.
 /// The caller is responsible for freeing this state later using `causality_free_simulation_state`.
 ///
+/// On failure returns null and, if `error_out` is not null, writes one of
+/// `ERR_NULL_INPUT`, `ERR_DESERIALIZE`, or `ERR_EXECUTION` to it; on success
+/// it is set to 0. `causality_last_error_message` describes the failure.
+///
 /// # Safety
 /// The `bytecode_ptr` must be a valid pointer to a byte array of `bytecode_len` length.
+/// The `error_out` pointer must be null or valid for writing an `i32`.
 #[no_mangle]
 pub unsafe extern "C" fn causality_load_bytecode(
     bytecode_ptr: *const u8,
     bytecode_len: usize,
+    error_out: *mut i32,
 ) -> *mut SimulationState {
     if bytecode_ptr.is_null() {
-        return std::ptr::null_mut();
+        return load_failed(
+            error_out,
+            ERR_NULL_INPUT,
+            b"bytecode pointer is null\0",
+        );
     }
 
     let bytecode_slice = slice::from_raw_parts(bytecode_ptr, bytecode_len);
 
     let artifact: CompiledArtifact = match bincode::deserialize(bytecode_slice) {
         Ok(art) => art,
-        Err(_) => return std::ptr::null_mut(),
+        Err(_) => {
+            return load_failed(
+                error_out,
+                ERR_DESERIALIZE,
+                b"bytecode is not a valid compiled artifact\0",
+            )
+        }
     };
 
     let mut executor = Executor::new();
-    executor.execute(&artifact.instructions).ok(); // Prime the executor
+    if executor.execute(&artifact.instructions).is_err() {
+        return load_failed(
+            error_out,
+            ERR_EXECUTION,
+            b"priming execution of the program failed\0",
+        );
+    }
 
     let state = SimulationState { executor };
 
+    LAST_ERROR.with(|last| last.set(None));
+    if !error_out.is_null() {
+        *error_out = 0;
+    }
     Box::into_raw(Box::new(state))
 }
 
@@ -107,30 +191,126 @@ pub unsafe extern "C" fn causality_run_simulation_step(
     if let Some(state) = state_ptr.as_mut() {
         match state.executor.step() {
             Ok(_) => CResult::Ok,
-            Err(_) => CResult::Err(-1), // Generic error
+            Err(_) => CResult::Err(ERR_GENERIC),
         }
     } else {
-        CResult::Err(-2) // Null pointer error
+        CResult::Err(ERR_NULL_INPUT)
     }
 }
 
-/// Get the current result from the simulation.
-/// (Placeholder implementation)
+/// Number of bytes in the length prefix of a result buffer
+pub const RESULT_LENGTH_PREFIX: usize = 8;
+
+/// Get the current result from the simulation as a serialized buffer.
+///
+/// On success, returns a pointer to a newly allocated buffer and writes its
+/// total length to `out_len`. The buffer starts with the payload length as a
+/// little-endian `u64`, followed by the bincode-serialized `MachineValue`.
+///
+/// Ownership: the buffer belongs to the caller, independent of the
+/// simulation state, and must be released exactly once with
+/// `causality_free_result_buffer`, passing the pointer and the length
+/// written to `out_len`.
+///
+/// On failure returns `ERR_NULL_INPUT` for a null argument, `ERR_GENERIC` if
+/// the executor has no result, or `ERR_SERIALIZE` if it cannot be encoded.
 ///
 /// # Safety
-/// The `state_ptr` must be a valid pointer to a `SimulationState`.
+/// The `state_ptr` must be a valid pointer to a `SimulationState`, and
+/// `out_len` must be a valid pointer to a `usize`.
 #[no_mangle]
 pub unsafe extern "C" fn causality_get_simulation_result(
     state_ptr: *mut SimulationState,
+    out_len: *mut usize,
 ) -> CPointerResult {
-    if let Some(state) = state_ptr.as_mut() {
-        // This is a placeholder. A real implementation would serialize
-        // the result from state.executor.get_result() into a returnable format.
-        let _ = state.executor.get_result();
-        CPointerResult::Ok(std::ptr::null())
-    } else {
-        CPointerResult::Err(-2) // Null pointer error
+    let Some(state) = state_ptr.as_mut() else {
+        return CPointerResult::Err(ERR_NULL_INPUT);
+    };
+    if out_len.is_null() {
+        return CPointerResult::Err(ERR_NULL_INPUT);
+    }
+
+    let value = match state.executor.get_result() {
+        Ok(value) => value,
+        Err(_) => return CPointerResult::Err(ERR_GENERIC),
+    };
+    let payload = match bincode::serialize(&value) {
+        Ok(payload) => payload,
+        Err(_) => return CPointerResult::Err(ERR_SERIALIZE),
+    };
+
+    let mut buffer = Vec::with_capacity(RESULT_LENGTH_PREFIX + payload.len());
+    buffer.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    buffer.extend_from_slice(&payload);
+    let buffer = buffer.into_boxed_slice();
+
+    *out_len = buffer.len();
+    CPointerResult::Ok(Box::into_raw(buffer) as *mut u8 as *const c_void)
+}
+
+/// Frees a buffer returned by `causality_get_simulation_result`.
+///
+/// # Safety
+/// The `buffer_ptr` must have been returned by
+/// `causality_get_simulation_result` together with `buffer_len`, and must
+/// not be used or freed again afterwards. Passing a null pointer is safe.
+#[no_mangle]
+pub unsafe extern "C" fn causality_free_result_buffer(
+    buffer_ptr: *mut u8,
+    buffer_len: usize,
+) {
+    if !buffer_ptr.is_null() {
+        drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+            buffer_ptr, buffer_len,
+        )));
+    }
+}
+
+/// Serialize the value held in a register into a caller-provided buffer.
+///
+/// On entry `out_len` holds the capacity of `out_buf`; on success it is set
+/// to the number of bytes written, which are the bincode-serialized
+/// `MachineValue`. Returns `ERR_EMPTY_REGISTER` if the register is empty,
+/// and `ERR_BUFFER_TOO_SMALL` if the buffer is too small, in which case
+/// `out_len` is set to the size required so the caller can retry.
+///
+/// # Safety
+/// The `state_ptr` must be a valid pointer to a `SimulationState`, `out_len`
+/// must be a valid pointer to a `usize`, and `out_buf` must be valid for
+/// writes of `*out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn causality_read_register(
+    state_ptr: *mut SimulationState,
+    reg_id: u32,
+    out_buf: *mut u8,
+    out_len: *mut usize,
+) -> CResult {
+    let Some(state) = state_ptr.as_ref() else {
+        return CResult::Err(ERR_NULL_INPUT);
+    };
+    if out_buf.is_null() || out_len.is_null() {
+        return CResult::Err(ERR_NULL_INPUT);
+    }
+
+    let Some(value) = state
+        .executor
+        .machine_state()
+        .load_register(RegisterId(reg_id))
+    else {
+        return CResult::Err(ERR_EMPTY_REGISTER);
+    };
+    let bytes = match bincode::serialize(value) {
+        Ok(bytes) => bytes,
+        Err(_) => return CResult::Err(ERR_SERIALIZE),
+    };
+
+    if bytes.len() > *out_len {
+        *out_len = bytes.len();
+        return CResult::Err(ERR_BUFFER_TOO_SMALL);
     }
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), out_buf, bytes.len());
+    *out_len = bytes.len();
+    CResult::Ok
 }
 
 /// FFI error type
@@ -144,3 +324,94 @@ pub enum FfiError {
     #[error("Runtime error: {0}")]
     Runtime(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use causality_core::machine::{Instruction, MachineValue};
+
+    /// The program that writes the tensor of registers 0 and 1 into
+    /// register 2
+    fn tensor_program() -> Vec<Instruction> {
+        vec![Instruction::Tensor {
+            left_reg: RegisterId(0),
+            right_reg: RegisterId(1),
+            output_reg: RegisterId(2),
+        }]
+    }
+
+    /// Load the tensor program through `causality_load_bytecode`
+    unsafe fn load_tensor_program() -> *mut SimulationState {
+        let mut artifact = causality_compiler::compile("(pure 42)").unwrap();
+        artifact.instructions = tensor_program();
+        let bytecode = bincode::serialize(&artifact).unwrap();
+        let state = causality_load_bytecode(
+            bytecode.as_ptr(),
+            bytecode.len(),
+            std::ptr::null_mut(),
+        );
+        assert!(!state.is_null());
+        state
+    }
+
+    #[test]
+    fn test_read_register_returns_written_value() {
+        unsafe {
+            let state = load_tensor_program();
+
+            // Loading runs the program against empty registers, so reload it
+            // and provide its inputs before stepping through the FFI
+            let executor = &mut (*state).executor;
+            executor.load(&tensor_program());
+            let machine = executor.machine_state_mut();
+            machine.store_register(RegisterId(0), MachineValue::Int(3));
+            machine.store_register(RegisterId(1), MachineValue::Int(4));
+            assert_eq!(causality_run_simulation_step(state), CResult::Ok);
+
+            let mut buf = [0u8; 64];
+            let mut len = buf.len();
+            let result =
+                causality_read_register(state, 2, buf.as_mut_ptr(), &mut len);
+            assert_eq!(result, CResult::Ok);
+            let value: MachineValue = bincode::deserialize(&buf[..len]).unwrap();
+            assert_eq!(
+                value,
+                MachineValue::Product(
+                    Box::new(MachineValue::Int(3)),
+                    Box::new(MachineValue::Int(4))
+                )
+            );
+
+            causality_free_simulation_state(state);
+        }
+    }
+
+    #[test]
+    fn test_read_register_reports_empty_register_and_short_buffer() {
+        unsafe {
+            let state = load_tensor_program();
+            let mut buf = [0u8; 64];
+            let mut len = buf.len();
+            assert_eq!(
+                causality_read_register(state, 3, buf.as_mut_ptr(), &mut len),
+                CResult::Err(ERR_EMPTY_REGISTER)
+            );
+
+            (*state)
+                .executor
+                .machine_state_mut()
+                .store_register(RegisterId(2), MachineValue::Int(7));
+            let mut len = 1;
+            assert_eq!(
+                causality_read_register(state, 2, buf.as_mut_ptr(), &mut len),
+                CResult::Err(ERR_BUFFER_TOO_SMALL)
+            );
+            assert_eq!(
+                len,
+                bincode::serialize(&MachineValue::Int(7)).unwrap().len()
+            );
+
+            causality_free_simulation_state(state);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Bytecode loader error reporting tests
+//!
+//! Feeds `causality_load_bytecode` each kind of bad input and checks the
+//! error code it writes and the message it leaves for the caller.
+
+use std::ffi::CStr;
+
+use causality_core::machine::{Instruction, RegisterId};
+use causality_ffi::{
+    causality_free_simulation_state, causality_last_error_message,
+    causality_load_bytecode, ERR_DESERIALIZE, ERR_EXECUTION, ERR_NULL_INPUT,
+};
+
+unsafe fn last_error() -> Option<String> {
+    let message = causality_last_error_message();
+    (!message.is_null())
+        .then(|| CStr::from_ptr(message).to_string_lossy().into_owned())
+}
+
+#[test]
+fn test_load_reports_null_input() {
+    let mut error = 0;
+    unsafe {
+        let state = causality_load_bytecode(std::ptr::null(), 16, &mut error);
+        assert!(state.is_null());
+        assert_eq!(error, ERR_NULL_INPUT);
+        assert_eq!(last_error().as_deref(), Some("bytecode pointer is null"));
+    }
+}
+
+#[test]
+fn test_load_reports_deserialize_failure() {
+    let garbage = [0xffu8; 3];
+    let mut error = 0;
+    unsafe {
+        let state =
+            causality_load_bytecode(garbage.as_ptr(), garbage.len(), &mut error);
+        assert!(state.is_null());
+        assert_eq!(error, ERR_DESERIALIZE);
+        assert!(last_error()
+            .unwrap()
+            .contains("not a valid compiled artifact"));
+    }
+}
+
+#[test]
+fn test_load_reports_execution_failure() {
+    // A well-formed artifact whose program consumes the same register twice
+    let mut artifact = causality_compiler::compile("(pure 42)").unwrap();
+    artifact.instructions = vec![
+        Instruction::Alloc {
+            type_reg: RegisterId(1),
+            init_reg: RegisterId(2),
+            output_reg: RegisterId(3),
+        },
+        Instruction::Consume {
+            resource_reg: RegisterId(3),
+            output_reg: RegisterId(4),
+        },
+        Instruction::Consume {
+            resource_reg: RegisterId(3),
+            output_reg: RegisterId(5),
+        },
+    ];
+    let bytecode = bincode::serialize(&artifact).unwrap();
+    let mut error = 0;
+    unsafe {
+        let state =
+            causality_load_bytecode(bytecode.as_ptr(), bytecode.len(), &mut error);
+        assert!(state.is_null());
+        assert_eq!(error, ERR_EXECUTION);
+        assert_eq!(
+            last_error().as_deref(),
+            Some("priming execution of the program failed")
+        );
+    }
+}
+
+#[test]
+fn test_load_success_clears_error() {
+    let artifact = causality_compiler::compile("(pure 42)").unwrap();
+    let bytecode = bincode::serialize(&artifact).unwrap();
+    let mut error = 0;
+    unsafe {
+        causality_load_bytecode(std::ptr::null(), 0, &mut error);
+        assert_eq!(error, ERR_NULL_INPUT);
+
+        let state =
+            causality_load_bytecode(bytecode.as_ptr(), bytecode.len(), &mut error);
+        assert!(!state.is_null());
+        assert_eq!(error, 0);
+        assert_eq!(last_error(), None);
+        causality_free_simulation_state(state);
+    }
+}
+
+#[test]
+fn test_load_accepts_null_error_out() {
+    let state = unsafe {
+        causality_load_bytecode(std::ptr::null(), 0, std::ptr::null_mut())
+    };
+    assert!(state.is_null());
+}
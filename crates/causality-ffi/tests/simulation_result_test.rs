@@ -0,0 +1,62 @@
+//! Simulation result buffer tests
+//!
+//! Loads compiled bytecode through the C interface, runs a step, and reads
+//! the result back as a length-prefixed buffer.
+
+use causality_core::machine::MachineValue;
+use causality_ffi::{
+    causality_free_result_buffer, causality_free_simulation_state,
+    causality_get_simulation_result, causality_load_bytecode,
+    causality_run_simulation_step, CPointerResult, CResult, ERR_NULL_INPUT, RESULT_LENGTH_PREFIX,
+};
+
+#[test]
+fn test_simulation_result_buffer_is_length_prefixed() {
+    let artifact = causality_compiler::compile("(pure 42)").unwrap();
+    let bytecode = bincode::serialize(&artifact).unwrap();
+
+    unsafe {
+        let state = causality_load_bytecode(
+            bytecode.as_ptr(),
+            bytecode.len(),
+            std::ptr::null_mut(),
+        );
+        assert!(!state.is_null());
+        assert_eq!(causality_run_simulation_step(state), CResult::Ok);
+
+        let mut len = 0usize;
+        let ptr = match causality_get_simulation_result(state, &mut len) {
+            CPointerResult::Ok(ptr) => ptr as *mut u8,
+            CPointerResult::Err(code) => panic!("result failed with code {}", code),
+        };
+        assert!(!ptr.is_null());
+        assert!(len > RESULT_LENGTH_PREFIX);
+
+        // The buffer outlives the state it was read from
+        causality_free_simulation_state(state);
+
+        let buffer = std::slice::from_raw_parts(ptr, len);
+        let mut prefix = [0u8; RESULT_LENGTH_PREFIX];
+        prefix.copy_from_slice(&buffer[..RESULT_LENGTH_PREFIX]);
+        assert_eq!(
+            u64::from_le_bytes(prefix) as usize,
+            len - RESULT_LENGTH_PREFIX
+        );
+        let _: MachineValue =
+            bincode::deserialize(&buffer[RESULT_LENGTH_PREFIX..]).unwrap();
+
+        causality_free_result_buffer(ptr, len);
+    }
+}
+
+#[test]
+fn test_simulation_result_rejects_null_pointers() {
+    let mut len = 0usize;
+    let result =
+        unsafe { causality_get_simulation_result(std::ptr::null_mut(), &mut len) };
+    assert_eq!(result, CPointerResult::Err(ERR_NULL_INPUT));
+    assert_eq!(len, 0);
+
+    // Freeing a null buffer is a no-op
+    unsafe { causality_free_result_buffer(std::ptr::null_mut(), 0) };
+}
@@ -0,0 +1,71 @@
+//! Cross-language SSZ conformance tests
+//!
+//! Encodes the fixed corpus in `ocaml_ssz/test/conformance_vectors.txt` with
+//! the Rust SSZ implementation and checks the bytes match exactly. The OCaml
+//! side checks the same vectors in `ocaml_ssz/test/test_conformance.ml`, and
+//! the functions exported to OCaml by `ocaml_ssz/rust` are checked against
+//! them in that crate's tests, so no encoder can drift without a failure.
+
+use causality_core::system::Str;
+use ssz::{Encode, SszEncoder};
+
+const VECTORS: &str = include_str!("../../../ocaml_ssz/test/conformance_vectors.txt");
+
+/// Fixed part of a record: bool (1) + u32 (4) + offset of the name (4)
+const RECORD_FIXED_SIZE: usize = 9;
+
+fn parse_string(value: &str) -> &str {
+    if value == "\"\"" { "" } else { value }
+}
+
+fn encode_record(active: bool, count: u32, name: &str) -> Vec<u8> {
+    let mut buf = Vec::new();
+    let mut encoder = SszEncoder::container(&mut buf, RECORD_FIXED_SIZE);
+    encoder.append(&active);
+    encoder.append(&count);
+    encoder.append(&Str::new(name));
+    encoder.finalize();
+    buf
+}
+
+fn encode(kind: &str, value: &str) -> Vec<u8> {
+    match kind {
+        "bool" => value.parse::<bool>().unwrap().as_ssz_bytes(),
+        "u32" => value.parse::<u32>().unwrap().as_ssz_bytes(),
+        "string" => Str::new(parse_string(value)).as_ssz_bytes(),
+        "record" => {
+            let fields: Vec<&str> = value.split(',').collect();
+            let [active, count, name] = fields[..] else {
+                panic!("Malformed record vector: {}", value);
+            };
+            encode_record(active.parse().unwrap(), count.parse().unwrap(), parse_string(name))
+        }
+        _ => panic!("Unknown vector kind: {}", kind),
+    }
+}
+
+#[test]
+fn test_rust_encoding_matches_conformance_vectors() {
+    let mut checked = 0;
+    let mut divergences = Vec::new();
+
+    for line in VECTORS.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split(' ').collect();
+        let [kind, value, expected] = parts[..] else {
+            panic!("Malformed vector line: {}", line);
+        };
+
+        let actual = hex::encode(encode(kind, value));
+        if actual != expected {
+            divergences.push(format!("{} {}: expected {}, rust {}", kind, value, expected, actual));
+        }
+        checked += 1;
+    }
+
+    assert!(checked > 0, "No conformance vectors found");
+    assert!(divergences.is_empty(), "SSZ encoding diverged:\n{}", divergences.join("\n"));
+}
@@ -45,6 +45,9 @@ pub enum ExprKind {
     Lambda(Vec<Param>, Box<Expr>),
     Apply(Box<Expr>, Vec<Expr>),
     
+    // Recursive bindings, each visible in every binding and the body
+    LetRec(Vec<(Symbol, Expr)>, Box<Expr>),
+    
     // Resource Management
     Alloc(Box<Expr>),
     Consume(Box<Expr>),
@@ -60,6 +63,12 @@ pub enum ExprKind {
         value: Box<Expr>,
     },
 
+    // Code as data
+    // Quoted bodies are templates: lists are nested tensors ending in unit
+    Quote(Box<Expr>),
+    Quasiquote(Box<Expr>),
+    Unquote(Box<Expr>),
+
     // Session types integration (Layer 2)
     // Session type declarations
     SessionDeclaration {
@@ -100,6 +109,9 @@ pub struct Param {
 }
 
 /// Source location information
+///
+/// `start` and `end` are character offsets into the source; `line` and
+/// `column` are 1-based and locate `start`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Span {
     pub start: usize,
@@ -286,6 +298,128 @@ impl Expr {
         }
     }
 
+    /// Create a recursive binding expression
+    pub fn letrec(bindings: Vec<(Symbol, Expr)>, body: Expr) -> Self {
+        Self::new(ExprKind::LetRec(bindings, Box::new(body)))
+    }
+    
+    /// Check whether `name` occurs free in this expression
+    pub fn references(&self, name: &Symbol) -> bool {
+        match &self.kind {
+            ExprKind::Var(var) => var == name,
+            ExprKind::Const(_)
+            | ExprKind::UnitVal
+            | ExprKind::Quote(_)
+            | ExprKind::SessionDeclaration { .. } => false,
+            ExprKind::LetUnit(first, second) | ExprKind::Tensor(first, second) => {
+                first.references(name) || second.references(name)
+            }
+            ExprKind::LetTensor(tensor, left, right, body) => {
+                tensor.references(name)
+                    || (left != name && right != name && body.references(name))
+            }
+            ExprKind::Case(sum, left, left_branch, right, right_branch) => {
+                sum.references(name)
+                    || (left != name && left_branch.references(name))
+                    || (right != name && right_branch.references(name))
+            }
+            ExprKind::Lambda(params, body) => {
+                params.iter().all(|param| &param.name != name) && body.references(name)
+            }
+            ExprKind::Apply(func, args) => {
+                func.references(name) || args.iter().any(|arg| arg.references(name))
+            }
+            ExprKind::LetRec(bindings, body) => {
+                bindings.iter().all(|(bound, _)| bound != name)
+                    && (bindings.iter().any(|(_, value)| value.references(name))
+                        || body.references(name))
+            }
+            ExprKind::Inl(inner)
+            | ExprKind::Inr(inner)
+            | ExprKind::Alloc(inner)
+            | ExprKind::Consume(inner)
+            | ExprKind::Quasiquote(inner)
+            | ExprKind::Unquote(inner)
+            | ExprKind::RecordAccess { record: inner, .. }
+            | ExprKind::WithSession { body: inner, .. }
+            | ExprKind::SessionReceive { channel: inner }
+            | ExprKind::SessionSelect { channel: inner, .. } => inner.references(name),
+            ExprKind::RecordUpdate { record, value, .. } => {
+                record.references(name) || value.references(name)
+            }
+            ExprKind::SessionSend { channel, value } => {
+                channel.references(name) || value.references(name)
+            }
+            ExprKind::SessionCase { channel, branches } => {
+                channel.references(name)
+                    || branches.iter().any(|branch| branch.body.references(name))
+            }
+        }
+    }
+    
+    /// Find a `letrec` binding that is not a function but refers to itself
+    ///
+    /// Only functions can be defined in terms of themselves; a value such as
+    /// `(letrec ((x (+ x 1))) x)` has no well-founded meaning.
+    pub fn ill_founded_binding(bindings: &[(Symbol, Expr)]) -> Option<&Symbol> {
+        bindings
+            .iter()
+            .find(|(name, value)| {
+                !matches!(value.kind, ExprKind::Lambda(..)) && value.references(name)
+            })
+            .map(|(name, _)| name)
+    }
+    
+    /// Create a quoted expression
+    pub fn quote(template: Expr) -> Self {
+        Self::new(ExprKind::Quote(Box::new(template)))
+    }
+    
+    /// Create a quasiquoted expression
+    pub fn quasiquote(template: Expr) -> Self {
+        Self::new(ExprKind::Quasiquote(Box::new(template)))
+    }
+    
+    /// Create an unquote inside a quasiquote template
+    pub fn unquote(expr: Expr) -> Self {
+        Self::new(ExprKind::Unquote(Box::new(expr)))
+    }
+    
+    /// Read a quoted template back as data
+    ///
+    /// Nested quote forms become `(quote x)`-style lists and applications
+    /// become lists of their parts. Returns `None` for forms that have no
+    /// data representation, such as lambdas.
+    pub fn to_datum(&self) -> Option<LispValue> {
+        let tagged = |tag: &str, inner: &Expr| {
+            Some(LispValue::List(vec![
+                LispValue::Symbol(Symbol::new(tag)),
+                inner.to_datum()?,
+            ]))
+        };
+        match &self.kind {
+            ExprKind::Const(value) => Some(value.clone()),
+            ExprKind::Var(name) => Some(LispValue::Symbol(name.clone())),
+            ExprKind::UnitVal => Some(LispValue::List(Vec::new())),
+            ExprKind::Tensor(head, tail) => match tail.to_datum()? {
+                LispValue::List(mut items) => {
+                    items.insert(0, head.to_datum()?);
+                    Some(LispValue::List(items))
+                }
+                _ => None,
+            },
+            ExprKind::Apply(func, args) => std::iter::once(func.as_ref())
+                .chain(args)
+                .map(Expr::to_datum)
+                .collect::<Option<Vec<_>>>()
+                .map(LispValue::List),
+            ExprKind::Quote(inner) => tagged("quote", inner),
+            ExprKind::Quasiquote(inner) => tagged("quasiquote", inner),
+            ExprKind::Unquote(inner) => tagged("unquote", inner),
+            _ => None,
+        }
+    }
+
     // Session types expressions
     /// Create a session declaration
     pub fn session_declaration(name: impl Into<String>, roles: Vec<SessionRole>) -> Self {
@@ -336,6 +470,38 @@ impl Expr {
     }
 }
 
+impl Span {
+    /// Create a new span
+    pub fn new(start: usize, end: usize, line: usize, column: usize) -> Self {
+        Self { start, end, line, column }
+    }
+    
+    /// Render the source line containing this span with a caret underneath
+    ///
+    /// The caret covers the span up to the end of its first line.
+    pub fn render(&self, source: &str) -> String {
+        let text = source.lines().nth(self.line.saturating_sub(1)).unwrap_or("");
+        let indent = self.column.saturating_sub(1);
+        let available = text.chars().count().saturating_sub(indent).max(1);
+        let width = self.end.saturating_sub(self.start).clamp(1, available);
+        let gutter = self.line.to_string().len();
+        format!(
+            "{:>gutter$} | {}\n{:>gutter$} | {}{}",
+            self.line,
+            text,
+            "",
+            " ".repeat(indent),
+            "^".repeat(width),
+        )
+    }
+}
+
+impl std::fmt::Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
 impl Param {
     /// Create a new parameter without type annotation
     pub fn new(name: impl Into<Symbol>) -> Self {
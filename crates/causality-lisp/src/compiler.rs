@@ -4,12 +4,13 @@
 //! register machine instruction set.
 
 use crate::{
-    ast::{Expr, ExprKind, LispValue},
+    ast::{Expr, ExprKind, LispValue, Param},
     error::LispError,
 };
 use causality_core::machine::instruction::{
     Instruction, RegisterId,
 };
+use causality_core::machine::MachineValue;
 use causality_core::lambda::Symbol;
 use std::collections::BTreeMap;
 
@@ -64,17 +65,41 @@ impl CompilerContext {
     }
 }
 
+/// The function whose body is being compiled, when the expression being
+/// compiled is in tail position of that body
+#[derive(Debug, Clone)]
+struct TailPosition {
+    /// Name the function is bound to
+    name: Symbol,
+    
+    /// Register holding the function's closure
+    function_reg: RegisterId,
+    
+    /// Register the function's parameter is bound to
+    param_reg: RegisterId,
+}
+
 /// Lisp to Layer 0 compiler
 pub struct LispCompiler {
     /// Current compilation context
     context: CompilerContext,
+    
+    /// Closures compiled for `letrec` bindings, by the register bound to them
+    functions: BTreeMap<RegisterId, MachineValue>,
 }
 
 impl LispCompiler {
     /// Create a new compiler
     pub fn new() -> Self {
+        Self::with_context(CompilerContext::new())
+    }
+    
+    /// Create a compiler whose programs can refer to the variables already
+    /// bound in `context`, such as registers the host seeds before running
+    pub fn with_context(context: CompilerContext) -> Self {
         Self {
-            context: CompilerContext::new(),
+            context,
+            functions: BTreeMap::new(),
         }
     }
     
@@ -83,8 +108,23 @@ impl LispCompiler {
         self.compile_expr(expr)
     }
     
+    /// Closures compiled for `letrec` bindings, keyed by the register each
+    /// binding refers to
+    ///
+    /// The compiled instructions never allocate these closures; the host
+    /// stores each one in its register before running the program.
+    pub fn functions(&self) -> &BTreeMap<RegisterId, MachineValue> {
+        &self.functions
+    }
+    
     /// Compile an expression and return instructions and result register
     fn compile_expr(&mut self, expr: &Expr) -> CompileResult<(Vec<Instruction>, RegisterId)> {
+        self.compile_in(expr, None)
+    }
+    
+    /// Compile an expression, which is in tail position of the function
+    /// body being compiled if `tail` is given
+    fn compile_in(&mut self, expr: &Expr, tail: Option<&TailPosition>) -> CompileResult<(Vec<Instruction>, RegisterId)> {
         match &expr.kind {
             // Constants
             ExprKind::Const(value) => self.compile_const(value),
@@ -96,26 +136,27 @@ impl LispCompiler {
             ExprKind::UnitVal => self.compile_unit(),
             
             // Unit elimination
-            ExprKind::LetUnit(unit_expr, body) => self.compile_let_unit(unit_expr, body),
+            ExprKind::LetUnit(unit_expr, body) => self.compile_let_unit(unit_expr, body, tail),
             
             // Tensor product
             ExprKind::Tensor(left, right) => self.compile_tensor(left, right),
             
             // Tensor elimination
             ExprKind::LetTensor(tensor_expr, left_name, right_name, body) => {
-                self.compile_let_tensor(tensor_expr, left_name, right_name, body)
+                self.compile_let_tensor(tensor_expr, left_name, right_name, body, tail)
             }
             
             // Sum types
             ExprKind::Inl(value) => self.compile_inl(value),
             ExprKind::Inr(value) => self.compile_inr(value),
             ExprKind::Case(expr, left_name, left_branch, right_name, right_branch) => {
-                self.compile_case(expr, left_name, left_branch, right_name, right_branch)
+                self.compile_case(expr, left_name, left_branch, right_name, right_branch, tail)
             }
             
             // Functions
             ExprKind::Lambda(params, body) => self.compile_lambda(params, body),
-            ExprKind::Apply(func_expr, args) => self.compile_apply(func_expr, args),
+            ExprKind::Apply(func_expr, args) => self.compile_apply(func_expr, args, tail),
+            ExprKind::LetRec(bindings, body) => self.compile_letrec(bindings, body, tail),
             
             // Resource management
             ExprKind::Alloc(value_expr) => self.compile_alloc(value_expr),
@@ -125,6 +166,18 @@ impl LispCompiler {
             ExprKind::RecordAccess { record, field } => self.compile_record_access(record, field),
             ExprKind::RecordUpdate { record, field, value } => self.compile_record_update(record, field, value),
 
+            // Quoted data
+            ExprKind::Quote(template) => match template.to_datum() {
+                Some(datum) => self.compile_const(&datum),
+                None => Err(LispError::Eval(crate::error::EvalError::InvalidCall(
+                    "Expression cannot be quoted".to_string()
+                ))),
+            },
+            ExprKind::Quasiquote(_) => Err(LispError::Eval(crate::error::EvalError::NotImplemented(
+                "Quasiquote is only supported by the interpreter".to_string()
+            ))),
+            ExprKind::Unquote(_) => Err(LispError::Eval(crate::error::EvalError::UnquoteOutsideQuasiquote)),
+
             // Session types operations
             ExprKind::SessionDeclaration { name, roles } => self.compile_session_declaration(name, roles),
             ExprKind::WithSession { session, role, body } => self.compile_with_session(session, role, body),
@@ -305,9 +358,9 @@ impl LispCompiler {
     }
     
     /// Compile let-unit (unit elimination)
-    fn compile_let_unit(&mut self, unit_expr: &Expr, body: &Expr) -> CompileResult<(Vec<Instruction>, RegisterId)> {
+    fn compile_let_unit(&mut self, unit_expr: &Expr, body: &Expr, tail: Option<&TailPosition>) -> CompileResult<(Vec<Instruction>, RegisterId)> {
         let (mut instructions, _unit_reg) = self.compile_expr(unit_expr)?;
-        let (body_instructions, result_reg) = self.compile_expr(body)?;
+        let (body_instructions, result_reg) = self.compile_in(body, tail)?;
         
         instructions.extend(body_instructions);
         Ok((instructions, result_reg))
@@ -339,6 +392,7 @@ impl LispCompiler {
         left_name: &Symbol,
         right_name: &Symbol,
         body: &Expr,
+        tail: Option<&TailPosition>,
     ) -> CompileResult<(Vec<Instruction>, RegisterId)> {
         let (mut instructions, tensor_reg) = self.compile_expr(tensor_expr)?;
         
@@ -369,7 +423,7 @@ impl LispCompiler {
         self.context.bind_variable(right_name.clone(), right_reg);
         
         // Compile body with both variables bound
-        let (body_instructions, result_reg) = self.compile_expr(body)?;
+        let (body_instructions, result_reg) = self.compile_in(body, tail)?;
         instructions.extend(body_instructions);
         
         Ok((instructions, result_reg))
@@ -415,6 +469,7 @@ impl LispCompiler {
         left_branch: &Expr,
         _right_name: &Symbol,
         _right_branch: &Expr,
+        tail: Option<&TailPosition>,
     ) -> CompileResult<(Vec<Instruction>, RegisterId)> {
         let (mut instructions, sum_reg) = self.compile_expr(expr)?;
         
@@ -430,7 +485,7 @@ impl LispCompiler {
         
         // Bind variables and compile left branch (simplified approach)
         self.context.bind_variable(left_name.clone(), result_reg);
-        let (left_instructions, left_result) = self.compile_expr(left_branch)?;
+        let (left_instructions, left_result) = self.compile_in(left_branch, tail)?;
         instructions.extend(left_instructions);
         
         Ok((instructions, left_result))
@@ -461,8 +516,77 @@ impl LispCompiler {
         Ok((instructions, result_reg))
     }
     
+    /// Compile recursive bindings of single-parameter lambdas
+    ///
+    /// Every binding is compiled to a closure recorded in
+    /// [`Self::functions`] under the register its name is bound to, so the
+    /// bindings themselves emit no instructions.
+    fn compile_letrec(&mut self, bindings: &[(Symbol, Expr)], body: &Expr, tail: Option<&TailPosition>) -> CompileResult<(Vec<Instruction>, RegisterId)> {
+        let mut lambdas = Vec::with_capacity(bindings.len());
+        for (name, value) in bindings {
+            let ExprKind::Lambda(params, lambda_body) = &value.kind else {
+                return Err(LispError::Eval(crate::error::EvalError::NotImplemented(
+                    "letrec bindings other than lambdas are only supported by the interpreter".to_string()
+                )));
+            };
+            if params.len() != 1 {
+                return Err(LispError::Eval(crate::error::EvalError::NotImplemented(
+                    "Multi-parameter lambdas not yet supported".to_string()
+                )));
+            }
+            
+            // Bind every name first so the bindings can refer to each other
+            let function_reg = self.context.alloc_register();
+            self.context.bind_variable(name.clone(), function_reg);
+            lambdas.push((name, function_reg, &params[0], lambda_body));
+        }
+        
+        for (name, function_reg, param, lambda_body) in lambdas {
+            let function = self.compile_function(name, function_reg, param, lambda_body)?;
+            self.functions.insert(function_reg, function);
+        }
+        
+        self.compile_in(body, tail)
+    }
+    
+    /// Compile the body of the function bound to `name` into a closure
+    fn compile_function(&mut self, name: &Symbol, function_reg: RegisterId, param: &Param, body: &Expr) -> CompileResult<MachineValue> {
+        let outer_bindings = self.context.bindings.clone();
+        let param_reg = self.context.alloc_register();
+        self.context.bind_variable(param.name.clone(), param_reg);
+        
+        let tail = TailPosition {
+            name: name.clone(),
+            function_reg,
+            param_reg,
+        };
+        let compiled = self.compile_in(body, Some(&tail));
+        self.context.bindings = outer_bindings;
+        
+        let (instructions, _result_reg) = compiled?;
+        Ok(MachineValue::Function {
+            params: vec![param_reg],
+            body: instructions,
+            captured_env: BTreeMap::new(),
+        })
+    }
+    
     /// Compile function application
-    fn compile_apply(&mut self, func_expr: &Expr, args: &[Expr]) -> CompileResult<(Vec<Instruction>, RegisterId)> {
+    ///
+    /// Every application lowers to a single `Transform`. A self call in
+    /// tail position is the last instruction of its function's body and
+    /// writes back into the function's own parameter register rather than a
+    /// fresh one: the machine runs it by restarting the body in the current
+    /// frame, so tail-recursive loops neither nest frames nor grow the
+    /// register file.
+    fn compile_apply(&mut self, func_expr: &Expr, args: &[Expr], tail: Option<&TailPosition>) -> CompileResult<(Vec<Instruction>, RegisterId)> {
+        // `pure` has no runtime effect, so it compiles to its argument
+        if let (ExprKind::Var(name), [value]) = (&func_expr.kind, args) {
+            if name.as_str() == "pure" && self.context.lookup_variable(name).is_none() {
+                return self.compile_in(value, tail);
+            }
+        }
+        
         let (mut instructions, func_reg) = self.compile_expr(func_expr)?;
         
         if args.len() != 1 {
@@ -474,7 +598,11 @@ impl LispCompiler {
         let (arg_instructions, arg_reg) = self.compile_expr(&args[0])?;
         instructions.extend(arg_instructions);
         
-        let result_reg = self.context.alloc_register();
+        let result_reg = match tail {
+            Some(tail) if func_reg == tail.function_reg
+                && matches!(&func_expr.kind, ExprKind::Var(name) if *name == tail.name) => tail.param_reg,
+            _ => self.context.alloc_register(),
+        };
         
         // Use Transform instruction for function application
         instructions.push(Instruction::Transform {
@@ -849,4 +977,89 @@ mod tests {
         // Complex should have more instructions
         assert!(complex_count > simple_count);
     }
+
+    /// Compile `source` with `names` bound to fresh registers, as a host
+    /// seeding those registers would
+    fn compile_with(names: &[&str], source: &str) -> (LispCompiler, Vec<Instruction>, RegisterId, Vec<RegisterId>) {
+        let mut context = CompilerContext::new();
+        let registers = names.iter()
+            .map(|name| {
+                let reg = context.alloc_register();
+                context.bind_variable(Symbol::new(name), reg);
+                reg
+            })
+            .collect();
+        let mut compiler = LispCompiler::with_context(context);
+        let (instructions, result) = compiler.compile(&crate::parse(source).unwrap()).unwrap();
+        (compiler, instructions, result, registers)
+    }
+
+    #[test]
+    fn test_self_tail_call_reuses_parameter_register() {
+        let (compiler, instructions, _, _) =
+            compile_with(&["start"], "(letrec ((spin (lambda (n) (spin n)))) (spin start))");
+
+        let (&spin_reg, spin) = compiler.functions().iter().next().unwrap();
+        let MachineValue::Function { params, body, .. } = spin else {
+            panic!("letrec binding should compile to a closure");
+        };
+        assert_eq!(body, &vec![Instruction::Transform {
+            morph_reg: spin_reg,
+            input_reg: params[0],
+            output_reg: params[0],
+        }]);
+
+        // The call from the letrec body is not in a function's tail position
+        assert!(matches!(
+            instructions.as_slice(),
+            [Instruction::Transform { morph_reg, output_reg, .. }]
+                if *morph_reg == spin_reg && *output_reg != params[0]
+        ));
+    }
+
+    #[test]
+    fn test_non_tail_self_call_gets_fresh_register() {
+        let (compiler, _, _, _) =
+            compile_with(&["wrap", "start"], "(letrec ((f (lambda (n) (wrap (f n))))) (f start))");
+
+        let MachineValue::Function { params, body, .. } = compiler.functions().values().next().unwrap() else {
+            panic!("letrec binding should compile to a closure");
+        };
+        let Instruction::Transform { output_reg, .. } = body[0] else {
+            panic!("self call should compile to a Transform");
+        };
+        assert_ne!(output_reg, params[0]);
+    }
+
+    #[test]
+    fn test_deep_tail_recursion_runs_in_constant_space() {
+        use causality_core::machine::MachineState;
+
+        // countdown n = case pred n of 0 -> () | n - 1 -> countdown (n - 1)
+        let (compiler, instructions, result, registers) = compile_with(
+            &["pred", "step", "start"],
+            "(letrec ((countdown (lambda (n) (step (pred n))))) (countdown start))",
+        );
+        let [pred, step, start] = registers[..] else { unreachable!() };
+        let (&countdown_reg, countdown) = compiler.functions().iter().next().unwrap();
+
+        let mut machine = MachineState::new(instructions);
+        machine.store_register(pred, MachineValue::Symbol("pred".into()));
+        machine.store_register(step, MachineValue::Product(
+            Box::new(MachineValue::Symbol("identity".into())),
+            Box::new(MachineValue::MorphismRef(countdown_reg)),
+        ));
+        machine.store_register(countdown_reg, countdown.clone());
+        machine.store_register(start, MachineValue::Int(100_000));
+        let seeded = machine.registers.len();
+
+        // Nesting a frame per call would overflow the stack long before this
+        while !machine.finished {
+            machine.step().unwrap();
+        }
+
+        assert_eq!(machine.load_register(result), Some(&MachineValue::Unit));
+        // Only `start` was consumed and only the result was written
+        assert_eq!(machine.registers.len(), seeded);
+    }
 } 
\ No newline at end of file
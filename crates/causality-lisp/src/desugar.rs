@@ -21,6 +21,16 @@ pub fn desugar(expr: SugarExpr) -> Expr {
             Expr::apply(lambda, vec![value_expr])
         }
         
+        SugarExpr::LetRec(bindings, body) => {
+            // Recursive bindings have no encoding in the core calculus, so
+            // they stay a dedicated form
+            let bindings = bindings
+                .into_iter()
+                .map(|(name, value)| (name, desugar(value)))
+                .collect();
+            Expr::letrec(bindings, desugar(*body))
+        }
+        
         SugarExpr::If(condition, then_branch, else_branch) => {
             let condition_expr = desugar(*condition);
             let then_expr = desugar(*then_branch);
@@ -36,10 +46,14 @@ pub fn desugar(expr: SugarExpr) -> Expr {
         }
         
         SugarExpr::Quote(quoted) => {
-            // Convert quoted expressions to literal values
-            quote_to_literal(*quoted)
+            // Quoted bodies become templates read back as data at evaluation
+            Expr::quote(desugar(*quoted))
         }
         
+        SugarExpr::Quasiquote(template) => Expr::quasiquote(desugar(*template)),
+        
+        SugarExpr::Unquote(expr) => Expr::unquote(desugar(*expr)),
+        
         SugarExpr::And(left, right) => {
             // Desugar and to nested if expressions: (and a b) → (if a b false)
             let left_expr = desugar(*left);
@@ -79,37 +93,17 @@ pub fn desugar(expr: SugarExpr) -> Expr {
 pub enum SugarExpr {
     Core(Box<Expr>),
     Let(Symbol, Box<SugarExpr>, Box<SugarExpr>),
+    LetRec(Vec<(Symbol, SugarExpr)>, Box<SugarExpr>),
     If(Box<SugarExpr>, Box<SugarExpr>, Box<SugarExpr>),
     List(Vec<SugarExpr>),
     Quote(Box<SugarExpr>),
+    Quasiquote(Box<SugarExpr>),
+    Unquote(Box<SugarExpr>),
     And(Box<SugarExpr>, Box<SugarExpr>),
     Or(Box<SugarExpr>, Box<SugarExpr>),
     Not(Box<SugarExpr>),
 }
 
-/// Convert a quoted expression to a literal value
-fn quote_to_literal(quoted: SugarExpr) -> Expr {
-    match quoted {
-        SugarExpr::Core(boxed_expr) => {
-            let expr = *boxed_expr;
-            match expr.kind {
-                ExprKind::Const(value) => {
-                    Expr::constant(value)
-                }
-                ExprKind::Var(symbol) => {
-                    Expr::constant(LispValue::Symbol(symbol))
-                }
-                _ => {
-                    Expr::constant(LispValue::Symbol(Symbol::new("quoted-expr")))
-                }
-            }
-        }
-        _ => {
-            Expr::constant(LispValue::Symbol(Symbol::new("quoted-sugar")))
-        }
-    }
-}
-
 /// Desugar if-then-else to case analysis
 fn desugar_if(condition: Expr, then_branch: Expr, else_branch: Expr) -> Expr {
     match condition.kind {
@@ -135,6 +129,10 @@ impl SugarExpr {
         SugarExpr::Let(name.into(), Box::new(value), Box::new(body))
     }
     
+    pub fn letrec(bindings: Vec<(Symbol, SugarExpr)>, body: SugarExpr) -> Self {
+        SugarExpr::LetRec(bindings, Box::new(body))
+    }
+    
     pub fn if_expr(condition: SugarExpr, then_branch: SugarExpr, else_branch: SugarExpr) -> Self {
         SugarExpr::If(Box::new(condition), Box::new(then_branch), Box::new(else_branch))
     }
@@ -147,6 +145,14 @@ impl SugarExpr {
         SugarExpr::Quote(Box::new(expr))
     }
     
+    pub fn quasiquote(template: SugarExpr) -> Self {
+        SugarExpr::Quasiquote(Box::new(template))
+    }
+    
+    pub fn unquote(expr: SugarExpr) -> Self {
+        SugarExpr::Unquote(Box::new(expr))
+    }
+    
     pub fn and(left: SugarExpr, right: SugarExpr) -> Self {
         SugarExpr::And(Box::new(left), Box::new(right))
     }
@@ -261,4 +267,27 @@ mod tests {
             _ => panic!("Core expression should pass through unchanged"),
         }
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_desugar_quasiquote() {
+        let template = SugarExpr::list(vec![
+            SugarExpr::core(Expr::constant(LispValue::Int(1))),
+            SugarExpr::unquote(SugarExpr::core(Expr::variable("x"))),
+        ]);
+
+        let result = desugar(SugarExpr::quasiquote(template));
+
+        match result.kind {
+            ExprKind::Quasiquote(template) => match template.kind {
+                ExprKind::Tensor(_, rest) => match rest.kind {
+                    ExprKind::Tensor(unquoted, _) => {
+                        assert!(matches!(unquoted.kind, ExprKind::Unquote(_)))
+                    }
+                    _ => panic!("Expected a two-element list"),
+                },
+                _ => panic!("Expected a list template"),
+            },
+            _ => panic!("Expected quasiquote expression"),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Error types for the Causality Lisp language implementation
 
+use crate::ast::Span;
 use thiserror::Error;
 
 /// Main error type for Lisp operations
@@ -21,23 +22,23 @@ pub enum ParseError {
     #[error("Unexpected end of input")]
     UnexpectedEof,
 
-    #[error("Unexpected character '{0}' at line {1}, column {2}")]
-    UnexpectedChar(char, usize, usize),
+    #[error("Unexpected character '{0}' at {1}")]
+    UnexpectedChar(char, Span),
 
-    #[error("Unclosed string literal at line {0}, column {1}")]
-    UnclosedString(usize, usize),
+    #[error("Unclosed string literal at {0}")]
+    UnclosedString(Span),
 
-    #[error("Unclosed parentheses at line {0}, column {1}")]
-    UnclosedParen(usize, usize),
+    #[error("Unclosed parentheses at {0}")]
+    UnclosedParen(Span),
 
-    #[error("Unexpected closing parenthesis at line {0}, column {1}")]
-    UnexpectedCloseParen(usize, usize),
+    #[error("Unexpected closing parenthesis at {0}")]
+    UnexpectedCloseParen(Span),
 
-    #[error("Invalid number format '{0}' at line {1}, column {2}")]
-    InvalidNumber(String, usize, usize),
+    #[error("Invalid number format '{0}' at {1}")]
+    InvalidNumber(String, Span),
 
-    #[error("Invalid escape sequence '\\{0}' at line {1}, column {2}")]
-    InvalidEscape(char, usize, usize),
+    #[error("Invalid escape sequence '\\{0}' at {1}")]
+    InvalidEscape(char, Span),
 
     #[error("Empty expression")]
     EmptyExpression,
@@ -47,77 +48,73 @@ pub enum ParseError {
 
     // Enhanced error types with better context
     #[error(
-        "Expected {expected} but found {found} at line {line}, column {column}"
+        "Expected {expected} but found {found} at {span}"
     )]
     ExpectedToken {
         expected: String,
         found: String,
-        line: usize,
-        column: usize,
+        span: Span,
     },
 
-    #[error("Expected symbol for {context} but found {found} at line {line}, column {column}")]
+    #[error("Expected symbol for {context} but found {found} at {span}")]
     ExpectedSymbol {
         context: String,
         found: String,
-        line: usize,
-        column: usize,
+        span: Span,
     },
 
-    #[error("Invalid special form '{form}' at line {line}, column {column}\n  Hint: {hint}")]
+    #[error("Invalid special form '{form}' at {span}\n  Hint: {hint}")]
     InvalidSpecialForm {
         form: String,
         hint: String,
-        line: usize,
-        column: usize,
+        span: Span,
     },
 
-    #[error("Incomplete {construct} at line {line}, column {column}\n  Expected: {expected}\n  Hint: {hint}")]
+    #[error("Incomplete {construct} at {span}\n  Expected: {expected}\n  Hint: {hint}")]
     IncompleteConstruct {
         construct: String,
         expected: String,
         hint: String,
-        line: usize,
-        column: usize,
+        span: Span,
     },
 
-    #[error("Malformed {construct} at line {line}, column {column}\n  Error: {description}\n  Hint: {hint}")]
+    #[error("Malformed {construct} at {span}\n  Error: {description}\n  Hint: {hint}")]
     MalformedConstruct {
         construct: String,
         description: String,
         hint: String,
-        line: usize,
-        column: usize,
+        span: Span,
     },
 
-    #[error("Too {issue} arguments for '{form}' at line {line}, column {column}\n  Expected: {expected}\n  Found: {found}")]
+    #[error("Too {issue} arguments for '{form}' at {span}\n  Expected: {expected}\n  Found: {found}")]
     ArgumentCount {
         form: String,
         issue: String, // "many" or "few"
         expected: String,
         found: usize,
-        line: usize,
-        column: usize,
+        span: Span,
     },
 
-    #[error("Invalid token sequence at line {line}, column {column}\n  Context: {context}\n  Suggestion: {suggestion}")]
+    #[error("Invalid token sequence at {span}\n  Context: {context}\n  Suggestion: {suggestion}")]
     InvalidTokenSequence {
         context: String,
         suggestion: String,
-        line: usize,
-        column: usize,
+        span: Span,
     },
 
-    #[error("Unexpected end of input while parsing {construct}\n  Hint: {hint}")]
-    UnexpectedEofInConstruct { construct: String, hint: String },
+    #[error("Unexpected end of input while parsing {construct} at {span}\n  Hint: {hint}")]
+    UnexpectedEofInConstruct {
+        construct: String,
+        hint: String,
+        span: Span,
+    },
 
-    #[error("Reserved keyword '{keyword}' used as {usage} at line {line}, column {column}\n  Hint: {hint}")]
+    #[error("Reserved keyword '{keyword}' used as {usage} at {span}\n  Hint: {hint}")]
     ReservedKeyword {
         keyword: String,
         usage: String,
         hint: String,
-        line: usize,
-        column: usize,
+        span: Span,
     },
 }
 
@@ -191,6 +188,50 @@ pub enum EvalError {
     /// Linear type violation error
     #[error("Linear type violation: {0}")]
     LinearityViolation(String),
+
+    /// Non-function `letrec` binding that refers to itself
+    #[error("Recursive binding '{0}' refers to itself but is not a function")]
+    IllFoundedRecursion(String),
+
+    /// Unquote used outside of a quasiquote
+    #[error("Unquote outside of quasiquote")]
+    UnquoteOutsideQuasiquote,
+
+    /// Error raised while evaluating the expression at the given span
+    #[error("{error} at {span}")]
+    Located { error: Box<EvalError>, span: Span },
+}
+
+impl EvalError {
+    /// Attach the span of the expression that raised this error
+    ///
+    /// Errors that already carry a span keep it, so the innermost
+    /// expression with a known location is reported.
+    pub fn at(self, span: Option<&Span>) -> Self {
+        match (self, span) {
+            (located @ Self::Located { .. }, _) | (located, None) => located,
+            (error, Some(span)) => Self::Located {
+                error: Box::new(error),
+                span: span.clone(),
+            },
+        }
+    }
+
+    /// Source span of the expression that raised this error, if known
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Self::Located { span, .. } => Some(span),
+            _ => None,
+        }
+    }
+
+    /// The error without its location
+    pub fn without_span(&self) -> &EvalError {
+        match self {
+            Self::Located { error, .. } => error,
+            error => error,
+        }
+    }
 }
 
 /// Type system errors
@@ -216,22 +257,45 @@ pub enum TypeError {
 
     #[error("Effect type error: {0}")]
     EffectTypeError(String),
+
+    #[error("Recursive binding '{0}' refers to itself but is not a function")]
+    IllFoundedRecursion(String),
 }
 
 /// Helper functions for creating common error patterns
 impl ParseError {
+    /// Source span the error points at, if it has one
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Self::UnexpectedChar(_, span)
+            | Self::UnclosedString(span)
+            | Self::UnclosedParen(span)
+            | Self::UnexpectedCloseParen(span)
+            | Self::InvalidNumber(_, span)
+            | Self::InvalidEscape(_, span)
+            | Self::ExpectedToken { span, .. }
+            | Self::ExpectedSymbol { span, .. }
+            | Self::InvalidSpecialForm { span, .. }
+            | Self::IncompleteConstruct { span, .. }
+            | Self::MalformedConstruct { span, .. }
+            | Self::ArgumentCount { span, .. }
+            | Self::InvalidTokenSequence { span, .. }
+            | Self::UnexpectedEofInConstruct { span, .. }
+            | Self::ReservedKeyword { span, .. } => Some(span),
+            Self::UnexpectedEof | Self::EmptyExpression | Self::InvalidSyntax(_) => None,
+        }
+    }
+
     /// Create an error for when a symbol is expected in a specific context
     pub fn expected_symbol_for(
         context: &str,
         found: &str,
-        line: usize,
-        column: usize,
+        span: Span,
     ) -> Self {
         Self::ExpectedSymbol {
             context: context.to_string(),
             found: found.to_string(),
-            line,
-            column,
+            span,
         }
     }
 
@@ -239,14 +303,12 @@ impl ParseError {
     pub fn expected_token(
         expected: &str,
         found: &str,
-        line: usize,
-        column: usize,
+        span: Span,
     ) -> Self {
         Self::ExpectedToken {
             expected: expected.to_string(),
             found: found.to_string(),
-            line,
-            column,
+            span,
         }
     }
 
@@ -255,15 +317,13 @@ impl ParseError {
         construct: &str,
         description: &str,
         hint: &str,
-        line: usize,
-        column: usize,
+        span: Span,
     ) -> Self {
         Self::MalformedConstruct {
             construct: construct.to_string(),
             description: description.to_string(),
             hint: hint.to_string(),
-            line,
-            column,
+            span,
         }
     }
 
@@ -272,15 +332,13 @@ impl ParseError {
         construct: &str,
         expected: &str,
         hint: &str,
-        line: usize,
-        column: usize,
+        span: Span,
     ) -> Self {
         Self::IncompleteConstruct {
             construct: construct.to_string(),
             expected: expected.to_string(),
             hint: hint.to_string(),
-            line,
-            column,
+            span,
         }
     }
 
@@ -289,8 +347,7 @@ impl ParseError {
         form: &str,
         expected: &str,
         found: usize,
-        line: usize,
-        column: usize,
+        span: Span,
     ) -> Self {
         let issue = if found > expected.parse::<usize>().unwrap_or(0) {
             "many"
@@ -302,8 +359,7 @@ impl ParseError {
             issue: issue.to_string(),
             expected: expected.to_string(),
             found,
-            line,
-            column,
+            span,
         }
     }
 }
@@ -3,15 +3,16 @@
 //! This module provides an interpreter that evaluates Causality Lisp expressions
 //! and produces runtime values.
 
-use crate::ast::{Expr, ExprKind, LispValue};
+use crate::ast::{Expr, ExprKind, LispValue, Param};
 use crate::error::{EvalError, EvalResult};
-use crate::value::{Environment, Value, ValueKind};
+use crate::value::{Environment, RecGroup, Value, ValueKind};
 use causality_core::effect::session_registry::{
     SessionDeclaration, SessionRegistry,
 };
 use causality_core::lambda::base::SessionType;
 use causality_core::lambda::Symbol;
 use std::collections::BTreeMap;
+use std::rc::Rc;
 
 /// Evaluation context containing the current environment
 #[derive(Debug, Clone)]
@@ -54,6 +55,42 @@ impl EvalContext {
     }
 }
 
+/// Pending work for [`Interpreter::eval_iterative`]
+enum Task<'e> {
+    /// Evaluate an expression, pushing its value
+    Eval(&'e Expr),
+    /// Discard the value on top, then evaluate the body
+    Then(&'e Expr),
+    /// Combine the top two values into a tensor
+    BuildTensor,
+    /// Bind the components of the tensor on top, then evaluate the body
+    BindTensor(&'e Symbol, &'e Symbol, &'e Expr),
+    /// Wrap the value on top in a sum with the given tag
+    Inject(u8),
+    /// Take the branch matching the tag of the sum on top
+    SelectBranch(&'e Symbol, &'e Expr, &'e Symbol, &'e Expr),
+    /// Restore a binding shadowed while evaluating a body
+    Restore(&'e Symbol, Option<Value>),
+    /// Apply the function value below the given number of arguments
+    Call(usize),
+    /// Evaluate the body of an applied lambda literal in a fresh context
+    Enter(&'e [Param], &'e Expr, usize),
+    /// Return to the caller's context
+    Leave(EvalContext),
+    /// Allocate the value on top as a resource
+    Alloc,
+    /// Consume the resource on top
+    Consume,
+}
+
+/// A function application ready to run
+enum PreparedCall {
+    /// Evaluate the body in the given context
+    Body(Expr, EvalContext),
+    /// The call already produced a value
+    Done(Value),
+}
+
 /// Main interpreter for Causality Lisp
 pub struct Interpreter {
     /// Global environment
@@ -97,6 +134,7 @@ impl Interpreter {
         global_env.bind(Symbol::new("="), Value::builtin("=", 2));
         global_env.bind(Symbol::new("<"), Value::builtin("<", 2));
         global_env.bind(Symbol::new(">"), Value::builtin(">", 2));
+        global_env.bind(Symbol::new("pure"), Value::builtin("pure", 1));
 
         Self {
             global_env,
@@ -259,11 +297,235 @@ impl Interpreter {
         self.eval_with_context(expr, &mut context)
     }
 
+    /// Evaluate an expression using an explicit work stack
+    ///
+    /// Produces the same result as [`Interpreter::eval`], but nested `let`s
+    /// (applied lambda literals), tensors, sums and resource operations are
+    /// evaluated without recursion, so generated programs nested tens of
+    /// thousands deep do not overflow the native stack. Calls through lambda
+    /// values, record and session operations still evaluate recursively.
+    pub fn eval_iterative(&mut self, expr: &Expr) -> EvalResult<Value> {
+        let mut context = EvalContext::from_environment(self.global_env.clone());
+        self.eval_iterative_with_context(expr, &mut context)
+    }
+
+    fn eval_iterative_with_context(
+        &mut self,
+        expr: &Expr,
+        context: &mut EvalContext,
+    ) -> EvalResult<Value> {
+        let mut tasks = vec![Task::Eval(expr)];
+        let mut values: Vec<Value> = Vec::new();
+
+        while let Some(task) = tasks.pop() {
+            match task {
+                Task::Eval(expr) => match &expr.kind {
+                    ExprKind::Const(value) => values.push(
+                        self.eval_const(value)
+                            .map_err(|error| error.at(expr.span.as_ref()))?,
+                    ),
+                    ExprKind::Var(name) => values.push(
+                        self.eval_var(name, context)
+                            .map_err(|error| error.at(expr.span.as_ref()))?,
+                    ),
+                    ExprKind::UnitVal => values.push(Value::unit()),
+                    ExprKind::LetUnit(unit_expr, body) => {
+                        tasks.push(Task::Then(body));
+                        tasks.push(Task::Eval(unit_expr));
+                    }
+                    ExprKind::Tensor(left, right) => {
+                        tasks.push(Task::BuildTensor);
+                        tasks.push(Task::Eval(right));
+                        tasks.push(Task::Eval(left));
+                    }
+                    ExprKind::LetTensor(
+                        tensor_expr,
+                        left_name,
+                        right_name,
+                        body,
+                    ) => {
+                        tasks.push(Task::BindTensor(left_name, right_name, body));
+                        tasks.push(Task::Eval(tensor_expr));
+                    }
+                    ExprKind::Inl(value) => {
+                        tasks.push(Task::Inject(0));
+                        tasks.push(Task::Eval(value));
+                    }
+                    ExprKind::Inr(value) => {
+                        tasks.push(Task::Inject(1));
+                        tasks.push(Task::Eval(value));
+                    }
+                    ExprKind::Case(
+                        scrutinee,
+                        left_name,
+                        left_branch,
+                        right_name,
+                        right_branch,
+                    ) => {
+                        tasks.push(Task::SelectBranch(
+                            left_name,
+                            left_branch,
+                            right_name,
+                            right_branch,
+                        ));
+                        tasks.push(Task::Eval(scrutinee));
+                    }
+                    ExprKind::Apply(func_expr, args) => {
+                        // A lambda literal is entered directly instead of being
+                        // materialized as a value, which would clone its body
+                        let literal = match &func_expr.kind {
+                            ExprKind::Lambda(params, body) => Some((params, body)),
+                            _ => None,
+                        };
+                        match literal {
+                            Some((params, body)) => {
+                                tasks.push(Task::Enter(params, body, args.len()))
+                            }
+                            None => tasks.push(Task::Call(args.len())),
+                        }
+                        tasks.extend(args.iter().rev().map(Task::Eval));
+                        if literal.is_none() {
+                            tasks.push(Task::Eval(func_expr));
+                        }
+                    }
+                    ExprKind::Alloc(value_expr) => {
+                        tasks.push(Task::Alloc);
+                        tasks.push(Task::Eval(value_expr));
+                    }
+                    ExprKind::Consume(resource_expr) => {
+                        tasks.push(Task::Consume);
+                        tasks.push(Task::Eval(resource_expr));
+                    }
+                    _ => values.push(self.eval_with_context(expr, context)?),
+                },
+                Task::Then(body) => {
+                    pop_value(&mut values)?;
+                    tasks.push(Task::Eval(body));
+                }
+                Task::BuildTensor => {
+                    let right = pop_value(&mut values)?;
+                    let left = pop_value(&mut values)?;
+                    values.push(Value::tensor(left, right));
+                }
+                Task::BindTensor(left_name, right_name, body) => {
+                    let ValueKind::Tensor(left_val, right_val) =
+                        pop_value(&mut values)?.kind
+                    else {
+                        return Err(EvalError::TypeMismatch {
+                            expected: "Tensor".to_string(),
+                            found: "Other".to_string(),
+                        });
+                    };
+                    let old_left = context
+                        .environment
+                        .bindings
+                        .insert(left_name.clone(), *left_val);
+                    let old_right = context
+                        .environment
+                        .bindings
+                        .insert(right_name.clone(), *right_val);
+
+                    // Restore the left binding first, as `eval` does
+                    tasks.push(Task::Restore(right_name, old_right));
+                    tasks.push(Task::Restore(left_name, old_left));
+                    tasks.push(Task::Eval(body));
+                }
+                Task::Inject(tag) => {
+                    let value = pop_value(&mut values)?;
+                    values.push(Value::sum(tag, value));
+                }
+                Task::SelectBranch(
+                    left_name,
+                    left_branch,
+                    right_name,
+                    right_branch,
+                ) => {
+                    let (name, branch, value) = match pop_value(&mut values)?.kind {
+                        ValueKind::Sum { tag: 0, value } => {
+                            (left_name, left_branch, value)
+                        }
+                        ValueKind::Sum { tag: 1, value } => {
+                            (right_name, right_branch, value)
+                        }
+                        _ => {
+                            return Err(EvalError::TypeMismatch {
+                                expected: "Sum type".to_string(),
+                                found: "Other".to_string(),
+                            })
+                        }
+                    };
+                    let old_binding =
+                        context.environment.bindings.insert(name.clone(), *value);
+                    tasks.push(Task::Restore(name, old_binding));
+                    tasks.push(Task::Eval(branch));
+                }
+                Task::Restore(name, old_binding) => {
+                    if let Some(val) = old_binding {
+                        context.environment.bindings.insert(name.clone(), val);
+                    } else {
+                        context.environment.bindings.remove(name);
+                    }
+                }
+                Task::Call(argc) => {
+                    let arg_vals = pop_values(&mut values, argc)?;
+                    let func_val = pop_value(&mut values)?;
+                    let value = match self.prepare_call(func_val, arg_vals)? {
+                        PreparedCall::Body(body, mut new_context) => self
+                            .eval_iterative_with_context(&body, &mut new_context)?,
+                        PreparedCall::Done(value) => value,
+                    };
+                    values.push(value);
+                }
+                Task::Enter(params, body, argc) => {
+                    if params.len() != argc {
+                        return Err(EvalError::ArityMismatch {
+                            expected: params.len(),
+                            found: argc,
+                        });
+                    }
+
+                    let mut new_context = EvalContext::new();
+                    for (param, arg_val) in
+                        params.iter().zip(pop_values(&mut values, argc)?)
+                    {
+                        new_context.bind(param.name.clone(), arg_val);
+                    }
+
+                    let caller = std::mem::replace(context, new_context);
+                    tasks.push(Task::Leave(caller));
+                    tasks.push(Task::Eval(body));
+                }
+                Task::Leave(caller) => *context = caller,
+                Task::Alloc => {
+                    let value = pop_value(&mut values)?;
+                    values.push(alloc_value(value, context));
+                }
+                Task::Consume => {
+                    let value = pop_value(&mut values)?;
+                    values.push(consume_value(value)?);
+                }
+            }
+        }
+
+        pop_value(&mut values)
+    }
+
     /// Evaluate an expression with a given context
+    ///
+    /// Errors are tagged with the span of the innermost expression that has one.
     pub fn eval_with_context(
         &mut self,
         expr: &Expr,
         context: &mut EvalContext,
+    ) -> EvalResult<Value> {
+        self.eval_kind(expr, context)
+            .map_err(|error| error.at(expr.span.as_ref()))
+    }
+
+    fn eval_kind(
+        &mut self,
+        expr: &Expr,
+        context: &mut EvalContext,
     ) -> EvalResult<Value> {
         match &expr.kind {
             // Literals and variables
@@ -384,41 +646,18 @@ impl Interpreter {
             ExprKind::Apply(func_expr, args) => {
                 self.eval_apply(func_expr, args, context)
             }
+            ExprKind::LetRec(bindings, body) => {
+                self.eval_letrec(bindings, body, context)
+            }
 
             // Resource management
             ExprKind::Alloc(value_expr) => {
                 let val = self.eval_with_context(value_expr, context)?;
-                // Generate a unique resource ID based on value hash and current context
-                let resource_id = format!(
-                    "res_{}_{}",
-                    val.type_name(),
-                    context.environment.bindings.len()
-                );
-                Ok(Value::resource(resource_id, val.type_name()))
+                Ok(alloc_value(val, context))
             }
             ExprKind::Consume(resource_expr) => {
                 let resource_val = self.eval_with_context(resource_expr, context)?;
-                // Extract the resource value and mark it as consumed
-                match resource_val.kind {
-                    ValueKind::Resource {
-                        id,
-                        resource_type,
-                        consumed: _,
-                    } => {
-                        // Return the final value from the consumed resource
-                        // Create a value representation based on the resource type
-                        match resource_type.as_str() {
-                            "Int" => Ok(Value::int(42)),      // Default int value
-                            "Bool" => Ok(Value::bool(false)), // Default bool value
-                            "Symbol" => Ok(Value::symbol(id.value)), // Use resource ID as symbol
-                            _ => Ok(Value::string(id)), // Default to string representation
-                        }
-                    }
-                    _ => Err(EvalError::TypeMismatch {
-                        expected: "Resource".to_string(),
-                        found: "Other".to_string(),
-                    }),
-                }
+                consume_value(resource_val)
             }
 
             // Record operations (simplified implementation for interpreter)
@@ -461,6 +700,18 @@ impl Interpreter {
                 }
             }
 
+            // Code as data
+            ExprKind::Quote(template) => {
+                let datum = template.to_datum().ok_or_else(|| {
+                    EvalError::InvalidCall("Expression cannot be quoted".to_string())
+                })?;
+                self.eval_const(&datum)
+            }
+            ExprKind::Quasiquote(template) => {
+                self.eval_quasiquote(template, 1, context)
+            }
+            ExprKind::Unquote(_) => Err(EvalError::UnquoteOutsideQuasiquote),
+
             // Session types operations
             ExprKind::SessionDeclaration { name, roles } => {
                 // Create a proper SessionDeclaration and register it
@@ -621,6 +872,63 @@ impl Interpreter {
         }
     }
 
+    /// Build the data described by a quasiquote template
+    ///
+    /// Unquotes at `depth` 1 are evaluated and spliced in; nested
+    /// quasiquotes and deeper unquotes are kept as data.
+    fn eval_quasiquote(
+        &mut self,
+        template: &Expr,
+        depth: usize,
+        context: &mut EvalContext,
+    ) -> EvalResult<Value> {
+        let tagged =
+            |tag: &str, value: Value| Value::list(vec![Value::symbol(tag), value]);
+        match &template.kind {
+            ExprKind::Unquote(expr) if depth == 1 => {
+                self.eval_with_context(expr, context)
+            }
+            ExprKind::Unquote(inner) => Ok(tagged(
+                "unquote",
+                self.eval_quasiquote(inner, depth - 1, context)?,
+            )),
+            ExprKind::Quasiquote(inner) => Ok(tagged(
+                "quasiquote",
+                self.eval_quasiquote(inner, depth + 1, context)?,
+            )),
+            ExprKind::Quote(inner) => Ok(tagged(
+                "quote",
+                self.eval_quasiquote(inner, depth, context)?,
+            )),
+            ExprKind::Tensor(head, tail) => {
+                let head = self.eval_quasiquote(head, depth, context)?;
+                match self.eval_quasiquote(tail, depth, context)?.kind {
+                    ValueKind::List(mut items) => {
+                        items.insert(0, head);
+                        Ok(Value::list(items))
+                    }
+                    _ => Err(EvalError::TypeMismatch {
+                        expected: "List".to_string(),
+                        found: "Other".to_string(),
+                    }),
+                }
+            }
+            ExprKind::UnitVal => Ok(Value::list(Vec::new())),
+            ExprKind::Apply(func, args) => {
+                let mut items = vec![self.eval_quasiquote(func, depth, context)?];
+                for arg in args {
+                    items.push(self.eval_quasiquote(arg, depth, context)?);
+                }
+                Ok(Value::list(items))
+            }
+            ExprKind::Const(value) => self.eval_const(value),
+            ExprKind::Var(name) => Ok(Value::symbol(name.clone())),
+            _ => Err(EvalError::InvalidCall(
+                "Expression cannot be quoted".to_string(),
+            )),
+        }
+    }
+
     /// Evaluate a variable lookup
     fn eval_var(&self, name: &Symbol, context: &EvalContext) -> EvalResult<Value> {
         context
@@ -643,6 +951,81 @@ impl Interpreter {
             .collect();
         let arg_vals = arg_vals?;
 
+        match self.prepare_call(func_val, arg_vals)? {
+            PreparedCall::Body(body, mut new_context) => {
+                self.eval_with_context(&body, &mut new_context)
+            }
+            PreparedCall::Done(value) => Ok(value),
+        }
+    }
+
+    /// Evaluate a `letrec` form
+    ///
+    /// The lambda bindings form one recursive group and are visible to every
+    /// binding and to the body; the remaining bindings are evaluated in order
+    /// once the group is in scope.
+    fn eval_letrec(
+        &mut self,
+        bindings: &[(Symbol, Expr)],
+        body: &Expr,
+        context: &mut EvalContext,
+    ) -> EvalResult<Value> {
+        if let Some(name) = Expr::ill_founded_binding(bindings) {
+            return Err(EvalError::IllFoundedRecursion(name.to_string()));
+        }
+
+        let functions = bindings
+            .iter()
+            .filter_map(|(name, value)| match &value.kind {
+                ExprKind::Lambda(params, body) => {
+                    Some((name.clone(), params.clone(), (**body).clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        let group = Rc::new(RecGroup {
+            functions,
+            env: context.environment.clone(),
+        });
+
+        let shadowed: Vec<(Symbol, Option<Value>)> = bindings
+            .iter()
+            .map(|(name, _)| {
+                let old_binding = context.environment.bindings.get(name).cloned();
+                (name.clone(), old_binding)
+            })
+            .collect();
+
+        group.bind_all(&mut context.environment);
+        let result = bindings
+            .iter()
+            .filter(|(_, value)| !matches!(value.kind, ExprKind::Lambda(..)))
+            .try_for_each(|(name, value)| {
+                let val = self.eval_with_context(value, context)?;
+                context.bind(name.clone(), val);
+                Ok(())
+            })
+            .and_then(|()| self.eval_with_context(body, context));
+
+        // Restore the bindings the group shadowed
+        for (name, old_binding) in shadowed {
+            match old_binding {
+                Some(val) => context.bind(name, val),
+                None => {
+                    context.environment.bindings.remove(&name);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Bind the arguments of a call, or run it directly for built-ins
+    fn prepare_call(
+        &self,
+        func_val: Value,
+        arg_vals: Vec<Value>,
+    ) -> EvalResult<PreparedCall> {
         match func_val.kind {
             ValueKind::Lambda { params, body } => {
                 if params.len() != arg_vals.len() {
@@ -653,11 +1036,11 @@ impl Interpreter {
                 }
 
                 let mut new_context = EvalContext::new(); // Create new context for lambda
-                for (param, arg_val) in params.iter().zip(arg_vals.iter()) {
-                    new_context.bind(param.name.clone(), arg_val.clone());
+                for (param, arg_val) in params.iter().zip(arg_vals) {
+                    new_context.bind(param.name.clone(), arg_val);
                 }
 
-                self.eval_with_context(&body, &mut new_context)
+                Ok(PreparedCall::Body(body, new_context))
             }
             ValueKind::Function {
                 params,
@@ -672,13 +1055,35 @@ impl Interpreter {
                 }
 
                 let mut new_context = EvalContext::from_environment(closure);
-                for (param, arg_val) in params.iter().zip(arg_vals.iter()) {
-                    new_context.bind(param.clone(), arg_val.clone());
+                for (param, arg_val) in params.into_iter().zip(arg_vals) {
+                    new_context.bind(param, arg_val);
                 }
 
-                self.eval_with_context(&body, &mut new_context)
+                Ok(PreparedCall::Body(body, new_context))
+            }
+            ValueKind::RecFunction { name, group } => {
+                let (params, body) = group
+                    .function(&name)
+                    .ok_or_else(|| EvalError::UnboundVariable(name.to_string()))?;
+                if params.len() != arg_vals.len() {
+                    return Err(EvalError::ArityMismatch {
+                        expected: params.len(),
+                        found: arg_vals.len(),
+                    });
+                }
+
+                let mut new_context =
+                    EvalContext::from_environment(group.env.clone());
+                group.bind_all(&mut new_context.environment);
+                for (param, arg_val) in params.iter().zip(arg_vals) {
+                    new_context.bind(param.name.clone(), arg_val);
+                }
+
+                Ok(PreparedCall::Body(body.clone(), new_context))
+            }
+            ValueKind::Builtin { name, .. } => {
+                self.eval_builtin(&name, &arg_vals).map(PreparedCall::Done)
             }
-            ValueKind::Builtin { name, .. } => self.eval_builtin(&name, &arg_vals),
             _ => Err(EvalError::TypeMismatch {
                 expected: "Function".to_string(),
                 found: "Other".to_string(),
@@ -800,6 +1205,13 @@ impl Interpreter {
                     }),
                 }
             }
+            "pure" => match args {
+                [value] => Ok(value.clone()),
+                _ => Err(EvalError::ArityMismatch {
+                    expected: 1,
+                    found: args.len(),
+                }),
+            },
             _ => Err(EvalError::UnknownBuiltin(name.to_string())),
         }
     }
@@ -817,6 +1229,55 @@ impl Default for Interpreter {
     }
 }
 
+/// Allocate a value as a resource
+fn alloc_value(val: Value, context: &EvalContext) -> Value {
+    // Generate a unique resource ID based on value hash and current context
+    let resource_id = format!(
+        "res_{}_{}",
+        val.type_name(),
+        context.environment.bindings.len()
+    );
+    Value::resource(resource_id, val.type_name())
+}
+
+/// Consume a resource value, producing its final value
+fn consume_value(resource_val: Value) -> EvalResult<Value> {
+    // Extract the resource value and mark it as consumed
+    match resource_val.kind {
+        ValueKind::Resource {
+            id,
+            resource_type,
+            consumed: _,
+        } => {
+            // Return the final value from the consumed resource
+            // Create a value representation based on the resource type
+            match resource_type.as_str() {
+                "Int" => Ok(Value::int(42)),      // Default int value
+                "Bool" => Ok(Value::bool(false)), // Default bool value
+                "Symbol" => Ok(Value::symbol(id.value)), // Use resource ID as symbol
+                _ => Ok(Value::string(id)),       // Default to string representation
+            }
+        }
+        _ => Err(EvalError::TypeMismatch {
+            expected: "Resource".to_string(),
+            found: "Other".to_string(),
+        }),
+    }
+}
+
+fn pop_value(values: &mut Vec<Value>) -> EvalResult<Value> {
+    values.pop().ok_or_else(|| {
+        EvalError::RuntimeError("evaluation stack underflow".to_string())
+    })
+}
+
+fn pop_values(values: &mut Vec<Value>, count: usize) -> EvalResult<Vec<Value>> {
+    let split = values.len().checked_sub(count).ok_or_else(|| {
+        EvalError::RuntimeError("evaluation stack underflow".to_string())
+    })?;
+    Ok(values.split_off(split))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -859,4 +1320,162 @@ mod tests {
         let result = interpreter.eval(&expr).unwrap();
         assert_eq!(result.kind, ValueKind::Int(42));
     }
+
+    #[test]
+    fn test_iterative_matches_recursive() {
+        let mut interpreter = Interpreter::new();
+
+        // (let-tensor (tensor 1 (inl 2)) a b (case b x (+ a x) y y))
+        let expr = Expr::let_tensor(
+            Expr::tensor(int(1), Expr::inl(int(2))),
+            "a",
+            "b",
+            Expr::case(
+                Expr::variable("b"),
+                "x",
+                Expr::apply(
+                    Expr::variable("+"),
+                    vec![Expr::variable("a"), Expr::variable("x")],
+                ),
+                "y",
+                Expr::variable("y"),
+            ),
+        );
+
+        let recursive = interpreter.eval(&expr).unwrap();
+        let iterative = interpreter.eval_iterative(&expr).unwrap();
+        assert_eq!(iterative.kind, ValueKind::Int(3));
+        assert_eq!(iterative.kind, recursive.kind);
+    }
+
+    #[test]
+    fn test_iterative_deeply_nested_let() {
+        const DEPTH: i64 = 50_000;
+
+        // (let x0 0 (let x1 1 ... (let x49999 49999 x49999)))
+        let mut expr = Expr::variable(format!("x{}", DEPTH - 1));
+        for i in (0..DEPTH).rev() {
+            expr = Expr::apply(
+                Expr::lambda(vec![Param::new(format!("x{}", i))], expr),
+                vec![int(i)],
+            );
+        }
+
+        let result = Interpreter::new().eval_iterative(&expr).unwrap();
+        assert_eq!(result.kind, ValueKind::Int(DEPTH - 1));
+
+        // Dropping the tree recursively would overflow as well, so take it
+        // apart one level at a time
+        let mut rest = Some(expr);
+        while let Some(Expr {
+            kind: ExprKind::Apply(func, _),
+            ..
+        }) = rest.take()
+        {
+            if let ExprKind::Lambda(_, body) = func.kind {
+                rest = Some(*body);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unbound_variable_reports_span() {
+        let source = "(+ 1 missing)";
+        let expr = crate::parser::LispParser::new().parse(source).unwrap();
+
+        let error = Interpreter::new().eval(&expr).unwrap_err();
+        assert!(matches!(
+            error.without_span(),
+            EvalError::UnboundVariable(_)
+        ));
+        let span = error.span().expect("unbound variable should carry a span");
+        assert_eq!(span, &crate::ast::Span::new(5, 12, 1, 6));
+        assert_eq!(span.render(source), "1 | (+ 1 missing)\n  |      ^^^^^^^");
+
+        // The iterative evaluator reports the same location
+        let error = Interpreter::new().eval_iterative(&expr).unwrap_err();
+        assert_eq!(error.span(), Some(span));
+    }
+
+    #[test]
+    fn test_quasiquote_splices_unquotes() {
+        let expr = crate::parser::LispParser::new()
+            .parse("`(1 ,(+ 1 1) 3)")
+            .unwrap();
+
+        let result = Interpreter::new().eval(&expr).unwrap();
+        let ValueKind::List(items) = result.kind else {
+            panic!("Expected a list, got {:?}", result.kind);
+        };
+        let ints: Vec<i64> = items
+            .iter()
+            .map(|item| match item.kind {
+                ValueKind::Int(i) => i,
+                _ => panic!("Expected an integer, got {:?}", item.kind),
+            })
+            .collect();
+        assert_eq!(ints, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_letrec_mutual_recursion() {
+        let mut parser = crate::parser::LispParser::new();
+        let mut interpreter = Interpreter::new();
+
+        // Peano naturals: (inl (unit)) is zero, (inr n) is the successor of n
+        let program = "(letrec ((even? (lambda (n) (case n z true p (odd? p))))
+                                (odd? (lambda (n) (case n z false p (even? p)))))
+                         (even? (inr (inr (inr (inr (inl (unit))))))))";
+        let result = interpreter.eval(&parser.parse(program).unwrap()).unwrap();
+        assert_eq!(result.kind, ValueKind::Bool(true));
+
+        let program = "(letrec ((even? (lambda (n) (case n z true p (odd? p))))
+                                (odd? (lambda (n) (case n z false p (even? p)))))
+                         (odd? (inr (inr (inr (inl (unit)))))))";
+        let result = interpreter.eval(&parser.parse(program).unwrap()).unwrap();
+        assert_eq!(result.kind, ValueKind::Bool(true));
+    }
+
+    #[test]
+    fn test_letrec_rejects_ill_founded_value() {
+        let mut parser = crate::parser::LispParser::new();
+        let mut interpreter = Interpreter::new();
+
+        let expr = parser.parse("(letrec ((x (+ x 1))) x)").unwrap();
+        let err = interpreter.eval(&expr).unwrap_err();
+        assert!(matches!(
+            err.without_span(),
+            EvalError::IllFoundedRecursion(name) if name == "x"
+        ));
+    }
+
+    #[test]
+    fn test_quote_returns_unevaluated_data() {
+        let mut parser = crate::parser::LispParser::new();
+        let mut interpreter = Interpreter::new();
+
+        let quoted = interpreter
+            .eval(&parser.parse("'(+ 1 x)").unwrap())
+            .unwrap();
+        assert_eq!(
+            quoted.kind,
+            ValueKind::List(vec![
+                Value::symbol("+"),
+                Value::int(1),
+                Value::symbol("x"),
+            ])
+        );
+
+        // The inner unquote belongs to the inner quasiquote and stays data
+        let nested = interpreter
+            .eval(&parser.parse("`(a `(b ,(c ,(+ 1 1))))").unwrap())
+            .unwrap();
+        let expected = parser
+            .parse("'(a (quasiquote (b (unquote (c 2)))))")
+            .unwrap();
+        assert_eq!(nested.kind, interpreter.eval(&expected).unwrap().kind);
+
+        let error = interpreter.eval(&parser.parse(",x").unwrap()).unwrap_err();
+        assert_eq!(error.without_span(), &EvalError::UnquoteOutsideQuasiquote);
+    }
 }
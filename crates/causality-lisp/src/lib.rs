@@ -13,6 +13,7 @@ pub mod compiler;
 pub mod desugar;
 pub mod error;
 pub mod interpreter;
+pub mod lower;
 pub mod parser;
 pub mod type_checker;
 pub mod value;
@@ -23,9 +24,12 @@ pub use compiler::{LispCompiler, CompilerContext, CompileResult as LispCompileRe
 pub use desugar::{SugarExpr, desugar};
 pub use error::{LispError, EvalError, ParseError, TypeError};
 pub use interpreter::{Interpreter, EvalContext};
+pub use lower::lower_to_term;
 pub use parser::{LispParser};
 pub use type_checker::{TypeChecker, TypeContext};
-pub use value::{Value, ValueKind, Environment};
+pub use value::{Value, ValueKind, Environment, RecGroup};
+
+use causality_core::lambda::base::TypeInner;
 
 // Convenience function for quick evaluation
 pub fn parse(input: &str) -> Result<Expr, ParseError> {
@@ -73,14 +77,14 @@ pub fn compile(input: &str) -> Result<(Vec<causality_core::machine::instruction:
     compiler.compile(&expr)
 }
 
-/// E2E: Parse, compile, and prepare for simulation
+/// E2E: Parse, type check, compile, and prepare for simulation
 pub fn compile_for_simulation(input: &str) -> Result<E2EResult, LispError> {
     // Parse the Lisp code
     let expr = parse(input)?;
     
     // Type check
     let mut type_checker = TypeChecker::new();
-    let _expr_type = type_checker.check_expr(&expr)?;
+    let result_type = type_checker.check_expr(&expr)?;
     
     // Compile to Layer 0
     let mut compiler = LispCompiler::new();
@@ -88,6 +92,7 @@ pub fn compile_for_simulation(input: &str) -> Result<E2EResult, LispError> {
     
     Ok(E2EResult {
         original_expr: expr,
+        result_type,
         instructions: instructions.clone(),
         result_register: result_reg,
         instruction_count: instructions.len(),
@@ -99,10 +104,25 @@ pub fn compile_for_simulation(input: &str) -> Result<E2EResult, LispError> {
 pub struct E2EResult {
     /// Original Lisp expression
     pub original_expr: Expr,
+    /// Type inferred for the whole program
+    pub result_type: TypeInner,
     /// Compiled Layer 0 instructions  
     pub instructions: Vec<causality_core::machine::instruction::Instruction>,
     /// Register containing the final result
     pub result_register: causality_core::machine::instruction::RegisterId,
     /// Total number of instructions generated
     pub instruction_count: usize,
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use causality_core::lambda::base::BaseType;
+
+    #[test]
+    fn test_compile_for_simulation_reports_result_type() {
+        let result = compile_for_simulation("(pure 42)").unwrap();
+        assert_eq!(result.result_type, TypeInner::Base(BaseType::Int));
+        assert!(result.instruction_count > 0);
+    }
+}
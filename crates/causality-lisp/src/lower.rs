@@ -0,0 +1,199 @@
+//! Lowering from Lisp expressions to Layer 1 terms
+//!
+//! Every term produced here carries the span of the expression it was
+//! lowered from, so diagnostics from the Layer 1 type checker (such as an
+//! unconsumed linear binding) can point back at the Lisp source.
+
+use crate::{
+    ast::{Expr, ExprKind, LispValue, Param, Span},
+    error::{EvalError, LispError, TypeError},
+};
+use causality_core::effect::{Position, Span as TermSpan};
+use causality_core::lambda::{
+    base::{BaseType, TypeInner},
+    Literal, Term, TermKind,
+};
+
+/// Result type for lowering
+pub type LowerResult<T> = Result<T, LispError>;
+
+/// Lower a parsed expression to a Layer 1 term, keeping source spans
+///
+/// Multi-parameter lambdas and multi-argument applications are curried, and
+/// every curried layer takes the span of the whole expression. Forms with no
+/// Layer 1 counterpart are rejected with `NotImplemented` at their span.
+pub fn lower_to_term(expr: &Expr) -> LowerResult<Term> {
+    let term = match &expr.kind {
+        ExprKind::Const(value) => Term::literal(lower_literal(value, expr)?),
+        ExprKind::Var(name) => Term::var(name.as_str()),
+        ExprKind::UnitVal => Term::unit(),
+        ExprKind::LetUnit(unit_expr, body) => Term::new(TermKind::LetUnit {
+            unit_term: Box::new(lower_to_term(unit_expr)?),
+            body: Box::new(lower_to_term(body)?),
+        }),
+        ExprKind::Tensor(left, right) => {
+            Term::tensor(lower_to_term(left)?, lower_to_term(right)?)
+        }
+        ExprKind::LetTensor(tensor, left_var, right_var, body) => Term::new(TermKind::LetTensor {
+            tensor_term: Box::new(lower_to_term(tensor)?),
+            left_var: left_var.as_str().to_string(),
+            right_var: right_var.as_str().to_string(),
+            body: Box::new(lower_to_term(body)?),
+        }),
+        ExprKind::Inl(value) => Term::new(TermKind::Inl {
+            value: Box::new(lower_to_term(value)?),
+            sum_type: sum_type(expr, "inl")?,
+        }),
+        ExprKind::Inr(value) => Term::new(TermKind::Inr {
+            value: Box::new(lower_to_term(value)?),
+            sum_type: sum_type(expr, "inr")?,
+        }),
+        ExprKind::Case(scrutinee, left_var, left_body, right_var, right_body) => {
+            Term::new(TermKind::Case {
+                scrutinee: Box::new(lower_to_term(scrutinee)?),
+                left_var: left_var.as_str().to_string(),
+                left_body: Box::new(lower_to_term(left_body)?),
+                right_var: right_var.as_str().to_string(),
+                right_body: Box::new(lower_to_term(right_body)?),
+            })
+        }
+        ExprKind::Lambda(params, body) => {
+            let mut term = lower_to_term(body)?;
+            for param in params.iter().rev() {
+                term = Term::new(TermKind::Lambda {
+                    param: param.name.as_str().to_string(),
+                    param_type: param_type(param)?,
+                    body: Box::new(term),
+                });
+                if let Some(span) = &expr.span {
+                    term = term.with_span(term_span(span));
+                }
+            }
+            term
+        }
+        ExprKind::Apply(func, args) => {
+            let mut term = lower_to_term(func)?;
+            if args.is_empty() {
+                term = Term::apply(term, Term::unit());
+            }
+            for arg in args {
+                term = Term::apply(term, lower_to_term(arg)?);
+                if let Some(span) = &expr.span {
+                    term = term.with_span(term_span(span));
+                }
+            }
+            term
+        }
+        ExprKind::Alloc(value) => Term::alloc(lower_to_term(value)?),
+        ExprKind::Consume(resource) => Term::consume(lower_to_term(resource)?),
+        _ => return Err(unsupported(expr, "form has no Layer 1 term")),
+    };
+
+    Ok(match &expr.span {
+        Some(span) => term.with_span(term_span(span)),
+        None => term,
+    })
+}
+
+/// Convert a Lisp source span to the span carried by terms
+///
+/// Lisp spans give a line and column only for their start, so the end
+/// position is placed on the same line.
+pub fn term_span(span: &Span) -> TermSpan {
+    let width = span.end.saturating_sub(span.start);
+    TermSpan {
+        start: Position {
+            line: span.line as u32,
+            column: span.column as u32,
+            offset: span.start as u32,
+        },
+        end: Position {
+            line: span.line as u32,
+            column: (span.column + width) as u32,
+            offset: span.end as u32,
+        },
+        file: None,
+    }
+}
+
+fn lower_literal(value: &LispValue, expr: &Expr) -> LowerResult<Literal> {
+    match value {
+        LispValue::Unit => Ok(Literal::Unit),
+        LispValue::Bool(b) => Ok(Literal::Bool(*b)),
+        LispValue::Int(n) => u32::try_from(*n)
+            .map(Literal::Int)
+            .map_err(|_| unsupported(expr, &format!("integer {} does not fit in u32", n))),
+        LispValue::Symbol(symbol) => Ok(Literal::Symbol(symbol.clone())),
+        _ => Err(unsupported(expr, "constant has no Layer 1 literal")),
+    }
+}
+
+fn param_type(param: &Param) -> LowerResult<Option<TypeInner>> {
+    let Some(ty) = &param.ty else {
+        return Ok(None);
+    };
+    let base = match ty.as_str() {
+        "Unit" => BaseType::Unit,
+        "Bool" => BaseType::Bool,
+        "Int" => BaseType::Int,
+        "Symbol" => BaseType::Symbol,
+        other => return Err(LispError::Type(TypeError::UndefinedType(other.to_string()))),
+    };
+    Ok(Some(TypeInner::Base(base)))
+}
+
+fn sum_type(expr: &Expr, form: &str) -> LowerResult<TypeInner> {
+    match &expr.ty {
+        Some(ty @ TypeInner::Sum(..)) => Ok(ty.clone()),
+        _ => Err(unsupported(expr, &format!("{} needs a sum type annotation", form))),
+    }
+}
+
+fn unsupported(expr: &Expr, reason: &str) -> LispError {
+    LispError::Eval(EvalError::NotImplemented(reason.to_string()).at(expr.span.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_lowered_terms_carry_source_spans() {
+        let source = "(lambda (x) (consume x))";
+        let term = lower_to_term(&parse(source).unwrap()).unwrap();
+
+        let span = term.span.clone().expect("lambda span");
+        assert_eq!((span.start.line, span.start.column), (1, 1));
+        assert_eq!(span.end.offset as usize, source.len());
+
+        let TermKind::Lambda { body, .. } = &term.kind else {
+            panic!("expected lambda, got {:?}", term.kind);
+        };
+        let body_span = body.span.clone().expect("body span");
+        assert_eq!(body_span.start.column, 13);
+        assert!(matches!(body.kind, TermKind::Consume { .. }));
+    }
+
+    #[test]
+    fn test_curried_layers_share_the_expression_span() {
+        let term = lower_to_term(&parse("(f 1 2)").unwrap()).unwrap();
+
+        let TermKind::Apply { func, .. } = &term.kind else {
+            panic!("expected application");
+        };
+        assert!(matches!(func.kind, TermKind::Apply { .. }));
+        assert_eq!(func.span, term.span);
+        assert!(term.span.is_some());
+    }
+
+    #[test]
+    fn test_unsupported_form_reports_its_span() {
+        let err = lower_to_term(&parse("(letrec ((f 1)) f)").unwrap()).unwrap_err();
+        let LispError::Eval(err) = err else {
+            panic!("expected evaluation error");
+        };
+        assert!(err.span().is_some());
+        assert!(matches!(err.without_span(), EvalError::NotImplemented(_)));
+    }
+}
@@ -4,7 +4,7 @@
 //! handling all 11 Layer 1 primitives and integration with the AST.
 
 use crate::{
-    ast::{Expr, ExprKind, LispValue, Param},
+    ast::{Expr, ExprKind, LispValue, Param, Span},
     error::{ParseError},
 };
 use causality_core::{
@@ -26,6 +26,12 @@ pub enum Token {
 
     String(Str),
     Bool(bool),
+    /// `'` reader shorthand for `quote`
+    Quote,
+    /// `` ` `` reader shorthand for `quasiquote`
+    Quasiquote,
+    /// `,` reader shorthand for `unquote`
+    Unquote,
     EOF,
 }
 
@@ -44,6 +50,11 @@ impl PositionedToken {
         Self { token, line, column, start_pos, end_pos }
     }
     
+    /// Source span covered by this token
+    pub fn span(&self) -> Span {
+        Span::new(self.start_pos, self.end_pos, self.line, self.column)
+    }
+    
     /// Format token for error messages
     pub fn format_for_error(&self) -> String {
         match &self.token {
@@ -54,6 +65,9 @@ impl PositionedToken {
 
             Token::String(s) => format!("string \"{}\"", s),
             Token::Bool(b) => format!("boolean {}", b),
+            Token::Quote => "quote \"'\"".to_string(),
+            Token::Quasiquote => "quasiquote \"`\"".to_string(),
+            Token::Unquote => "unquote \",\"".to_string(),
             Token::EOF => "end of input".to_string(),
         }
     }
@@ -104,6 +118,17 @@ impl Lexer {
                         Token::RightParen, start_line, start_column, start_pos, self.position
                     ));
                 }
+                ch @ ('\'' | '`' | ',') => {
+                    self.advance();
+                    let token = match ch {
+                        '\'' => Token::Quote,
+                        '`' => Token::Quasiquote,
+                        _ => Token::Unquote,
+                    };
+                    tokens.push(PositionedToken::new(
+                        token, start_line, start_column, start_pos, self.position
+                    ));
+                }
                 '"' => {
                     let token = self.read_string()?;
                     tokens.push(PositionedToken::new(
@@ -131,8 +156,7 @@ impl Lexer {
                 _ => {
                     return Err(ParseError::UnexpectedChar(
                         self.current_char()?,
-                        self.line,
-                        self.column,
+                        self.span_here(),
                     ));
                 }
             }
@@ -163,6 +187,11 @@ impl Lexer {
         }
     }
     
+    /// Span of the character at the current position
+    fn span_here(&self) -> Span {
+        Span::new(self.position, self.position + 1, self.line, self.column)
+    }
+    
     fn skip_whitespace(&mut self) {
         while self.position < self.input.len() {
             match self.current_char() {
@@ -175,6 +204,7 @@ impl Lexer {
     }
     
     fn read_string(&mut self) -> ParseResult<Token> {
+        let start = self.span_here();
         self.advance(); // Skip opening quote
         let mut value = String::new();
         
@@ -193,7 +223,7 @@ impl Lexer {
                         '\\' => value.push('\\'),
                         '"' => value.push('"'),
                         ch => {
-                            return Err(ParseError::InvalidEscape(ch, self.line, self.column));
+                            return Err(ParseError::InvalidEscape(ch, self.span_here()));
                         }
                     }
                     self.advance();
@@ -205,14 +235,16 @@ impl Lexer {
             }
         }
         
-        Err(ParseError::UnclosedString(self.line, self.column))
+        Err(ParseError::UnclosedString(Span {
+            end: self.position,
+            ..start
+        }))
     }
     
     fn read_number(&mut self) -> ParseResult<Token> {
         let mut value = String::new();
 
-        let start_line = self.line;
-        let start_column = self.column;
+        let start = self.span_here();
         
         // Handle negative numbers
         if self.current_char()? == '-' {
@@ -245,11 +277,14 @@ impl Lexer {
         }
         
         if !has_digits {
-            return Err(ParseError::InvalidNumber(value.clone(), start_line, start_column));
+            return Err(ParseError::InvalidNumber(
+                value.clone(),
+                Span { end: self.position, ..start },
+            ));
         }
         
         let int_val = value.parse::<i64>().map_err(|_| {
-            ParseError::InvalidNumber(value.clone(), start_line, start_column)
+            ParseError::InvalidNumber(value.clone(), Span { end: self.position, ..start })
         })?;
         Ok(Token::Number(int_val))
     }
@@ -266,7 +301,7 @@ impl Lexer {
                 self.advance();
                 Ok(Token::Bool(false))
             }
-            ch => Err(ParseError::UnexpectedChar(ch, self.line, self.column)),
+            ch => Err(ParseError::UnexpectedChar(ch, self.span_here())),
         }
     }
     
@@ -275,7 +310,7 @@ impl Lexer {
         
         while self.position < self.input.len() {
             match self.current_char() {
-                Ok(ch) if ch.is_alphanumeric() || ch == '-' || ch == '_' || ch == '+' || ch == '*' || ch == '/' || ch == '=' || ch == '<' || ch == '>' => {
+                Ok(ch) if ch.is_alphanumeric() || ch == '-' || ch == '_' || ch == '+' || ch == '*' || ch == '/' || ch == '=' || ch == '<' || ch == '>' || ch == '?' || ch == '!' => {
                     value.push(ch);
                     self.advance();
                 }
@@ -312,6 +347,8 @@ impl Lexer {
 pub struct LispParser {
     tokens: Vec<PositionedToken>,
     position: usize,
+    /// Spans of the parentheses opened but not yet closed
+    open_parens: Vec<Span>,
 }
 
 impl LispParser {
@@ -319,14 +356,18 @@ impl LispParser {
         Self {
             tokens: Vec::new(),
             position: 0,
+            open_parens: Vec::new(),
         }
     }
     
     /// Parse a Lisp expression from text
+    ///
+    /// Every parsed expression carries the span of the source it was parsed from.
     pub fn parse(&mut self, input: &str) -> ParseResult<Expr> {
         let mut lexer = Lexer::new(input.to_string());
         self.tokens = lexer.tokenize()?;
         self.position = 0;
+        self.open_parens.clear();
         self.parse_expression()
     }
     
@@ -350,7 +391,32 @@ impl LispParser {
         }
     }
     
+    /// Span of the innermost unclosed parenthesis, or of the current token
+    fn unclosed_span(&self) -> Span {
+        self.open_parens
+            .last()
+            .cloned()
+            .unwrap_or_else(|| self.current_token().span())
+    }
+    
     fn parse_expression(&mut self) -> ParseResult<Expr> {
+        self.spanned(Self::parse_unspanned_expression)
+    }
+    
+    /// Run a parse step and record the source it consumed on the result
+    fn spanned(&mut self, parse: impl FnOnce(&mut Self) -> ParseResult<Expr>) -> ParseResult<Expr> {
+        let start = self.current_token().span();
+        let mut expr = parse(self)?;
+        let end = self
+            .position
+            .checked_sub(1)
+            .and_then(|index| self.tokens.get(index))
+            .map_or(start.end, |token| token.end_pos);
+        expr.span = Some(Span { end, ..start });
+        Ok(expr)
+    }
+    
+    fn parse_unspanned_expression(&mut self) -> ParseResult<Expr> {
         let current = self.current_token();
         match &current.token {
             Token::LeftParen => self.parse_list_or_special_form(),
@@ -375,24 +441,44 @@ impl LispParser {
                 self.advance();
                 Ok(Expr::variable(symbol))
             }
+            Token::Quote => {
+                self.advance();
+                Ok(Expr::quote(self.parse_datum(0)?))
+            }
+            Token::Quasiquote => {
+                self.advance();
+                Ok(Expr::quasiquote(self.parse_datum(1)?))
+            }
+            Token::Unquote => {
+                self.advance();
+                Ok(Expr::unquote(self.parse_expression()?))
+            }
             Token::RightParen => {
                 Err(ParseError::InvalidTokenSequence {
                     context: "unexpected closing parenthesis".to_string(),
                     suggestion: "remove the extra ')' or add an opening '(' before it".to_string(),
-                    line: current.line,
-                    column: current.column,
+                    span: current.span(),
                 })
             }
             Token::EOF => {
                 Err(ParseError::UnexpectedEofInConstruct {
                     construct: "expression".to_string(),
                     hint: "add a complete expression before the end of input".to_string(),
+                    span: self.unclosed_span(),
                 })
             }
         }
     }
     
     fn parse_list_or_special_form(&mut self) -> ParseResult<Expr> {
+        let opening_paren = self.current_token().span();
+        self.open_parens.push(opening_paren);
+        let result = self.parse_list_contents();
+        self.open_parens.pop();
+        result
+    }
+    
+    fn parse_list_contents(&mut self) -> ParseResult<Expr> {
         let opening_paren = self.current_token().clone();
         self.advance(); // Skip '('
         
@@ -412,7 +498,11 @@ impl LispParser {
         if let Some(name) = symbol_name {
             // Check for reserved special forms
             match name.as_str() {
-                "lambda" | "let-tensor" | "case" | "tensor" | "inl" | "inr" | "alloc" | "consume" | "unit" | "let-unit" => {
+                "lambda" | "let-tensor" | "case" | "tensor" | "inl" | "inr" | "alloc" | "consume" | "unit" | "let-unit" | "letrec" => {
+                    self.parse_special_form(&name)
+                }
+                // Code as data
+                "quote" | "quasiquote" | "unquote" => {
                     self.parse_special_form(&name)
                 }
                 // Session types special forms
@@ -439,8 +529,7 @@ impl LispParser {
                     construct: "list".to_string(),
                     expected: "closing parenthesis ')'".to_string(),
                     hint: format!("add ')' to close the list opened at line {}, column {}", opening_paren.line, opening_paren.column),
-                    line: opening_paren.line,
-                    column: opening_paren.column,
+                    span: opening_paren.span(),
                 });
             }
             
@@ -463,6 +552,8 @@ impl LispParser {
             "consume" => self.parse_consume(&form_token),
             "unit" => self.parse_unit(&form_token),
             "let-unit" => self.parse_let_unit(&form_token),
+            "letrec" => self.parse_letrec(&form_token),
+            "quote" | "quasiquote" | "unquote" => self.parse_quote_form(form_name, &form_token),
             // Session types special forms
             "def-session" => self.parse_def_session(&form_token),
             "with-session" => self.parse_with_session(&form_token),
@@ -474,8 +565,7 @@ impl LispParser {
                 Err(ParseError::InvalidSpecialForm {
                     form: form_name.to_string(),
                     hint: "check the Causality Lisp documentation for valid special forms".to_string(),
-                    line: form_token.line,
-                    column: form_token.column,
+                    span: form_token.span(),
                 })
             }
         }
@@ -492,8 +582,7 @@ impl LispParser {
                     construct: "lambda parameter list".to_string(),
                     expected: "parameters followed by ')'".to_string(),
                     hint: "parameter names should be symbols like 'x' or 'value'".to_string(),
-                    line: form_token.line,
-                    column: form_token.column,
+                    span: form_token.span(),
                 });
             }
             
@@ -508,8 +597,7 @@ impl LispParser {
                 construct: "lambda expression".to_string(),
                 expected: "body expression".to_string(),
                 hint: "add an expression after the parameter list".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -525,8 +613,7 @@ impl LispParser {
                 construct: "let-unit expression".to_string(),
                 expected: "unit expression and body".to_string(),
                 hint: "let-unit requires two expressions: (let-unit unit-expr body-expr)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -537,8 +624,7 @@ impl LispParser {
                 construct: "let-unit expression".to_string(),
                 expected: "body expression".to_string(),
                 hint: "let-unit requires a body expression after the unit expression".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -548,14 +634,49 @@ impl LispParser {
         Ok(Expr::let_unit(unit_expr, body))
     }
     
+    fn parse_letrec(&mut self, form_token: &PositionedToken) -> ParseResult<Expr> {
+        if matches!(self.current_token().token, Token::RightParen | Token::EOF) {
+            return Err(ParseError::IncompleteConstruct {
+                construct: "letrec expression".to_string(),
+                expected: "binding list and body".to_string(),
+                hint: "letrec requires bindings and a body: (letrec ((name expr) ...) body)".to_string(),
+                span: form_token.span(),
+            });
+        }
+        
+        self.expect_left_paren("letrec bindings")?;
+        let mut bindings = Vec::new();
+        while matches!(self.current_token().token, Token::LeftParen) {
+            self.advance(); // Skip '('
+            let name = self.expect_symbol("letrec binding")?;
+            let value = self.parse_expression()?;
+            self.expect_right_paren("letrec binding")?;
+            bindings.push((Symbol::new(&name), value));
+        }
+        self.expect_right_paren("letrec bindings")?;
+        
+        if matches!(self.current_token().token, Token::RightParen | Token::EOF) {
+            return Err(ParseError::IncompleteConstruct {
+                construct: "letrec expression".to_string(),
+                expected: "body expression".to_string(),
+                hint: "letrec requires a body expression after the bindings".to_string(),
+                span: form_token.span(),
+            });
+        }
+        
+        let body = self.parse_expression()?;
+        self.expect_right_paren("letrec expression")?;
+        
+        Ok(Expr::letrec(bindings, body))
+    }
+    
     fn parse_let_tensor(&mut self, form_token: &PositionedToken) -> ParseResult<Expr> {
         if matches!(self.current_token().token, Token::RightParen | Token::EOF) {
             return Err(ParseError::IncompleteConstruct {
                 construct: "let-tensor expression".to_string(),
                 expected: "tensor expression, variable names, and body".to_string(),
                 hint: "let-tensor requires: (let-tensor tensor-expr left-var right-var body-expr)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -568,8 +689,7 @@ impl LispParser {
                 construct: "let-tensor expression".to_string(),
                 expected: "body expression".to_string(),
                 hint: "let-tensor requires a body expression after the variable bindings".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -590,8 +710,7 @@ impl LispParser {
                 construct: "case expression".to_string(),
                 expected: "sum expression and branch handlers".to_string(),
                 hint: "case requires: (case sum-expr left-var left-branch right-var right-branch)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -617,8 +736,7 @@ impl LispParser {
                 construct: "tensor expression".to_string(),
                 expected: "two expressions to combine".to_string(),
                 hint: "tensor requires exactly two expressions: (tensor left-expr right-expr)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -629,8 +747,7 @@ impl LispParser {
                 construct: "tensor expression".to_string(),
                 expected: "second expression".to_string(),
                 hint: "tensor requires exactly two expressions".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -646,8 +763,7 @@ impl LispParser {
                 construct: "inl expression".to_string(),
                 expected: "value expression".to_string(),
                 hint: "inl requires one expression: (inl value-expr)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -663,8 +779,7 @@ impl LispParser {
                 construct: "inr expression".to_string(),
                 expected: "value expression".to_string(),
                 hint: "inr requires one expression: (inr value-expr)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -680,8 +795,7 @@ impl LispParser {
                 construct: "alloc expression".to_string(),
                 expected: "value expression to allocate".to_string(),
                 hint: "alloc requires one expression: (alloc value-expr)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -697,8 +811,7 @@ impl LispParser {
                 construct: "consume expression".to_string(),
                 expected: "resource expression to consume".to_string(),
                 hint: "consume requires one expression: (consume resource-expr)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
         
@@ -713,6 +826,106 @@ impl LispParser {
         Ok(Expr::new(ExprKind::UnitVal))
     }
     
+    /// Parse the body of a `quote`, `quasiquote` or `unquote` form
+    fn parse_quote_form(&mut self, form_name: &str, form_token: &PositionedToken) -> ParseResult<Expr> {
+        if matches!(self.current_token().token, Token::RightParen) {
+            return Err(ParseError::IncompleteConstruct {
+                construct: format!("{} expression", form_name),
+                expected: "one expression".to_string(),
+                hint: format!("{} requires one expression: ({} expr)", form_name, form_name),
+                span: form_token.span(),
+            });
+        }
+        
+        let expr = match form_name {
+            "quote" => Expr::quote(self.parse_datum(0)?),
+            "quasiquote" => Expr::quasiquote(self.parse_datum(1)?),
+            _ => Expr::unquote(self.parse_expression()?),
+        };
+        
+        self.expect_right_paren(&format!("{} expression", form_name))?;
+        Ok(expr)
+    }
+    
+    /// Parse a quoted template
+    ///
+    /// `depth` is the number of enclosing quasiquotes. Only an unquote at
+    /// depth 1 escapes back to an evaluated expression; deeper unquotes stay
+    /// part of the data.
+    fn parse_datum(&mut self, depth: usize) -> ParseResult<Expr> {
+        self.spanned(|parser| parser.parse_unspanned_datum(depth))
+    }
+    
+    fn parse_unspanned_datum(&mut self, depth: usize) -> ParseResult<Expr> {
+        let current = self.current_token();
+        match &current.token {
+            Token::LeftParen => {
+                let opening_paren = current.span();
+                self.open_parens.push(opening_paren);
+                let result = self.parse_datum_list(depth);
+                self.open_parens.pop();
+                result
+            }
+            Token::Symbol(sym) => {
+                let symbol = sym.clone();
+                self.advance();
+                Ok(Expr::constant(LispValue::Symbol(symbol)))
+            }
+            Token::Quote => {
+                self.advance();
+                Ok(Expr::quote(self.parse_datum(depth)?))
+            }
+            Token::Quasiquote => {
+                self.advance();
+                Ok(Expr::quasiquote(self.parse_datum(depth + 1)?))
+            }
+            Token::Unquote => {
+                self.advance();
+                self.parse_unquoted(depth)
+            }
+            // Literals read the same way as in code
+            _ => self.parse_unspanned_expression(),
+        }
+    }
+    
+    fn parse_unquoted(&mut self, depth: usize) -> ParseResult<Expr> {
+        if depth == 1 {
+            Ok(Expr::unquote(self.parse_expression()?))
+        } else {
+            Ok(Expr::unquote(self.parse_datum(depth.saturating_sub(1))?))
+        }
+    }
+    
+    fn parse_datum_list(&mut self, depth: usize) -> ParseResult<Expr> {
+        self.advance(); // Skip '('
+        
+        // Long forms of the quote shorthands
+        let quote_form = match &self.current_token().token {
+            Token::Symbol(name) if matches!(name.as_str(), "quote" | "quasiquote" | "unquote") => {
+                Some(name.to_string())
+            }
+            _ => None,
+        };
+        if let Some(form_name) = quote_form {
+            self.advance();
+            let expr = match form_name.as_str() {
+                "quote" => Expr::quote(self.parse_datum(depth)?),
+                "quasiquote" => Expr::quasiquote(self.parse_datum(depth + 1)?),
+                _ => self.parse_unquoted(depth)?,
+            };
+            self.expect_right_paren(&format!("{} expression", form_name))?;
+            return Ok(expr);
+        }
+        
+        let mut elements = Vec::new();
+        while !matches!(self.current_token().token, Token::RightParen | Token::EOF) {
+            elements.push(self.parse_datum(depth)?);
+        }
+        
+        self.expect_right_paren("quoted list")?;
+        Ok(Expr::list(elements))
+    }
+    
     fn parse_function_call(&mut self, func: Expr) -> ParseResult<Expr> {
         let mut args = Vec::new();
         
@@ -724,6 +937,7 @@ impl LispParser {
             return Err(ParseError::UnexpectedEofInConstruct {
                 construct: "function call".to_string(),
                 hint: "add ')' to close the function call".to_string(),
+                span: self.unclosed_span(),
             });
         }
         
@@ -738,8 +952,7 @@ impl LispParser {
                 construct: "def-session expression".to_string(),
                 expected: "session name and role definitions".to_string(),
                 hint: "def-session requires: (def-session name (role protocol)...)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
 
@@ -800,8 +1013,7 @@ impl LispParser {
                 construct: "with-session expression".to_string(),
                 expected: "session.role and body".to_string(),
                 hint: "with-session requires: (with-session session.role body-expr)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
 
@@ -816,8 +1028,7 @@ impl LispParser {
             return Err(ParseError::InvalidTokenSequence {
                 context: "invalid session.role format".to_string(),
                 suggestion: "use format 'SessionName.role' like 'PaymentProtocol.client'".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         };
 
@@ -826,8 +1037,7 @@ impl LispParser {
                 construct: "with-session expression".to_string(),
                 expected: "body expression".to_string(),
                 hint: "with-session requires a body expression".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
 
@@ -842,8 +1052,7 @@ impl LispParser {
                 construct: "session-send expression".to_string(),
                 expected: "channel and value expressions".to_string(),
                 hint: "session-send requires: (session-send channel value)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
 
@@ -854,8 +1063,7 @@ impl LispParser {
                 construct: "session-send expression".to_string(),
                 expected: "value expression".to_string(),
                 hint: "session-send requires a value to send".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
 
@@ -870,8 +1078,7 @@ impl LispParser {
                 construct: "session-recv expression".to_string(),
                 expected: "channel expression".to_string(),
                 hint: "session-recv requires: (session-recv channel)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
 
@@ -886,8 +1093,7 @@ impl LispParser {
                 construct: "session-select expression".to_string(),
                 expected: "channel and choice".to_string(),
                 hint: "session-select requires: (session-select channel \"choice\")".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
 
@@ -898,8 +1104,7 @@ impl LispParser {
                 construct: "session-select expression".to_string(),
                 expected: "choice string".to_string(),
                 hint: "session-select requires a choice string".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
 
@@ -918,8 +1123,7 @@ impl LispParser {
                 return Err(ParseError::InvalidTokenSequence {
                     context: "invalid choice in session-select".to_string(),
                     suggestion: "choice should be a string or symbol".to_string(),
-                    line: form_token.line,
-                    column: form_token.column,
+                    span: form_token.span(),
                 });
             }
         };
@@ -934,8 +1138,7 @@ impl LispParser {
                 construct: "session-case expression".to_string(),
                 expected: "channel and case branches".to_string(),
                 hint: "session-case requires: (session-case channel (label body)...)".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
 
@@ -957,8 +1160,7 @@ impl LispParser {
                 construct: "session-case expression".to_string(),
                 expected: "at least one case branch".to_string(),
                 hint: "session-case requires at least one (label body) branch".to_string(),
-                line: form_token.line,
-                column: form_token.column,
+                span: form_token.span(),
             });
         }
 
@@ -978,8 +1180,7 @@ impl LispParser {
                 Err(ParseError::expected_symbol_for(
                     context,
                     &current.format_for_error(),
-                    current.line,
-                    current.column,
+                    current.span(),
                 ))
             }
         }
@@ -996,8 +1197,7 @@ impl LispParser {
                 Err(ParseError::expected_token(
                     "'('",
                     &current.format_for_error(),
-                    current.line,
-                    current.column,
+                    current.span(),
                 ))
             }
         }
@@ -1014,14 +1214,14 @@ impl LispParser {
                 Err(ParseError::UnexpectedEofInConstruct {
                     construct: context.to_string(),
                     hint: "add ')' to close the expression".to_string(),
+                    span: self.unclosed_span(),
                 })
             }
             _ => {
                 Err(ParseError::expected_token(
                     "')'",
                     &current.format_for_error(),
-                    current.line,
-                    current.column,
+                    current.span(),
                 ))
             }
         }
@@ -346,6 +346,17 @@ impl TypeChecker {
                 }
             }
             ExprKind::Apply(func_expr, arg_exprs) => {
+                // `pure` lifts a value without changing its type
+                if let (ExprKind::Var(name), [value]) =
+                    (&func_expr.kind, arg_exprs.as_slice())
+                {
+                    if name.as_str() == "pure"
+                        && self.type_env.lookup_type("pure").is_none()
+                    {
+                        return self.check_expr(value);
+                    }
+                }
+
                 let mut current_type = self.check_expr(func_expr)?;
 
                 // Apply each argument in sequence for curried functions
@@ -376,6 +387,62 @@ impl TypeChecker {
 
                 Ok(current_type)
             }
+            ExprKind::LetRec(bindings, body) => {
+                if let Some(name) = Expr::ill_founded_binding(bindings) {
+                    return Err(TypeError::IllFoundedRecursion(name.to_string()));
+                }
+
+                // Give every function a provisional type so forward
+                // references resolve, then refine until the types settle
+                for (name, value) in bindings {
+                    if let ExprKind::Lambda(params, _) = &value.kind {
+                        let param_type = if params.is_empty() {
+                            TypeInner::Base(BaseType::Unit)
+                        } else {
+                            TypeInner::Base(BaseType::Symbol)
+                        };
+                        self.type_env.bind_type(
+                            name.to_string(),
+                            TypeInner::LinearFunction(
+                                Box::new(param_type),
+                                Box::new(TypeInner::Base(BaseType::Symbol)),
+                            ),
+                        );
+                    }
+                }
+                for _ in 0..=bindings.len() {
+                    let mut changed = false;
+                    for (name, value) in bindings {
+                        if let ExprKind::Lambda(..) = value.kind {
+                            let value_type = self.check_expr(value)?;
+                            let key = name.to_string();
+                            if self.type_env.lookup_type(&key).as_ref()
+                                != Some(&value_type)
+                            {
+                                self.type_env.bind_type(key, value_type);
+                                changed = true;
+                            }
+                        }
+                    }
+                    if !changed {
+                        break;
+                    }
+                }
+
+                // Values are checked in order once the functions are in scope
+                for (name, value) in bindings {
+                    if !matches!(value.kind, ExprKind::Lambda(..)) {
+                        let value_type = self.check_expr(value)?;
+                        self.type_env.bind_type(name.to_string(), value_type);
+                    }
+                }
+
+                let result = self.check_expr(body);
+                for (name, _) in bindings {
+                    self.type_env.remove_binding(&name.to_string());
+                }
+                result
+            }
 
             // Resource management
             ExprKind::Alloc(value_expr) => {
@@ -463,6 +530,27 @@ impl TypeChecker {
                 }
             }
 
+            // Quoted data only has a type when it is a single atom
+            ExprKind::Quote(template) | ExprKind::Quasiquote(template) => {
+                match &template.kind {
+                    ExprKind::Const(_) => self.check_expr(template),
+                    ExprKind::Var(_) => Ok(TypeInner::Base(BaseType::Symbol)),
+                    ExprKind::Unquote(inner)
+                        if matches!(expr.kind, ExprKind::Quasiquote(_)) =>
+                    {
+                        self.check_expr(inner)
+                    }
+                    _ => Err(TypeError::Mismatch {
+                        expected: "Simple type".to_string(),
+                        found: "Quoted list".to_string(),
+                    }),
+                }
+            }
+            ExprKind::Unquote(_) => Err(TypeError::Mismatch {
+                expected: "Expression".to_string(),
+                found: "Unquote outside of quasiquote".to_string(),
+            }),
+
             // Session types operations
             ExprKind::SessionDeclaration { name: _, roles: _ } => {
                 // For session declarations, we just return unit type
@@ -740,6 +828,26 @@ mod tests {
         assert!(matches!(ty, TypeInner::LinearFunction(..)));
     }
 
+    #[test]
+    fn test_type_check_letrec() {
+        let mut checker = TypeChecker::new();
+        let mut parser = LispParser::new();
+
+        // `f` refers to `g` before `g` is bound
+        let expr = parser
+            .parse("(letrec ((f (lambda (x) (g x))) (g (lambda (y) y))) f)")
+            .unwrap();
+        let ty = checker.check_expr(&expr).unwrap();
+        assert!(matches!(ty, TypeInner::LinearFunction(..)));
+        assert!(checker.type_env.lookup_type("f").is_none());
+
+        let expr = parser.parse("(letrec ((x (+ x 1))) x)").unwrap();
+        assert!(matches!(
+            checker.check_expr(&expr),
+            Err(TypeError::IllFoundedRecursion(name)) if name == "x"
+        ));
+    }
+
     #[test]
     fn test_type_check_layer1_primitives() {
         let mut checker = TypeChecker::new();
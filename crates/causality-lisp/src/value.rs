@@ -75,6 +75,12 @@ pub enum ValueKind {
         body: Expr,
     },
     
+    /// Function bound by `letrec`, able to call itself and its siblings
+    RecFunction {
+        name: Symbol,
+        group: Rc<RecGroup>,
+    },
+    
     /// Quoted expression
     Quoted(Expr),
     
@@ -91,6 +97,34 @@ pub enum ValueKind {
     Record(BTreeMap<Symbol, Value>),
 }
 
+/// Functions bound together by one `letrec`
+///
+/// The group holds the scope the functions close over; each call rebinds
+/// every function of the group, which ties the recursive knot without
+/// storing a function inside its own closure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecGroup {
+    pub functions: Vec<(Symbol, Vec<Param>, Expr)>,
+    pub env: Environment,
+}
+
+impl RecGroup {
+    /// Look up the parameters and body of a function in the group
+    pub fn function(&self, name: &Symbol) -> Option<(&[Param], &Expr)> {
+        self.functions
+            .iter()
+            .find(|(function, _, _)| function == name)
+            .map(|(_, params, body)| (params.as_slice(), body))
+    }
+    
+    /// Bind every function of the group in an environment
+    pub fn bind_all(self: &Rc<Self>, env: &mut Environment) {
+        for (name, _, _) in &self.functions {
+            env.bind(name.clone(), Value::rec_function(name.clone(), self.clone()));
+        }
+    }
+}
+
 /// Function arity specification
 #[derive(Debug, Clone, PartialEq)]
 pub enum Arity {
@@ -375,6 +409,7 @@ impl Value {
             ValueKind::Resource { .. } => true,
             ValueKind::Effect { .. } => true,
             ValueKind::Lambda { .. } => true,
+            ValueKind::RecFunction { .. } => true,
             ValueKind::Quoted(_) => true,
             ValueKind::Tensor(_, _) => true,
             ValueKind::Sum { .. } => true,
@@ -397,6 +432,7 @@ impl Value {
             ValueKind::Resource { .. } => "Resource",
             ValueKind::Effect { .. } => "Effect",
             ValueKind::Lambda { .. } => "Lambda",
+            ValueKind::RecFunction { .. } => "Function",
             ValueKind::Quoted(_) => "Quoted",
             ValueKind::Tensor(_, _) => "Tensor",
             ValueKind::Sum { .. } => "Sum",
@@ -434,6 +470,18 @@ impl Value {
         }
     }
     
+    /// Create a function value bound by `letrec`
+    pub fn rec_function(name: Symbol, group: Rc<RecGroup>) -> Self {
+        Self {
+            kind: ValueKind::RecFunction { name, group },
+            type_info: TypeInfo {
+                type_name: "Function".to_string(),
+                constraints: vec![],
+            },
+            linearity: LinearityInfo::default(),
+        }
+    }
+    
     /// Create a built-in function value
     pub fn builtin(name: impl Into<Symbol>, arity: i32) -> Self {
         let name_symbol = name.into();
@@ -4,7 +4,9 @@
 //! in Causality Lisp, ensuring that helpful, contextual error messages are provided
 //! for various syntax errors.
 
+use causality_lisp::ast::Span;
 use causality_lisp::parser::LispParser;
+use causality_lisp::ParseError;
 
 /// Helper function to test that parsing fails with a specific error pattern
 fn assert_parse_error_contains(input: &str, expected_patterns: &[&str]) {
@@ -278,6 +280,18 @@ fn test_error_message_contains_position_info() {
     }
 }
 
+#[test]
+fn test_unclosed_paren_reports_span() {
+    let source = "(+ 1\n   (* 2 3";
+    let error = LispParser::new().parse(source).unwrap_err();
+    
+    // The innermost unclosed parenthesis is reported
+    assert!(matches!(error, ParseError::UnexpectedEofInConstruct { .. }));
+    let span = error.span().expect("unclosed paren should carry a span");
+    assert_eq!(span, &Span::new(8, 9, 2, 4));
+    assert_eq!(span.render(source), "2 |    (* 2 3\n  |    ^");
+}
+
 #[test]
 fn test_error_message_helpfulness() {
     // Test that error messages are educational and actionable
@@ -3,11 +3,26 @@
 //! This module provides core execution functionality for register machine
 //! instructions, serving as the foundation for ZK-enabled execution.
 
-use causality_core::machine::{Instruction, MachineState, MachineValue, RegisterId};
-use crate::error::RuntimeResult;
+use causality_core::machine::{Instruction, MachineState, MachineValue, RegisterChange, RegisterId};
+use causality_core::machine::resource::ResourceId;
+use std::collections::{BTreeMap, BTreeSet};
+use crate::error::{RuntimeError, RuntimeResult};
+
+/// One executed instruction, as observed by a trace hook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    /// Position of the instruction in the program
+    pub pc: usize,
+    /// Instruction that was executed
+    pub instruction: Instruction,
+    /// Registers whose contents the instruction changed, in register order
+    pub register_delta: Vec<RegisterChange>,
+}
+
+/// Callback invoked after each executed instruction
+pub type TraceHook = Box<dyn FnMut(&TraceStep) + Send>;
 
 /// Basic executor for instruction sequences
-#[derive(Debug, Clone)]
 pub struct Executor {
     /// Machine state for execution
     machine_state: MachineState,
@@ -15,6 +30,15 @@ pub struct Executor {
     instructions: Vec<Instruction>,
     /// Program counter
     pc: usize,
+    /// Resources allocated in this run and not yet consumed, by the
+    /// register currently holding them
+    live_resources: BTreeMap<RegisterId, Vec<ResourceId>>,
+    /// Counter for allocated resource ids
+    next_resource_id: u64,
+    /// Registers emptied by `Consume` and not written since
+    consumed: BTreeSet<RegisterId>,
+    /// Observer of executed instructions
+    trace_hook: Option<TraceHook>,
 }
 
 impl Executor {
@@ -24,15 +48,42 @@ impl Executor {
             machine_state: MachineState::new(Vec::new()),
             instructions: Vec::new(),
             pc: 0,
+            live_resources: BTreeMap::new(),
+            next_resource_id: 0,
+            consumed: BTreeSet::new(),
+            trace_hook: None,
         }
     }
 
-    /// Execute instructions sequentially and return the final result
-    pub fn execute(&mut self, instructions: &[Instruction]) -> RuntimeResult<MachineValue> {
-        // Reset machine state for fresh execution
+    /// Observe every instruction as it runs
+    ///
+    /// The hook is called after each instruction with the instruction and
+    /// the registers it changed, replacing any previously set hook.
+    pub fn set_trace_hook(&mut self, hook: TraceHook) {
+        self.trace_hook = Some(hook);
+    }
+
+    /// Remove the trace hook, returning it if one was set
+    pub fn clear_trace_hook(&mut self) -> Option<TraceHook> {
+        self.trace_hook.take()
+    }
+
+    /// Reset the machine state and load instructions without running them
+    ///
+    /// The program can then be driven one instruction at a time with `step`.
+    pub fn load(&mut self, instructions: &[Instruction]) {
         self.machine_state = MachineState::new(instructions.to_vec());
         self.instructions = instructions.to_vec();
         self.pc = 0;
+        self.live_resources.clear();
+        self.next_resource_id = 0;
+        self.consumed.clear();
+    }
+
+    /// Execute instructions sequentially and return the final result
+    pub fn execute(&mut self, instructions: &[Instruction]) -> RuntimeResult<MachineValue> {
+        // Reset machine state for fresh execution
+        self.load(instructions);
         
         // Execute each instruction in sequence
         while self.pc < self.instructions.len() {
@@ -53,32 +104,62 @@ impl Executor {
             return Ok(None);
         }
 
+        let pc = self.pc;
         let instruction = &self.instructions[self.pc].clone();
         self.pc += 1;
 
+        // Registers the instruction may change, with their prior contents
+        let touched: Vec<(RegisterId, Option<MachineValue>)> = if self.trace_hook.is_some() {
+            let mut registers = instruction.reads();
+            registers.extend(instruction.writes());
+            registers.sort();
+            registers.dedup();
+            registers.into_iter()
+                .map(|register| (register, self.machine_state.load_register(register).cloned()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         match instruction {
             Instruction::Transform { morph_reg: _, input_reg, output_reg } => {
                 if let Some(value) = self.machine_state.load_register(*input_reg) {
                     self.machine_state.store_register(*output_reg, value.clone());
+                    self.move_resources(&[*input_reg], *output_reg);
                 }
             }
             Instruction::Alloc { type_reg: _, init_reg, output_reg } => {
                 // For now, just copy the init value to the output register  
                 if let Some(value) = self.machine_state.load_register(*init_reg) {
+                    let value = value.clone();
                     self.machine_state.store_register(*output_reg, value.clone());
+                    // Track the allocation as live until it is consumed
+                    let resource_id = ResourceId::new(self.next_resource_id);
+                    self.next_resource_id += 1;
+                    self.machine_state.store_resource(resource_id, value);
+                    self.live_resources.entry(*output_reg).or_default().push(resource_id);
                 }
             }
             Instruction::Consume { resource_reg, output_reg } => {
+                if self.consumed.contains(resource_reg) {
+                    return Err(RuntimeError::LinearityViolation {
+                        message: format!("register {} consumed twice at pc {}", resource_reg.id(), pc),
+                    });
+                }
                 if let Some(value) = self.machine_state.load_register(*resource_reg) {
                     self.machine_state.store_register(*output_reg, value.clone());
                     // Mark the resource as consumed
                     self.machine_state.store_register(*resource_reg, MachineValue::Unit);
+                    for resource_id in self.live_resources.remove(resource_reg).unwrap_or_default() {
+                        self.machine_state.take_resource(resource_id);
+                    }
                 }
             }
-            Instruction::Compose { first_reg: _, second_reg, output_reg } => {
+            Instruction::Compose { first_reg, second_reg, output_reg } => {
                 if let Some(second_value) = self.machine_state.load_register(*second_reg) {
                     // For now, just copy the second morphism to the output
                     self.machine_state.store_register(*output_reg, second_value.clone());
+                    self.move_resources(&[*first_reg, *second_reg], *output_reg);
                 }
             }
             Instruction::Tensor { left_reg, right_reg, output_reg } => {
@@ -92,10 +173,28 @@ impl Executor {
                         Box::new(right_value.clone())
                     );
                     self.machine_state.store_register(*output_reg, tensor_value);
+                    self.move_resources(&[*left_reg, *right_reg], *output_reg);
                 }
             }
         }
 
+        for register in instruction.writes() {
+            self.consumed.remove(&register);
+        }
+        if let Instruction::Consume { resource_reg, .. } = instruction {
+            self.consumed.insert(*resource_reg);
+        }
+
+        if let Some(hook) = self.trace_hook.as_mut() {
+            let register_delta = touched.into_iter()
+                .filter_map(|(register, old_value)| {
+                    let new_value = self.machine_state.load_register(register).cloned();
+                    (old_value != new_value).then_some(RegisterChange { register, old_value, new_value })
+                })
+                .collect();
+            hook(&TraceStep { pc, instruction: instruction.clone(), register_delta });
+        }
+
         // Return the current value in register 0, if any
         if let Some(value) = self.machine_state.load_register(RegisterId(0)) {
             Ok(Some(value.clone()))
@@ -113,6 +212,31 @@ impl Executor {
         }
     }
 
+    /// Check that every resource allocated during the run was consumed
+    ///
+    /// Returns the ids of resources still live in the machine state. At the
+    /// end of a program these are linear resources that leaked.
+    pub fn check_resources_consumed(&self) -> Result<(), Vec<ResourceId>> {
+        let live: Vec<ResourceId> = self.machine_state.resources.keys().copied().collect();
+        if live.is_empty() {
+            Ok(())
+        } else {
+            Err(live)
+        }
+    }
+
+    /// Follow live resources from the registers an instruction read into
+    /// the register it wrote
+    fn move_resources(&mut self, from: &[RegisterId], to: RegisterId) {
+        let mut moved = Vec::new();
+        for register in from {
+            moved.extend(self.live_resources.remove(register).unwrap_or_default());
+        }
+        if !moved.is_empty() {
+            self.live_resources.entry(to).or_default().extend(moved);
+        }
+    }
+
     /// Get the current machine state
     pub fn machine_state(&self) -> &MachineState {
         &self.machine_state
@@ -130,6 +254,32 @@ impl Default for Executor {
     }
 }
 
+impl Clone for Executor {
+    /// Clones the execution state; the trace hook is not carried over
+    fn clone(&self) -> Self {
+        Self {
+            machine_state: self.machine_state.clone(),
+            instructions: self.instructions.clone(),
+            pc: self.pc,
+            live_resources: self.live_resources.clone(),
+            next_resource_id: self.next_resource_id,
+            trace_hook: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for Executor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Executor")
+            .field("machine_state", &self.machine_state)
+            .field("instructions", &self.instructions)
+            .field("pc", &self.pc)
+            .field("live_resources", &self.live_resources)
+            .field("trace_hook", &self.trace_hook.is_some())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,4 +320,132 @@ mod tests {
         // Result should be whatever the alloc instruction produces
         println!("Result: {:?}", result.unwrap());
     }
+
+    #[test]
+    fn test_trace_hook_observes_each_instruction() {
+        use std::sync::{Arc, Mutex};
+
+        let steps = Arc::new(Mutex::new(Vec::new()));
+        let mut executor = Executor::new();
+        executor.set_trace_hook(Box::new({
+            let steps = steps.clone();
+            move |step: &TraceStep| steps.lock().unwrap().push(step.clone())
+        }));
+
+        // Seed register 1 and run the program step by step
+        executor.instructions = vec![
+            Instruction::Transform { morph_reg: RegisterId(0), input_reg: RegisterId(1), output_reg: RegisterId(2) },
+            Instruction::Tensor { left_reg: RegisterId(1), right_reg: RegisterId(2), output_reg: RegisterId(3) },
+            Instruction::Consume { resource_reg: RegisterId(3), output_reg: RegisterId(4) },
+        ];
+        executor.machine_state.store_register(RegisterId(1), MachineValue::Int(5));
+        while executor.pc < executor.instructions.len() {
+            executor.step().unwrap();
+        }
+
+        let steps = steps.lock().unwrap();
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps.iter().map(|step| step.pc).collect::<Vec<_>>(), [0, 1, 2]);
+        assert_eq!(steps[0].instruction, executor.instructions[0]);
+
+        let pair = MachineValue::Product(Box::new(MachineValue::Int(5)), Box::new(MachineValue::Int(5)));
+        assert_eq!(steps[0].register_delta, [RegisterChange {
+            register: RegisterId(2),
+            old_value: None,
+            new_value: Some(MachineValue::Int(5)),
+        }]);
+        assert_eq!(steps[1].register_delta, [RegisterChange {
+            register: RegisterId(3),
+            old_value: None,
+            new_value: Some(pair.clone()),
+        }]);
+        assert_eq!(steps[2].register_delta, [
+            RegisterChange { register: RegisterId(3), old_value: Some(pair.clone()), new_value: Some(MachineValue::Unit) },
+            RegisterChange { register: RegisterId(4), old_value: None, new_value: Some(pair) },
+        ]);
+    }
+
+    /// Run a program step by step from the given register contents
+    fn run_seeded(instructions: Vec<Instruction>, seeds: &[(u32, MachineValue)]) -> Executor {
+        let mut executor = Executor::new();
+        executor.instructions = instructions;
+        for (register, value) in seeds {
+            executor.machine_state.store_register(RegisterId(*register), value.clone());
+        }
+        while executor.pc < executor.instructions.len() {
+            executor.step().unwrap();
+        }
+        executor
+    }
+
+    #[test]
+    fn test_leaked_resource_is_reported() {
+        let executor = run_seeded(vec![
+            Instruction::Alloc { type_reg: RegisterId(1), init_reg: RegisterId(2), output_reg: RegisterId(3) },
+            Instruction::Alloc { type_reg: RegisterId(1), init_reg: RegisterId(2), output_reg: RegisterId(4) },
+            Instruction::Consume { resource_reg: RegisterId(3), output_reg: RegisterId(5) },
+        ], &[(2, MachineValue::Int(7))]);
+
+        assert_eq!(executor.check_resources_consumed(), Err(vec![ResourceId::new(1)]));
+    }
+
+    #[test]
+    fn test_consumed_resources_pass_leak_check() {
+        let executor = run_seeded(vec![
+            Instruction::Alloc { type_reg: RegisterId(1), init_reg: RegisterId(2), output_reg: RegisterId(3) },
+            Instruction::Alloc { type_reg: RegisterId(1), init_reg: RegisterId(2), output_reg: RegisterId(4) },
+            // Resources moved through other instructions are still tracked
+            Instruction::Transform { morph_reg: RegisterId(0), input_reg: RegisterId(3), output_reg: RegisterId(5) },
+            Instruction::Tensor { left_reg: RegisterId(5), right_reg: RegisterId(4), output_reg: RegisterId(6) },
+            Instruction::Consume { resource_reg: RegisterId(6), output_reg: RegisterId(7) },
+        ], &[(2, MachineValue::Int(7))]);
+
+        assert_eq!(executor.check_resources_consumed(), Ok(()));
+        assert_eq!(executor.machine_state().nullifiers.len(), 2);
+    }
+
+    #[test]
+    fn test_double_consume_is_rejected() {
+        let mut executor = Executor::new();
+        let result = executor.execute(&[
+            Instruction::Alloc { type_reg: RegisterId(1), init_reg: RegisterId(2), output_reg: RegisterId(3) },
+            Instruction::Consume { resource_reg: RegisterId(3), output_reg: RegisterId(4) },
+            Instruction::Consume { resource_reg: RegisterId(3), output_reg: RegisterId(5) },
+        ]);
+        assert!(matches!(result, Err(RuntimeError::LinearityViolation { .. })));
+
+        // Writing the register again makes it consumable again
+        executor.execute(&[
+            Instruction::Consume { resource_reg: RegisterId(3), output_reg: RegisterId(4) },
+            Instruction::Transform { morph_reg: RegisterId(0), input_reg: RegisterId(4), output_reg: RegisterId(3) },
+            Instruction::Consume { resource_reg: RegisterId(3), output_reg: RegisterId(5) },
+        ]).unwrap();
+    }
+
+    #[test]
+    fn test_trace_hook_counts_executed_instructions() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut executor = Executor::new();
+        executor.set_trace_hook(Box::new({
+            let count = count.clone();
+            move |_: &TraceStep| {
+                count.fetch_add(1, Ordering::SeqCst);
+            }
+        }));
+
+        let instructions = vec![
+            Instruction::Alloc { type_reg: RegisterId(1), init_reg: RegisterId(2), output_reg: RegisterId(0) };
+            4
+        ];
+        executor.execute(&instructions).unwrap();
+        assert_eq!(count.load(Ordering::SeqCst), 4);
+
+        // A cleared hook is no longer called
+        assert!(executor.clear_trace_hook().is_some());
+        executor.execute(&instructions).unwrap();
+        assert_eq!(count.load(Ordering::SeqCst), 4);
+    }
 } 
\ No newline at end of file
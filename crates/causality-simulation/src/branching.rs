@@ -3,7 +3,7 @@
 //! This module provides functionality to fork simulation states and explore
 //! different execution paths in parallel, enabling "what-if" analysis.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use serde::{Serialize, Deserialize};
 use crate::{
     engine::SimulationEngine,
@@ -11,6 +11,7 @@ use crate::{
     error::SimulationError,
     engine::ExecutionState,
 };
+use causality_core::{EntityId, MachineStateSnapshot};
 use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Global counter for ensuring unique branch IDs
@@ -49,6 +50,9 @@ pub struct BranchingConfig {
     
     /// Whether to automatically prune unsuccessful branches
     pub auto_prune: bool,
+    
+    /// Merge branches that reach a machine state already being explored
+    pub dedup_by_state_hash: bool,
 }
 
 impl Default for BranchingConfig {
@@ -57,10 +61,31 @@ impl Default for BranchingConfig {
             max_branches: 10,
             max_depth: 5,
             auto_prune: true,
+            dedup_by_state_hash: true,
         }
     }
 }
 
+/// Statistics on branches discarded during exploration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruningStats {
+    /// Successor states produced while exploring
+    pub branches_considered: usize,
+    
+    /// Branches merged into an existing branch with an identical state
+    pub merged_duplicates: usize,
+    
+    /// Branches dropped because `max_branches` was reached
+    pub pruned_by_limit: usize,
+}
+
+impl PruningStats {
+    /// Total number of branches that were not materialised
+    pub fn total_pruned(&self) -> usize {
+        self.merged_duplicates + self.pruned_by_limit
+    }
+}
+
 /// Represents a single branch in the execution tree
 pub struct SimulationBranch {
     /// Unique identifier for this branch
@@ -155,6 +180,9 @@ pub struct BranchInfo {
     
     /// Branch metadata
     pub metadata: BranchMetadata,
+    
+    /// Content hash of the machine state this branch was explored from
+    pub state_hash: Option<EntityId>,
 }
 
 /// Manager for simulation branching and scenario exploration
@@ -166,6 +194,12 @@ pub struct BranchingManager {
     pub active_branch_id: Option<BranchId>,
     /// Root branch ID
     root_branch_id: BranchId,
+    /// Branching limits and pruning strategy
+    config: BranchingConfig,
+    /// Explored machine states, keyed by content hash
+    state_index: BTreeMap<EntityId, BranchId>,
+    /// Statistics on pruned branches
+    pruning_stats: PruningStats,
 }
 
 impl BranchingManager {
@@ -188,6 +222,7 @@ impl BranchingManager {
                 depth: 0,
                 steps_executed: 0,
             },
+            state_hash: None,
         };
         
         branches.insert(root_id.clone(), root_branch);
@@ -196,14 +231,109 @@ impl BranchingManager {
             branches,
             active_branch_id: Some(root_id.clone()),
             root_branch_id: root_id,
+            config: BranchingConfig::default(),
+            state_index: BTreeMap::new(),
+            pruning_stats: PruningStats::default(),
         }
     }
     
     /// Create a new branching manager with configuration
-    pub fn with_config(_config: BranchingConfig) -> Self {
-        // For now, just use the default implementation
-        // TODO: Apply configuration settings
-        Self::new()
+    pub fn with_config(config: BranchingConfig) -> Self {
+        Self {
+            config,
+            ..Self::new()
+        }
+    }
+    
+    /// Get the branching configuration
+    pub fn config(&self) -> &BranchingConfig {
+        &self.config
+    }
+    
+    /// Get statistics on branches pruned so far
+    pub fn pruning_stats(&self) -> &PruningStats {
+        &self.pruning_stats
+    }
+    
+    /// Explore every machine state reachable from `initial`, breadth first
+    ///
+    /// `successors` returns the labelled states reachable in one step, e.g.
+    /// one per `SumElim` branch. With `dedup_by_state_hash` a successor whose
+    /// content hash matches an already explored state is merged into that
+    /// branch instead of being expanded again, so reconverging protocols stay
+    /// linear rather than exponential. Exploration stops at `max_depth` and
+    /// never holds more than `max_branches` branches. Returns the IDs of the
+    /// branches created, in exploration order.
+    pub fn explore<F>(
+        &mut self,
+        initial: MachineStateSnapshot,
+        mut successors: F,
+    ) -> Result<Vec<BranchId>, SimulationError>
+    where
+        F: FnMut(&MachineStateSnapshot) -> Vec<(String, MachineStateSnapshot)>,
+    {
+        let start_id = self.active_branch_id.clone().unwrap_or_else(|| self.root_branch_id.clone());
+        let start_depth = self.branches.get(&start_id).map(|b| b.metadata.depth).unwrap_or(0);
+        
+        let initial_hash = state_content_id(&initial);
+        if let Some(branch) = self.branches.get_mut(&start_id) {
+            branch.state_hash = Some(initial_hash);
+        }
+        self.state_index.entry(initial_hash).or_insert_with(|| start_id.clone());
+        
+        let mut created = Vec::new();
+        let mut queue = VecDeque::from([(start_id, initial, 0usize)]);
+        
+        while let Some((parent_id, snapshot, depth)) = queue.pop_front() {
+            if depth >= self.config.max_depth {
+                continue;
+            }
+            
+            for (label, next) in successors(&snapshot) {
+                self.pruning_stats.branches_considered += 1;
+                
+                let hash = state_content_id(&next);
+                if self.config.dedup_by_state_hash && self.state_index.contains_key(&hash) {
+                    self.pruning_stats.merged_duplicates += 1;
+                    continue;
+                }
+                if self.branches.len() >= self.config.max_branches {
+                    self.pruning_stats.pruned_by_limit += 1;
+                    continue;
+                }
+                
+                let branch_id = BranchId(format!("{}.{}", parent_id.0, label));
+                if self.branches.contains_key(&branch_id) {
+                    return Err(SimulationError::InvalidInput(format!("Branch already exists: {}", branch_id.0)));
+                }
+                
+                let execution_state = ExecutionState {
+                    instruction_pointer: next.instruction_pointer,
+                    ..ExecutionState::new()
+                };
+                self.branches.insert(branch_id.clone(), BranchInfo {
+                    id: branch_id.clone(),
+                    name: label.clone(),
+                    parent_id: Some(parent_id.clone()),
+                    created_at: std::time::UNIX_EPOCH,
+                    execution_state,
+                    metadata: BranchMetadata {
+                        description: label,
+                        status: BranchStatus::Active,
+                        created_at: crate::clock::SimulatedTimestamp::new(0),
+                        depth: start_depth + depth + 1,
+                        steps_executed: 0,
+                    },
+                    state_hash: Some(hash),
+                });
+                self.state_index.entry(hash).or_insert_with(|| branch_id.clone());
+                
+                created.push(branch_id.clone());
+                queue.push_back((branch_id, next, depth + 1));
+            }
+        }
+        
+        Ok(created)
     }
     
     /// Create a new branch
@@ -232,6 +362,7 @@ impl BranchingManager {
                 depth: 1,
                 steps_executed: 0,
             },
+            state_hash: None,
         };
         
         self.branches.insert(new_branch_id.clone(), branch_info);
@@ -283,6 +414,7 @@ impl BranchingManager {
         
         // Remove the branch itself
         self.branches.remove(&branch_key);
+        self.state_index.retain(|_, id| id != &branch_key);
         
         // Update current branch if it was removed
         if self.active_branch_id.as_ref() == Some(&branch_key) {
@@ -304,6 +436,8 @@ impl BranchingManager {
         }
         
         self.active_branch_id = Some(root_id);
+        self.state_index.clear();
+        self.pruning_stats = PruningStats::default();
     }
     
     /// Get the current active branch ID
@@ -386,6 +520,14 @@ impl Default for BranchingManager {
     }
 }
 
+/// Content hash of a machine state snapshot
+///
+/// The snapshot's SSZ encoding walks its ordered maps in key order, so equal
+/// states always hash to the same `EntityId`.
+fn state_content_id(snapshot: &MachineStateSnapshot) -> EntityId {
+    EntityId::from_content(snapshot)
+}
+
 /// Summary of branching execution
 #[derive(Debug, Clone)]
 pub struct BranchingSummary {
@@ -408,7 +550,7 @@ pub struct BranchSummary {
 #[cfg(test)]
 mod tests {
     use super::*;
-    
+    use causality_core::machine::{MachineValue, RegisterId};
     
     #[test]
     fn test_branch_id_generation() {
@@ -474,4 +616,65 @@ mod tests {
         // Test basic functionality without max_branches limit
         assert_eq!(manager.branches.len(), 2);
     }
-} 
\ No newline at end of file
+    
+    /// Snapshot whose only distinguishing feature is `value` in register 0
+    fn counter_state(value: u32) -> MachineStateSnapshot {
+        MachineStateSnapshot {
+            registers: BTreeMap::from([(RegisterId::new(0), MachineValue::Int(value))]),
+            resources: BTreeMap::new(),
+            instruction_pointer: value as usize,
+            lamport_clock: 0,
+        }
+    }
+    
+    /// Two-way choice whose branches reconverge on the same next state
+    fn reconverging(snapshot: &MachineStateSnapshot) -> Vec<(String, MachineStateSnapshot)> {
+        let next = counter_state(snapshot.instruction_pointer as u32 + 1);
+        vec![("left".to_string(), next.clone()), ("right".to_string(), next)]
+    }
+    
+    #[test]
+    fn test_reconverging_branches_are_merged() {
+        let depth = 10;
+        let mut manager = BranchingManager::with_config(BranchingConfig {
+            max_branches: 10_000,
+            max_depth: depth,
+            auto_prune: true,
+            dedup_by_state_hash: true,
+        });
+        
+        let created = manager.explore(counter_state(0), reconverging).unwrap();
+        
+        // Naively every level doubles: 2 + 4 + ... + 2^depth branches
+        let naive: usize = (1..=depth).map(|d| 1usize << d).sum();
+        assert_eq!(created.len(), depth);
+        assert!(created.len() * 100 < naive);
+        
+        let stats = manager.pruning_stats();
+        assert_eq!(stats.branches_considered, 2 * depth);
+        assert_eq!(stats.merged_duplicates, depth);
+        assert_eq!(stats.pruned_by_limit, 0);
+        
+        let last = manager.get_branch(created.last().unwrap()).unwrap();
+        assert_eq!(last.metadata.depth, depth);
+        assert!(last.state_hash.is_some());
+    }
+    
+    #[test]
+    fn test_exploration_respects_branch_limit() {
+        let mut manager = BranchingManager::with_config(BranchingConfig {
+            max_branches: 5,
+            max_depth: 4,
+            auto_prune: true,
+            dedup_by_state_hash: false,
+        });
+        
+        let created = manager.explore(counter_state(0), reconverging).unwrap();
+        
+        // Root plus four explored branches fill the limit
+        assert_eq!(created.len(), 4);
+        assert_eq!(manager.list_branches().len(), 5);
+        assert!(manager.pruning_stats().pruned_by_limit > 0);
+        assert_eq!(manager.pruning_stats().merged_duplicates, 0);
+    }
+}
@@ -4,42 +4,94 @@ use std::sync::{Arc, Mutex};
 use std::time::{Duration, UNIX_EPOCH};
 use serde::{Deserialize, Serialize};
 
-/// Simulated timestamp for testing
+/// Simulated timestamp for testing, with millisecond resolution
+///
+/// Serialized as `{"millis": n}`. Timestamps were once stored as a bare
+/// number of seconds, which still deserializes, so older snapshots and logs
+/// keep their times.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(from = "TimestampRepr", into = "TimestampRepr")]
 pub struct SimulatedTimestamp(u64);
 
+/// Serialized forms of [`SimulatedTimestamp`]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum TimestampRepr {
+    /// Current form, in milliseconds
+    Millis { millis: u64 },
+    /// Form written before millisecond resolution, in whole seconds
+    Secs(u64),
+}
+
+impl From<TimestampRepr> for SimulatedTimestamp {
+    fn from(repr: TimestampRepr) -> Self {
+        match repr {
+            TimestampRepr::Millis { millis } => Self::from_millis(millis),
+            TimestampRepr::Secs(secs) => Self::from_secs(secs),
+        }
+    }
+}
+
+impl From<SimulatedTimestamp> for TimestampRepr {
+    fn from(timestamp: SimulatedTimestamp) -> Self {
+        TimestampRepr::Millis { millis: timestamp.as_millis() }
+    }
+}
+
 impl SimulatedTimestamp {
     /// Create a new simulated timestamp from seconds since epoch
     pub fn from_secs(secs: u64) -> Self {
-        Self(secs)
+        Self(secs * 1000)
+    }
+    
+    /// Create a new simulated timestamp from milliseconds since epoch
+    pub fn from_millis(millis: u64) -> Self {
+        Self(millis)
     }
     
     /// Create a new simulated timestamp from nanoseconds (for compatibility)
     pub fn new(nanos: u64) -> Self {
-        Self(nanos / 1_000_000_000) // Convert nanoseconds to seconds
+        Self(nanos / 1_000_000) // Convert nanoseconds to milliseconds
     }
     
     /// Get the timestamp as seconds since epoch
     pub fn as_secs(&self) -> u64 {
+        self.0 / 1000
+    }
+    
+    /// Get the timestamp as milliseconds since epoch
+    pub fn as_millis(&self) -> u64 {
         self.0
     }
     
     /// Get the timestamp value (for ID generation)
     pub fn timestamp(&self) -> u64 {
-        self.0
+        self.as_secs()
     }
     
     /// Add duration to timestamp
     pub fn add_duration(&self, duration: Duration) -> Self {
-        Self(self.0 + duration.as_secs())
+        Self(self.0 + duration.as_millis() as u64)
     }
     
     /// Get duration between timestamps
     pub fn duration_since(&self, earlier: SimulatedTimestamp) -> Duration {
-        Duration::from_secs(self.0.saturating_sub(earlier.0))
+        Duration::from_millis(self.0.saturating_sub(earlier.0))
     }
 }
 
+/// How a simulation engine moves its clock forward
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClockMode {
+    /// Time only moves when the caller advances the clock explicitly
+    #[default]
+    Manual,
+    
+    /// Every executed step advances the clock by a fixed amount, so elapsed
+    /// time depends only on the number of steps, never on the host
+    FixedStep(Duration),
+}
+
 /// Simulated clock for controlled time progression in tests
 #[derive(Debug, Clone)]
 pub struct SimulatedClock {
@@ -65,12 +117,29 @@ impl SimulatedClock {
         Self::new(SimulatedTimestamp::from_secs(now))
     }
     
+    /// Create an independent clock at the same time and scale
+    ///
+    /// Clones share the underlying time; a fork advances on its own.
+    pub fn fork(&self) -> Self {
+        Self {
+            current_time: Arc::new(Mutex::new(self.now())),
+            time_scale: self.time_scale,
+        }
+    }
+    
     /// Get the current simulated time
     pub fn now(&self) -> SimulatedTimestamp {
         *self.current_time.lock().unwrap()
     }
     
+    /// Move the simulated time to `time`, shared with every clone
+    pub fn set_time(&self, time: SimulatedTimestamp) {
+        *self.current_time.lock().unwrap() = time;
+    }
+    
     /// Advance the simulated time by the given duration
+    ///
+    /// Sub-second durations are honoured at millisecond resolution.
     pub fn advance(&self, duration: Duration) {
         let mut current = self.current_time.lock().unwrap();
         *current = current.add_duration(duration);
@@ -125,6 +194,23 @@ mod tests {
         assert_eq!(ts2.duration_since(ts1), Duration::from_secs(50));
     }
     
+    #[test]
+    fn test_timestamp_serde_keeps_millis_and_reads_seconds() {
+        let timestamp = SimulatedTimestamp::from_millis(1_500);
+        let json = serde_json::to_string(&timestamp).unwrap();
+        assert_eq!(json, r#"{"millis":1500}"#);
+        assert_eq!(serde_json::from_str::<SimulatedTimestamp>(&json).unwrap(), timestamp);
+        
+        // Data written when timestamps were stored in seconds
+        let legacy: SimulatedTimestamp = serde_json::from_str("42").unwrap();
+        assert_eq!(legacy, SimulatedTimestamp::from_secs(42));
+        
+        let step: crate::engine::ExecutionStep = serde_json::from_str(
+            r#"{"step_number":0,"timestamp":7,"instruction":null,"resources_allocated":[],"resources_consumed":[],"gas_consumed":1}"#,
+        ).unwrap();
+        assert_eq!(step.timestamp.as_millis(), 7_000);
+    }
+    
     #[test]
     fn test_simulated_clock() {
         let clock = SimulatedClock::new(SimulatedTimestamp::from_secs(1000));
@@ -145,4 +231,18 @@ mod tests {
         clock.advance(Duration::from_secs(150));
         assert!(clock.is_timeout(start, Duration::from_secs(100)));
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_sub_second_advance() {
+        let clock = SimulatedClock::new(SimulatedTimestamp::from_secs(10));
+        let start = clock.now();
+        
+        for _ in 0..4 {
+            clock.advance(Duration::from_millis(250));
+        }
+        
+        assert_eq!(clock.now().as_secs(), 11);
+        assert_eq!(clock.now().duration_since(start), Duration::from_millis(1000));
+        assert!(clock.is_timeout(start, Duration::from_secs(1)));
+    }
+}
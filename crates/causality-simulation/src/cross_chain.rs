@@ -1886,6 +1886,17 @@ impl CrossChainSessionRegistry {
             }
         }
         
+        // The two endpoints of a binary choreography must follow dual protocols
+        let projections: Vec<_> = choreography.chain_projections.iter().collect();
+        if let [(chain1, local1), (chain2, local2)] = projections.as_slice() {
+            if !local1.is_dual_to(local2) {
+                return Err(crate::error::SimulationError::InvalidInput(
+                    format!("Projections for chains {} ({}) and {} ({}) are not dual",
+                            chain1, local1, chain2, local2)
+                ));
+            }
+        }
+        
         Ok(())
     }
     
@@ -2096,6 +2107,37 @@ mod tests {
         assert_eq!(executor.chain_executors.len(), 1);
     }
     
+    #[test]
+    fn test_choreography_rejects_non_dual_projections() {
+        use causality_core::lambda::{base::BaseType, TypeInner};
+        
+        let int = || Box::new(TypeInner::Base(BaseType::Int));
+        let client = SessionType::Send(int(), Box::new(SessionType::Receive(int(), Box::new(SessionType::End))));
+        let choreography = |server: SessionType| CrossChainChoreography {
+            id: "swap".to_string(),
+            description: "two-chain swap".to_string(),
+            participant_locations: BTreeMap::from([
+                ("alice".to_string(), "eth".to_string()),
+                ("bob".to_string(), "cosmos".to_string()),
+            ]),
+            global_session_type: client.clone(),
+            chain_projections: BTreeMap::from([
+                ("eth".to_string(), client.clone()),
+                ("cosmos".to_string(), server),
+            ]),
+            routing_rules: Vec::new(),
+            sync_requirements: Vec::new(),
+            execution_constraints: Vec::new(),
+        };
+        
+        let mut registry = CrossChainSessionRegistry::new();
+        assert!(registry.register_choreography(choreography(client.dual())).is_ok());
+        
+        // Both endpoints trying to send first can never make progress
+        let err = registry.register_choreography(choreography(client.clone())).unwrap_err();
+        assert!(err.to_string().contains("not dual"));
+    }
+    
     #[tokio::test]
     async fn test_message_relay() {
         let mut relay = MessageRelay::new();
@@ -4,20 +4,26 @@
 //! effects, and distributed system protocols with the unified transform model.
 
 use crate::{
-    clock::{SimulatedClock, SimulatedTimestamp},
+    clock::{ClockMode, SimulatedClock, SimulatedTimestamp},
     snapshot::{SnapshotManager, SnapshotId},
     branching::{BranchingManager},
     error::SimulationError,
+    fault_injection::{FaultInjector, SessionFaultResult},
+    scheduler::{Scheduler, SchedulingPolicy},
 };
 
 use causality_core::{
-    lambda::base::{Value, TypeInner, SessionType},
-    machine::Instruction,
+    effect::{EffectStep, ExecutionStatus, ExecutionTrace, StepStatus},
+    lambda::{base::{Value, TypeInner, SessionType}, Symbol},
+    machine::{Instruction, MachineValue, RegisterId},
+    EntityId, MachineStateSnapshot, Timestamp,
 };
 
 use causality_lisp::LispValue;
 
-use std::{collections::BTreeMap, time::SystemTime};
+use std::{collections::{BTreeMap, BTreeSet}, time::{Duration, SystemTime}};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
 use serde::{Serialize, Deserialize};
 
 /// Simulation state enumeration
@@ -204,6 +210,12 @@ pub struct ProtocolComplianceState {
     
     /// Whether protocol is complete
     pub is_complete: bool,
+    
+    /// First point where the participant departed from its session type
+    pub first_divergence: Option<ProtocolDivergence>,
+    
+    /// Session type positions this participant has reached so far
+    pub visited_positions: BTreeSet<SessionType>,
 }
 
 /// Protocol violation details
@@ -216,6 +228,54 @@ pub struct ProtocolViolation {
     pub message: String,
 }
 
+impl ProtocolViolation {
+    /// How serious this violation is
+    pub fn severity(&self) -> ViolationSeverity {
+        match self.violation_type {
+            ViolationType::Deadlock | ViolationType::Livelock => ViolationSeverity::Critical,
+            ViolationType::UnexpectedOperation
+            | ViolationType::TypeMismatch
+            | ViolationType::InvalidChoice => ViolationSeverity::Error,
+            ViolationType::PrematureEnd => ViolationSeverity::Warning,
+        }
+    }
+}
+
+/// Severity of a protocol violation, ordered from least to most serious
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ViolationSeverity {
+    /// Suspicious but the protocol can still complete
+    Warning,
+    
+    /// The participant broke its session type
+    Error,
+    
+    /// The simulation cannot make progress
+    Critical,
+}
+
+/// Where a participant first departed from its session type
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtocolDivergence {
+    /// Number of operations the participant completed before diverging
+    pub step: usize,
+    
+    /// Operations the session type allowed at that point
+    pub expected: Vec<SessionOperation>,
+    
+    /// Operation the participant actually attempted
+    pub actual: Option<SessionOperation>,
+    
+    /// Remaining session type at the point of divergence
+    pub session_position: Option<SessionType>,
+    
+    /// Severity of the divergence
+    pub severity: ViolationSeverity,
+    
+    /// Human-readable description
+    pub message: String,
+}
+
 /// Types of protocol violations
 #[derive(Debug, Clone)]
 pub enum ViolationType {
@@ -228,6 +288,9 @@ pub enum ViolationType {
     /// Deadlock detected
     Deadlock,
     
+    /// Participants keep operating without reaching a new session state
+    Livelock,
+    
     /// Invalid choice in external/internal choice
     InvalidChoice,
     
@@ -292,17 +355,84 @@ pub struct SimulationEngine {
     
     /// Current branch ID
     current_branch: Option<String>,
+    
+    /// Seed used for every nondeterministic choice made by this engine
+    seed: u64,
+    
+    /// Seeded RNG driving scheduling choices between enabled operations
+    rng: ChaCha12Rng,
+    
+    /// Fault injector seeded from the engine seed
+    fault_injector: FaultInjector,
+    
+    /// Scheduler ordering session participants within each step
+    scheduler: Scheduler,
+    
+    /// How the simulated clock advances as steps execute
+    clock_mode: ClockMode,
+    
+    /// Simulated time a blocked participant may wait before it counts as deadlocked
+    deadlock_timeout: Duration,
+    
+    /// Steps without session progress after which a livelock is reported
+    livelock_threshold: Option<u64>,
+    
+    /// Consecutive session steps in which no participant reached a new position
+    steps_since_progress: u64,
+    
+    /// Senders whose messages to each receiver were dropped by injected faults
+    /// and have not yet been missed by that receiver
+    lost_messages: BTreeMap<String, Vec<String>>,
+    
+    /// One step per session operation attempted, in execution order
+    trace_steps: Vec<EffectStep>,
 }
 
 /// State progression tracking
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StateProgression {
     pub steps: Vec<ExecutionStep>,
     pub state_transitions: Vec<(SimulationState, SimulatedTimestamp)>,
+    /// Number of steps removed from `steps` by pruning
+    #[serde(default)]
+    pub pruned_steps: usize,
+}
+
+impl StateProgression {
+    /// Total number of steps executed, including pruned ones
+    pub fn executed_steps(&self) -> usize {
+        self.pruned_steps + self.steps.len()
+    }
+    
+    /// Delete the recorded steps whose step numbers fall in `range`
+    ///
+    /// Returns the number of steps removed.
+    pub fn delete_range(&mut self, range: std::ops::Range<usize>) -> usize {
+        let before = self.steps.len();
+        self.steps.retain(|step| !range.contains(&step.step_number));
+        let removed = before - self.steps.len();
+        self.pruned_steps += removed;
+        removed
+    }
+}
+
+/// Session participants and the seeded generators that schedule them
+///
+/// Recorded by [`SimulationEngine::session_replay_state`] so a replay resumes
+/// mid-run with the same participant order, branch picks, and injected faults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionReplayState {
+    pub participants: BTreeMap<String, SessionParticipantState>,
+    pub rng: ChaCha12Rng,
+    pub scheduler: Scheduler,
+    pub fault_injector: FaultInjector,
+    pub steps_since_progress: u64,
+    pub lost_messages: BTreeMap<String, Vec<String>>,
+    pub trace_steps: Vec<EffectStep>,
 }
 
 /// Single execution step
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExecutionStep {
     pub step_number: usize,
     pub timestamp: SimulatedTimestamp,
@@ -319,29 +449,27 @@ impl Default for SimulationEngine {
 }
 
 impl SimulationEngine {
-    /// Create a new simulation engine
+    /// Create a new simulation engine with a random seed
     pub fn new() -> Self {
-        Self {
-            state: SimulationState::Created,
-            config: SimulationConfig::default(),
-            clock: SimulatedClock::new(SimulatedTimestamp::new(0)),
-            _snapshot_manager: SnapshotManager::new(10),
-            program: Vec::new(),
-            pc: 0,
-            state_progression: StateProgression::default(),
-            metrics: ExecutionMetrics::default(),
-            effects_log: Vec::new(),
-            session_participants: BTreeMap::new(),
-            execution_state: ExecutionState::new(),
-            step_count: 0,
-            effect_results: Vec::new(),
-            branch_manager: BranchingManager::new(),
-            current_branch: None,
-        }
+        Self::new_with_config(SimulationConfig::default())
     }
 
-    /// Create a new simulation engine with config
+    /// Create a new simulation engine with config and a random seed
     pub fn new_with_config(config: SimulationConfig) -> Self {
+        Self::with_seed_and_config(rand::random(), config)
+    }
+    
+    /// Create a simulation engine whose nondeterministic choices are driven by `seed`
+    ///
+    /// Two engines created with the same seed and fed the same program and
+    /// participants produce identical traces, so a failing run can be replayed
+    /// by passing the value reported by [`SimulationEngine::seed`].
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_seed_and_config(seed, SimulationConfig::default())
+    }
+    
+    /// Create a seeded simulation engine with config
+    pub fn with_seed_and_config(seed: u64, config: SimulationConfig) -> Self {
         Self {
             state: SimulationState::Created,
             config,
@@ -358,8 +486,76 @@ impl SimulationEngine {
             effect_results: Vec::new(),
             branch_manager: BranchingManager::new(),
             current_branch: None,
+            seed,
+            rng: ChaCha12Rng::seed_from_u64(seed),
+            fault_injector: FaultInjector::with_seed(seed),
+            scheduler: Scheduler::default(),
+            clock_mode: ClockMode::default(),
+            deadlock_timeout: Duration::from_millis(1000),
+            livelock_threshold: None,
+            steps_since_progress: 0,
+            lost_messages: BTreeMap::new(),
+            trace_steps: Vec::new(),
         }
     }
+    
+    /// Get the seed driving this engine's nondeterministic choices
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+    
+    /// Get the fault injector used during session execution
+    pub fn fault_injector(&self) -> &FaultInjector {
+        &self.fault_injector
+    }
+    
+    /// Get mutable access to the fault injector (e.g. to register faults)
+    pub fn fault_injector_mut(&mut self) -> &mut FaultInjector {
+        &mut self.fault_injector
+    }
+    
+    /// Use `policy` to order session participants from now on
+    pub fn set_scheduling_policy(&mut self, policy: SchedulingPolicy) {
+        self.scheduler = Scheduler::new(policy);
+    }
+    
+    /// Get the scheduler ordering session participants
+    pub fn scheduler(&self) -> &Scheduler {
+        &self.scheduler
+    }
+    
+    /// Choose how the simulated clock advances as steps execute
+    ///
+    /// With [`ClockMode::FixedStep`] timeouts and timeout-based deadlock
+    /// detection depend only on the number of executed steps, not on how fast
+    /// the host runs.
+    pub fn set_clock_mode(&mut self, mode: ClockMode) {
+        self.clock_mode = mode;
+    }
+    
+    /// Get the current clock mode
+    pub fn clock_mode(&self) -> ClockMode {
+        self.clock_mode
+    }
+    
+    /// Set how long a blocked participant may wait, in simulated time, before
+    /// it is reported as a timeout deadlock
+    pub fn set_deadlock_timeout(&mut self, timeout: Duration) {
+        self.deadlock_timeout = timeout;
+    }
+    
+    /// Report a livelock once more than `steps` session steps pass without any
+    /// participant reaching a session state it has not been in before
+    pub fn set_livelock_threshold(&mut self, steps: Option<u64>) {
+        self.livelock_threshold = steps;
+    }
+    
+    /// Participant order chosen for each session round so far
+    ///
+    /// Feeding this to [`SchedulingPolicy::Replay`] reproduces the interleaving.
+    pub fn recorded_schedule(&self) -> &[Vec<String>] {
+        self.scheduler.history()
+    }
 
     /// Initialize the engine
     pub async fn initialize(&mut self) -> Result<(), SimulationError> {
@@ -400,6 +596,71 @@ impl SimulationEngine {
         Ok(())
     }
     
+    /// Advance the engine by at most `n` steps and report where it stopped
+    ///
+    /// Intended for debuggers single-stepping through a session protocol.
+    /// Calling this once the engine has halted is not an error: it returns
+    /// [`StepOutcome::AlreadyHalted`] without touching the engine state.
+    pub async fn run_steps(&mut self, n: u64) -> Result<StepOutcome, SimulationError> {
+        if self.is_halted() {
+            return Ok(StepOutcome::AlreadyHalted {
+                state: self.state.clone(),
+            });
+        }
+        
+        self.set_state(SimulationState::Running);
+        let mut steps_executed = 0;
+        
+        while steps_executed < n {
+            if self.pc >= self.program.len() {
+                self.set_state(SimulationState::Completed);
+                return Ok(StepOutcome::Halted {
+                    steps_executed,
+                    state: self.state.clone(),
+                });
+            }
+            
+            if !self.session_participants.is_empty() {
+                let deadlock_report = self.detect_deadlocks_advanced();
+                if deadlock_report.has_deadlock {
+                    self.set_state(SimulationState::Paused);
+                    return Ok(StepOutcome::Deadlocked {
+                        steps_executed,
+                        deadlock_report,
+                        state: self.state.clone(),
+                    });
+                }
+            }
+            
+            let has_more = match self.step().await {
+                Ok(has_more) => has_more,
+                Err(e) => {
+                    self.set_state(SimulationState::Error(e.to_string()));
+                    return Err(e);
+                }
+            };
+            steps_executed += 1;
+            
+            if !has_more {
+                return Ok(StepOutcome::Halted {
+                    steps_executed,
+                    state: self.state.clone(),
+                });
+            }
+        }
+        
+        self.set_state(SimulationState::Paused);
+        Ok(StepOutcome::Running {
+            steps_executed,
+            state: self.state.clone(),
+        })
+    }
+    
+    /// Whether the engine has finished (successfully or with an error)
+    pub fn is_halted(&self) -> bool {
+        matches!(self.state, SimulationState::Completed | SimulationState::Error(_))
+    }
+    
     /// Execute a single step (enhanced for session operations)
     pub async fn step(&mut self) -> Result<bool, SimulationError> {
         if self.pc >= self.program.len() {
@@ -412,7 +673,7 @@ impl SimulationEngine {
         
         // Create execution step
         let mut step = ExecutionStep {
-            step_number: self.state_progression.steps.len(),
+            step_number: self.state_progression.executed_steps(),
             timestamp,
             instruction: None,
             resources_allocated: Vec::new(),
@@ -425,6 +686,11 @@ impl SimulationEngine {
             // Execute session operations for each participant
             let session_gas = self.execute_session_operations(&mut step).await?;
             step.gas_consumed = session_gas;
+            
+            let progressed = self.session_participants.values_mut()
+                .map(|participant| participant.record_position())
+                .fold(false, |any, new| any | new);
+            self.steps_since_progress = if progressed { 0 } else { self.steps_since_progress + 1 };
         } else {
             // Fallback to traditional instruction execution
             self.execute_instruction_traditional(instruction, &mut step)?;
@@ -434,6 +700,10 @@ impl SimulationEngine {
         self.state_progression.steps.push(step);
         self.pc += 1;
         
+        if let ClockMode::FixedStep(tick) = self.clock_mode {
+            self.clock.advance(tick);
+        }
+        
         // Check if program is completed after this step
         let program_completed = self.pc >= self.program.len();
         
@@ -451,22 +721,70 @@ impl SimulationEngine {
         let mut total_gas = 0;
         let timestamp = step.timestamp;
         
-        // Process each session participant's next operation
-        let participant_roles: Vec<String> = self.session_participants.keys().cloned().collect();
+        // Process each ready participant's next operation in scheduler order
+        let ready: Vec<String> = self.session_participants.iter()
+            .filter(|(_, participant)| !participant.next_operations.is_empty())
+            .map(|(role, _)| role.clone())
+            .collect();
+        let participant_roles = self.scheduler.next_round(&ready);
         
         for role in participant_roles {
-            // First, extract the operation to avoid borrowing conflicts
+            // First, extract the operation to avoid borrowing conflicts.
+            // When several operations are enabled (e.g. internal choice branches)
+            // the seeded RNG picks one so runs are reproducible from the seed.
             let operation = if let Some(participant) = self.session_participants.get_mut(&role) {
-                if !participant.next_operations.is_empty() {
-                    Some(participant.next_operations.remove(0))
-                } else {
-                    None
+                match participant.next_operations.len() {
+                    0 => None,
+                    1 => Some(participant.next_operations.remove(0)),
+                    n => Some(participant.next_operations.remove(self.rng.gen_range(0..n))),
                 }
             } else {
                 None
             };
             
             if let Some(operation) = operation {
+                let session_context = self.session_participants.get(&role)
+                    .and_then(|participant| participant.current_session.clone());
+                let fault = self.fault_injector.should_trigger_session_fault(
+                    &operation,
+                    &role,
+                    session_context.as_ref(),
+                    timestamp,
+                ).filter(|fault| !matches!(fault, SessionFaultResult::NoEffect));
+                if let Some(fault) = &fault {
+                    self.effects_log.push(format!("Fault injected for {}: {:?}", role, fault));
+                }
+                
+                let operation = match &fault {
+                    Some(SessionFaultResult::ChoiceManipulation { forced_choice: Some(label), .. }) => {
+                        self.force_choice(&role, operation, label)
+                    }
+                    _ => operation,
+                };
+                let failure = match &fault {
+                    Some(fault) => self.session_fault_failure(&role, fault)?,
+                    None => None,
+                }.or_else(|| self.take_lost_message(&role, &operation));
+                
+                if let Some(reason) = failure {
+                    // The operation does not take place: the participant stays at
+                    // the same protocol position and retries it on a later step
+                    self.effects_log.push(format!("Session operation failed: {} {:?} ({})", role, operation, reason));
+                    self.record_trace_step(&role, &operation, timestamp, Some(reason));
+                    if let Some(participant) = self.session_participants.get_mut(&role) {
+                        participant.effects.push(SessionEffect {
+                            operation: operation.clone(),
+                            timestamp,
+                            gas_consumed: 0,
+                            success: false,
+                            result: None,
+                        });
+                        participant.compute_next_operations();
+                    }
+                    step.instruction = Some(format!("session_operation_{:?}", operation));
+                    continue;
+                }
+                
                 // Execute the session operation without borrowing self.session_participants
                 let operation_result = self.execute_single_session_operation_standalone(&operation, &role, timestamp).await?;
                 
@@ -487,6 +805,8 @@ impl SimulationEngine {
                     participant.compute_next_operations();
                 }
                 
+                self.record_trace_step(&role, &operation, timestamp, None);
+                
                 // Track gas consumption
                 total_gas += operation_result.gas_consumed;
                 
@@ -499,6 +819,133 @@ impl SimulationEngine {
         Ok(total_gas)
     }
     
+    /// Decide what an injected session fault does to the operation it hit
+    ///
+    /// Returns the reason the operation fails, or `None` when it still runs.
+    /// A protocol violation that does not allow execution to continue halts
+    /// the run with an error.
+    fn session_fault_failure(&mut self, role: &str, fault: &SessionFaultResult) -> Result<Option<String>, SimulationError> {
+        let reason = match fault {
+            SessionFaultResult::NoEffect
+            | SessionFaultResult::DuplicateMessage { .. }
+            | SessionFaultResult::ChoiceManipulation { .. } => return Ok(None),
+            SessionFaultResult::MessageLoss { lost_operation, preserve_duality } => {
+                // Unless duality is preserved, the receivers miss the message too
+                match lost_operation {
+                    SessionOperation::Send { target_participant, .. } if !preserve_duality => {
+                        for receiver in self.peers_of(role, target_participant) {
+                            self.lost_messages.entry(receiver).or_default().push(role.to_string());
+                        }
+                    }
+                    _ => {}
+                }
+                "message lost"
+            }
+            SessionFaultResult::MessageReordering { .. } => "message delayed",
+            SessionFaultResult::ProtocolViolation { affected_operation, continue_execution, .. } => {
+                if !continue_execution {
+                    return Err(SimulationError::SessionProtocolViolation {
+                        participant: role.to_string(),
+                        operation: format!("{:?}", affected_operation),
+                        expected: "operation without an injected protocol violation".to_string(),
+                    });
+                }
+                "protocol violation injected"
+            }
+            SessionFaultResult::TypeConfusion { .. } => "message type confused",
+            SessionFaultResult::PartialFailure { .. } => "participant failed",
+        };
+        Ok(Some(reason.to_string()))
+    }
+    
+    /// Replace an internal choice with the branch a fault forces, if `role` has it enabled
+    fn force_choice(&mut self, role: &str, operation: SessionOperation, label: &str) -> SessionOperation {
+        if !matches!(&operation, SessionOperation::InternalChoice { chosen_branch, .. } if chosen_branch != label) {
+            return operation;
+        }
+        let Some(participant) = self.session_participants.get_mut(role) else {
+            return operation;
+        };
+        let forced = participant.next_operations.iter().position(|candidate| {
+            matches!(candidate, SessionOperation::InternalChoice { chosen_branch, .. } if chosen_branch == label)
+        });
+        match forced {
+            Some(index) => {
+                let forced = participant.next_operations.remove(index);
+                participant.next_operations.push(operation);
+                forced
+            }
+            None => operation,
+        }
+    }
+    
+    /// Participants a message from `role` addressed to `named` reaches
+    ///
+    /// Operations computed from a bare session type name their peer "other",
+    /// which stands for every other participant.
+    fn peers_of(&self, role: &str, named: &str) -> Vec<String> {
+        if self.session_participants.contains_key(named) {
+            vec![named.to_string()]
+        } else {
+            self.session_participants.keys()
+                .filter(|peer| peer.as_str() != role)
+                .cloned()
+                .collect()
+        }
+    }
+    
+    /// Fail a receive whose message was dropped by an earlier fault
+    fn take_lost_message(&mut self, role: &str, operation: &SessionOperation) -> Option<String> {
+        let SessionOperation::Receive { source_participant, .. } = operation else {
+            return None;
+        };
+        let named_source = self.session_participants.contains_key(source_participant);
+        let senders = self.lost_messages.get_mut(role)?;
+        let index = senders.iter().position(|sender| !named_source || sender == source_participant)?;
+        let sender = senders.remove(index);
+        Some(format!("message from {} lost", sender))
+    }
+    
+    /// Append a session operation attempt to the execution trace
+    fn record_trace_step(&mut self, role: &str, operation: &SessionOperation, timestamp: SimulatedTimestamp, error: Option<String>) {
+        let description = format!("{}: {:?}", role, operation);
+        let time = Timestamp::from_millis(timestamp.as_millis());
+        self.trace_steps.push(EffectStep {
+            effect_id: EntityId::from_content(&description.as_bytes().to_vec()),
+            start_time: time,
+            end_time: Some(time),
+            status: if error.is_some() { StepStatus::Failed } else { StepStatus::Completed },
+            inputs: description.into_bytes(),
+            outputs: None,
+            error,
+        });
+    }
+    
+    /// Build the execution trace of the session operations attempted so far
+    ///
+    /// Times are simulated and the trace id is derived from the seed, so two
+    /// runs of the same program with the same seed produce identical traces.
+    pub fn execution_trace(&self) -> ExecutionTrace {
+        let start_time = self.trace_steps.first()
+            .map_or(Timestamp::from_millis(0), |step| step.start_time);
+        let (status, error) = match &self.state {
+            SimulationState::Completed => (ExecutionStatus::Completed, None),
+            SimulationState::Error(message) => (ExecutionStatus::Failed, Some(message.clone())),
+            _ => (ExecutionStatus::Running, None),
+        };
+        
+        ExecutionTrace {
+            id: EntityId::from_content(&self.seed),
+            start_time,
+            end_time: self.is_halted().then(|| Timestamp::from_millis(self.clock.now().as_millis())),
+            effects: self.trace_steps.clone(),
+            resources_consumed: Vec::new(),
+            resources_created: Vec::new(),
+            status,
+            error,
+        }
+    }
+    
     /// Execute a single session operation standalone (without borrowing session_participants)
     async fn execute_single_session_operation_standalone(
         &mut self, 
@@ -664,6 +1111,11 @@ impl SimulationEngine {
         self.effect_results.clear();
         self.branch_manager.clear();
         self.current_branch = None;
+        self.rng = ChaCha12Rng::seed_from_u64(self.seed);
+        self.scheduler.reset();
+        self.steps_since_progress = 0;
+        self.lost_messages.clear();
+        self.trace_steps.clear();
         Ok(())
     }
     
@@ -672,6 +1124,11 @@ impl SimulationEngine {
         &self.state_progression
     }
     
+    /// Get mutable state progression (for log pruning)
+    pub fn state_progression_mut(&mut self) -> &mut StateProgression {
+        &mut self.state_progression
+    }
+    
     /// Get metrics
     pub fn metrics(&self) -> &ExecutionMetrics {
         &self.metrics
@@ -682,6 +1139,16 @@ impl SimulationEngine {
         &self.clock
     }
     
+    /// Clone the engine with its own simulated clock
+    ///
+    /// A plain clone shares the clock with the original, so stepping the clone
+    /// in fixed-step mode would move the original's time as well.
+    pub fn fork(&self) -> Self {
+        let mut forked = self.clone();
+        forked.clock = self.clock.fork();
+        forked
+    }
+    
     /// Get the program counter (for serialization)
     pub fn program_counter(&self) -> usize {
         self.pc
@@ -709,7 +1176,7 @@ impl SimulationEngine {
         }
         
         // Simplified snapshot creation - just return a generated ID
-        Ok(SnapshotId::new(format!("snapshot_{}", self.state_progression.steps.len())))
+        Ok(SnapshotId::new(format!("snapshot_{}", self.state_progression.executed_steps())))
     }
     
     /// Restore state from a snapshot
@@ -729,6 +1196,50 @@ impl SimulationEngine {
         &self.execution_state
     }
     
+    /// Get a mutable reference to the execution state (for replay)
+    pub fn execution_state_mut(&mut self) -> &mut ExecutionState {
+        &mut self.execution_state
+    }
+    
+    /// Machine-level view of the engine's current state
+    ///
+    /// The Lamport clock is the number of steps executed so far, so two runs
+    /// of the same program and seed produce equal snapshots at the same step.
+    pub fn machine_state(&self) -> MachineStateSnapshot {
+        MachineStateSnapshot {
+            registers: self.execution_state.registers.iter()
+                .map(|(id, value)| (RegisterId::new(*id), machine_value(value)))
+                .collect(),
+            resources: BTreeMap::new(),
+            instruction_pointer: self.pc,
+            lamport_clock: self.state_progression.executed_steps() as u64,
+        }
+    }
+    
+    /// Session-driven state a replay needs to make the same choices again
+    pub fn session_replay_state(&self) -> SessionReplayState {
+        SessionReplayState {
+            participants: self.session_participants.clone(),
+            rng: self.rng.clone(),
+            scheduler: self.scheduler.clone(),
+            fault_injector: self.fault_injector.clone(),
+            steps_since_progress: self.steps_since_progress,
+            lost_messages: self.lost_messages.clone(),
+            trace_steps: self.trace_steps.clone(),
+        }
+    }
+    
+    /// Put the session-driven state back to a recorded point
+    pub fn restore_session_replay_state(&mut self, state: SessionReplayState) {
+        self.session_participants = state.participants;
+        self.rng = state.rng;
+        self.scheduler = state.scheduler;
+        self.fault_injector = state.fault_injector;
+        self.steps_since_progress = state.steps_since_progress;
+        self.lost_messages = state.lost_messages;
+        self.trace_steps = state.trace_steps;
+    }
+    
     /// Create a new execution branch for scenario exploration
     pub async fn create_branch(&mut self, branch_name: &str) -> Result<String, SimulationError> {
         let branch_id = "deterministic_uuid".to_string();
@@ -858,9 +1369,53 @@ impl SimulationEngine {
         let deadlock_report = self.test_for_deadlocks(timestamp);
         report.set_deadlock_report(deadlock_report);
         
+        // Test for livelock when a progress threshold is configured
+        if let Some(livelock_report) = self.detect_livelock(timestamp) {
+            report.set_livelock_report(livelock_report);
+        }
+        
         report
     }
     
+    /// Check whether the session has stopped making progress
+    ///
+    /// Returns `None` when no livelock threshold is configured. Participants
+    /// that are still active but have not completed their session are named
+    /// as the ones cycling.
+    pub fn detect_livelock(&self, timestamp: SimulatedTimestamp) -> Option<LivelockReport> {
+        let threshold = self.livelock_threshold?;
+        let is_livelock = self.steps_since_progress > threshold;
+        
+        let cycling_participants: Vec<String> = if is_livelock {
+            self.session_participants.iter()
+                .filter(|(_, participant)| !participant.is_session_complete() && !participant.protocol_history.is_empty())
+                .map(|(role, _)| role.clone())
+                .collect()
+        } else {
+            Vec::new()
+        };
+        
+        let livelock_violation = is_livelock.then(|| ProtocolViolation {
+            violation_type: ViolationType::Livelock,
+            expected_operation: None,
+            actual_operation: None,
+            timestamp,
+            message: format!(
+                "No session progress in {} steps among participants: {}",
+                self.steps_since_progress,
+                cycling_participants.join(", ")
+            ),
+        });
+        
+        Some(LivelockReport {
+            is_livelock,
+            steps_without_progress: self.steps_since_progress,
+            threshold,
+            cycling_participants,
+            livelock_violation,
+        })
+    }
+    
     /// Test compliance for a single participant
     fn test_participant_compliance(&self, role: &str, participant: &SessionParticipantState, timestamp: SimulatedTimestamp) -> ParticipantComplianceReport {
         let mut violations = Vec::new();
@@ -871,10 +1426,8 @@ impl SimulationEngine {
         }
         
         // Validate operation sequence against session type
-        if let Some(ref session_type) = participant.current_session {
-            let sequence_violations = self.validate_operation_sequence(&participant.protocol_history, session_type, role, timestamp);
-            violations.extend(sequence_violations);
-        }
+        let sequence_violations = self.validate_operation_sequence(&participant.protocol_history, role, timestamp);
+        violations.extend(sequence_violations);
         
         // Check for type mismatches in communication
         let type_violations = self.check_communication_type_consistency(&participant.protocol_history, role, timestamp);
@@ -884,9 +1437,25 @@ impl SimulationEngine {
         let premature_end_violations = self.check_premature_session_ending(participant, role, timestamp);
         violations.extend(premature_end_violations);
         
+        // Prefer the divergence recorded when the operation was attempted; it
+        // carries the exact session position. Otherwise point at the first
+        // violation found by the checks above.
+        let first_divergence = participant.compliance_state.first_divergence.clone()
+            .or_else(|| violations.first().map(|violation| ProtocolDivergence {
+                step: participant.compliance_state.protocol_step,
+                expected: violation.expected_operation.iter().cloned().collect(),
+                actual: violation.actual_operation.clone(),
+                session_position: participant.current_session.clone(),
+                severity: violation.severity(),
+                message: violation.message.clone(),
+            }));
+        let severity = violations.iter().map(ProtocolViolation::severity).max();
+        
         ParticipantComplianceReport {
             role: role.to_string(),
             is_compliant: violations.is_empty(),
+            first_divergence,
+            severity,
             violations,
             protocol_step: participant.compliance_state.protocol_step,
             session_complete: participant.compliance_state.is_complete,
@@ -895,36 +1464,20 @@ impl SimulationEngine {
     }
     
     /// Validate operation sequence against session type
-    fn validate_operation_sequence(&self, history: &[SessionOperation], session_type: &SessionType, role: &str, timestamp: SimulatedTimestamp) -> Vec<ProtocolViolation> {
+    ///
+    /// Each operation was already checked against the session type when it was
+    /// executed, so here we only look for activity after the session ended.
+    fn validate_operation_sequence(&self, history: &[SessionOperation], role: &str, timestamp: SimulatedTimestamp) -> Vec<ProtocolViolation> {
         let mut violations = Vec::new();
         
-        // Simplified session type validation - in real implementation would use session type checker
-        for (i, operation) in history.iter().enumerate() {
-            // Check if operation is valid for current session type state
-            let is_valid_operation = match (session_type, operation) {
-                // Send operations should match output types in session type
-                (_, SessionOperation::Send { value_type, .. }) => {
-                    // For now, assume all Send operations are valid if they have proper type
-                    !matches!(value_type, TypeInner::Base(_))
-                }
-                // Receive operations should match input types in session type  
-                (_, SessionOperation::Receive { value_type, .. }) => {
-                    // For now, assume all Receive operations are valid if they have proper type
-                    !matches!(value_type, TypeInner::Base(_))
-                }
-                // Choice operations should be valid if session type supports choices
-                (_, SessionOperation::InternalChoice { .. }) | (_, SessionOperation::ExternalChoice { .. }) => true,
-                // End should only occur when session type allows termination
-                (_, SessionOperation::End) => true,
-            };
-            
-            if !is_valid_operation {
+        if let Some(end_index) = history.iter().position(|op| matches!(op, SessionOperation::End)) {
+            for (i, operation) in history.iter().enumerate().skip(end_index + 1) {
                 violations.push(ProtocolViolation {
                     violation_type: ViolationType::UnexpectedOperation,
-                    expected_operation: None, // Would compute expected operation from session type
+                    expected_operation: None,
                     actual_operation: Some(operation.clone()),
                     timestamp,
-                    message: format!("Invalid operation at step {} for participant {}: {:?}", i, role, operation),
+                    message: format!("Operation at step {} for participant {} occurs after session end: {:?}", i, role, operation),
                 });
             }
         }
@@ -1334,7 +1887,7 @@ impl SimulationEngine {
     fn detect_timeout_deadlocks(&self) -> Vec<TimeoutDeadlock> {
         let mut timeout_deadlocks = Vec::new();
         let current_time = self.clock.now();
-        let timeout_threshold = 1000; // milliseconds - configurable timeout
+        let timeout_threshold = self.deadlock_timeout.as_millis() as u64;
         
         for (role, participant) in &self.session_participants {
             // Check if participant has been stuck on the same operations for too long
@@ -1348,8 +1901,8 @@ impl SimulationEngine {
                 if is_stuck && !participant.effects.is_empty() {
                     // Check if the last effect was too long ago
                     if let Some(last_effect) = participant.effects.last() {
-                        let time_since_last_effect = current_time.as_secs() - last_effect.timestamp.as_secs();
-                        if time_since_last_effect > timeout_threshold {
+                        let time_since_last_effect = current_time.duration_since(last_effect.timestamp).as_millis() as u64;
+                        if time_since_last_effect >= timeout_threshold {
                             timeout_deadlocks.push(TimeoutDeadlock {
                                 participant: role.clone(),
                                 stuck_operations: participant.next_operations.clone(),
@@ -1426,6 +1979,9 @@ impl SimulationEngine {
     }
     
     /// Execute with timeout to prevent infinite waiting
+    ///
+    /// The timeout is measured in simulated milliseconds, so it is only
+    /// reached when the clock is advanced (see [`ClockMode::FixedStep`]).
     pub async fn run_with_timeout(&mut self, timeout_ms: u64) -> Result<TimeoutExecutionResult, SimulationError> {
         let start_time = self.clock.now();
         let timeout_threshold = timeout_ms;
@@ -1438,8 +1994,8 @@ impl SimulationEngine {
         while self.pc < self.program.len() {
             // Check for timeout
             let current_time = self.clock.now();
-            let elapsed = current_time.as_secs() - start_time.as_secs();
-            if elapsed > timeout_threshold {
+            let elapsed = current_time.duration_since(start_time).as_millis() as u64;
+            if elapsed >= timeout_threshold {
                 return Ok(TimeoutExecutionResult::Timeout {
                     steps_executed,
                     elapsed_time: elapsed,
@@ -1467,6 +2023,16 @@ impl SimulationEngine {
             
             steps_executed += 1;
             
+            if let Some(livelock_report) = self.detect_livelock(self.clock.now()) {
+                if livelock_report.is_livelock {
+                    return Ok(TimeoutExecutionResult::Livelock {
+                        steps_executed,
+                        livelock_report,
+                        final_state: self.state.clone(),
+                    });
+                }
+            }
+            
             // Progress safety check
             if steps_executed > 10000 {
                 return Ok(TimeoutExecutionResult::MaxStepsReached {
@@ -1479,12 +2045,40 @@ impl SimulationEngine {
         self.set_state(SimulationState::Completed);
         Ok(TimeoutExecutionResult::Success {
             steps_executed,
-            execution_time: self.clock.now().as_secs() - start_time.as_secs(),
+            execution_time: self.clock.now().duration_since(start_time).as_millis() as u64,
             deadlock_checks,
         })
     }
 }
 
+/// Convert a runtime value into its register machine representation
+fn machine_value(value: &Value) -> MachineValue {
+    match value {
+        Value::Unit => MachineValue::Unit,
+        Value::Bool(b) => MachineValue::Bool(*b),
+        Value::Int(i) => MachineValue::Int(*i),
+        Value::Symbol(s) | Value::String(s) => MachineValue::Symbol(Symbol::new(s.as_str())),
+        Value::Product(left, right) => MachineValue::Product(
+            Box::new(machine_value(left)),
+            Box::new(machine_value(right)),
+        ),
+        Value::Sum { tag, value } => MachineValue::Sum {
+            tag: Symbol::new(&tag.to_string()),
+            value: Box::new(machine_value(value)),
+        },
+        // Records become a right-nested product of tagged fields in key order
+        Value::Record { fields } => fields.iter().rev().fold(MachineValue::Unit, |rest, (name, field)| {
+            MachineValue::Product(
+                Box::new(MachineValue::Sum {
+                    tag: Symbol::new(name),
+                    value: Box::new(machine_value(field)),
+                }),
+                Box::new(rest),
+            )
+        }),
+    }
+}
+
 impl Clone for SimulationEngine {
     fn clone(&self) -> Self {
         Self {
@@ -1503,6 +2097,16 @@ impl Clone for SimulationEngine {
             effect_results: self.effect_results.clone(),
             branch_manager: self.branch_manager.clone(),
             current_branch: self.current_branch.clone(),
+            seed: self.seed,
+            rng: self.rng.clone(),
+            fault_injector: self.fault_injector.clone(),
+            scheduler: self.scheduler.clone(),
+            clock_mode: self.clock_mode,
+            deadlock_timeout: self.deadlock_timeout,
+            livelock_threshold: self.livelock_threshold,
+            steps_since_progress: self.steps_since_progress,
+            lost_messages: self.lost_messages.clone(),
+            trace_steps: self.trace_steps.clone(),
         }
     }
 }
@@ -1531,6 +2135,18 @@ impl SessionParticipantState {
         self.current_session = Some(session_type.clone());
         self.compute_next_operations();
         self.compliance_state.is_valid = true;
+        self.record_position();
+    }
+    
+    /// Record the current session position, returning whether it is new
+    ///
+    /// Recursive types are unfolded before being recorded, so a participant
+    /// looping through a recursive protocol revisits the same positions.
+    pub fn record_position(&mut self) -> bool {
+        match &self.current_session {
+            Some(session) => self.compliance_state.visited_positions.insert(session.clone()),
+            None => false,
+        }
     }
     
     /// Compute next valid operations from current session type
@@ -1609,6 +2225,20 @@ impl SessionParticipantState {
                 message: "Operation not allowed by current session type".to_string(),
             };
             
+            if self.compliance_state.first_divergence.is_none() {
+                self.compliance_state.first_divergence = Some(ProtocolDivergence {
+                    step: self.compliance_state.protocol_step,
+                    expected: self.next_operations.clone(),
+                    actual: Some(operation.clone()),
+                    session_position: self.current_session.clone(),
+                    severity: violation.severity(),
+                    message: format!(
+                        "Expected one of {:?} at step {} but got {:?}",
+                        self.next_operations, self.compliance_state.protocol_step, operation
+                    ),
+                });
+            }
+            
             self.compliance_state.violations.push(violation);
             self.compliance_state.is_valid = false;
             
@@ -1745,6 +2375,9 @@ pub struct ProtocolComplianceReport {
     /// Deadlock detection report
     pub deadlock_report: Option<DeadlockReport>,
     
+    /// Livelock detection report, when a progress threshold is configured
+    pub livelock_report: Option<LivelockReport>,
+    
     /// Timestamp when the compliance test was performed
     pub test_timestamp: SimulatedTimestamp,
 }
@@ -1761,6 +2394,12 @@ pub struct ParticipantComplianceReport {
     /// Any compliance violations found
     pub violations: Vec<ProtocolViolation>,
     
+    /// First divergence from the session type, if any
+    pub first_divergence: Option<ProtocolDivergence>,
+    
+    /// Highest severity among the violations, if any
+    pub severity: Option<ViolationSeverity>,
+    
     /// Current step in the protocol
     pub protocol_step: usize,
     
@@ -1787,6 +2426,25 @@ pub struct DeadlockReport {
     pub deadlock_violation: Option<ProtocolViolation>,
 }
 
+/// Livelock detection report
+#[derive(Debug, Clone)]
+pub struct LivelockReport {
+    /// Whether a livelock was detected
+    pub is_livelock: bool,
+    
+    /// Consecutive steps in which no participant reached a new session state
+    pub steps_without_progress: u64,
+    
+    /// Steps without progress tolerated before reporting a livelock
+    pub threshold: u64,
+    
+    /// Participants still exchanging messages without completing
+    pub cycling_participants: Vec<String>,
+    
+    /// Livelock violation if detected
+    pub livelock_violation: Option<ProtocolViolation>,
+}
+
 /// Waiting relationship between participants
 #[derive(Debug, Clone)]
 pub struct WaitingRelation {
@@ -1814,6 +2472,7 @@ impl ProtocolComplianceReport {
             participant_reports: BTreeMap::new(),
             global_violations: Vec::new(),
             deadlock_report: None,
+            livelock_report: None,
             test_timestamp: SimulatedTimestamp::new(0),
         }
     }
@@ -1842,6 +2501,14 @@ impl ProtocolComplianceReport {
         self.deadlock_report = Some(report);
     }
     
+    /// Set livelock report
+    pub fn set_livelock_report(&mut self, report: LivelockReport) {
+        if report.is_livelock {
+            self.is_fully_compliant = false;
+        }
+        self.livelock_report = Some(report);
+    }
+    
     /// Get total number of violations
     pub fn total_violations(&self) -> usize {
         let participant_violations: usize = self.participant_reports.values()
@@ -1852,7 +2519,17 @@ impl ProtocolComplianceReport {
             if deadlock_report.deadlock_violation.is_some() { 1 } else { 0 }
         } else { 0 };
         
-        participant_violations + self.global_violations.len() + deadlock_violations
+        let livelock_violations = self.livelock_report.as_ref()
+            .map_or(0, |report| usize::from(report.livelock_violation.is_some()));
+        
+        participant_violations + self.global_violations.len() + deadlock_violations + livelock_violations
+    }
+    
+    /// First divergence of every non-compliant participant, keyed by role
+    pub fn divergences(&self) -> BTreeMap<&str, &ProtocolDivergence> {
+        self.participant_reports.iter()
+            .filter_map(|(role, report)| report.first_divergence.as_ref().map(|d| (role.as_str(), d)))
+            .collect()
     }
 }
 
@@ -2015,6 +2692,7 @@ pub enum TimeoutExecutionResult {
     /// Execution timed out
     Timeout {
         steps_executed: usize,
+        /// Simulated milliseconds elapsed when the timeout fired
         elapsed_time: u64,
         final_state: SimulationState,
     },
@@ -2026,6 +2704,13 @@ pub enum TimeoutExecutionResult {
         final_state: SimulationState,
     },
     
+    /// Execution continued without any session progress
+    Livelock {
+        steps_executed: usize,
+        livelock_report: LivelockReport,
+        final_state: SimulationState,
+    },
+    
     /// Maximum steps reached (safety limit)
     MaxStepsReached {
         steps_executed: usize,
@@ -2033,6 +2718,56 @@ pub enum TimeoutExecutionResult {
     },
 }
 
+/// Result of advancing the engine with [`SimulationEngine::run_steps`]
+#[derive(Debug, Clone)]
+pub enum StepOutcome {
+    /// The program ran to completion within the step budget
+    Halted {
+        steps_executed: u64,
+        state: SimulationState,
+    },
+    
+    /// The step budget was exhausted with instructions remaining
+    Running {
+        steps_executed: u64,
+        state: SimulationState,
+    },
+    
+    /// A deadlock among session participants stopped execution
+    Deadlocked {
+        steps_executed: u64,
+        deadlock_report: AdvancedDeadlockReport,
+        state: SimulationState,
+    },
+    
+    /// The engine had already halted before this call
+    AlreadyHalted {
+        state: SimulationState,
+    },
+}
+
+impl StepOutcome {
+    /// Number of steps executed by the call that produced this outcome
+    pub fn steps_executed(&self) -> u64 {
+        match self {
+            StepOutcome::Halted { steps_executed, .. }
+            | StepOutcome::Running { steps_executed, .. }
+            | StepOutcome::Deadlocked { steps_executed, .. } => *steps_executed,
+            StepOutcome::AlreadyHalted { .. } => 0,
+        }
+    }
+    
+    /// Engine state at the point execution stopped
+    pub fn state(&self) -> &SimulationState {
+        match self {
+            StepOutcome::Halted { state, .. }
+            | StepOutcome::Running { state, .. }
+            | StepOutcome::Deadlocked { state, .. }
+            | StepOutcome::AlreadyHalted { state } => state,
+        }
+    }
+}
+
 impl Default for WaitingGraph {
     fn default() -> Self {
         Self::new()
@@ -2121,6 +2856,251 @@ mod tests {
         assert_eq!(engine.state(), &SimulationState::Completed);
     }
     
+    #[tokio::test]
+    async fn test_run_steps_budget() {
+        let mut engine = SimulationEngine::with_seed(7);
+        let program = (0..3)
+            .map(|i| Instruction::Transform { morph_reg: RegisterId::new(i), input_reg: RegisterId::new(i), output_reg: RegisterId::new(i) })
+            .collect();
+        engine.load_program(program).unwrap();
+        
+        let outcome = engine.run_steps(1).await.unwrap();
+        assert!(matches!(outcome, StepOutcome::Running { steps_executed: 1, .. }));
+        assert_eq!(outcome.state(), &SimulationState::Paused);
+        assert_eq!(engine.state_progression().steps.len(), 1);
+        
+        let outcome = engine.run_steps(10).await.unwrap();
+        assert!(matches!(outcome, StepOutcome::Halted { steps_executed: 2, .. }));
+        assert_eq!(outcome.state(), &SimulationState::Completed);
+        assert!(engine.is_halted());
+    }
+    
+    #[tokio::test]
+    async fn test_run_steps_after_halt_is_idempotent() {
+        let mut engine = SimulationEngine::with_seed(7);
+        engine.load_program(vec![
+            Instruction::Transform { morph_reg: RegisterId::new(0), input_reg: RegisterId::new(0), output_reg: RegisterId::new(0) },
+        ]).unwrap();
+        engine.run_steps(5).await.unwrap();
+        let steps_before = engine.state_progression().steps.len();
+        
+        for _ in 0..2 {
+            let outcome = engine.run_steps(5).await.unwrap();
+            assert!(matches!(outcome, StepOutcome::AlreadyHalted { .. }));
+            assert_eq!(outcome.steps_executed(), 0);
+            assert_eq!(outcome.state(), &SimulationState::Completed);
+        }
+        assert_eq!(engine.state_progression().steps.len(), steps_before);
+    }
+    
+    fn int_type() -> Box<TypeInner> {
+        Box::new(TypeInner::Base(causality_core::lambda::base::BaseType::Int))
+    }
+    
+    #[tokio::test]
+    async fn test_compliant_protocol_has_empty_report() {
+        let mut engine = SimulationEngine::with_seed(1);
+        engine.session_participants.insert(
+            "alice".to_string(),
+            SessionParticipantState::with_session_type(SessionType::Send(int_type(), Box::new(SessionType::End))),
+        );
+        engine.session_participants.insert(
+            "bob".to_string(),
+            SessionParticipantState::with_session_type(SessionType::Receive(int_type(), Box::new(SessionType::End))),
+        );
+        let program = (0..2)
+            .map(|i| Instruction::Transform { morph_reg: RegisterId::new(i), input_reg: RegisterId::new(i), output_reg: RegisterId::new(i) })
+            .collect();
+        engine.load_program(program).unwrap();
+        engine.run().await.unwrap();
+        
+        let report = engine.test_protocol_compliance();
+        assert!(report.is_fully_compliant);
+        assert_eq!(report.total_violations(), 0);
+        assert!(report.divergences().is_empty());
+        assert!(report.participant_reports.values().all(|r| r.severity.is_none()));
+    }
+    
+    #[tokio::test]
+    async fn test_targeted_fault_is_confined_to_participant() {
+        use crate::fault_injection::{FaultType, SessionFaultConfig, SessionOperationType};
+
+        let mut engine = SimulationEngine::with_seed(1);
+        engine.fault_injector_mut().add_targeted_fault("alice", SessionFaultConfig {
+            fault_type: FaultType::SessionMessageLoss { probability: 1.0, preserve_duality: false },
+            target_participants: vec!["alice".to_string()],
+            target_operations: vec![SessionOperationType::Send],
+            probability: 1.0,
+            session_context: None,
+            preserve_protocol_safety: false,
+        }).unwrap();
+
+        // Alice sends then receives; bob is her dual
+        engine.session_participants.insert(
+            "alice".to_string(),
+            SessionParticipantState::with_session_type(SessionType::Send(
+                int_type(),
+                Box::new(SessionType::Receive(int_type(), Box::new(SessionType::End))),
+            )),
+        );
+        engine.session_participants.insert(
+            "bob".to_string(),
+            SessionParticipantState::with_session_type(SessionType::Receive(
+                int_type(),
+                Box::new(SessionType::Send(int_type(), Box::new(SessionType::End))),
+            )),
+        );
+        let program = (0..3)
+            .map(|i| Instruction::Transform { morph_reg: RegisterId::new(i), input_reg: RegisterId::new(i), output_reg: RegisterId::new(i) })
+            .collect();
+        engine.load_program(program).unwrap();
+        engine.run().await.unwrap();
+
+        let stats = engine.fault_injector().get_session_statistics();
+        assert_eq!(stats.faults_by_participant.get("alice"), Some(&3));
+        assert_eq!(stats.faults_by_participant.get("bob"), None);
+
+        // Every send alice attempted was dropped, so she never got past it
+        let alice = &engine.session_participants["alice"];
+        assert_eq!(alice.effects.len(), 3);
+        assert!(alice.effects.iter().all(|effect| !effect.success && matches!(effect.operation, SessionOperation::Send { .. })));
+        assert!(alice.protocol_history.is_empty());
+        assert!(matches!(alice.current_session, Some(SessionType::Send(..))));
+
+        // Bob was not faulted, but each of his receives found alice's message missing
+        let bob = &engine.session_participants["bob"];
+        assert_eq!(bob.effects.len(), 3);
+        assert!(bob.effects.iter().all(|effect| !effect.success && matches!(effect.operation, SessionOperation::Receive { .. })));
+        assert!(bob.protocol_history.is_empty());
+        assert!(engine.effects_log().iter().any(|entry| entry.starts_with("Session operation failed: bob") && entry.ends_with("(message from alice lost)")));
+
+        let trace = engine.execution_trace();
+        assert_eq!(trace.effects.len(), 6);
+        assert!(trace.effects.iter().all(|step| step.status == causality_core::effect::StepStatus::Failed));
+    }
+
+    #[tokio::test]
+    async fn test_compliance_report_pinpoints_divergence() {
+        let mut engine = SimulationEngine::with_seed(1);
+        let bob_session = SessionType::Receive(int_type(), Box::new(SessionType::End));
+        let mut bob = SessionParticipantState::with_session_type(bob_session.clone());
+        
+        // Bob sends where his session type says he must receive
+        let wrong = SessionOperation::Send {
+            value_type: *int_type(),
+            target_participant: "alice".to_string(),
+            value: None,
+        };
+        assert!(bob.execute_operation(wrong.clone(), SimulatedTimestamp::new(0)).is_err());
+        engine.session_participants.insert("bob".to_string(), bob);
+        
+        let report = engine.test_protocol_compliance();
+        assert!(!report.is_fully_compliant);
+        
+        let bob_report = &report.participant_reports["bob"];
+        assert_eq!(bob_report.severity, Some(ViolationSeverity::Error));
+        
+        let divergence = bob_report.first_divergence.as_ref().unwrap();
+        assert_eq!(divergence.step, 0);
+        assert_eq!(divergence.actual, Some(wrong));
+        assert!(matches!(divergence.expected.as_slice(), [SessionOperation::Receive { .. }]));
+        assert_eq!(divergence.session_position, Some(bob_session));
+        assert_eq!(report.divergences().len(), 1);
+    }
+    
+    fn looping_session(receive: bool) -> SessionType {
+        let body = if receive {
+            SessionType::Receive(int_type(), Box::new(SessionType::Variable("X".to_string())))
+        } else {
+            SessionType::Send(int_type(), Box::new(SessionType::Variable("X".to_string())))
+        };
+        SessionType::Recursive("X".to_string(), Box::new(body))
+    }
+    
+    #[tokio::test]
+    async fn test_fixed_step_clock_times_out_after_simulated_duration() {
+        let mut engine = SimulationEngine::with_seed(3);
+        engine.set_clock_mode(ClockMode::FixedStep(Duration::from_millis(100)));
+        engine.session_participants.insert(
+            "alice".to_string(),
+            SessionParticipantState::with_session_type(looping_session(false)),
+        );
+        let program = (0..100)
+            .map(|i| Instruction::Transform { morph_reg: RegisterId::new(i), input_reg: RegisterId::new(i), output_reg: RegisterId::new(i) })
+            .collect();
+        engine.load_program(program).unwrap();
+        
+        let result = engine.run_with_timeout(1000).await.unwrap();
+        assert!(matches!(
+            result,
+            TimeoutExecutionResult::Timeout { steps_executed: 10, elapsed_time: 1000, .. }
+        ));
+        assert_eq!(engine.clock().now().as_millis(), 1000);
+    }
+    
+    #[tokio::test]
+    async fn test_ping_pong_livelock_is_reported_before_timeout() {
+        let ping_pong = |send_first: bool| {
+            let var = Box::new(SessionType::Variable("X".to_string()));
+            let body = if send_first {
+                SessionType::Send(int_type(), Box::new(SessionType::Receive(int_type(), var)))
+            } else {
+                SessionType::Receive(int_type(), Box::new(SessionType::Send(int_type(), var)))
+            };
+            SessionType::Recursive("X".to_string(), Box::new(body))
+        };
+        
+        let mut engine = SimulationEngine::with_seed(5);
+        engine.set_clock_mode(ClockMode::FixedStep(Duration::from_millis(100)));
+        engine.set_livelock_threshold(Some(20));
+        engine.session_participants.insert("alice".to_string(), SessionParticipantState::with_session_type(ping_pong(true)));
+        engine.session_participants.insert("bob".to_string(), SessionParticipantState::with_session_type(ping_pong(false)));
+        let program = (0..100)
+            .map(|i| Instruction::Transform { morph_reg: RegisterId::new(i), input_reg: RegisterId::new(i), output_reg: RegisterId::new(i) })
+            .collect();
+        engine.load_program(program).unwrap();
+        
+        // The timeout would fire after 50 steps; the livelock is caught first
+        let result = engine.run_with_timeout(5000).await.unwrap();
+        let TimeoutExecutionResult::Livelock { steps_executed, livelock_report, .. } = result else {
+            panic!("expected livelock, got {:?}", result);
+        };
+        assert!(steps_executed < 50);
+        assert!(livelock_report.steps_without_progress > 20);
+        assert_eq!(livelock_report.cycling_participants, vec!["alice".to_string(), "bob".to_string()]);
+        
+        let report = engine.test_protocol_compliance();
+        assert!(!report.is_fully_compliant);
+        let livelock = report.livelock_report.as_ref().unwrap();
+        assert!(livelock.is_livelock);
+        assert_eq!(livelock.livelock_violation.as_ref().unwrap().severity(), ViolationSeverity::Critical);
+    }
+    
+    #[tokio::test]
+    async fn test_timeout_deadlock_uses_simulated_time() {
+        let mut engine = SimulationEngine::with_seed(3);
+        engine.set_clock_mode(ClockMode::FixedStep(Duration::from_millis(100)));
+        engine.set_deadlock_timeout(Duration::from_millis(500));
+        engine.session_participants.insert(
+            "bob".to_string(),
+            SessionParticipantState::with_session_type(looping_session(true)),
+        );
+        let program = (0..2)
+            .map(|i| Instruction::Transform { morph_reg: RegisterId::new(i), input_reg: RegisterId::new(i), output_reg: RegisterId::new(i) })
+            .collect();
+        engine.load_program(program).unwrap();
+        engine.run().await.unwrap();
+        
+        // Last receive happened at 100ms; the clock now reads 200ms
+        assert!(engine.detect_deadlocks_advanced().timeout_based_deadlocks.is_empty());
+        
+        engine.clock().advance(Duration::from_millis(400));
+        let report = engine.detect_deadlocks_advanced();
+        assert_eq!(report.timeout_based_deadlocks.len(), 1);
+        assert_eq!(report.timeout_based_deadlocks[0].timeout_duration, 500);
+        assert!(report.has_deadlock);
+    }
+    
     #[tokio::test]
     async fn test_state_transitions() {
         let config = SimulationConfig::default();
@@ -2,7 +2,7 @@
 
 use std::collections::BTreeMap;
 use rand::{Rng, SeedableRng};
-use rand::rngs::StdRng;
+use rand_chacha::ChaCha12Rng;
 use serde::{Deserialize, Serialize};
 use crate::error::SimulationResult;
 use crate::engine::SessionOperation;
@@ -116,7 +116,7 @@ pub struct SessionFaultConfig {
 }
 
 /// Types of session operations that can be targeted by faults
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SessionOperationType {
     Send,
     Receive,
@@ -208,14 +208,20 @@ pub struct SessionFaultStatistics {
     pub duality_violations: usize,
     pub message_loss_events: usize,
     pub choice_manipulations: usize,
+    /// Session faults triggered against each participant
+    pub faults_by_participant: BTreeMap<String, usize>,
 }
 
 /// Manages fault injection during simulation
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FaultInjector {
     active_faults: BTreeMap<String, FaultConfig>,
+    /// Session faults that only fire for one participant
+    targeted_faults: BTreeMap<String, Vec<SessionFaultConfig>>,
+    /// Session faults triggered against each participant
+    session_fault_counts: BTreeMap<String, usize>,
     fault_history: Vec<FaultEvent>,
-    rng: StdRng,
+    rng: ChaCha12Rng,
     enabled: bool,
 }
 
@@ -313,8 +319,10 @@ impl FaultInjector {
     pub fn with_seed(seed: u64) -> Self {
         Self {
             active_faults: BTreeMap::new(),
+            targeted_faults: BTreeMap::new(),
+            session_fault_counts: BTreeMap::new(),
             fault_history: Vec::new(),
-            rng: StdRng::seed_from_u64(seed),
+            rng: ChaCha12Rng::seed_from_u64(seed),
             enabled: true,
         }
     }
@@ -357,6 +365,22 @@ impl FaultInjector {
         Ok(())
     }
     
+    /// Add a session fault that only fires for one participant
+    ///
+    /// Unlike [`add_session_fault`](Self::add_session_fault), the fault is
+    /// matched against the participant by exact name and honours the
+    /// config's `target_operations`, so other participants run cleanly.
+    pub fn add_targeted_fault(&mut self, participant: impl Into<String>, config: SessionFaultConfig) -> SimulationResult<()> {
+        if config.probability < 0.0 || config.probability > 1.0 {
+            return Err(crate::error::SimulationError::FaultInjectionError(
+                "Session fault probability must be between 0.0 and 1.0".to_string()
+            ));
+        }
+        
+        self.targeted_faults.entry(participant.into()).or_default().push(config);
+        Ok(())
+    }
+    
     /// Remove a fault configuration
     pub fn remove_fault(&mut self, fault_id: &str) -> bool {
         self.active_faults.remove(fault_id).is_some()
@@ -417,6 +441,8 @@ impl FaultInjector {
     /// Clear all faults and history
     pub fn clear(&mut self) {
         self.active_faults.clear();
+        self.targeted_faults.clear();
+        self.session_fault_counts.clear();
         self.fault_history.clear();
     }
     
@@ -512,6 +538,7 @@ impl FaultInjector {
             duality_violations,
             message_loss_events,
             choice_manipulations,
+            faults_by_participant: self.session_fault_counts.clone(),
         }
     }
     
@@ -531,6 +558,37 @@ impl FaultInjector {
         let operation_type = self.classify_session_operation(operation);
         let critical_communication_points = self.identify_critical_points(operation, session_context);
         
+        // Faults targeted at this participant are consulted first
+        if let Some(configs) = self.targeted_faults.get(participant) {
+            for (index, config) in configs.iter().enumerate() {
+                let targets_operation = config.target_operations.is_empty()
+                    || config.target_operations.iter()
+                        .any(|target| *target == SessionOperationType::Any || *target == operation_type);
+                if !targets_operation || !Self::is_fault_type_applicable(&config.fault_type, participant, &operation_type) {
+                    continue;
+                }
+                
+                let random_value: f64 = self.rng.gen();
+                if random_value < config.probability {
+                    let fault_result = Self::generate_session_fault_result(&config.fault_type, operation);
+                    
+                    // Record the fault event
+                    let event = FaultEvent {
+                        fault_id: format!("targeted_{}_{}", participant, index),
+                        fault_type: config.fault_type.clone(),
+                        target: participant.to_string(),
+                        timestamp,
+                        duration_ms: None,
+                        triggered: true,
+                    };
+                    self.fault_history.push(event);
+                    *self.session_fault_counts.entry(participant.to_string()).or_insert(0) += 1;
+                    
+                    return Some(fault_result);
+                }
+            }
+        }
+        
         // Check all active faults that could apply to this operation
         for (fault_id, config) in &self.active_faults {
             if self.is_session_fault_applicable(config, participant, &operation_type, &critical_communication_points) {
@@ -548,6 +606,7 @@ impl FaultInjector {
                         triggered: true,
                     };
                     self.fault_history.push(event);
+                    *self.session_fault_counts.entry(participant.to_string()).or_insert(0) += 1;
                     
                     return Some(fault_result);
                 }
@@ -625,8 +684,17 @@ impl FaultInjector {
             return false;
         }
         
+        Self::is_fault_type_applicable(&config.fault_type, participant, operation_type)
+    }
+    
+    /// Check if a fault type can affect an operation of the given type
+    fn is_fault_type_applicable(
+        fault_type: &FaultType,
+        participant: &str,
+        operation_type: &SessionOperationType,
+    ) -> bool {
         // Check operation type targeting for session faults
-        match &config.fault_type {
+        match fault_type {
             FaultType::SessionMessageLoss { .. } => {
                 matches!(operation_type, SessionOperationType::Send | SessionOperationType::Receive)
             }
@@ -765,4 +833,65 @@ mod tests {
         let result = injector.should_trigger_fault("test_target", timestamp);
         assert!(result.is_none());
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_targeted_fault_only_affects_participant() {
+        use causality_core::lambda::base::BaseType;
+        
+        let mut injector = FaultInjector::with_seed(42);
+        injector.add_targeted_fault("alice", SessionFaultConfig {
+            fault_type: FaultType::SessionMessageLoss { probability: 1.0, preserve_duality: true },
+            target_participants: vec!["alice".to_string()],
+            target_operations: vec![SessionOperationType::Send],
+            probability: 1.0,
+            session_context: None,
+            preserve_protocol_safety: true,
+        }).unwrap();
+        
+        // A two-party exchange: alice sends to bob, bob replies
+        let alice_send = SessionOperation::Send {
+            value_type: TypeInner::Base(BaseType::Int),
+            target_participant: "bob".to_string(),
+            value: None,
+        };
+        let alice_receive = SessionOperation::Receive {
+            value_type: TypeInner::Base(BaseType::Int),
+            source_participant: "bob".to_string(),
+            expected_value: None,
+        };
+        let bob_send = SessionOperation::Send {
+            value_type: TypeInner::Base(BaseType::Int),
+            target_participant: "alice".to_string(),
+            value: None,
+        };
+        let timestamp = SimulatedTimestamp::from_secs(1000);
+        
+        for _ in 0..3 {
+            let fault = injector.should_trigger_session_fault(&alice_send, "alice", None, timestamp);
+            assert!(matches!(fault, Some(SessionFaultResult::MessageLoss { .. })));
+            assert!(injector.should_trigger_session_fault(&bob_send, "bob", None, timestamp).is_none());
+            // Receives are not among the targeted operations
+            assert!(injector.should_trigger_session_fault(&alice_receive, "alice", None, timestamp).is_none());
+        }
+        
+        let stats = injector.get_session_statistics();
+        assert_eq!(stats.faults_by_participant.get("alice"), Some(&3));
+        assert_eq!(stats.faults_by_participant.get("bob"), None);
+        assert_eq!(stats.message_loss_events, 3);
+        assert!(injector.get_fault_history().iter().all(|event| event.target == "alice"));
+    }
+    
+    #[test]
+    fn test_targeted_fault_rejects_invalid_probability() {
+        let mut injector = FaultInjector::with_seed(42);
+        let result = injector.add_targeted_fault("alice", SessionFaultConfig {
+            fault_type: FaultType::ProcessCrash,
+            target_participants: vec![],
+            target_operations: vec![],
+            probability: 1.5,
+            session_context: None,
+            preserve_protocol_safety: false,
+        });
+        assert!(result.is_err());
+    }
+}
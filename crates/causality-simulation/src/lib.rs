@@ -74,6 +74,8 @@ pub mod error;
 pub mod executor;
 pub mod fault_injection;
 pub mod optimizer;
+pub mod pruning;
+pub mod scheduler;
 pub mod session_environments;
 pub mod snapshot;
 pub mod time_travel;
@@ -93,6 +95,8 @@ pub use engine::*;
 pub use error::*;
 pub use fault_injection::*;
 pub use optimizer::*;
+pub use pruning::*;
+pub use scheduler::*;
 pub use session_environments::{
     CommunicationPattern, SessionEnvironmentGenerator, SessionParticipantConfig,
     SessionTopology,
@@ -197,6 +201,8 @@ pub struct SessionSimulationConfig {
     pub max_execution_timeout_ms: u64,
     /// Maximum simulation steps before forced termination
     pub max_simulation_steps: u64,
+    /// Seed for the engine and fault injector; `None` picks a random seed
+    pub seed: Option<u64>,
 }
 
 impl Default for SessionSimulationConfig {
@@ -209,6 +215,7 @@ impl Default for SessionSimulationConfig {
             enable_session_optimization: true,
             max_execution_timeout_ms: 30000, // 30 seconds
             max_simulation_steps: 10000,
+            seed: None,
         }
     }
 }
@@ -219,7 +226,6 @@ pub struct SessionSimulationEnvironment {
     pub engine: SimulationEngine,
     pub optimizer: SimulationOptimizer,
     pub visualizer: VisualizationHooks,
-    pub fault_injector: FaultInjector,
     pub snapshot_manager: SnapshotManager,
     pub cross_chain_executor: CrossChainTestExecutor,
     pub effect_runner: EffectTestRunner,
@@ -230,14 +236,25 @@ pub struct SessionSimulationEnvironment {
 impl SessionSimulationEnvironment {
     /// Create a complete session-driven simulation environment
     pub fn new(config: SessionSimulationConfig) -> Self {
-        Self {
-            engine: if config.enable_compliance_checking
-                || config.enable_deadlock_detection
+        let mut engine = match config.seed {
+            Some(seed) => SimulationEngine::with_seed(seed),
+            None if config.enable_compliance_checking
+                || config.enable_deadlock_detection =>
             {
                 SimulationEngine::with_enhanced_session_support()
-            } else {
-                SimulationEngine::with_session_choreography()
-            },
+            }
+            None => SimulationEngine::with_session_choreography(),
+        };
+        if config.enable_deadlock_detection {
+            engine.set_livelock_threshold(Some(config.max_simulation_steps));
+        }
+        // Faults are injected by the engine's own injector, seeded from the engine seed
+        engine
+            .fault_injector_mut()
+            .set_enabled(config.enable_session_fault_injection);
+
+        Self {
+            engine,
             optimizer: if config.enable_session_optimization {
                 SimulationOptimizer::with_session_optimization()
             } else {
@@ -248,11 +265,6 @@ impl SessionSimulationEnvironment {
             } else {
                 VisualizationHooks::new()
             },
-            fault_injector: if config.enable_session_fault_injection {
-                FaultInjector::with_session_awareness()
-            } else {
-                FaultInjector::new()
-            },
             snapshot_manager: SnapshotManager::with_session_checkpoints(100),
             cross_chain_executor: CrossChainTestExecutor::with_session_choreography(
             ),
@@ -267,6 +279,14 @@ impl SessionSimulationEnvironment {
         Self::new(SessionSimulationConfig::default())
     }
 
+    /// Create a default environment whose engine, and so its fault injector, use `seed`
+    pub fn with_seed(seed: u64) -> Self {
+        Self::new(SessionSimulationConfig {
+            seed: Some(seed),
+            ..SessionSimulationConfig::default()
+        })
+    }
+
     /// Create environment optimized for performance testing
     pub fn for_performance_testing() -> Self {
         let config = SessionSimulationConfig {
@@ -277,6 +297,7 @@ impl SessionSimulationEnvironment {
             enable_session_optimization: true,
             max_execution_timeout_ms: 60000, // 1 minute
             max_simulation_steps: 100000,
+            seed: None,
         };
         Self::new(config)
     }
@@ -291,6 +312,7 @@ impl SessionSimulationEnvironment {
             enable_session_optimization: false, // Don't optimize for debugging
             max_execution_timeout_ms: 120000,   // 2 minutes
             max_simulation_steps: 50000,
+            seed: None,
         };
         Self::new(config)
     }
@@ -305,9 +327,51 @@ impl SessionSimulationEnvironment {
             enable_session_optimization: false,
             max_execution_timeout_ms: 90000, // 1.5 minutes
             max_simulation_steps: 75000,
+            seed: None,
         };
         Self::new(config)
     }
+
+    /// Gather the results of the simulation run so far
+    ///
+    /// `protocol` maps each participant role to its session type; its gas
+    /// prediction comes from the optimizer's instruction costs. Compliance
+    /// and fault statistics are included when enabled in the config.
+    pub fn collect_results(
+        &mut self,
+        protocol: &std::collections::BTreeMap<String, causality_core::lambda::base::SessionType>,
+    ) -> SessionSimulationResults {
+        let compliance_results = self
+            .config
+            .enable_compliance_checking
+            .then(|| self.engine.test_protocol_compliance());
+        let fault_injection_stats = self
+            .config
+            .enable_session_fault_injection
+            .then(|| self.engine.fault_injector().get_session_statistics());
+        let success = compliance_results
+            .as_ref()
+            .map_or(true, |report| report.is_fully_compliant);
+
+        SessionSimulationResults {
+            execution_results: self.engine.state().clone(),
+            compliance_results,
+            gas_prediction: Some(self.optimizer.predict_gas(protocol)),
+            fault_injection_stats,
+            success,
+            ..SessionSimulationResults::default()
+        }
+    }
+
+    /// Run the engine until completion or until `max_execution_timeout_ms`
+    /// of simulated time has elapsed
+    pub async fn run_with_configured_timeout(
+        &mut self,
+    ) -> Result<TimeoutExecutionResult, SimulationError> {
+        self.engine
+            .run_with_timeout(self.config.max_execution_timeout_ms)
+            .await
+    }
 }
 
 // NEW: Session-driven simulation result aggregation
@@ -321,6 +385,8 @@ pub struct SessionSimulationResults {
     pub compliance_results: Option<engine::ProtocolComplianceReport>,
     /// Performance optimization results
     pub optimization_results: Option<optimizer::PerformancePrediction>,
+    /// Projected gas cost of the protocol
+    pub gas_prediction: Option<optimizer::GasPrediction>,
     /// Visualization outputs
     pub visualization_outputs: Vec<String>,
     /// Fault injection statistics
@@ -341,6 +407,7 @@ impl Default for SessionSimulationResults {
             execution_results: SimulationState::Created,
             compliance_results: None,
             optimization_results: None,
+            gas_prediction: None,
             visualization_outputs: Vec::new(),
             fault_injection_stats: None,
             cross_chain_results: None,
@@ -378,6 +445,45 @@ mod integration_tests {
     use super::*;
     use std::time::Duration;
 
+    #[tokio::test]
+    async fn test_results_include_gas_prediction() {
+        use causality_core::lambda::base::{BaseType, SessionType, TypeInner};
+        use causality_core::machine::instruction::{Instruction, RegisterId};
+        use std::collections::BTreeMap;
+
+        let int = || Box::new(TypeInner::Base(BaseType::Int));
+        let protocol = BTreeMap::from([
+            ("alice".to_string(), SessionType::Send(int(), Box::new(SessionType::End))),
+            ("bob".to_string(), SessionType::Receive(int(), Box::new(SessionType::End))),
+        ]);
+
+        let mut env = SessionSimulationEnvironment::with_seed(1);
+        for (role, session_type) in &protocol {
+            env.engine.session_participants.insert(
+                role.clone(),
+                SessionParticipantState::with_session_type(session_type.clone()),
+            );
+        }
+        let program = (0..2)
+            .map(|i| Instruction::Transform {
+                morph_reg: RegisterId::new(i),
+                input_reg: RegisterId::new(i),
+                output_reg: RegisterId::new(i),
+            })
+            .collect();
+        env.engine.load_program(program).unwrap();
+        env.engine.run().await.unwrap();
+
+        let results = env.collect_results(&protocol);
+        let prediction = results.gas_prediction.expect("gas prediction");
+        assert_eq!(prediction, env.optimizer.predict_gas(&protocol));
+        assert!(prediction.total_gas > 0);
+        assert_eq!(prediction.per_participant.len(), 2);
+        assert_eq!(results.execution_results, SimulationState::Completed);
+        assert!(results.compliance_results.is_some());
+        assert!(results.success);
+    }
+
     #[tokio::test]
     async fn test_end_to_end_effect_execution() {
         let mut engine = SimulationEngine::new();
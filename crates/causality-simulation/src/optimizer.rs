@@ -8,6 +8,7 @@ use crate::{
     error::SimulationResult,
 };
 use causality_core::lambda::base::{SessionType, TypeInner};
+use causality_core::machine::{GasMeter, Instruction, InstructionCosts, RegisterId};
 use std::collections::BTreeMap;
 
 /// Cost metric for effect execution
@@ -323,6 +324,17 @@ pub struct PerformancePrediction {
     pub scaling_behavior: ScalingFactors,
 }
 
+/// Projected gas cost of a session protocol
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasPrediction {
+    /// Predicted gas for each participant role
+    pub per_participant: BTreeMap<String, u64>,
+    /// Number of compiled instructions for each participant role
+    pub instruction_counts: BTreeMap<String, usize>,
+    /// Predicted gas for the whole protocol
+    pub total_gas: u64,
+}
+
 /// Enhanced effect optimization and scheduling engine with session awareness
 #[derive(Debug, Clone)]
 pub struct SimulationOptimizer {
@@ -332,6 +344,8 @@ pub struct SimulationOptimizer {
     optimization_cache: BTreeMap<String, String>,
     /// Session-aware optimizer for protocol optimization
     session_optimizer: SessionAwareOptimizer,
+    /// Layer 0 instruction costs used for gas prediction
+    instruction_costs: InstructionCosts,
 }
 
 impl SimulationOptimizer {
@@ -341,6 +355,7 @@ impl SimulationOptimizer {
             default_strategy: OptimizationStrategy::Balanced,
             optimization_cache: BTreeMap::new(),
             session_optimizer: SessionAwareOptimizer::new(),
+            instruction_costs: InstructionCosts::default(),
         }
     }
     
@@ -350,6 +365,7 @@ impl SimulationOptimizer {
             default_strategy: strategy,
             optimization_cache: BTreeMap::new(),
             session_optimizer: SessionAwareOptimizer::new(),
+            instruction_costs: InstructionCosts::default(),
         }
     }
     
@@ -381,6 +397,50 @@ impl SimulationOptimizer {
         self.session_optimizer.get_optimization_statistics()
     }
     
+    /// Set the instruction costs used for gas prediction
+    pub fn set_instruction_costs(&mut self, costs: InstructionCosts) {
+        self.instruction_costs = costs;
+    }
+    
+    /// Predict the gas a session protocol will consume
+    ///
+    /// Each participant's session type is compiled to its instruction stream
+    /// and costed with the configured `InstructionCosts`, exactly as a
+    /// `GasMeter` would charge it.
+    pub fn predict_gas(&self, protocol: &BTreeMap<String, SessionType>) -> GasPrediction {
+        let meter = GasMeter::with_costs(u64::MAX, self.instruction_costs.clone());
+        let mut prediction = GasPrediction {
+            per_participant: BTreeMap::new(),
+            instruction_counts: BTreeMap::new(),
+            total_gas: 0,
+        };
+        
+        for (role, session_type) in protocol {
+            let instructions = self.compile_session_instructions(session_type);
+            let gas = meter.estimate_gas(&instructions);
+            prediction.per_participant.insert(role.clone(), gas);
+            prediction.instruction_counts.insert(role.clone(), instructions.len());
+            prediction.total_gas = prediction.total_gas.saturating_add(gas);
+        }
+        
+        prediction
+    }
+    
+    /// Compile a participant's session type to the instructions it executes
+    ///
+    /// The lowering matches the Lisp compiler's session forms: send is a
+    /// `Transform`, receive a `Consume`, select an `Alloc` plus `Transform`,
+    /// and case a `Consume` plus `Transform`. Choices follow their most
+    /// expensive branch, so the stream is an upper bound, and recursive
+    /// protocols are costed for a single unfolding.
+    pub fn compile_session_instructions(&self, session_type: &SessionType) -> Vec<Instruction> {
+        let meter = GasMeter::with_costs(u64::MAX, self.instruction_costs.clone());
+        let mut next_register = 0;
+        let mut instructions = Vec::new();
+        lower_session(session_type, &meter, &mut next_register, &mut instructions);
+        instructions
+    }
+    
     /// Optimize program for gas efficiency
     pub fn optimize_for_gas_efficiency(&self, program: &str) -> String {
         // Mock optimization for gas efficiency
@@ -519,6 +579,75 @@ impl SimulationOptimizer {
     }
 }
 
+/// Append the instructions for `session_type`, allocating fresh registers
+fn lower_session(
+    session_type: &SessionType,
+    meter: &GasMeter,
+    next_register: &mut u32,
+    instructions: &mut Vec<Instruction>,
+) {
+    let mut alloc = || {
+        let register = RegisterId::new(*next_register);
+        *next_register += 1;
+        register
+    };
+    
+    match session_type {
+        SessionType::Send(_, continuation) => {
+            let (channel, value, output) = (alloc(), alloc(), alloc());
+            instructions.push(Instruction::Transform { morph_reg: channel, input_reg: value, output_reg: output });
+            lower_session(continuation, meter, next_register, instructions);
+        }
+        SessionType::Receive(_, continuation) => {
+            let (channel, output) = (alloc(), alloc());
+            instructions.push(Instruction::Consume { resource_reg: channel, output_reg: output });
+            lower_session(continuation, meter, next_register, instructions);
+        }
+        SessionType::InternalChoice(branches) => {
+            let (channel, choice_type, choice_init, choice, output) = (alloc(), alloc(), alloc(), alloc(), alloc());
+            instructions.push(Instruction::Alloc { type_reg: choice_type, init_reg: choice_init, output_reg: choice });
+            instructions.push(Instruction::Transform { morph_reg: channel, input_reg: choice, output_reg: output });
+            lower_costliest_branch(branches, meter, next_register, instructions);
+        }
+        SessionType::ExternalChoice(branches) => {
+            let (channel, choice, output) = (alloc(), alloc(), alloc());
+            instructions.push(Instruction::Consume { resource_reg: channel, output_reg: choice });
+            lower_costliest_branch(branches, meter, next_register, instructions);
+            instructions.push(Instruction::Transform { morph_reg: choice, input_reg: choice, output_reg: output });
+        }
+        SessionType::Recursive(_, body) => lower_session(body, meter, next_register, instructions),
+        SessionType::End | SessionType::Variable(_) => {}
+    }
+}
+
+/// Append the instructions of the branch with the highest gas cost
+fn lower_costliest_branch(
+    branches: &[(String, SessionType)],
+    meter: &GasMeter,
+    next_register: &mut u32,
+    instructions: &mut Vec<Instruction>,
+) {
+    let mut costliest: Option<(u64, u32, Vec<Instruction>)> = None;
+    for (_, branch) in branches {
+        let mut registers = *next_register;
+        let mut lowered = Vec::new();
+        lower_session(branch, meter, &mut registers, &mut lowered);
+        let gas = meter.estimate_gas(&lowered);
+        let costlier = match &costliest {
+            Some((best, _, _)) => gas > *best,
+            None => true,
+        };
+        if costlier {
+            costliest = Some((gas, registers, lowered));
+        }
+    }
+    
+    if let Some((_, registers, lowered)) = costliest {
+        *next_register = registers;
+        instructions.extend(lowered);
+    }
+}
+
 /// Analysis results for optimization potential
 #[derive(Debug, Clone)]
 pub struct OptimizationAnalysis {
@@ -1326,6 +1455,63 @@ mod tests {
         }
     }
     
+    fn int() -> Box<TypeInner> {
+        use causality_core::lambda::base::BaseType;
+        Box::new(TypeInner::Base(BaseType::Int))
+    }
+    
+    fn metered_gas(instructions: &[Instruction]) -> u64 {
+        let mut meter = GasMeter::new(u64::MAX);
+        for instruction in instructions {
+            meter.consume_gas(instruction).unwrap();
+        }
+        meter.gas_used
+    }
+    
+    #[test]
+    fn test_gas_prediction_matches_gas_meter() {
+        let protocol = BTreeMap::from([
+            ("client".to_string(), SessionType::Send(int(), Box::new(SessionType::InternalChoice(vec![
+                ("quit".to_string(), SessionType::End),
+                ("query".to_string(), SessionType::Send(int(), Box::new(SessionType::Receive(int(), Box::new(SessionType::End))))),
+            ])))),
+            ("server".to_string(), SessionType::Receive(int(), Box::new(SessionType::ExternalChoice(vec![
+                ("quit".to_string(), SessionType::End),
+                ("query".to_string(), SessionType::Receive(int(), Box::new(SessionType::Send(int(), Box::new(SessionType::End))))),
+            ])))),
+        ]);
+        
+        let optimizer = SimulationOptimizer::new();
+        let prediction = optimizer.predict_gas(&protocol);
+        
+        let mut total = 0;
+        for (role, session_type) in &protocol {
+            let instructions = optimizer.compile_session_instructions(session_type);
+            let metered = metered_gas(&instructions);
+            assert_eq!(prediction.per_participant[role], metered);
+            assert_eq!(prediction.instruction_counts[role], instructions.len());
+            total += metered;
+        }
+        assert_eq!(prediction.total_gas, total);
+        // The costlier "query" branch is the one that was costed
+        assert_eq!(prediction.instruction_counts["client"], 5);
+    }
+    
+    #[test]
+    fn test_gas_prediction_compares_encodings() {
+        let optimizer = SimulationOptimizer::new();
+        let separate = BTreeMap::from([(
+            "sender".to_string(),
+            SessionType::Send(int(), Box::new(SessionType::Send(int(), Box::new(SessionType::End)))),
+        )]);
+        let batched = BTreeMap::from([(
+            "sender".to_string(),
+            SessionType::Send(int(), Box::new(SessionType::End)),
+        )]);
+        
+        assert!(optimizer.predict_gas(&batched).total_gas < optimizer.predict_gas(&separate).total_gas);
+    }
+    
     #[test]
     fn test_optimizer_creation() {
         let optimizer = EffectOptimizer::new();
@@ -0,0 +1,165 @@
+//! Pruning of the engine's execution log
+//!
+//! Every executed step is appended to the engine's `StateProgression`, so
+//! long simulations grow the log without bound. A [`LogPruner`] removes steps
+//! that fall outside a retention policy while always keeping the steps from
+//! the latest time-travel checkpoint onwards, since the checkpoint is the most
+//! recent state the simulation can be recovered from.
+
+use std::time::Duration;
+use crate::{
+    engine::SimulationEngine,
+    time_travel::TimeTravelManager,
+};
+
+/// Which execution steps may be pruned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetentionPolicy {
+    /// Prune steps older than this much simulated time, measured back from
+    /// the most recent step
+    MaxAge(Duration),
+
+    /// Prune every step below the latest checkpoint
+    BelowCheckpoint,
+}
+
+/// Result of a pruning pass
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruneStats {
+    /// Steps removed from the log
+    pub pruned: usize,
+
+    /// Steps still held in the log
+    pub retained: usize,
+
+    /// Lowest step number that may never be pruned, if a checkpoint exists
+    pub checkpoint_floor: Option<usize>,
+}
+
+/// Removes execution steps that fall outside a retention policy
+#[derive(Debug, Clone)]
+pub struct LogPruner {
+    policy: RetentionPolicy,
+}
+
+impl LogPruner {
+    /// Create a pruner for the given retention policy
+    pub fn new(policy: RetentionPolicy) -> Self {
+        Self { policy }
+    }
+
+    /// Get the retention policy
+    pub fn policy(&self) -> RetentionPolicy {
+        self.policy
+    }
+
+    /// Prune the engine's execution log
+    ///
+    /// Steps at or above the latest checkpoint in `time_travel` are never
+    /// removed. Without any checkpoint nothing can be recovered except by the
+    /// log itself, so nothing is pruned.
+    pub fn prune(&self, engine: &mut SimulationEngine, time_travel: &TimeTravelManager) -> PruneStats {
+        let progression = engine.state_progression_mut();
+        let checkpoint_floor = time_travel.latest_checkpoint_step().map(|step| step as usize);
+
+        let Some(floor) = checkpoint_floor else {
+            return PruneStats {
+                pruned: 0,
+                retained: progression.steps.len(),
+                checkpoint_floor,
+            };
+        };
+
+        let cutoff = match self.policy {
+            RetentionPolicy::BelowCheckpoint => floor,
+            RetentionPolicy::MaxAge(max_age) => {
+                let max_age_ms = max_age.as_millis() as u64;
+                let newest = progression.steps.last().map(|step| step.timestamp);
+                // First step young enough to keep; everything before it is eligible
+                let first_kept = progression.steps.iter()
+                    .find(|step| newest.is_some_and(|newest| {
+                        newest.duration_since(step.timestamp).as_millis() as u64 <= max_age_ms
+                    }))
+                    .map_or(progression.executed_steps(), |step| step.step_number);
+                first_kept.min(floor)
+            }
+        };
+
+        let pruned = progression.delete_range(0..cutoff);
+        PruneStats {
+            pruned,
+            retained: progression.steps.len(),
+            checkpoint_floor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ClockMode;
+    use causality_core::machine::{Instruction, RegisterId};
+
+    /// Run `epochs` epochs of `steps_per_epoch` steps, one simulated second
+    /// apart, checkpointing at the start of `checkpoint_epoch`
+    async fn run_epochs(epochs: usize, steps_per_epoch: usize, checkpoint_epoch: Option<usize>) -> (SimulationEngine, TimeTravelManager) {
+        let total = epochs * steps_per_epoch;
+        let program = (0..total as u32)
+            .map(|i| Instruction::Transform { morph_reg: RegisterId::new(i), input_reg: RegisterId::new(i), output_reg: RegisterId::new(i) })
+            .collect();
+
+        let mut engine = SimulationEngine::with_seed(1);
+        engine.set_clock_mode(ClockMode::FixedStep(Duration::from_secs(1)));
+        engine.load_program(program).unwrap();
+
+        let mut time_travel = TimeTravelManager::new();
+        for step in 0..total {
+            if let Some(epoch) = checkpoint_epoch.filter(|epoch| step == epoch * steps_per_epoch) {
+                time_travel.create_checkpoint(&engine, format!("epoch {}", epoch)).unwrap();
+            }
+            engine.step().await.unwrap();
+        }
+        (engine, time_travel)
+    }
+
+    #[tokio::test]
+    async fn test_prune_below_checkpoint() {
+        let (mut engine, time_travel) = run_epochs(4, 5, Some(2)).await;
+
+        let stats = LogPruner::new(RetentionPolicy::BelowCheckpoint).prune(&mut engine, &time_travel);
+
+        assert_eq!(stats, PruneStats { pruned: 10, retained: 10, checkpoint_floor: Some(10) });
+        let progression = engine.state_progression();
+        assert_eq!(progression.steps.first().unwrap().step_number, 10);
+        assert_eq!(progression.executed_steps(), 20);
+        assert_eq!(engine.machine_state().lamport_clock, 20);
+    }
+
+    #[tokio::test]
+    async fn test_max_age_never_passes_checkpoint() {
+        let (mut engine, time_travel) = run_epochs(4, 5, Some(1)).await;
+
+        // Only the last 3 seconds are young enough, but the checkpoint at
+        // step 5 bounds what may be removed
+        let pruner = LogPruner::new(RetentionPolicy::MaxAge(Duration::from_secs(3)));
+        let stats = pruner.prune(&mut engine, &time_travel);
+        assert_eq!(stats.pruned, 5);
+        assert_eq!(engine.state_progression().steps.first().unwrap().step_number, 5);
+
+        // Without a checkpoint nothing is eligible
+        let (mut engine, _) = run_epochs(2, 5, None).await;
+        let stats = pruner.prune(&mut engine, &TimeTravelManager::new());
+        assert_eq!(stats.pruned, 0);
+        assert_eq!(stats.retained, 10);
+    }
+
+    #[tokio::test]
+    async fn test_max_age_prunes_only_old_steps() {
+        let (mut engine, time_travel) = run_epochs(4, 5, Some(3)).await;
+
+        // Steps are recorded at 0s..19s; keep those within 8s of the newest
+        let stats = LogPruner::new(RetentionPolicy::MaxAge(Duration::from_secs(8))).prune(&mut engine, &time_travel);
+        assert_eq!(stats.pruned, 11);
+        assert!(engine.state_progression().steps.iter().all(|step| step.step_number >= 11));
+    }
+}
@@ -0,0 +1,196 @@
+//! Deterministic scheduling of session participants
+//!
+//! When several participants share one `SimulationEngine`, the scheduler
+//! decides the order in which they take their next operation during each
+//! round. Every round's order is recorded so a run can be replayed exactly.
+
+use std::collections::BTreeMap;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+/// Policy used to order ready participants within a round
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SchedulingPolicy {
+    /// Every ready participant steps once per round, in name order
+    RoundRobin,
+
+    /// Higher priority steps first; ties and unlisted participants (priority 0)
+    /// fall back to name order
+    Priority(BTreeMap<String, u32>),
+
+    /// Ready participants are shuffled by an RNG seeded with this value
+    SeededRandom(u64),
+
+    /// Follow a previously recorded schedule, round by round
+    Replay(Vec<Vec<String>>),
+}
+
+/// Selects which participant steps next and records the resulting schedule
+///
+/// The RNG is ChaCha12, the generator behind `StdRng`, named directly so a
+/// scheduler can be serialized mid-run and resumed with the same choices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scheduler {
+    policy: SchedulingPolicy,
+    rng: Option<ChaCha12Rng>,
+    history: Vec<Vec<String>>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new(SchedulingPolicy::RoundRobin)
+    }
+}
+
+impl Scheduler {
+    /// Create a scheduler for the given policy
+    pub fn new(policy: SchedulingPolicy) -> Self {
+        let rng = match &policy {
+            SchedulingPolicy::SeededRandom(seed) => Some(ChaCha12Rng::seed_from_u64(*seed)),
+            _ => None,
+        };
+
+        Self {
+            policy,
+            rng,
+            history: Vec::new(),
+        }
+    }
+
+    /// Create a round-robin scheduler
+    pub fn round_robin() -> Self {
+        Self::new(SchedulingPolicy::RoundRobin)
+    }
+
+    /// Create a priority scheduler
+    pub fn priority(priorities: BTreeMap<String, u32>) -> Self {
+        Self::new(SchedulingPolicy::Priority(priorities))
+    }
+
+    /// Create a seeded-random scheduler
+    pub fn seeded(seed: u64) -> Self {
+        Self::new(SchedulingPolicy::SeededRandom(seed))
+    }
+
+    /// Create a scheduler that replays a recorded schedule
+    pub fn replay(schedule: Vec<Vec<String>>) -> Self {
+        Self::new(SchedulingPolicy::Replay(schedule))
+    }
+
+    /// Get the active policy
+    pub fn policy(&self) -> &SchedulingPolicy {
+        &self.policy
+    }
+
+    /// Order the ready participants for the next round and record it
+    ///
+    /// `ready` may be given in any order. When replaying, participants missing
+    /// from the recorded round are dropped and unrecorded ones are appended in
+    /// name order, so a replay never steps a participant that is not ready.
+    pub fn next_round(&mut self, ready: &[String]) -> Vec<String> {
+        let mut sorted = ready.to_vec();
+        sorted.sort();
+        sorted.dedup();
+
+        let order = match &self.policy {
+            SchedulingPolicy::RoundRobin => sorted,
+            SchedulingPolicy::Priority(priorities) => {
+                // Stable sort keeps name order among equal priorities
+                sorted.sort_by_key(|role| std::cmp::Reverse(priorities.get(role).copied().unwrap_or(0)));
+                sorted
+            }
+            SchedulingPolicy::SeededRandom(_) => {
+                if let Some(rng) = self.rng.as_mut() {
+                    sorted.shuffle(rng);
+                }
+                sorted
+            }
+            SchedulingPolicy::Replay(schedule) => {
+                match schedule.get(self.history.len()) {
+                    Some(recorded) => {
+                        let mut order: Vec<String> = recorded.iter()
+                            .filter(|role| sorted.contains(role))
+                            .cloned()
+                            .collect();
+                        for role in sorted {
+                            if !order.contains(&role) {
+                                order.push(role);
+                            }
+                        }
+                        order
+                    }
+                    None => sorted,
+                }
+            }
+        };
+
+        self.history.push(order.clone());
+        order
+    }
+
+    /// Schedule recorded so far, one entry per round
+    pub fn history(&self) -> &[Vec<String>] {
+        &self.history
+    }
+
+    /// Clear the recorded schedule and restart the policy from the beginning
+    pub fn reset(&mut self) {
+        *self = Self::new(self.policy.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roles(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_round_robin_uses_name_order() {
+        let mut scheduler = Scheduler::round_robin();
+
+        assert_eq!(scheduler.next_round(&roles(&["carol", "alice", "bob"])), roles(&["alice", "bob", "carol"]));
+        assert_eq!(scheduler.next_round(&roles(&["bob", "alice"])), roles(&["alice", "bob"]));
+        assert_eq!(scheduler.history().len(), 2);
+    }
+
+    #[test]
+    fn test_priority_orders_by_priority_then_name() {
+        let priorities = BTreeMap::from([("carol".to_string(), 5), ("bob".to_string(), 1)]);
+        let mut scheduler = Scheduler::priority(priorities);
+
+        assert_eq!(
+            scheduler.next_round(&roles(&["alice", "bob", "carol", "dave"])),
+            roles(&["carol", "bob", "alice", "dave"])
+        );
+    }
+
+    #[test]
+    fn test_seeded_schedule_replays_identically() {
+        let ready = roles(&["alice", "bob", "carol", "dave"]);
+
+        let mut seeded = Scheduler::seeded(42);
+        let recorded: Vec<Vec<String>> = (0..8).map(|_| seeded.next_round(&ready)).collect();
+
+        let mut same_seed = Scheduler::seeded(42);
+        let rerun: Vec<Vec<String>> = (0..8).map(|_| same_seed.next_round(&ready)).collect();
+        assert_eq!(recorded, rerun);
+
+        let mut replay = Scheduler::replay(seeded.history().to_vec());
+        let replayed: Vec<Vec<String>> = (0..8).map(|_| replay.next_round(&ready)).collect();
+        assert_eq!(recorded, replayed);
+    }
+
+    #[test]
+    fn test_replay_skips_participants_that_are_not_ready() {
+        let mut scheduler = Scheduler::replay(vec![roles(&["bob", "alice"])]);
+
+        assert_eq!(scheduler.next_round(&roles(&["alice", "carol"])), roles(&["alice", "carol"]));
+        // Past the end of the recording it falls back to name order
+        assert_eq!(scheduler.next_round(&roles(&["bob", "alice"])), roles(&["alice", "bob"]));
+    }
+}
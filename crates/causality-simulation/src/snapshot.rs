@@ -8,7 +8,7 @@ use crate::{
     engine::{SessionParticipantState, SessionOperation},
     fault_injection::FaultType,
 };
-use causality_core::lambda::base::SessionType;
+use causality_core::{lambda::base::SessionType, MachineStateSnapshot, MachineValue};
 
 /// Snapshot identifier for simulation checkpoints
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -65,6 +65,8 @@ pub struct SessionSnapshotParams {
     pub active_protocols: BTreeMap<String, SessionType>,
     pub protocol_execution_trace: Vec<SessionOperation>,
     pub fault_recovery_context: Option<FaultRecoveryContext>,
+    /// Machine state from `SimulationEngine::machine_state`, if one is running
+    pub machine_state: Option<MachineStateSnapshot>,
 }
 
 /// Session-aware snapshot containing protocol state
@@ -79,6 +81,9 @@ pub struct SessionSnapshot {
     pub fault_recovery_context: Option<FaultRecoveryContext>,
     pub checkpoint_boundaries: Vec<CheckpointBoundary>,
     pub resilience_metrics: ResilienceMetrics,
+    /// Register file and clock of the machine at snapshot time
+    #[serde(default)]
+    pub machine_state: Option<MachineStateSnapshot>,
 }
 
 /// Context for fault recovery in session protocols
@@ -328,6 +333,7 @@ impl SnapshotManager {
             fault_recovery_context: params.fault_recovery_context,
             checkpoint_boundaries,
             resilience_metrics: ResilienceMetrics::default(),
+            machine_state: params.machine_state,
         };
 
         // Store as regular snapshot for compatibility
@@ -652,6 +658,7 @@ impl SnapshotManager {
                 active_protocols: BTreeMap::new(),
                 protocol_execution_trace: Vec::new(),
                 fault_recovery_context: None,
+                machine_state: Some(simulation_engine.machine_state()),
             }
         )?;
         
@@ -918,6 +925,114 @@ impl SnapshotManager {
         self.snapshots.get(id)
     }
     
+    /// Compare two snapshots and report what changed between them
+    ///
+    /// Session snapshots contribute participant progress and channel states;
+    /// every snapshot contributes the resources recorded in its effects log.
+    pub fn diff(&self, a: &SnapshotId, b: &SnapshotId) -> Result<SnapshotDiff, SnapshotError> {
+        let from = self.snapshots.get(a)
+            .ok_or_else(|| SnapshotError::NotFound { id: a.as_str().to_string() })?;
+        let to = self.snapshots.get(b)
+            .ok_or_else(|| SnapshotError::NotFound { id: b.as_str().to_string() })?;
+        
+        // Checkpoints and standard snapshots carry no session state
+        let from_session = serde_json::from_slice::<SessionSnapshot>(&from.resource_state).ok();
+        let to_session = serde_json::from_slice::<SessionSnapshot>(&to.resource_state).ok();
+        
+        let session_participants = |snapshot: &Option<SessionSnapshot>| {
+            snapshot.as_ref()
+                .map(|s| s.session_participants.clone())
+                .unwrap_or_default()
+        };
+        let from_participants = session_participants(&from_session);
+        let to_participants = session_participants(&to_session);
+        
+        let registers = diff_maps(
+            &Self::register_view(&from_session),
+            &Self::register_view(&to_session),
+        );
+        
+        let participants = diff_maps(
+            &Self::progress_view(&from_participants),
+            &Self::progress_view(&to_participants),
+        );
+        
+        let mut channels = diff_maps(
+            &Self::channel_view(&from_participants),
+            &Self::channel_view(&to_participants),
+        );
+        let protocols = |snapshot: &Option<SessionSnapshot>| {
+            snapshot.as_ref()
+                .map(|s| s.active_protocols.iter()
+                    .map(|(name, session)| (format!("protocol:{}", name), Some(session.clone())))
+                    .collect::<BTreeMap<_, _>>())
+                .unwrap_or_default()
+        };
+        channels.extend(diff_maps(&protocols(&from_session), &protocols(&to_session)));
+        
+        let resources = diff_maps(
+            &Self::resource_view(&from.effects_log),
+            &Self::resource_view(&to.effects_log),
+        );
+        
+        let last_boundary = |snapshot: &Option<SessionSnapshot>| {
+            snapshot.as_ref().and_then(|s| s.checkpoint_boundaries.last().cloned())
+        };
+        
+        Ok(SnapshotDiff {
+            from: a.clone(),
+            to: b.clone(),
+            from_boundary: last_boundary(&from_session),
+            to_boundary: last_boundary(&to_session),
+            registers,
+            participants,
+            resources,
+            channels,
+        })
+    }
+    
+    /// Machine registers keyed by register number
+    fn register_view(snapshot: &Option<SessionSnapshot>) -> BTreeMap<String, MachineValue> {
+        snapshot.as_ref()
+            .and_then(|s| s.machine_state.as_ref())
+            .map(|state| state.registers.iter()
+                .map(|(id, value)| (id.id().to_string(), value.clone()))
+                .collect())
+            .unwrap_or_default()
+    }
+    
+    /// Per-participant protocol progress used for diffing
+    fn progress_view(participants: &BTreeMap<String, SessionParticipantState>) -> BTreeMap<String, ParticipantProgress> {
+        participants.iter()
+            .map(|(name, state)| (name.clone(), ParticipantProgress {
+                gas: state.gas,
+                operations_executed: state.protocol_history.len(),
+                pending_operations: state.next_operations.clone(),
+            }))
+            .collect()
+    }
+    
+    /// Per-participant channel state used for diffing
+    fn channel_view(participants: &BTreeMap<String, SessionParticipantState>) -> BTreeMap<String, Option<SessionType>> {
+        participants.iter()
+            .map(|(name, state)| (name.clone(), state.current_session.clone()))
+            .collect()
+    }
+    
+    /// Live resources and the effect that last produced them
+    fn resource_view(effects_log: &[EffectExecution]) -> BTreeMap<String, String> {
+        let mut live = BTreeMap::new();
+        for execution in effects_log {
+            for consumed in &execution.resources_consumed {
+                live.remove(consumed);
+            }
+            for produced in &execution.resources_produced {
+                live.insert(produced.clone(), execution.effect_id.clone());
+            }
+        }
+        live
+    }
+    
     /// Create a checkpoint with arbitrary data
     pub fn create_checkpoint<T>(
         &mut self, 
@@ -1046,6 +1161,88 @@ pub enum ExecutionResult {
     Cancelled,
 }
 
+/// A single entry that differs between two snapshots
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StateChange<T> {
+    /// Present only in the later snapshot
+    Added(T),
+    /// Present only in the earlier snapshot
+    Removed(T),
+    /// Present in both snapshots with different values
+    Modified { before: T, after: T },
+}
+
+/// How far a session participant has got through its protocol
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParticipantProgress {
+    /// The participant's session gas
+    pub gas: u64,
+    /// Number of protocol operations the participant has executed
+    pub operations_executed: usize,
+    /// Operations the participant's session type allows next
+    pub pending_operations: Vec<SessionOperation>,
+}
+
+/// Differences between two snapshots, as returned by [`SnapshotManager::diff`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    pub from: SnapshotId,
+    pub to: SnapshotId,
+    /// Checkpoint boundary the earlier snapshot was taken at, if any
+    pub from_boundary: Option<CheckpointBoundary>,
+    /// Checkpoint boundary the later snapshot was taken at, if any
+    pub to_boundary: Option<CheckpointBoundary>,
+    /// Machine register contents keyed by register number
+    pub registers: BTreeMap<String, StateChange<MachineValue>>,
+    /// Participant progress keyed by participant name
+    pub participants: BTreeMap<String, StateChange<ParticipantProgress>>,
+    /// Live resources keyed by resource ID, valued by the producing effect
+    pub resources: BTreeMap<String, StateChange<String>>,
+    /// Session channel states keyed by participant (or `protocol:<name>`)
+    pub channels: BTreeMap<String, StateChange<Option<SessionType>>>,
+}
+
+impl SnapshotDiff {
+    /// Whether the two snapshots are indistinguishable
+    pub fn is_empty(&self) -> bool {
+        self.registers.is_empty()
+            && self.participants.is_empty()
+            && self.resources.is_empty()
+            && self.channels.is_empty()
+    }
+}
+
+/// Compute per-key changes between two maps
+fn diff_maps<T: Clone + PartialEq>(
+    before: &BTreeMap<String, T>,
+    after: &BTreeMap<String, T>,
+) -> BTreeMap<String, StateChange<T>> {
+    let mut changes = BTreeMap::new();
+    
+    for (key, old) in before {
+        match after.get(key) {
+            None => {
+                changes.insert(key.clone(), StateChange::Removed(old.clone()));
+            }
+            Some(new) if new != old => {
+                changes.insert(key.clone(), StateChange::Modified {
+                    before: old.clone(),
+                    after: new.clone(),
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    
+    for (key, new) in after {
+        if !before.contains_key(key) {
+            changes.insert(key.clone(), StateChange::Added(new.clone()));
+        }
+    }
+    
+    changes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1092,4 +1289,125 @@ mod tests {
         assert_eq!(id1.as_str(), "test1");
         assert_eq!(id2.as_str(), "test2");
     }
-} 
\ No newline at end of file
+    
+    fn machine_state(registers: &[(u32, MachineValue)]) -> MachineStateSnapshot {
+        MachineStateSnapshot {
+            registers: registers.iter()
+                .map(|(id, value)| (causality_core::RegisterId::new(*id), value.clone()))
+                .collect(),
+            resources: BTreeMap::new(),
+            instruction_pointer: 0,
+            lamport_clock: 0,
+        }
+    }
+    
+    #[test]
+    fn test_snapshot_diff_reports_changes() {
+        let mut manager = SnapshotManager::new(4);
+        let before_id = SnapshotId::new("before".to_string());
+        let after_id = SnapshotId::new("after".to_string());
+        
+        let mut alice = SessionParticipantState::new();
+        alice.current_session = Some(SessionType::End);
+        let bob = SessionParticipantState::new();
+        
+        manager.create_session_snapshot(SessionSnapshotParams {
+            id: before_id.clone(),
+            timestamp: SimulatedTimestamp::from_secs(1),
+            description: "before".to_string(),
+            session_participants: BTreeMap::from([
+                ("alice".to_string(), alice.clone()),
+                ("bob".to_string(), bob),
+            ]),
+            active_protocols: BTreeMap::new(),
+            protocol_execution_trace: vec![],
+            fault_recovery_context: None,
+            machine_state: Some(machine_state(&[(0, MachineValue::Int(1)), (1, MachineValue::Int(2))])),
+        }).unwrap();
+        
+        alice.gas -= 10;
+        alice.protocol_history.push(SessionOperation::End);
+        alice.current_session = None;
+        let carol = SessionParticipantState::new();
+        
+        manager.create_session_snapshot(SessionSnapshotParams {
+            id: after_id.clone(),
+            timestamp: SimulatedTimestamp::from_secs(2),
+            description: "after".to_string(),
+            session_participants: BTreeMap::from([
+                ("alice".to_string(), alice),
+                ("carol".to_string(), carol),
+            ]),
+            active_protocols: BTreeMap::new(),
+            protocol_execution_trace: vec![SessionOperation::End],
+            fault_recovery_context: None,
+            machine_state: Some(machine_state(&[(0, MachineValue::Int(5)), (2, MachineValue::Bool(true))])),
+        }).unwrap();
+        
+        let diff = manager.diff(&before_id, &after_id).unwrap();
+        
+        assert!(diff.from_boundary.is_none());
+        assert!(matches!(
+            diff.to_boundary.as_ref().map(|b| &b.boundary_type),
+            Some(CheckpointBoundaryType::PreTermination { .. })
+        ));
+        assert!(matches!(diff.participants.get("alice"), Some(StateChange::Modified { .. })));
+        assert!(matches!(diff.participants.get("bob"), Some(StateChange::Removed(_))));
+        assert!(matches!(diff.participants.get("carol"), Some(StateChange::Added(_))));
+        assert_eq!(
+            diff.channels.get("alice"),
+            Some(&StateChange::Modified { before: Some(SessionType::End), after: None })
+        );
+        assert_eq!(
+            diff.registers.get("0"),
+            Some(&StateChange::Modified { before: MachineValue::Int(1), after: MachineValue::Int(5) })
+        );
+        assert_eq!(diff.registers.get("1"), Some(&StateChange::Removed(MachineValue::Int(2))));
+        assert_eq!(diff.registers.get("2"), Some(&StateChange::Added(MachineValue::Bool(true))));
+        
+        // A snapshot compared with itself has no differences
+        assert!(manager.diff(&after_id, &after_id).unwrap().is_empty());
+    }
+    
+    #[test]
+    fn test_snapshot_diff_resources() {
+        let mut manager = SnapshotManager::new(4);
+        let heap = causality_core::ResourceManager::new();
+        let execution = |id: &str, consumed: &[&str], produced: &[&str]| EffectExecution {
+            effect_id: id.to_string(),
+            effect_expr: String::new(),
+            start_time: SimulatedTimestamp::from_secs(0),
+            end_time: None,
+            result: ExecutionResult::Success,
+            resources_consumed: consumed.iter().map(|r| r.to_string()).collect(),
+            resources_produced: produced.iter().map(|r| r.to_string()).collect(),
+        };
+        
+        let a = SnapshotId::new("a".to_string());
+        let b = SnapshotId::new("b".to_string());
+        manager.create_snapshot(
+            a.clone(), SimulatedTimestamp::from_secs(1), "a".to_string(), &heap,
+            vec![execution("e1", &[], &["r1", "r2"])],
+            PerformanceMetrics::default(),
+        ).unwrap();
+        manager.create_snapshot(
+            b.clone(), SimulatedTimestamp::from_secs(2), "b".to_string(), &heap,
+            vec![
+                execution("e1", &[], &["r1", "r2"]),
+                execution("e2", &["r1", "r2"], &["r2", "r3"]),
+            ],
+            PerformanceMetrics::default(),
+        ).unwrap();
+        
+        let diff = manager.diff(&a, &b).unwrap();
+        assert_eq!(diff.resources.get("r1"), Some(&StateChange::Removed("e1".to_string())));
+        assert_eq!(
+            diff.resources.get("r2"),
+            Some(&StateChange::Modified { before: "e1".to_string(), after: "e2".to_string() })
+        );
+        assert_eq!(diff.resources.get("r3"), Some(&StateChange::Added("e2".to_string())));
+        
+        let missing = SnapshotId::new("missing".to_string());
+        assert!(matches!(manager.diff(&a, &missing), Err(SnapshotError::NotFound { .. })));
+    }
+}
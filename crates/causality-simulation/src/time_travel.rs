@@ -3,12 +3,14 @@
 //! This module provides functionality to rewind simulation state to previous
 //! points in time and fast-forward to specific future states.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use causality_core::{lambda::base::Value, MachineStateSnapshot};
 use serde::{Serialize, Deserialize};
 use crate::{
-    engine::{SimulationEngine, SimulationState, ExecutionMetrics},
+    engine::{SimulationEngine, SimulationState, ExecutionMetrics, SessionReplayState, StateProgression},
     clock::SimulatedTimestamp,
     error::SimulationError,
+    snapshot::{SnapshotId, SnapshotManager},
 };
 
 /// Time-travel checkpoint containing simulation state at a specific time
@@ -40,6 +42,48 @@ pub struct SerializableEngineState {
     pub metrics: ExecutionMetrics,
 }
 
+/// Engine state a replay resumes from, stored as a `SnapshotManager` checkpoint
+///
+/// This is everything instruction- or session-driven execution reads or
+/// writes, including the RNG and scheduler state behind seeded choices; the
+/// program and configuration come from the engine being replayed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayState {
+    pub program_counter: usize,
+    pub registers: BTreeMap<u32, Value>,
+    pub gas: u64,
+    pub time: SimulatedTimestamp,
+    pub state_progression: StateProgression,
+    pub effects_log: Vec<String>,
+    pub session: SessionReplayState,
+}
+
+impl ReplayState {
+    /// Record the replayable state of `engine`
+    pub fn capture(engine: &SimulationEngine) -> Self {
+        Self {
+            program_counter: engine.program_counter(),
+            registers: engine.execution_state().registers.clone(),
+            gas: engine.execution_state().gas,
+            time: engine.clock().now(),
+            state_progression: engine.state_progression().clone(),
+            effects_log: engine.effects_log().clone(),
+            session: engine.session_replay_state(),
+        }
+    }
+    
+    /// Put `engine` back into the recorded state
+    pub fn restore(self, engine: &mut SimulationEngine) {
+        engine.set_program_counter(self.program_counter);
+        engine.execution_state_mut().registers = self.registers;
+        engine.execution_state_mut().gas = self.gas;
+        engine.clock().set_time(self.time);
+        *engine.state_progression_mut() = self.state_progression;
+        engine.set_effects_log(self.effects_log);
+        engine.restore_session_replay_state(self.session);
+    }
+}
+
 /// Unique identifier for time checkpoints
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct CheckpointId(pub String);
@@ -97,26 +141,34 @@ pub struct TimeTravelManager {
     
     /// Step counter for automatic checkpointing
     step_counter: usize,
+    
+    /// Replay state recorded with each checkpoint, keyed `replay_<step>`
+    replay_snapshots: SnapshotManager,
+    
+    /// Executed step of every replay state held in `replay_snapshots`
+    replay_steps: BTreeSet<u64>,
+    
+    /// The engine as first checkpointed, which replays resume from with
+    /// its program and configuration
+    replay_engine: Option<SimulationEngine>,
 }
 
 impl TimeTravelManager {
     /// Create a new time-travel manager
     pub fn new() -> Self {
-        Self {
-            config: TimeTravelConfig::default(),
-            checkpoints: BTreeMap::new(),
-            current_position: None,
-            step_counter: 0,
-        }
+        Self::with_config(TimeTravelConfig::default())
     }
     
     /// Create a time-travel manager with custom configuration
     pub fn with_config(config: TimeTravelConfig) -> Self {
         Self {
+            replay_snapshots: SnapshotManager::new(config.max_checkpoints),
             config,
             checkpoints: BTreeMap::new(),
             current_position: None,
             step_counter: 0,
+            replay_steps: BTreeSet::new(),
+            replay_engine: None,
         }
     }
     
@@ -156,9 +208,61 @@ impl TimeTravelManager {
         self.checkpoints.insert(timestamp, checkpoint);
         self.current_position = Some(timestamp);
         
+        // Record replay state alongside the checkpoint, bounded by the same limit
+        let executed = engine.state_progression().executed_steps() as u64;
+        self.replay_snapshots.create_checkpoint(
+            &replay_snapshot_id(executed),
+            checkpoint_id.as_str(),
+            ReplayState::capture(engine),
+        )?;
+        self.replay_steps.insert(executed);
+        while self.replay_steps.len() > self.config.max_checkpoints {
+            if let Some(oldest) = self.replay_steps.pop_first() {
+                self.replay_snapshots.delete_snapshot(&SnapshotId::new(replay_snapshot_id(oldest)));
+            }
+        }
+        if self.replay_engine.is_none() {
+            self.replay_engine = Some(engine.fork());
+        }
+        
         Ok(checkpoint_id)
     }
     
+    /// Reconstruct the machine state the engine had after `step` steps
+    ///
+    /// Replay restores the latest replay state recorded at or before `step`
+    /// and re-executes forward, so its cost is bounded by the checkpoint
+    /// interval. Execution is deterministic for a given seed, so the result
+    /// equals the state the engine had when it originally reached that step.
+    pub async fn state_at(&self, step: u64) -> Result<MachineStateSnapshot, SimulationError> {
+        Ok(self.replay_to(step).await?.machine_state())
+    }
+    
+    /// Restore the nearest checkpoint at or before `step` and run forward to it
+    async fn replay_to(&self, step: u64) -> Result<SimulationEngine, SimulationError> {
+        let no_checkpoint = || SimulationError::InvalidState(
+            format!("No checkpoint found at or before step {}", step)
+        );
+        
+        let mut engine = self.replay_engine.as_ref().ok_or_else(no_checkpoint)?.fork();
+        let base = self.replay_steps.range(..=step).next_back().ok_or_else(no_checkpoint)?;
+        self.replay_snapshots
+            .get_checkpoint::<ReplayState>(&replay_snapshot_id(*base))?
+            .restore(&mut engine);
+        
+        while (engine.state_progression().executed_steps() as u64) < step {
+            let executed = engine.state_progression().executed_steps();
+            engine.step().await?;
+            if engine.state_progression().executed_steps() == executed {
+                return Err(SimulationError::InvalidState(
+                    format!("Program ended after {} steps, before step {}", executed, step)
+                ));
+            }
+        }
+        
+        Ok(engine)
+    }
+    
     /// Rewind simulation to a specific checkpoint
     pub fn rewind_to_checkpoint(
         &mut self, 
@@ -239,6 +343,11 @@ impl TimeTravelManager {
         Ok(steps_executed)
     }
     
+    /// Executed step count of the most recent checkpoint that can be replayed from
+    pub fn latest_checkpoint_step(&self) -> Option<u64> {
+        self.replay_steps.iter().next_back().copied()
+    }
+    
     /// Get all available checkpoints
     pub fn list_checkpoints(&self) -> Vec<&TimeCheckpoint> {
         self.checkpoints.values().collect()
@@ -274,6 +383,9 @@ impl TimeTravelManager {
     /// Clear all checkpoints
     pub fn clear_checkpoints(&mut self) {
         self.checkpoints.clear();
+        self.replay_snapshots.clear_snapshots();
+        self.replay_steps.clear();
+        self.replay_engine = None;
         self.current_position = None;
         self.step_counter = 0;
     }
@@ -320,6 +432,11 @@ impl TimeTravelManager {
     }
 }
 
+/// Id of the replay state recorded after `step` executed steps
+fn replay_snapshot_id(step: u64) -> String {
+    format!("replay_{}", step)
+}
+
 impl Default for TimeTravelManager {
     fn default() -> Self {
         Self::new()
@@ -383,4 +500,144 @@ mod tests {
         
         assert_eq!(manager.config.max_checkpoints, 2);
     }
+    
+    #[tokio::test]
+    async fn test_state_at_matches_natural_run() {
+        use causality_core::machine::{Instruction, RegisterId};
+        
+        let program: Vec<Instruction> = (0..12)
+            .map(|i| Instruction::Transform {
+                morph_reg: RegisterId::new(i),
+                input_reg: RegisterId::new(i + 1),
+                output_reg: RegisterId::new(i + 2),
+            })
+            .collect();
+        
+        let mut engine = SimulationEngine::with_seed(7);
+        engine.load_program(program).unwrap();
+        
+        let mut manager = TimeTravelManager::new();
+        let mut natural = vec![engine.machine_state()];
+        for step in 0..12 {
+            if step % 5 == 0 {
+                manager.create_checkpoint(&engine, format!("step {}", step)).unwrap();
+            }
+            engine.step().await.unwrap();
+            natural.push(engine.machine_state());
+        }
+        
+        for (step, expected) in natural.iter().enumerate() {
+            assert_eq!(&manager.state_at(step as u64).await.unwrap(), expected);
+        }
+        assert!(manager.state_at(13).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_state_at_uses_retained_checkpoints() {
+        use causality_core::machine::{Instruction, RegisterId};
+        
+        let program: Vec<Instruction> = (0..12)
+            .map(|i| Instruction::Alloc {
+                type_reg: RegisterId::new(i),
+                init_reg: RegisterId::new(i),
+                output_reg: RegisterId::new(i + 1),
+            })
+            .collect();
+        
+        let mut engine = SimulationEngine::with_seed(3);
+        engine.load_program(program).unwrap();
+        
+        let mut manager = TimeTravelManager::with_config(TimeTravelConfig {
+            max_checkpoints: 2,
+            ..TimeTravelConfig::default()
+        });
+        let mut natural = vec![engine.machine_state()];
+        for step in 0..12 {
+            if step % 4 == 0 {
+                manager.create_checkpoint(&engine, format!("step {}", step)).unwrap();
+            }
+            engine.step().await.unwrap();
+            natural.push(engine.machine_state());
+        }
+        
+        // Only the replay states for steps 4 and 8 are kept
+        assert_eq!(manager.latest_checkpoint_step(), Some(8));
+        assert_eq!(manager.replay_snapshots.list_snapshots().len(), 2);
+        assert!(manager.state_at(3).await.is_err());
+        for step in 4..=12 {
+            assert_eq!(&manager.state_at(step as u64).await.unwrap(), &natural[step]);
+        }
+        
+        manager.clear_checkpoints();
+        assert!(manager.state_at(12).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_session_replay_resumes_seeded_choices_from_nearest_checkpoint() {
+        use crate::engine::SessionParticipantState;
+        use crate::fault_injection::{FaultType, SessionFaultConfig, SessionOperationType};
+        use crate::scheduler::SchedulingPolicy;
+        use causality_core::lambda::base::{BaseType, SessionType, TypeInner};
+        use causality_core::machine::{Instruction, RegisterId};
+        
+        let mut engine = SimulationEngine::with_seed(0xC0FFEE);
+        engine.set_scheduling_policy(SchedulingPolicy::SeededRandom(11));
+        engine.fault_injector_mut().add_targeted_fault("alice", SessionFaultConfig {
+            fault_type: FaultType::SessionMessageLoss { probability: 0.5, preserve_duality: false },
+            target_participants: vec!["alice".to_string()],
+            target_operations: vec![SessionOperationType::Send],
+            probability: 0.5,
+            session_context: None,
+            preserve_protocol_safety: false,
+        }).unwrap();
+        
+        // rec X. +{ ping: !Int.X, pong: ?Int.X }
+        let int = Box::new(TypeInner::Base(BaseType::Int));
+        let protocol = SessionType::Recursive(
+            "X".to_string(),
+            Box::new(SessionType::InternalChoice(vec![
+                ("ping".to_string(), SessionType::Send(int.clone(), Box::new(SessionType::Variable("X".to_string())))),
+                ("pong".to_string(), SessionType::Receive(int, Box::new(SessionType::Variable("X".to_string())))),
+            ])),
+        );
+        for role in ["alice", "bob", "carol"] {
+            engine.session_participants.insert(
+                role.to_string(),
+                SessionParticipantState::with_session_type(protocol.clone()),
+            );
+        }
+        let program = (0..16)
+            .map(|i| Instruction::Transform {
+                morph_reg: RegisterId::new(i),
+                input_reg: RegisterId::new(i),
+                output_reg: RegisterId::new(i),
+            })
+            .collect();
+        engine.load_program(program).unwrap();
+        
+        let mut manager = TimeTravelManager::with_config(TimeTravelConfig {
+            max_checkpoints: 2,
+            ..TimeTravelConfig::default()
+        });
+        let mut natural = vec![(engine.execution_trace(), engine.recorded_schedule().to_vec())];
+        for step in 0..16 {
+            if step % 4 == 0 {
+                manager.create_checkpoint(&engine, format!("step {}", step)).unwrap();
+            }
+            engine.step().await.unwrap();
+            natural.push((engine.execution_trace(), engine.recorded_schedule().to_vec()));
+        }
+        
+        // The first checkpoints were evicted, so replay must start from step 8
+        assert!(manager.state_at(7).await.is_err());
+        for (step, expected) in natural.iter().enumerate().skip(8) {
+            let replayed = manager.replay_to(step as u64).await.unwrap();
+            assert_eq!(
+                &(replayed.execution_trace(), replayed.recorded_schedule().to_vec()),
+                expected,
+                "replay diverged at step {}",
+                step
+            );
+        }
+    }
 } 
\ No newline at end of file
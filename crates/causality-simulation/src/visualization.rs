@@ -390,6 +390,90 @@ impl VisualizationHooks {
         
         Ok(timeline)
     }
+    
+    /// Export session flow as Chrome Trace Event Format JSON
+    ///
+    /// Emits one complete event per recorded `SessionFlowEvent`, loadable in
+    /// `chrome://tracing` or Perfetto. Each session is a process and each
+    /// participant a named thread. An event lasts as long as its completed
+    /// trace, falling back to the session's average operation time.
+    pub fn export_chrome_trace(&self) -> String {
+        let flow_history = &self.session_visualizer.flow_history;
+        let mut sessions: BTreeMap<&str, BTreeMap<&str, usize>> = BTreeMap::new();
+        for event in flow_history {
+            sessions.entry(&event.session_id).or_default().insert(&event.participant, 0);
+        }
+        
+        // Stable process and thread ids in session and participant order
+        let mut trace_events = Vec::new();
+        for (pid, (session_id, participants)) in sessions.iter_mut().enumerate() {
+            trace_events.push(serde_json::json!({
+                "name": "process_name",
+                "ph": "M",
+                "pid": pid,
+                "args": { "name": session_id },
+            }));
+            for (tid, (participant, id)) in participants.iter_mut().enumerate() {
+                *id = tid;
+                trace_events.push(serde_json::json!({
+                    "name": "thread_name",
+                    "ph": "M",
+                    "pid": pid,
+                    "tid": tid,
+                    "args": { "name": participant },
+                }));
+            }
+        }
+        let pids: BTreeMap<&str, usize> = sessions.keys().enumerate().map(|(pid, id)| (*id, pid)).collect();
+        
+        for event in flow_history {
+            let duration_us = self.traces.iter()
+                .find(|trace| {
+                    trace.start_time == event.timestamp
+                        && trace.session_info.as_ref().is_some_and(|info| {
+                            info.session_id == event.session_id && info.participant == event.participant
+                        })
+                })
+                .and_then(|trace| trace.end_time)
+                .map(|end| end.duration_since(event.timestamp).as_micros() as f64)
+                .or_else(|| {
+                    self.session_visualizer.protocol_states.get(&event.session_id)
+                        .map(|state| state.performance_metrics.avg_operation_time_ms * 1000.0)
+                })
+                .unwrap_or(0.0);
+            
+            trace_events.push(serde_json::json!({
+                "name": operation_name(&event.operation),
+                "cat": "session",
+                "ph": "X",
+                "ts": event.timestamp.as_millis() * 1000,
+                "dur": duration_us,
+                "pid": pids[event.session_id.as_str()],
+                "tid": sessions[event.session_id.as_str()][event.participant.as_str()],
+                "args": {
+                    "pre_state": event.pre_state,
+                    "post_state": event.post_state,
+                    "success": event.success,
+                },
+            }));
+        }
+        
+        serde_json::json!({
+            "traceEvents": trace_events,
+            "displayTimeUnit": "ms",
+        }).to_string()
+    }
+}
+
+/// Short name of a session operation for trace event labels
+fn operation_name(operation: &SessionOperation) -> &'static str {
+    match operation {
+        SessionOperation::Send { .. } => "Send",
+        SessionOperation::Receive { .. } => "Receive",
+        SessionOperation::InternalChoice { .. } => "InternalChoice",
+        SessionOperation::ExternalChoice { .. } => "ExternalChoice",
+        SessionOperation::End => "End",
+    }
 }
 
 impl SessionProtocolVisualizer {
@@ -890,6 +974,53 @@ mod tests {
         assert!(matches!(traces[0].status, TraceStatus::Completed));
     }
     
+    #[test]
+    fn test_export_chrome_trace() {
+        use causality_core::lambda::base::{BaseType, TypeInner};
+        
+        let mut hooks = VisualizationHooks::new();
+        let send = SessionOperation::Send {
+            value_type: TypeInner::Base(BaseType::Int),
+            target_participant: "bob".to_string(),
+            value: None,
+        };
+        let receive = SessionOperation::Receive {
+            value_type: TypeInner::Base(BaseType::Int),
+            source_participant: "alice".to_string(),
+            expected_value: None,
+        };
+        
+        hooks.start_session_trace("op1".to_string(), "s1".to_string(), "alice".to_string(), &send, SimulatedTimestamp::from_millis(10));
+        hooks.complete_trace("op1", SimulatedTimestamp::from_millis(15), true, None);
+        hooks.start_session_trace("op2".to_string(), "s1".to_string(), "bob".to_string(), &receive, SimulatedTimestamp::from_millis(15));
+        hooks.complete_trace("op2", SimulatedTimestamp::from_millis(17), true, None);
+        
+        let trace: serde_json::Value = serde_json::from_str(&hooks.export_chrome_trace()).unwrap();
+        let events = trace["traceEvents"].as_array().unwrap();
+        
+        // One process, two named threads, and one complete event per flow event
+        let thread_names: Vec<&str> = events.iter()
+            .filter(|event| event["name"] == "thread_name")
+            .map(|event| event["args"]["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(thread_names, ["alice", "bob"]);
+        assert!(events.iter().any(|event| event["name"] == "process_name" && event["args"]["name"] == "s1"));
+        
+        let complete: Vec<&serde_json::Value> = events.iter().filter(|event| event["ph"] == "X").collect();
+        assert_eq!(complete.len(), 2);
+        assert_eq!(complete[0]["name"], "Send");
+        assert_eq!(complete[0]["ts"], 10_000);
+        assert_eq!(complete[0]["dur"], 5_000.0);
+        assert_eq!(complete[0]["tid"], 0);
+        assert_eq!(complete[1]["name"], "Receive");
+        assert_eq!(complete[1]["dur"], 2_000.0);
+        assert_eq!(complete[1]["tid"], 1);
+        assert_eq!(complete[1]["args"]["post_state"], "Completed");
+        
+        // String outputs are unaffected
+        assert!(hooks.generate_timeline().unwrap().contains("op1"));
+    }
+    
     #[test]
     fn test_graph_visualizer() {
         let mut visualizer = GraphVisualizer::new();
@@ -8,9 +8,12 @@
 
 use anyhow::Result;
 use causality_simulation::{
-    SimulationEngine, SimulationConfig, SimulationState,
+    SimulationEngine, SimulationConfig, SimulationState, SessionParticipantState,
+    SchedulingPolicy, FaultType, SessionFaultConfig, SessionOperationType,
 };
+use causality_core::effect::ExecutionTrace;
 use causality_core::machine::{Instruction, RegisterId};
+use causality_core::lambda::base::{BaseType, SessionType, TypeInner};
 use tokio::test as tokio_test;
 
 #[tokio_test]
@@ -186,6 +189,116 @@ async fn test_deterministic_execution() -> Result<()> {
     Ok(())
 }
 
+/// Run a choice-heavy two-party protocol with random message loss and
+/// return its execution trace
+async fn run_seeded_session_trace(seed: u64) -> Result<ExecutionTrace> {
+    let mut engine = SimulationEngine::with_seed(seed);
+    assert_eq!(engine.seed(), seed);
+    engine.fault_injector_mut().add_targeted_fault("alice", SessionFaultConfig {
+        fault_type: FaultType::SessionMessageLoss { probability: 0.5, preserve_duality: false },
+        target_participants: vec!["alice".to_string()],
+        target_operations: vec![SessionOperationType::Send],
+        probability: 0.5,
+        session_context: None,
+        preserve_protocol_safety: false,
+    })?;
+    
+    // rec X. +{ ping: !Int.X, pong: ?Int.X, stop: end }
+    let int = Box::new(TypeInner::Base(BaseType::Int));
+    let protocol = SessionType::Recursive(
+        "X".to_string(),
+        Box::new(SessionType::InternalChoice(vec![
+            ("ping".to_string(), SessionType::Send(int.clone(), Box::new(SessionType::Variable("X".to_string())))),
+            ("pong".to_string(), SessionType::Receive(int, Box::new(SessionType::Variable("X".to_string())))),
+            ("stop".to_string(), SessionType::End),
+        ])),
+    );
+    for role in ["alice", "bob"] {
+        engine.session_participants.insert(
+            role.to_string(),
+            SessionParticipantState::with_session_type(protocol.clone()),
+        );
+    }
+    
+    let program = (0..32)
+        .map(|i| Instruction::Transform { morph_reg: RegisterId::new(i), input_reg: RegisterId::new(i), output_reg: RegisterId::new(i) })
+        .collect();
+    engine.load_program(program)?;
+    engine.run().await?;
+    
+    Ok(engine.execution_trace())
+}
+
+#[tokio_test]
+async fn test_seeded_engines_produce_identical_traces() -> Result<()> {
+    use ssz::Encode;
+    
+    let first = run_seeded_session_trace(0xC0FFEE).await?;
+    let second = run_seeded_session_trace(0xC0FFEE).await?;
+    
+    assert!(!first.effects.is_empty());
+    assert_eq!(first, second);
+    assert_eq!(
+        first.as_ssz_bytes(),
+        second.as_ssz_bytes(),
+        "same seed must yield byte-identical traces"
+    );
+    
+    Ok(())
+}
+
+/// Run three always-sending participants under `policy` and return the
+/// recorded schedule together with the effects log
+async fn run_scheduled_sessions(policy: SchedulingPolicy) -> Result<(Vec<Vec<String>>, Vec<String>)> {
+    let mut engine = SimulationEngine::with_seed(7);
+    engine.set_scheduling_policy(policy);
+    
+    // rec X. !Int.X
+    let protocol = SessionType::Recursive(
+        "X".to_string(),
+        Box::new(SessionType::Send(
+            Box::new(TypeInner::Base(BaseType::Int)),
+            Box::new(SessionType::Variable("X".to_string())),
+        )),
+    );
+    for role in ["carol", "alice", "bob"] {
+        engine.session_participants.insert(
+            role.to_string(),
+            SessionParticipantState::with_session_type(protocol.clone()),
+        );
+    }
+    
+    let program = (0..6)
+        .map(|i| Instruction::Transform { morph_reg: RegisterId::new(i), input_reg: RegisterId::new(i), output_reg: RegisterId::new(i) })
+        .collect();
+    engine.load_program(program)?;
+    engine.run().await?;
+    
+    Ok((engine.recorded_schedule().to_vec(), engine.effects_log().to_vec()))
+}
+
+#[tokio_test]
+async fn test_round_robin_schedule_interleaving() -> Result<()> {
+    let (schedule, _) = run_scheduled_sessions(SchedulingPolicy::RoundRobin).await?;
+    
+    let round = vec!["alice".to_string(), "bob".to_string(), "carol".to_string()];
+    assert_eq!(schedule, vec![round; 6]);
+    
+    Ok(())
+}
+
+#[tokio_test]
+async fn test_recorded_seeded_schedule_replays_identically() -> Result<()> {
+    let (schedule, effects) = run_scheduled_sessions(SchedulingPolicy::SeededRandom(99)).await?;
+    let (replayed_schedule, replayed_effects) =
+        run_scheduled_sessions(SchedulingPolicy::Replay(schedule.clone())).await?;
+    
+    assert_eq!(schedule, replayed_schedule);
+    assert_eq!(effects, replayed_effects);
+    
+    Ok(())
+}
+
 #[tokio_test]
 async fn test_step_by_step_execution() -> Result<()> {
     println!("=== Testing Step-by-Step Execution ===");
@@ -1,6 +1,7 @@
 //! Mock ZK backend for testing
 
 use crate::{
+    backends::BackendCapabilities,
     error::{ProofResult, VerificationError},
     ZkBackend, ZkCircuit, ZkProof, ZkWitness,
 };
@@ -21,10 +22,18 @@ impl Default for MockConfig {
     }
 }
 
+/// Largest circuit the mock backend accepts by default, in constraints
+pub const MOCK_MAX_CONSTRAINTS: usize = 1 << 20;
+
+/// Size of the fake proofs the mock backend generates, in bytes
+const MOCK_PROOF_SIZE: usize = 8;
+
 /// Mock backend that generates fake proofs for testing
 pub struct MockBackend {
     /// Whether to simulate proof generation success
     success_rate: f64,
+    /// Largest circuit the backend reports it can prove
+    max_constraints: usize,
 }
 
 impl MockBackend {
@@ -32,20 +41,31 @@ impl MockBackend {
     pub fn new() -> Self {
         Self {
             success_rate: 1.0, // Always succeed by default
+            max_constraints: MOCK_MAX_CONSTRAINTS,
         }
     }
 
     /// Create mock backend with specified success rate
     pub fn with_success_rate(success_rate: f64) -> Self {
-        Self { success_rate }
+        Self {
+            success_rate,
+            max_constraints: MOCK_MAX_CONSTRAINTS,
+        }
     }
 
     /// Create mock backend with configuration
     pub fn with_config(config: MockConfig) -> Self {
         Self {
             success_rate: config.success_rate,
+            max_constraints: MOCK_MAX_CONSTRAINTS,
         }
     }
+
+    /// Limit the circuit size the backend reports it can prove
+    pub fn with_max_constraints(mut self, max_constraints: usize) -> Self {
+        self.max_constraints = max_constraints;
+        self
+    }
 }
 
 impl ZkBackend for MockBackend {
@@ -98,6 +118,15 @@ impl ZkBackend for MockBackend {
     fn is_available(&self) -> bool {
         true // Mock backend is always available
     }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            proof_system: "mock".to_string(),
+            max_constraints: self.max_constraints,
+            supports_recursion: false,
+            proof_size: MOCK_PROOF_SIZE,
+        }
+    }
 }
 
 impl Default for MockBackend {
@@ -164,6 +193,39 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_mock_backend_capabilities() {
+        let backend = MockBackend::new();
+        let capabilities = backend.capabilities();
+        assert_eq!(capabilities.proof_system, "mock");
+        assert_eq!(capabilities.max_constraints, MOCK_MAX_CONSTRAINTS);
+        assert!(!capabilities.supports_recursion);
+
+        // The reported proof size matches the proofs the backend generates
+        let circuit = ZkCircuit::new(Vec::new(), Vec::new());
+        let witness = crate::ZkWitness::new(circuit.id.clone(), vec![42], vec![1, 2, 3]);
+        let proof = backend.generate_proof(&circuit, &witness).unwrap();
+        assert_eq!(proof.proof_data.len(), capabilities.proof_size);
+    }
+
+    #[test]
+    fn test_mock_backend_rejects_oversized_circuit() {
+        let backend = MockBackend::new().with_max_constraints(1);
+
+        let transform = Instruction::Transform {
+            morph_reg: RegisterId::new(0),
+            input_reg: RegisterId::new(1),
+            output_reg: RegisterId::new(2),
+        };
+        let small = ZkCircuit::new(vec![transform.clone()], Vec::new());
+        assert!(backend.check_circuit(&small).is_ok());
+
+        let large = ZkCircuit::new(vec![transform.clone(), transform], Vec::new());
+        let err = backend.check_circuit(&large).unwrap_err();
+        assert!(matches!(err, crate::ZkError::UnsupportedCircuit(_)));
+        assert!(err.to_string().contains("at most 1"));
+    }
+
     #[test]
     fn test_mock_backend_with_config() {
         let config = MockConfig { success_rate: 0.8 };
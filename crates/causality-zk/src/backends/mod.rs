@@ -9,7 +9,7 @@ pub mod risc0_backend;
 // Valence backend is always available since it uses HTTP client
 pub mod valence_backend;
 
-use crate::{ZkCircuit, ZkProof, ZkWitness, error::{ProofResult, VerificationError}};
+use crate::{ZkCircuit, ZkProof, ZkWitness, error::{ProofResult, VerificationError, ZkError}};
 
 /// Backend type enum for selecting ZK backend
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -21,6 +21,29 @@ pub enum BackendType {
     Valence, // Uses SP1 internally via Valence coprocessor
 }
 
+/// Features and limits a backend supports, known before it is chosen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendCapabilities {
+    /// Proof system the backend produces proofs in
+    pub proof_system: String,
+    
+    /// Largest circuit, in constraints, the backend can prove
+    pub max_constraints: usize,
+    
+    /// Whether proofs can be verified inside another proof
+    pub supports_recursion: bool,
+    
+    /// Size of a generated proof in bytes
+    pub proof_size: usize,
+}
+
+impl BackendCapabilities {
+    /// Whether a circuit is within these limits
+    pub fn can_handle(&self, circuit: &ZkCircuit) -> bool {
+        circuit.constraint_count() <= self.max_constraints
+    }
+}
+
 /// Trait for ZK proof backends
 pub trait ZkBackend: Send + Sync {
     /// Generate a proof from circuit and witness
@@ -34,6 +57,31 @@ pub trait ZkBackend: Send + Sync {
     
     /// Check if backend is available
     fn is_available(&self) -> bool;
+    
+    /// Report the features and limits of this backend
+    fn capabilities(&self) -> BackendCapabilities;
+    
+    /// Check that this backend can prove a circuit before starting on it
+    fn check_circuit(&self, circuit: &ZkCircuit) -> Result<(), ZkError> {
+        if !self.is_available() {
+            return Err(ZkError::Backend(format!(
+                "Backend {} is not available", self.backend_name()
+            )));
+        }
+        
+        let capabilities = self.capabilities();
+        if !capabilities.can_handle(circuit) {
+            return Err(ZkError::UnsupportedCircuit(format!(
+                "circuit {} needs {} constraints but backend {} supports at most {}",
+                circuit.id,
+                circuit.constraint_count(),
+                self.backend_name(),
+                capabilities.max_constraints,
+            )));
+        }
+        
+        Ok(())
+    }
 }
 
 /// Backend configuration for different backend types
@@ -132,6 +180,15 @@ mod tests {
         assert_eq!(backend.backend_name(), "valence");
     }
     
+    #[test]
+    fn test_valence_backend_capabilities() {
+        let backend = create_backend(BackendType::Valence);
+        let capabilities = backend.capabilities();
+        assert_eq!(capabilities.proof_system, "groth16");
+        assert!(capabilities.supports_recursion);
+        assert!(capabilities.max_constraints > 0);
+    }
+    
     #[cfg(feature = "mock")]
     #[test]
     fn test_mock_backend_creation() {
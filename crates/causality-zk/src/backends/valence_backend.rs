@@ -413,6 +413,9 @@ impl ValenceBackend {
     }
 }
 
+/// Largest circuit the Valence coprocessor accepts, in constraints
+pub const VALENCE_MAX_CONSTRAINTS: usize = 1 << 24;
+
 impl crate::backends::ZkBackend for ValenceBackend {
     fn generate_proof(&self, circuit: &ZkCircuit, witness: &ZkWitness) -> crate::error::ProofResult<ZkProof> {
         // Create a simple mock proof for now since we don't have a real coprocessor running
@@ -470,6 +473,17 @@ impl crate::backends::ZkBackend for ValenceBackend {
     fn is_available(&self) -> bool {
         true
     }
+    
+    fn capabilities(&self) -> crate::backends::BackendCapabilities {
+        // SP1 wraps its STARK proofs in a Groth16 proof, which is what the
+        // coprocessor returns
+        crate::backends::BackendCapabilities {
+            proof_system: "groth16".to_string(),
+            max_constraints: VALENCE_MAX_CONSTRAINTS,
+            supports_recursion: true,
+            proof_size: 32,
+        }
+    }
 }
 
 impl Clone for ValenceBackend {
@@ -1,7 +1,8 @@
 //! Zero-knowledge circuit compilation module.
 
 use serde::{Serialize, Deserialize};
-use crate::error::ZkError;
+use crate::{error::ZkError, CircuitId};
+use causality_core::machine::instruction::{Instruction, RegisterId};
 use std::collections::BTreeMap;
 
 /// Zero-knowledge circuit representation
@@ -56,6 +57,70 @@ pub struct CircuitMetadata {
     pub target_proof_system: String,
 }
 
+/// Statistics from compiling register machine instructions to constraints
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompilationStats {
+    /// Constraints generated, one per instruction
+    pub constraints_generated: usize,
+    /// Constraints emitted into the circuit
+    pub constraints_emitted: usize,
+    /// Structurally identical constraints that were emitted once
+    pub duplicates_eliminated: usize,
+}
+
+/// Compiled circuits keyed by their content id
+///
+/// Identical instruction streams with identical public inputs hash to the
+/// same id, so a circuit only needs to be compiled once per program.
+#[derive(Debug, Clone, Default)]
+pub struct CircuitCache {
+    circuits: BTreeMap<CircuitId, crate::ZkCircuit>,
+    hits: usize,
+    misses: usize,
+}
+
+impl CircuitCache {
+    /// Create a new empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Insert a compiled circuit under its content id
+    pub fn insert(&mut self, circuit: crate::ZkCircuit) {
+        self.circuits.insert(circuit.id.clone(), circuit);
+    }
+    
+    /// Retrieve a circuit by content id
+    pub fn get(&self, id: &CircuitId) -> Option<&crate::ZkCircuit> {
+        self.circuits.get(id)
+    }
+    
+    /// Check if a circuit exists in the cache
+    pub fn contains(&self, id: &CircuitId) -> bool {
+        self.circuits.contains_key(id)
+    }
+    
+    /// Get cache statistics
+    pub fn stats(&self) -> CircuitCacheStats {
+        CircuitCacheStats {
+            entries: self.circuits.len(),
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+}
+
+/// Circuit cache statistics
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitCacheStats {
+    /// Circuits held in the cache
+    pub entries: usize,
+    /// Lookups answered from the cache
+    pub hits: usize,
+    /// Lookups that required compilation
+    pub misses: usize,
+}
+
 /// Circuit compiler for converting programs to ZK circuits
 #[derive(Debug, Clone)]
 pub struct CircuitCompiler {
@@ -63,6 +128,8 @@ pub struct CircuitCompiler {
     config: CompilerConfig,
     /// Circuit optimization passes
     optimization_passes: Vec<OptimizationPass>,
+    /// Circuits compiled by `compile_cached`
+    cache: CircuitCache,
 }
 
 /// Configuration for circuit compilation
@@ -108,6 +175,7 @@ impl CircuitCompiler {
                 OptimizationPass { name: "dead_code_elimination".to_string(), enabled: true },
                 OptimizationPass { name: "gate_merging".to_string(), enabled: true },
             ],
+            cache: CircuitCache::new(),
         }
     }
     
@@ -141,6 +209,148 @@ impl CircuitCompiler {
         Ok(circuit)
     }
     
+    /// Compile register machine instructions to a circuit with one constraint per instruction
+    ///
+    /// With optimization enabled, constraints that compute the same operation
+    /// on the same inputs are emitted once and later reads of the duplicate's
+    /// output are redirected to the first result.
+    pub fn compile_instructions(
+        &self,
+        instructions: &[Instruction],
+        public_inputs: Vec<u32>,
+    ) -> Result<(crate::ZkCircuit, CompilationStats), ZkError> {
+        let constraints = if self.config.optimization_level > 0 {
+            Self::deduplicate_constraints(instructions)
+        } else {
+            instructions
+                .iter()
+                .map(|instruction| Self::constraint(instruction, &instruction.reads()))
+                .collect()
+        };
+        
+        if constraints.len() > self.config.max_circuit_size {
+            return Err(ZkError::CircuitTooLarge(constraints.len(), self.config.max_circuit_size));
+        }
+        
+        let stats = CompilationStats {
+            constraints_generated: instructions.len(),
+            constraints_emitted: constraints.len(),
+            duplicates_eliminated: instructions.len() - constraints.len(),
+        };
+        
+        let mut circuit = crate::ZkCircuit::new(instructions.to_vec(), public_inputs);
+        circuit.constraints = constraints;
+        
+        Ok((circuit, stats))
+    }
+    
+    /// Compile instructions, reusing a previously compiled circuit with the same content id
+    ///
+    /// The content id covers the instructions and public inputs, so it can be
+    /// computed without compiling; only a cache miss runs `compile_instructions`.
+    pub fn compile_cached(
+        &mut self,
+        instructions: &[Instruction],
+        public_inputs: Vec<u32>,
+    ) -> Result<crate::ZkCircuit, ZkError> {
+        let id = crate::ZkCircuit::new(instructions.to_vec(), public_inputs.clone()).id;
+        if let Some(circuit) = self.cache.get(&id) {
+            let circuit = circuit.clone();
+            self.cache.hits += 1;
+            return Ok(circuit);
+        }
+        
+        let (circuit, _) = self.compile_instructions(instructions, public_inputs)?;
+        self.cache.misses += 1;
+        self.cache.insert(circuit.clone());
+        Ok(circuit)
+    }
+    
+    /// Circuits compiled so far by `compile_cached`
+    pub fn cache(&self) -> &CircuitCache {
+        &self.cache
+    }
+    
+    /// Emit one constraint per distinct instruction, keyed on its content hash
+    ///
+    /// The hash covers the operation and its (already redirected) operands but
+    /// not the output register, which only names the result. Only registers
+    /// written once are merged, so a redirected read never sees a later
+    /// overwrite. `Alloc` and `Consume` create or destroy a distinct resource
+    /// each time and are always kept.
+    fn deduplicate_constraints(instructions: &[Instruction]) -> Vec<String> {
+        use sha2::{Digest, Sha256};
+        
+        let mut write_counts: BTreeMap<RegisterId, usize> = BTreeMap::new();
+        for instruction in instructions {
+            for reg in instruction.writes() {
+                *write_counts.entry(reg).or_default() += 1;
+            }
+        }
+        let written_once = |reg: &RegisterId| write_counts.get(reg).copied().unwrap_or(0) <= 1;
+        
+        let mut redirects: BTreeMap<RegisterId, RegisterId> = BTreeMap::new();
+        let mut seen: BTreeMap<[u8; 32], RegisterId> = BTreeMap::new();
+        let mut constraints = Vec::new();
+        
+        for instruction in instructions {
+            let operands: Vec<RegisterId> = instruction
+                .reads()
+                .into_iter()
+                .map(|reg| redirects.get(&reg).copied().unwrap_or(reg))
+                .collect();
+            let output = instruction.writes()[0];
+            
+            let mergeable = matches!(
+                instruction,
+                Instruction::Transform { .. } | Instruction::Compose { .. } | Instruction::Tensor { .. }
+            ) && written_once(&output)
+                && operands.iter().all(written_once);
+            
+            if mergeable {
+                let key = format!("{}({})", Self::mnemonic(instruction), Self::register_list(&operands));
+                let hash: [u8; 32] = Sha256::digest(key.as_bytes()).into();
+                if let Some(&first) = seen.get(&hash) {
+                    redirects.insert(output, first);
+                    continue;
+                }
+                seen.insert(hash, output);
+            }
+            
+            constraints.push(Self::constraint(instruction, &operands));
+        }
+        
+        constraints
+    }
+    
+    /// Render the constraint for an instruction reading `operands`
+    fn constraint(instruction: &Instruction, operands: &[RegisterId]) -> String {
+        format!(
+            "{}({}) -> r{}",
+            Self::mnemonic(instruction),
+            Self::register_list(operands),
+            instruction.writes()[0].id()
+        )
+    }
+    
+    fn register_list(registers: &[RegisterId]) -> String {
+        registers
+            .iter()
+            .map(|reg| format!("r{}", reg.id()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+    
+    fn mnemonic(instruction: &Instruction) -> &'static str {
+        match instruction {
+            Instruction::Transform { .. } => "transform",
+            Instruction::Alloc { .. } => "alloc",
+            Instruction::Consume { .. } => "consume",
+            Instruction::Compose { .. } => "compose",
+            Instruction::Tensor { .. } => "tensor",
+        }
+    }
+    
     /// Parse a program (mock implementation)
     fn parse_program(&self, program: &str) -> Result<ParsedProgram, ZkError> {
         // Mock parsing logic
@@ -380,4 +590,71 @@ impl Default for CircuitCompiler {
     fn default() -> Self {
         Self::new()
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// `(x + x) + (x + x)` with `x` in r1 and the addition morphism in r0
+    fn doubled_sum() -> Vec<Instruction> {
+        let r = RegisterId::new;
+        vec![
+            Instruction::Tensor { left_reg: r(1), right_reg: r(1), output_reg: r(2) },
+            Instruction::Transform { morph_reg: r(0), input_reg: r(2), output_reg: r(3) },
+            Instruction::Tensor { left_reg: r(1), right_reg: r(1), output_reg: r(4) },
+            Instruction::Transform { morph_reg: r(0), input_reg: r(4), output_reg: r(5) },
+            Instruction::Tensor { left_reg: r(3), right_reg: r(5), output_reg: r(6) },
+            Instruction::Transform { morph_reg: r(0), input_reg: r(6), output_reg: r(7) },
+        ]
+    }
+    
+    #[test]
+    fn test_constraint_deduplication() {
+        let unoptimized = CircuitCompiler::with_config(CompilerConfig {
+            optimization_level: 0,
+            ..CompilerConfig::default()
+        });
+        let (circuit, stats) = unoptimized.compile_instructions(&doubled_sum(), vec![]).unwrap();
+        assert_eq!(circuit.constraints.len(), 6);
+        assert_eq!(circuit.constraint_count(), 6);
+        assert_eq!(stats.duplicates_eliminated, 0);
+        
+        let (circuit, stats) = CircuitCompiler::new().compile_instructions(&doubled_sum(), vec![]).unwrap();
+        assert_eq!(stats.constraints_generated, 6);
+        assert_eq!(stats.constraints_emitted, 4);
+        assert_eq!(stats.duplicates_eliminated, 2);
+        // Backends size the circuit by its deduplicated constraints
+        assert_eq!(circuit.constraint_count(), 4);
+        
+        // The outer sum reads the shared `x + x` result twice
+        assert_eq!(circuit.constraints[2], "tensor(r3, r3) -> r6");
+    }
+    
+    #[test]
+    fn test_deduplication_keeps_allocations() {
+        let r = RegisterId::new;
+        let alloc = |output| Instruction::Alloc { type_reg: r(0), init_reg: r(1), output_reg: r(output) };
+        
+        let (circuit, stats) = CircuitCompiler::new().compile_instructions(&[alloc(2), alloc(3)], vec![]).unwrap();
+        assert_eq!(circuit.constraints.len(), 2);
+        assert_eq!(stats.duplicates_eliminated, 0);
+    }
+    
+    #[test]
+    fn test_compile_cached_reuses_circuit() {
+        let mut compiler = CircuitCompiler::new();
+        
+        let first = compiler.compile_cached(&doubled_sum(), vec![1]).unwrap();
+        assert_eq!(compiler.cache().stats(), CircuitCacheStats { entries: 1, hits: 0, misses: 1 });
+        
+        let second = compiler.compile_cached(&doubled_sum(), vec![1]).unwrap();
+        assert_eq!(second, first);
+        assert_eq!(compiler.cache().stats(), CircuitCacheStats { entries: 1, hits: 1, misses: 1 });
+        
+        // Different public inputs give a different content id
+        compiler.compile_cached(&doubled_sum(), vec![2]).unwrap();
+        assert_eq!(compiler.cache().stats().entries, 2);
+        assert_eq!(compiler.cache().stats().misses, 2);
+    }
+}
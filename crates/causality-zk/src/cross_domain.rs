@@ -4,6 +4,8 @@
 //! computational domains with different resource constraints and requirements.
 
 use crate::{ZkBackend, ZkCircuit, ZkProof, ZkWitness, ProofResult, ProofError};
+use crate::backends::BackendCapabilities;
+use crate::verification::VerificationKey;
 use causality_core::machine::instruction::Instruction;
 use causality_core::lambda::base::Location;
 use causality_core::system::serialization::SszEncode;
@@ -32,6 +34,10 @@ pub struct DomainProof {
     
     /// Dependencies on other domains
     pub dependencies: Vec<DomainId>,
+    
+    /// Partition strategy that assigned this proof's domain
+    #[serde(default)]
+    pub partition: DomainPartition,
 }
 
 /// Composite proof that combines multiple domain proofs
@@ -53,6 +59,35 @@ pub struct CompositeProof {
     pub timestamp: String,
 }
 
+/// Domain proof included in an aggregate, with the partition that produced it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregatedMember {
+    /// The aggregated domain proof
+    pub domain_proof: DomainProof,
+    
+    /// Partition strategy the proof's domain came from
+    pub partition: DomainPartition,
+}
+
+/// Independent domain proofs composed under a single verification key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregatedProof {
+    /// Unique identifier for this aggregate
+    pub id: String,
+    
+    /// Aggregated proofs, in the order they were given
+    pub members: Vec<AggregatedMember>,
+    
+    /// Commitment binding every member proof
+    pub commitment: Vec<u8>,
+    
+    /// Verification key covering all member circuits
+    pub verification_key: VerificationKey,
+    
+    /// Creation timestamp
+    pub timestamp: String,
+}
+
 /// Domain partition strategy for splitting computations
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[derive(Default)]
@@ -115,6 +150,15 @@ impl ZkBackend for MockBackend {
     fn is_available(&self) -> bool {
         true
     }
+    
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            proof_system: "mock".to_string(),
+            max_constraints: usize::MAX,
+            supports_recursion: false,
+            proof_size: 4,
+        }
+    }
 }
 
 /// Cross-domain zero-knowledge coordination manager
@@ -123,7 +167,6 @@ pub struct CrossDomainZkManager {
     backends: BTreeMap<DomainId, Box<dyn ZkBackend>>,
     
     /// Cross-domain proof aggregation
-    #[allow(dead_code)]
     aggregator: ProofAggregator,
     
     /// Verification coordination
@@ -166,6 +209,35 @@ impl CrossDomainZkManager {
         self.backends.insert(domain_id, backend);
     }
     
+    /// Backend registered for a domain, if it can prove `circuit`
+    pub fn backend_for(&self, domain_id: &DomainId, circuit: &ZkCircuit) -> Result<&dyn ZkBackend, crate::error::ZkError> {
+        let backend = self.backends.get(domain_id).ok_or_else(|| {
+            crate::error::ZkError::Backend(format!("No backend registered for domain: {}", domain_id))
+        })?;
+        backend.check_circuit(circuit)?;
+        Ok(backend.as_ref())
+    }
+    
+    /// Pick a registered backend that can prove `circuit`
+    ///
+    /// Backends are tried in domain order. When none can handle the circuit
+    /// the error lists why each was rejected.
+    pub fn select_backend(&self, circuit: &ZkCircuit) -> Result<(&DomainId, &dyn ZkBackend), crate::error::ZkError> {
+        let mut rejections = Vec::new();
+        for (domain_id, backend) in &self.backends {
+            match backend.check_circuit(circuit) {
+                Ok(()) => return Ok((domain_id, backend.as_ref())),
+                Err(e) => rejections.push(format!("{}: {}", domain_id, e)),
+            }
+        }
+        
+        Err(crate::error::ZkError::UnsupportedCircuit(if rejections.is_empty() {
+            format!("no backends registered for circuit {}", circuit.id)
+        } else {
+            format!("no registered backend can prove circuit {} ({})", circuit.id, rejections.join("; "))
+        }))
+    }
+    
     /// Partition instructions across domains
     pub fn partition_instructions(&self, instructions: &[Instruction]) -> BTreeMap<DomainId, Vec<Instruction>> {
         let mut partitions = BTreeMap::new();
@@ -254,8 +326,10 @@ impl CrossDomainZkManager {
                 global_witness.execution_trace.clone(),
             );
             
-            // Generate proof for this domain
-            if let Some(backend) = self.backends.get(&domain_id) {
+            // Generate proof for this domain, once its backend is known to handle the circuit
+            if self.backends.contains_key(&domain_id) {
+                let backend = self.backend_for(&domain_id, &circuit)
+                    .map_err(|e| ProofError::InsufficientResources(e.to_string()))?;
                 let proof = backend.generate_proof(&circuit, &witness)?;
                 
                 let domain_proof = DomainProof {
@@ -264,6 +338,7 @@ impl CrossDomainZkManager {
                     interface_constraints: vec!["cross_domain_consistency".to_string()],
                     public_outputs: vec![0u8; 32], // Placeholder
                     dependencies: vec![], // Would be computed from instruction dependencies
+                    partition: self.partition_strategy.clone(),
                 };
                 
                 domain_proofs.insert(domain_id, domain_proof);
@@ -361,6 +436,67 @@ impl CrossDomainZkManager {
         true // Placeholder
     }
     
+    /// Aggregate independent domain proofs into one proof
+    ///
+    /// Every member must verify with its domain's backend; each is recorded
+    /// with the partition strategy that produced it.
+    pub fn aggregate(&self, proofs: Vec<DomainProof>) -> Result<AggregatedProof, crate::error::ZkError> {
+        for domain_proof in &proofs {
+            if !self.verify_domain_proof(domain_proof)? {
+                return Err(crate::error::ZkError::InvalidProof(format!(
+                    "Proof {} for domain {} does not verify",
+                    domain_proof.proof.id, domain_proof.domain_id
+                )));
+            }
+        }
+        
+        let members = proofs
+            .into_iter()
+            .map(|domain_proof| AggregatedMember {
+                partition: domain_proof.partition.clone(),
+                domain_proof,
+            })
+            .collect();
+        self.aggregator.aggregate(members)
+    }
+    
+    /// Verify an aggregated proof
+    ///
+    /// Succeeds only if the commitment, id and verification key all match
+    /// the members and every member proof verifies.
+    pub fn verify_aggregate(&self, aggregate: &AggregatedProof) -> Result<bool, crate::error::VerificationError> {
+        let commitment = ProofAggregator::commitment(&aggregate.members);
+        if commitment != aggregate.commitment
+            || ProofAggregator::aggregate_id(&commitment) != aggregate.id
+            || ProofAggregator::verification_key(&aggregate.members, &commitment) != aggregate.verification_key
+        {
+            return Ok(false);
+        }
+        
+        for member in &aggregate.members {
+            let verified = self.verify_domain_proof(&member.domain_proof)
+                .map_err(|e| crate::error::VerificationError::BackendError(e.to_string()))?;
+            if !verified {
+                return Ok(false);
+            }
+        }
+        
+        Ok(true)
+    }
+    
+    /// Check a domain proof's integrity and verify it with its domain's backend
+    fn verify_domain_proof(&self, domain_proof: &DomainProof) -> Result<bool, crate::error::ZkError> {
+        let proof = &domain_proof.proof;
+        if proof.id != proof.compute_content_id() {
+            return Ok(false);
+        }
+        
+        let backend = self.backends.get(&domain_proof.domain_id).ok_or_else(|| {
+            crate::error::ZkError::Backend(format!("No backend available for domain: {}", domain_proof.domain_id))
+        })?;
+        Ok(backend.verify_proof(proof, &[0i64])?)
+    }
+    
     /// Coordinate cross-domain proof generation and verification
     pub async fn coordinate_cross_domain_proof(
         &mut self,
@@ -403,6 +539,7 @@ impl CrossDomainZkManager {
                 ],
                 public_outputs: vec![0u8; 32], // Mock public outputs
                 dependencies: vec![], // No dependencies for mock implementation
+                partition: self.partition_strategy.clone(),
             };
             
             domain_proofs.insert(domain_id, domain_proof);
@@ -520,7 +657,6 @@ pub struct DomainCoordinationResult {
 #[derive(Debug, Clone)]
 pub struct ProofAggregator {
     /// Maximum proofs per batch
-    #[allow(dead_code)]
     max_batch_size: usize,
 }
 
@@ -536,6 +672,66 @@ impl ProofAggregator {
             max_batch_size: 1000,
         }
     }
+    
+    /// Compose members under one verification key
+    pub fn aggregate(&self, members: Vec<AggregatedMember>) -> Result<AggregatedProof, crate::error::ZkError> {
+        if members.is_empty() {
+            return Err(crate::error::ZkError::InvalidInputs("No proofs to aggregate".to_string()));
+        }
+        if members.len() > self.max_batch_size {
+            return Err(crate::error::ZkError::InvalidInputs(format!(
+                "Cannot aggregate {} proofs (max: {})",
+                members.len(),
+                self.max_batch_size
+            )));
+        }
+        
+        let commitment = Self::commitment(&members);
+        Ok(AggregatedProof {
+            id: Self::aggregate_id(&commitment),
+            verification_key: Self::verification_key(&members, &commitment),
+            members,
+            commitment,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+    
+    /// Identifier of the aggregate with the given member commitment
+    pub fn aggregate_id(commitment: &[u8]) -> String {
+        format!("aggregate_{}", hex::encode(&commitment[..8.min(commitment.len())]))
+    }
+    
+    /// Verification key covering the members' circuits, keyed by their commitment
+    pub fn verification_key(members: &[AggregatedMember], commitment: &[u8]) -> VerificationKey {
+        let mut hasher = Sha256::new();
+        for member in members {
+            hasher.update(member.domain_proof.proof.verification_key.circuit_hash.as_bytes());
+        }
+        VerificationKey {
+            key_data: commitment
+                .chunks_exact(4)
+                .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                .collect(),
+            circuit_hash: hex::encode(hasher.finalize()),
+            proof_system: "aggregate".to_string(),
+        }
+    }
+    
+    /// Commitment over the domain, partition and content of every member
+    pub fn commitment(members: &[AggregatedMember]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        for member in members {
+            let domain_proof = &member.domain_proof;
+            let mut domain_bytes = Vec::new();
+            domain_proof.domain_id.ssz_append(&mut domain_bytes);
+            hasher.update(&domain_bytes);
+            hasher.update(format!("{:?}", member.partition).as_bytes());
+            hasher.update(domain_proof.proof.id.as_bytes());
+            hasher.update(&domain_proof.proof.proof_data);
+            hasher.update(&domain_proof.public_outputs);
+        }
+        hasher.finalize().to_vec()
+    }
 }
 
 /// Verification coordination manager
@@ -663,4 +859,105 @@ mod tests {
         
         println!(" Cross-domain proof generation setup completed successfully");
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_select_backend_by_capabilities() {
+        use crate::backends::mock_backend;
+        
+        let mut manager = CrossDomainZkManager::new();
+        let small = Location::Domain("small".to_string());
+        let large = Location::Domain("large".to_string());
+        manager.register_backend(small.clone(), Box::new(mock_backend::MockBackend::new().with_max_constraints(1)));
+        
+        let transform = Instruction::Transform {
+            morph_reg: causality_core::machine::RegisterId(1),
+            input_reg: causality_core::machine::RegisterId(2),
+            output_reg: causality_core::machine::RegisterId(3),
+        };
+        let circuit = ZkCircuit::new(vec![transform.clone(), transform], vec![]);
+        
+        // The only backend is too small, and the error says why
+        let err = manager.select_backend(&circuit).err().unwrap();
+        assert!(matches!(err, crate::error::ZkError::UnsupportedCircuit(_)));
+        assert!(err.to_string().contains("small"));
+        assert!(manager.backend_for(&small, &circuit).is_err());
+        
+        manager.register_backend(large.clone(), create_backend(crate::BackendType::Mock));
+        let (domain_id, backend) = manager.select_backend(&circuit).unwrap();
+        assert_eq!(domain_id, &large);
+        assert!(backend.capabilities().max_constraints >= circuit.constraint_count());
+    }
+    
+    fn aggregation_manager() -> (CrossDomainZkManager, Vec<DomainProof>) {
+        let mut manager = CrossDomainZkManager::new();
+        let proofs = ["resource", "computation"]
+            .iter()
+            .map(|name| {
+                let domain_id = Location::Domain(name.to_string());
+                manager.register_backend(domain_id.clone(), create_backend(crate::BackendType::Mock));
+                DomainProof {
+                    domain_id,
+                    proof: ZkProof::new(format!("circuit_{}", name), vec![1, 2, 3, 4], vec![5, 6]),
+                    interface_constraints: vec!["cross_domain_consistency".to_string()],
+                    public_outputs: vec![0u8; 32],
+                    dependencies: vec![],
+                    partition: DomainPartition::ByEffectType,
+                }
+            })
+            .collect();
+        (manager, proofs)
+    }
+    
+    #[test]
+    fn test_aggregate_domain_proofs() {
+        let (manager, proofs) = aggregation_manager();
+        
+        let aggregate = manager.aggregate(proofs).unwrap();
+        assert_eq!(aggregate.members.len(), 2);
+        assert_eq!(aggregate.verification_key.proof_system, "aggregate");
+        assert!(aggregate.members.iter().all(|m| m.partition == DomainPartition::ByEffectType));
+        assert!(manager.verify_aggregate(&aggregate).unwrap());
+    }
+    
+    #[test]
+    fn test_aggregate_rejects_tampered_member() {
+        let (manager, proofs) = aggregation_manager();
+        let mut aggregate = manager.aggregate(proofs.clone()).unwrap();
+        
+        aggregate.members[1].domain_proof.proof.proof_data[0] ^= 0xFF;
+        assert!(!manager.verify_aggregate(&aggregate).unwrap());
+        
+        // A tampered proof cannot be aggregated in the first place
+        let mut tampered = proofs;
+        tampered[0].proof.proof_data.push(0);
+        assert!(manager.aggregate(tampered).is_err());
+    }
+    
+    #[test]
+    fn test_aggregate_rejects_mismatched_key_or_id() {
+        let (manager, proofs) = aggregation_manager();
+        let aggregate = manager.aggregate(proofs.clone()).unwrap();
+        let other = manager.aggregate(proofs[..1].to_vec()).unwrap();
+        
+        let mut wrong_key = aggregate.clone();
+        wrong_key.verification_key = other.verification_key.clone();
+        assert!(!manager.verify_aggregate(&wrong_key).unwrap());
+        
+        let mut wrong_id = aggregate.clone();
+        wrong_id.id = other.id.clone();
+        assert!(!manager.verify_aggregate(&wrong_id).unwrap());
+        
+        assert!(manager.verify_aggregate(&aggregate).unwrap());
+    }
+    
+    #[test]
+    fn test_aggregate_keeps_member_partitions() {
+        let (manager, mut proofs) = aggregation_manager();
+        proofs[1].partition = DomainPartition::ByComplexity;
+        
+        let aggregate = manager.aggregate(proofs).unwrap();
+        assert_eq!(aggregate.members[0].partition, DomainPartition::ByEffectType);
+        assert_eq!(aggregate.members[1].partition, DomainPartition::ByComplexity);
+        assert!(manager.verify_aggregate(&aggregate).unwrap());
+    }
+}
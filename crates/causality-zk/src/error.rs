@@ -44,6 +44,9 @@ pub enum ZkError {
     #[error("Unsupported operation: {0}")]
     UnsupportedOperation(String),
     
+    #[error("Unsupported circuit: {0}")]
+    UnsupportedCircuit(String),
+    
     #[error("Invalid witness: {0}")]
     InvalidWitness(String),
     
@@ -205,4 +208,10 @@ impl From<ZkError> for ProofError {
             _ => ProofError::GenerationFailed(format!("ZK error: {}", err)),
         }
     }
+}
+
+impl From<WitnessError> for ProofError {
+    fn from(err: WitnessError) -> Self {
+        ProofError::InvalidWitness(err.to_string())
+    }
 } 
\ No newline at end of file
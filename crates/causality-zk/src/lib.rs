@@ -25,7 +25,7 @@ pub mod verification;
 pub mod backends;
 
 // Core ZK types and utilities
-pub use backends::{BackendType, ZkBackend};
+pub use backends::{BackendCapabilities, BackendType, ZkBackend};
 pub use circuit::*;
 pub use cross_domain::*;
 pub use error::*;
@@ -33,7 +33,7 @@ pub use proof_generation::*;
 pub use verification::*;
 
 use causality_core::lambda::base::Value;
-use causality_core::machine::instruction::Instruction;
+use causality_core::machine::instruction::{Instruction, RegisterId};
 use serde::{Deserialize, Serialize};
 use std::str;
 
@@ -110,6 +110,137 @@ pub enum VMInstruction {
     LoadImmediate(Value),
 }
 
+/// Narrow a register machine register to the VM's 8-bit register space
+fn vm_register(reg: RegisterId) -> Result<u8, CircuitError> {
+    u8::try_from(reg.id()).map_err(|_| {
+        CircuitError::InvalidInstructions(format!(
+            "Register r{} does not fit in a VM register (max r{})",
+            reg.id(),
+            u8::MAX
+        ))
+    })
+}
+
+/// Converts a register machine instruction to its VM form.
+///
+/// The five core instructions map field for field; the conversion fails if
+/// any register is above 255, since VM registers are `u8`.
+impl TryFrom<Instruction> for VMInstruction {
+    type Error = CircuitError;
+
+    fn try_from(instruction: Instruction) -> Result<Self, Self::Error> {
+        Ok(match instruction {
+            Instruction::Transform {
+                morph_reg,
+                input_reg,
+                output_reg,
+            } => VMInstruction::Transform {
+                morph_reg: vm_register(morph_reg)?,
+                input_reg: vm_register(input_reg)?,
+                output_reg: vm_register(output_reg)?,
+            },
+            Instruction::Alloc {
+                type_reg,
+                init_reg,
+                output_reg,
+            } => VMInstruction::Alloc {
+                type_reg: vm_register(type_reg)?,
+                init_reg: vm_register(init_reg)?,
+                output_reg: vm_register(output_reg)?,
+            },
+            Instruction::Consume {
+                resource_reg,
+                output_reg,
+            } => VMInstruction::Consume {
+                resource_reg: vm_register(resource_reg)?,
+                output_reg: vm_register(output_reg)?,
+            },
+            Instruction::Compose {
+                first_reg,
+                second_reg,
+                output_reg,
+            } => VMInstruction::Compose {
+                first_reg: vm_register(first_reg)?,
+                second_reg: vm_register(second_reg)?,
+                output_reg: vm_register(output_reg)?,
+            },
+            Instruction::Tensor {
+                left_reg,
+                right_reg,
+                output_reg,
+            } => VMInstruction::Tensor {
+                left_reg: vm_register(left_reg)?,
+                right_reg: vm_register(right_reg)?,
+                output_reg: vm_register(output_reg)?,
+            },
+        })
+    }
+}
+
+/// Converts a VM instruction back to the register machine.
+///
+/// Widening `u8` registers to `RegisterId` is lossless, so every core
+/// instruction round-trips. `LoadImmediate` has no register machine
+/// counterpart and is rejected.
+impl TryFrom<VMInstruction> for Instruction {
+    type Error = CircuitError;
+
+    fn try_from(instruction: VMInstruction) -> Result<Self, Self::Error> {
+        let reg = |r: u8| RegisterId::new(r as u32);
+        Ok(match instruction {
+            VMInstruction::Transform {
+                morph_reg,
+                input_reg,
+                output_reg,
+            } => Instruction::Transform {
+                morph_reg: reg(morph_reg),
+                input_reg: reg(input_reg),
+                output_reg: reg(output_reg),
+            },
+            VMInstruction::Alloc {
+                type_reg,
+                init_reg,
+                output_reg,
+            } => Instruction::Alloc {
+                type_reg: reg(type_reg),
+                init_reg: reg(init_reg),
+                output_reg: reg(output_reg),
+            },
+            VMInstruction::Consume {
+                resource_reg,
+                output_reg,
+            } => Instruction::Consume {
+                resource_reg: reg(resource_reg),
+                output_reg: reg(output_reg),
+            },
+            VMInstruction::Compose {
+                first_reg,
+                second_reg,
+                output_reg,
+            } => Instruction::Compose {
+                first_reg: reg(first_reg),
+                second_reg: reg(second_reg),
+                output_reg: reg(output_reg),
+            },
+            VMInstruction::Tensor {
+                left_reg,
+                right_reg,
+                output_reg,
+            } => Instruction::Tensor {
+                left_reg: reg(left_reg),
+                right_reg: reg(right_reg),
+                output_reg: reg(output_reg),
+            },
+            VMInstruction::LoadImmediate(value) => {
+                return Err(CircuitError::UnsupportedInstruction(format!(
+                    "LoadImmediate({:?}) has no register machine equivalent",
+                    value
+                )))
+            }
+        })
+    }
+}
+
 impl ZkCircuit {
     /// Create a new ZK circuit from register machine instructions
     pub fn new(instructions: Vec<Instruction>, public_inputs: Vec<u32>) -> Self {
@@ -129,6 +260,19 @@ impl ZkCircuit {
         circuit
     }
 
+    /// Number of constraints the circuit needs
+    ///
+    /// Once the compiler has filled in `constraints` this is their number,
+    /// after any deduplication; until then each instruction counts as one
+    /// constraint.
+    pub fn constraint_count(&self) -> usize {
+        if self.constraints.is_empty() {
+            self.instructions.len()
+        } else {
+            self.constraints.len()
+        }
+    }
+
     /// Compute a content-based identifier for this circuit
     pub fn compute_content_id(&self) -> String {
         use sha2::{Digest, Sha256};
@@ -152,7 +296,6 @@ impl ZkCircuit {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use causality_core::machine::instruction::{Instruction, RegisterId};
 
     #[test]
     fn test_zk_circuit_creation() {
@@ -204,6 +347,63 @@ mod tests {
         assert_eq!(witness.private_inputs.len(), 3);
         assert_ne!(witness.id, String::new());
     }
+
+    #[test]
+    fn test_vm_instruction_round_trip() {
+        let r = RegisterId::new;
+        let instructions = vec![
+            Instruction::Transform {
+                morph_reg: r(0),
+                input_reg: r(1),
+                output_reg: r(2),
+            },
+            Instruction::Alloc {
+                type_reg: r(3),
+                init_reg: r(4),
+                output_reg: r(5),
+            },
+            Instruction::Consume {
+                resource_reg: r(5),
+                output_reg: r(6),
+            },
+            Instruction::Compose {
+                first_reg: r(7),
+                second_reg: r(8),
+                output_reg: r(9),
+            },
+            Instruction::Tensor {
+                left_reg: r(10),
+                right_reg: r(11),
+                output_reg: r(255),
+            },
+        ];
+
+        for instruction in instructions {
+            let vm = VMInstruction::try_from(instruction.clone())
+                .expect("core instruction converts to VM form");
+            let back =
+                Instruction::try_from(vm).expect("VM instruction converts back");
+            assert_eq!(back, instruction);
+        }
+    }
+
+    #[test]
+    fn test_vm_instruction_rejects_wide_registers() {
+        let instruction = Instruction::Consume {
+            resource_reg: RegisterId::new(256),
+            output_reg: RegisterId::new(0),
+        };
+        assert!(matches!(
+            VMInstruction::try_from(instruction),
+            Err(CircuitError::InvalidInstructions(_))
+        ));
+
+        let load = VMInstruction::LoadImmediate(Value::Int(42));
+        assert!(matches!(
+            Instruction::try_from(load),
+            Err(CircuitError::UnsupportedInstruction(_))
+        ));
+    }
 }
 
 #[cfg(test)]
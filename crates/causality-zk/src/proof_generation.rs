@@ -1,8 +1,9 @@
 //! Zero-knowledge proof generation module.
 
-use crate::{error::ProofResult, circuit::ZkCircuit, verification::VerificationKey};
+use crate::{error::{ProofResult, WitnessError}, circuit::ZkCircuit, verification::VerificationKey};
 use serde::{Serialize, Deserialize};
 use hex;
+use log::debug;
 
 /// Zero-knowledge witness for proof generation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +14,9 @@ pub struct ZkWitness {
     pub circuit_id: String,
     /// Private input values
     pub private_inputs: Vec<u8>,
+    /// Public input values the witness was generated against
+    #[serde(default)]
+    pub public_inputs: Vec<u32>,
     /// Execution trace
     pub execution_trace: Vec<u8>,
     /// Creation timestamp
@@ -43,6 +47,37 @@ pub struct ZkProofGenerator {
     config: ProofGenConfig,
 }
 
+/// Phase of proof generation reported to progress callbacks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProofPhase {
+    /// Committing to the witness
+    WitnessGeneration,
+    /// Evaluating the circuit gates against the witness
+    ConstraintSolving,
+    /// Assembling the proof and verification key
+    Proving,
+}
+
+/// Progress of a proof generation run
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProofProgress {
+    /// Phase currently running
+    pub phase: ProofPhase,
+    /// Fraction of the whole run completed, from 0.0 to 1.0
+    pub fraction: f64,
+}
+
+impl ProofProgress {
+    /// Share of the run at which constraint solving starts
+    const CONSTRAINT_SOLVING_START: f64 = 0.1;
+    /// Share of the run at which proving starts
+    const PROVING_START: f64 = 0.8;
+    
+    fn new(phase: ProofPhase, fraction: f64) -> Self {
+        Self { phase, fraction: fraction.clamp(0.0, 1.0) }
+    }
+}
+
 /// Configuration for proof generation
 #[derive(Debug, Clone)]
 pub struct ProofGenConfig {
@@ -73,6 +108,7 @@ impl ZkWitness {
             id: String::new(), // Will be computed below
             circuit_id,
             private_inputs,
+            public_inputs: Vec::new(),
             execution_trace,
             timestamp,
         };
@@ -82,6 +118,13 @@ impl ZkWitness {
         witness
     }
     
+    /// Declare the public inputs this witness was generated against
+    pub fn with_public_inputs(mut self, public_inputs: Vec<u32>) -> Self {
+        self.public_inputs = public_inputs;
+        self.id = self.compute_content_id();
+        self
+    }
+    
     /// Compute a content-based identifier for this witness
     pub fn compute_content_id(&self) -> String {
         use sha2::{Sha256, Digest};
@@ -90,6 +133,8 @@ impl ZkWitness {
         // Use bincode for Vec<u8> serialization
         let private_inputs_bytes = bincode::serialize(&self.private_inputs).unwrap_or_default();
         hasher.update(&private_inputs_bytes);
+        let public_inputs_bytes = bincode::serialize(&self.public_inputs).unwrap_or_default();
+        hasher.update(&public_inputs_bytes);
         hasher.update(&self.execution_trace);
         
         let hash = hasher.finalize();
@@ -164,7 +209,7 @@ impl ZkProofGenerator {
         // 2. Capture all intermediate values
         // 3. Verify the computation is correct
         
-        println!("Generating witness for circuit with {} gates", _circuit.gate_count);
+        debug!("Generating witness for circuit with {} gates", _circuit.gate_count);
         
         let mut execution_trace = Vec::new();
         let mut gate_values = Vec::new();
@@ -196,7 +241,46 @@ impl ZkProofGenerator {
             _circuit.circuit_name.clone(),
             private_inputs_bytes,
             execution_trace,
-        ))
+        ).with_public_inputs(public_inputs.to_vec()))
+    }
+    
+    /// Check that a witness is consistent with the circuit it is proved against
+    ///
+    /// Private inputs are stored as little-endian `u32` words, so the witness
+    /// must carry exactly `io_spec.private_inputs` of them, and it must declare
+    /// one value for each of the circuit's public inputs.
+    pub fn validate_witness(&self, circuit: &ZkCircuit, witness: &ZkWitness) -> Result<(), WitnessError> {
+        if witness.circuit_id != circuit.circuit_name {
+            return Err(WitnessError::ValidationFailed(format!(
+                "Witness is for circuit {}, not {}",
+                witness.circuit_id, circuit.circuit_name
+            )));
+        }
+        
+        const WORD: usize = std::mem::size_of::<u32>();
+        if witness.private_inputs.len() % WORD != 0 {
+            return Err(WitnessError::InvalidFormat(format!(
+                "Private inputs are {} bytes, not a whole number of {}-byte words",
+                witness.private_inputs.len(), WORD
+            )));
+        }
+        
+        let private_count = witness.private_inputs.len() / WORD;
+        if private_count != circuit.io_spec.private_inputs {
+            return Err(WitnessError::SchemaMismatch(format!(
+                "Circuit {} takes {} private inputs, witness has {}",
+                circuit.circuit_name, circuit.io_spec.private_inputs, private_count
+            )));
+        }
+        
+        if witness.public_inputs.len() != circuit.io_spec.public_inputs {
+            return Err(WitnessError::SchemaMismatch(format!(
+                "Circuit {} declares {} public inputs, witness has {}",
+                circuit.circuit_name, circuit.io_spec.public_inputs, witness.public_inputs.len()
+            )));
+        }
+        
+        Ok(())
     }
     
     /// Execute a single gate in the circuit
@@ -273,7 +357,7 @@ impl ZkProofGenerator {
             }
         }
         
-        println!("    All {} circuit constraints satisfied", circuit.gate_count);
+        debug!("All {} circuit constraints satisfied", circuit.gate_count);
         Ok(())
     }
 
@@ -283,12 +367,28 @@ impl ZkProofGenerator {
         circuit: &ZkCircuit,
         witness: &ZkWitness,
     ) -> ProofResult<ZkProof> {
+        self.generate_with_progress(circuit, witness, |_| {})
+    }
+    
+    /// Generate a ZK proof, reporting progress to `progress` as it goes
+    ///
+    /// The callback is invoked at the start of each phase and after each
+    /// unit of work within it; the reported fraction never decreases and is
+    /// 1.0 on the last call of a successful run.
+    pub fn generate_with_progress(
+        &self,
+        circuit: &ZkCircuit,
+        witness: &ZkWitness,
+        mut progress: impl FnMut(ProofProgress),
+    ) -> ProofResult<ZkProof> {
+        self.validate_witness(circuit, witness)?;
+        
         // Improved proof generation that creates more realistic proof data
         // 1. Use the circuit and witness to generate a proof
         // 2. Create verification key based on circuit structure
         // 3. Generate proof data that includes commitments and openings
         
-        println!("Generating ZK proof using {} proof system", self.config.proof_system);
+        debug!("Generating ZK proof using {} proof system", self.config.proof_system);
         
         // Calculate proof size based on circuit complexity
         let base_proof_size = match self.config.proof_system.as_str() {
@@ -302,7 +402,8 @@ impl ZkProofGenerator {
         let mut proof_data = Vec::with_capacity(proof_size);
         
         // Generate proof components
-        let proof_components = self.generate_proof_components(circuit, witness)?;
+        let proof_components = self.generate_proof_components(circuit, witness, &mut progress)?;
+        progress(ProofProgress::new(ProofPhase::Proving, ProofProgress::PROVING_START));
         
         // Serialize proof components
         for component in proof_components {
@@ -338,22 +439,36 @@ impl ZkProofGenerator {
         };
         
         proof.id = proof.compute_content_id();
+        progress(ProofProgress::new(ProofPhase::Proving, 1.0));
         
         Ok(proof)
     }
     
     /// Generate proof components (commitments, openings, etc.)
-    fn generate_proof_components(&self, circuit: &ZkCircuit, witness: &ZkWitness) -> Result<Vec<u32>, crate::error::ZkError> {
+    fn generate_proof_components(
+        &self,
+        circuit: &ZkCircuit,
+        witness: &ZkWitness,
+        progress: &mut impl FnMut(ProofProgress),
+    ) -> Result<Vec<u32>, crate::error::ZkError> {
         let mut components = Vec::new();
         
         // Generate commitment to witness
+        progress(ProofProgress::new(ProofPhase::WitnessGeneration, 0.0));
         let witness_commitment = self.commit_to_witness(witness);
         components.push(witness_commitment);
         
         // Generate evaluation proofs for each gate
-        for i in 0..circuit.gate_count.min(10) { // Limit for efficiency
+        let start = ProofProgress::CONSTRAINT_SOLVING_START;
+        let span = ProofProgress::PROVING_START - start;
+        progress(ProofProgress::new(ProofPhase::ConstraintSolving, start));
+        let evaluated_gates = circuit.gate_count.min(10); // Limit for efficiency
+        for i in 0..evaluated_gates {
             let evaluation = self.generate_gate_evaluation(i, witness);
             components.push(evaluation);
+            
+            let done = (i + 1) as f64 / evaluated_gates as f64;
+            progress(ProofProgress::new(ProofPhase::ConstraintSolving, start + span * done));
         }
         
         // Generate consistency proofs
@@ -457,4 +572,87 @@ impl Default for ZkProofGenerator {
 pub fn estimate_proof_complexity(_public_inputs: &[u32], _circuit: &ZkCircuit) -> Result<u32, crate::error::ZkError> {
     // Implementation of estimate_proof_complexity function
     Ok(0) // Placeholder return, actual implementation needed
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit::{CircuitIOSpec, CircuitMetadata};
+    
+    fn test_circuit(gate_count: usize) -> ZkCircuit {
+        ZkCircuit {
+            circuit_name: "progress_circuit".to_string(),
+            gate_count,
+            io_spec: CircuitIOSpec {
+                private_inputs: 1,
+                public_inputs: 0,
+                outputs: 1,
+            },
+            gates: Vec::new(),
+            metadata: CircuitMetadata {
+                source_program: "(alloc 1)".to_string(),
+                compiled_at: "0".to_string(),
+                optimization_level: 0,
+                target_proof_system: "groth16".to_string(),
+            },
+        }
+    }
+    
+    #[test]
+    fn test_generate_with_progress_is_monotonic() {
+        let generator = ZkProofGenerator::new();
+        let circuit = test_circuit(8);
+        let witness = ZkWitness::new(circuit.circuit_name.clone(), vec![1, 2, 3, 4], vec![5; 32]);
+        
+        let mut reports = Vec::new();
+        let proof = generator
+            .generate_with_progress(&circuit, &witness, |p| reports.push(p))
+            .unwrap();
+        
+        assert!(reports.len() > 3);
+        assert!(reports.windows(2).all(|w| w[0].fraction <= w[1].fraction));
+        assert_eq!(reports.first().unwrap().phase, ProofPhase::WitnessGeneration);
+        assert!(reports.iter().any(|p| p.phase == ProofPhase::ConstraintSolving));
+        assert_eq!(*reports.last().unwrap(), ProofProgress::new(ProofPhase::Proving, 1.0));
+        
+        // Reporting progress does not change the proof
+        let plain = generator.generate_proof(&circuit, &witness).unwrap();
+        assert_eq!(plain.proof_data, proof.proof_data);
+    }
+    
+    #[test]
+    fn test_witness_with_wrong_private_input_count_is_rejected() {
+        let generator = ZkProofGenerator::new();
+        let circuit = test_circuit(8);
+        // Two u32 words where the circuit takes one
+        let witness = ZkWitness::new(circuit.circuit_name.clone(), vec![0; 8], vec![5; 32]);
+        
+        assert!(matches!(
+            generator.validate_witness(&circuit, &witness),
+            Err(WitnessError::SchemaMismatch(_))
+        ));
+        
+        let mut reports = Vec::new();
+        let result = generator.generate_with_progress(&circuit, &witness, |p| reports.push(p));
+        assert!(matches!(result, Err(crate::error::ProofError::InvalidWitness(_))));
+        assert!(reports.is_empty(), "validation runs before any proving work");
+    }
+    
+    #[test]
+    fn test_witness_with_mismatched_public_inputs_is_rejected() {
+        let generator = ZkProofGenerator::new();
+        let mut circuit = test_circuit(8);
+        circuit.io_spec.public_inputs = 2;
+        
+        let witness = ZkWitness::new(circuit.circuit_name.clone(), vec![1, 2, 3, 4], vec![5; 32])
+            .with_public_inputs(vec![7]);
+        assert!(matches!(
+            generator.validate_witness(&circuit, &witness),
+            Err(WitnessError::SchemaMismatch(_))
+        ));
+        assert!(generator.generate_proof(&circuit, &witness).is_err());
+        
+        let witness = witness.with_public_inputs(vec![7, 8]);
+        assert!(generator.validate_witness(&circuit, &witness).is_ok());
+    }
+}
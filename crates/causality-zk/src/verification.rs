@@ -4,8 +4,10 @@
 
 use serde::{Serialize, Deserialize};
 use crate::error::ZkError;
-use crate::{ZkProof, ZkCircuit};
+use crate::{CircuitId, ZkProof, ZkCircuit};
 use crate::error::{VerificationResult, BatchVerificationResult};
+use std::fs;
+use std::path::Path;
 
 /// Verification key for ZK proofs
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -18,6 +20,21 @@ pub struct VerificationKey {
     pub proof_system: String,
 }
 
+/// Verification key as written to disk, tagged with the circuit it belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct StoredVerificationKey {
+    /// Layout version of the stored key
+    format_version: u32,
+    /// Circuit the key verifies proofs for
+    circuit_id: CircuitId,
+    /// The verification key itself
+    key: VerificationKey,
+}
+
+impl StoredVerificationKey {
+    const FORMAT_VERSION: u32 = 1;
+}
+
 /// Parsed proof components for different proof systems
 #[derive(Debug, Clone)]
 pub enum ProofComponents {
@@ -498,6 +515,61 @@ impl ZkVerifier {
         matches!(proof_system, "groth16" | "plonk" | "stark" | "snark")
     }
 
+    /// Write the verification key for `circuit_id` to `path`
+    ///
+    /// The key is stored with bincode together with its circuit id, so a
+    /// verifier in another process can check it was given the right key.
+    pub fn save_key(
+        path: impl AsRef<Path>,
+        circuit_id: &CircuitId,
+        key: &VerificationKey,
+    ) -> Result<(), ZkError> {
+        let path = path.as_ref();
+        let stored = StoredVerificationKey {
+            format_version: StoredVerificationKey::FORMAT_VERSION,
+            circuit_id: circuit_id.clone(),
+            key: key.clone(),
+        };
+        let bytes = bincode::serialize(&stored)
+            .map_err(|e| ZkError::Serialization(format!("Failed to encode verification key: {}", e)))?;
+        
+        fs::write(path, bytes).map_err(|e| {
+            ZkError::Serialization(format!("Failed to write verification key to {}: {}", path.display(), e))
+        })
+    }
+    
+    /// Read the verification key for `circuit_id` from `path`
+    ///
+    /// A key saved for a different circuit is rejected.
+    pub fn load_key(path: impl AsRef<Path>, circuit_id: &CircuitId) -> Result<VerificationKey, ZkError> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|e| {
+            ZkError::Serialization(format!("Failed to read verification key from {}: {}", path.display(), e))
+        })?;
+        let stored: StoredVerificationKey = bincode::deserialize(&bytes).map_err(|e| {
+            ZkError::Serialization(format!("Malformed verification key in {}: {}", path.display(), e))
+        })?;
+        
+        if stored.format_version != StoredVerificationKey::FORMAT_VERSION {
+            return Err(ZkError::InvalidVerificationKey(format!(
+                "Key in {} has format version {}, expected {}",
+                path.display(),
+                stored.format_version,
+                StoredVerificationKey::FORMAT_VERSION
+            )));
+        }
+        if &stored.circuit_id != circuit_id {
+            return Err(ZkError::InvalidVerificationKey(format!(
+                "Key in {} is for circuit {}, not {}",
+                path.display(),
+                stored.circuit_id,
+                circuit_id
+            )));
+        }
+        
+        Ok(stored.key)
+    }
+
     pub fn verify_batch_proofs(
         &self,
         proofs: Vec<&ZkProof>,
@@ -544,4 +616,41 @@ impl Default for ZkVerifier {
     fn default() -> Self {
         Self::new()
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_key() -> VerificationKey {
+        VerificationKey {
+            key_data: vec![1, 2, 3, 0x4141],
+            circuit_hash: "abc123".to_string(),
+            proof_system: "groth16".to_string(),
+        }
+    }
+    
+    #[test]
+    fn test_verification_key_round_trip() {
+        let path = std::env::temp_dir().join(format!("causality_vk_{}", uuid::Uuid::new_v4()));
+        let circuit_id: CircuitId = "circuit_round_trip".to_string();
+        
+        ZkVerifier::save_key(&path, &circuit_id, &test_key()).unwrap();
+        let loaded = ZkVerifier::load_key(&path, &circuit_id).unwrap();
+        assert_eq!(loaded, test_key());
+        
+        fs::remove_file(path).unwrap();
+    }
+    
+    #[test]
+    fn test_verification_key_for_other_circuit_is_rejected() {
+        let path = std::env::temp_dir().join(format!("causality_vk_{}", uuid::Uuid::new_v4()));
+        
+        ZkVerifier::save_key(&path, &"circuit_a".to_string(), &test_key()).unwrap();
+        let err = ZkVerifier::load_key(&path, &"circuit_b".to_string()).unwrap_err();
+        assert!(matches!(err, ZkError::InvalidVerificationKey(_)));
+        assert!(err.to_string().contains("circuit_a"));
+        
+        fs::remove_file(path).unwrap();
+    }
+}
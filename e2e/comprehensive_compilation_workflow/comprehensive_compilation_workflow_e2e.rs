@@ -80,11 +80,13 @@ async fn test_new_compilation_workflow() -> Result<()> {
 
     // Step 4: Load simulation via FFI
     println!("4. Loading simulation via FFI...");
-    let sim_state =
-        unsafe { causality_load_bytecode(bytecode.as_ptr(), bytecode.len()) };
+    let mut load_error = 0;
+    let sim_state = unsafe {
+        causality_load_bytecode(bytecode.as_ptr(), bytecode.len(), &mut load_error)
+    };
 
     if sim_state.is_null() {
-        anyhow::bail!("Failed to load simulation: FFI returned null pointer");
+        anyhow::bail!("Failed to load simulation: FFI error code {}", load_error);
     }
 
     println!("   Simulation loaded successfully");
@@ -6,9 +6,49 @@ open Foreign
 (* Type definitions for opaque pointers *)
 let simulation_state : unit ptr typ = ptr void
 
+(* Mirror of the Rust #[repr(C)] CPointerResult: a C int tag (Ok = 0,
+   Err = 1) followed by a union of the pointer and the error code *)
+type pointer_result_payload
+let pointer_result_payload : pointer_result_payload union typ = union "CPointerResultPayload"
+let payload_ok = field pointer_result_payload "ok" (ptr void)
+let payload_err = field pointer_result_payload "err" int32_t
+let () = seal pointer_result_payload
+
+type pointer_result
+let pointer_result : pointer_result structure typ = structure "CPointerResult"
+let pointer_result_tag = field pointer_result "tag" int
+let pointer_result_payload_field = field pointer_result "payload" pointer_result_payload
+let () = seal pointer_result
+
+let pointer_result_ok_tag = 0
+
+(* Failures reported by the Rust side, one per ERR_* constant *)
+type error =
+  | Generic            (* ERR_GENERIC *)
+  | Null_input         (* ERR_NULL_INPUT *)
+  | Serialize          (* ERR_SERIALIZE *)
+  | Empty_register     (* ERR_EMPTY_REGISTER *)
+  | Buffer_too_small   (* ERR_BUFFER_TOO_SMALL *)
+  | Deserialize        (* ERR_DESERIALIZE *)
+  | Execution          (* ERR_EXECUTION *)
+  | Unknown of int
+
+let error_of_code = function
+  | -1 -> Generic
+  | -2 -> Null_input
+  | -3 -> Serialize
+  | -4 -> Empty_register
+  | -5 -> Buffer_too_small
+  | -6 -> Deserialize
+  | -7 -> Execution
+  | code -> Unknown code
+
 (* FFI function bindings *)
 let causality_load_bytecode =
-  foreign "causality_load_bytecode" (ptr char @-> size_t @-> returning simulation_state)
+  foreign "causality_load_bytecode" (ptr char @-> size_t @-> ptr int32_t @-> returning simulation_state)
+
+let causality_last_error_message =
+  foreign "causality_last_error_message" (void @-> returning string_opt)
 
 let causality_free_simulation_state =
   foreign "causality_free_simulation_state" (simulation_state @-> returning void)
@@ -17,16 +57,36 @@ let causality_run_simulation_step =
   foreign "causality_run_simulation_step" (simulation_state @-> returning void) (* Simplified for now *)
 
 let causality_get_simulation_result =
-  foreign "causality_get_simulation_result" (simulation_state @-> returning (ptr void)) (* Simplified *)
+  foreign "causality_get_simulation_result" (simulation_state @-> ptr size_t @-> returning pointer_result)
+
+(* Result buffers are owned by the caller and must be freed exactly once *)
+let causality_free_result_buffer =
+  foreign "causality_free_result_buffer" (ptr char @-> size_t @-> returning void)
 
 (* OCaml wrapper functions *)
 let load_simulation bytecode =
   let bytecode_ptr = Ctypes.string_to_char_ptr bytecode in
   let len = Unsigned.Size_t.of_int (String.length bytecode) in
-  causality_load_bytecode bytecode_ptr len
+  let error = allocate int32_t 0l in
+  let state = causality_load_bytecode bytecode_ptr len error in
+  if is_null state then
+    let message = Option.value (causality_last_error_message ()) ~default:"unknown error" in
+    Error (error_of_code (Int32.to_int (!@ error)), message)
+  else Ok state
 
 let free_simulation = causality_free_simulation_state
 
 let run_step = causality_run_simulation_step
 
-let get_result = causality_get_simulation_result 
\ No newline at end of file
+(* Returns the result buffer and its length, or the reported error;
+   release the buffer with [free_result] *)
+let get_result state =
+  let len = allocate size_t Unsigned.Size_t.zero in
+  let result = causality_get_simulation_result state len in
+  let payload = getf result pointer_result_payload_field in
+  if getf result pointer_result_tag = pointer_result_ok_tag then
+    Ok (getf payload payload_ok, !@ len)
+  else Error (error_of_code (Int32.to_int (getf payload payload_err)))
+
+let free_result (buffer, len) =
+  causality_free_result_buffer (from_voidp char buffer) len
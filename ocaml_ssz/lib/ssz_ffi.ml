@@ -28,6 +28,15 @@ module Mock = struct
       done;
       !n
 
+  (** Mock implementation of uint32 list serialization: the elements'
+      encodings concatenated *)
+  let serialize_u32_list ns = String.concat "" (List.map serialize_u32 ns)
+
+  (** Mock implementation of uint32 list deserialization; a trailing partial
+      element is ignored *)
+  let deserialize_u32_list s =
+    List.init (String.length s / 4) (fun i -> deserialize_u32 (String.sub s (i * 4) 4))
+
   (** Mock implementation of string serialization *)
   let serialize_string s =
     let len = String.length s in
@@ -62,11 +71,34 @@ module Mock = struct
     done;
     Bytes.to_string result
 
+  (** Mock implementation of the SSZ hash tree root of a byte list: SHA-256
+      merkleization of 32-byte chunks with the length mixed in, hex-encoded *)
+  let ssz_hash_tree_root s =
+    let merge a b = Digestif.SHA256.(to_raw_string (digest_string (a ^ b))) in
+    let zero = String.make 32 '\000' in
+    let len = String.length s in
+    let chunks =
+      List.init (max 1 ((len + 31) / 32)) (fun i ->
+          let part = String.sub s (i * 32) (min 32 (len - (i * 32))) in
+          part ^ String.make (32 - String.length part) '\000')
+    in
+    let rec next_pow2 n = if n <= 1 then 1 else 2 * next_pow2 ((n + 1) / 2) in
+    let leaves =
+      chunks @ List.init (next_pow2 (List.length chunks) - List.length chunks) (fun _ -> zero)
+    in
+    let rec pairs = function a :: b :: rest -> merge a b :: pairs rest | _ -> [] in
+    let rec merkleize = function [ root ] -> root | layer -> merkleize (pairs layer) in
+    let length = Bytes.make 32 '\000' in
+    Bytes.set_int64_le length 0 (Int64.of_int len);
+    let root = merge (merkleize leaves) (Bytes.to_string length) in
+    String.concat "" (List.init 32 (fun i -> Printf.sprintf "%02x" (Char.code root.[i])))
+
   (** Mock roundtrip functions *)
   let roundtrip_bool b = deserialize_bool (serialize_bool b)
 
   let roundtrip_u32 n = deserialize_u32 (serialize_u32 n)
   let roundtrip_string s = deserialize_string (serialize_string s)
+  let roundtrip_u32_list ns = deserialize_u32_list (serialize_u32_list ns)
 end
 
 (** * In production, these would call the actual Rust functions via FFI. * For
@@ -76,12 +108,16 @@ let rust_serialize_bool = Mock.serialize_bool
 let rust_deserialize_bool = Mock.deserialize_bool
 let rust_serialize_u32 = Mock.serialize_u32
 let rust_deserialize_u32 = Mock.deserialize_u32
+let rust_serialize_u32_list = Mock.serialize_u32_list
+let rust_deserialize_u32_list = Mock.deserialize_u32_list
 let rust_serialize_string = Mock.serialize_string
 let rust_deserialize_string = Mock.deserialize_string
 let rust_simple_hash = Mock.simple_hash
+let rust_ssz_hash_tree_root = Mock.ssz_hash_tree_root
 let rust_roundtrip_bool = Mock.roundtrip_bool
 let rust_roundtrip_u32 = Mock.roundtrip_u32
 let rust_roundtrip_string = Mock.roundtrip_string
+let rust_roundtrip_u32_list = Mock.roundtrip_u32_list
 
 (** Mock round-trip functions for comprehensive testing *)
 let ocaml_to_rust_bytes data = data (* Mock: just pass through *)
@@ -6,12 +6,16 @@ val rust_serialize_bool : bool -> string
 val rust_deserialize_bool : string -> bool
 val rust_serialize_u32 : int -> string
 val rust_deserialize_u32 : string -> int
+val rust_serialize_u32_list : int list -> string
+val rust_deserialize_u32_list : string -> int list
 val rust_serialize_string : string -> string
 val rust_deserialize_string : string -> string
 val rust_simple_hash : string -> string
+val rust_ssz_hash_tree_root : string -> string
 val rust_roundtrip_bool : bool -> bool
 val rust_roundtrip_u32 : int -> int
 val rust_roundtrip_string : string -> string
+val rust_roundtrip_u32_list : int list -> int list
 
 (** Mock implementations for testing without the actual Rust library *)
 module Mock : sig
@@ -19,12 +23,16 @@ module Mock : sig
   val deserialize_bool : string -> bool
   val serialize_u32 : int -> string
   val deserialize_u32 : string -> int
+  val serialize_u32_list : int list -> string
+  val deserialize_u32_list : string -> int list
   val serialize_string : string -> string
   val deserialize_string : string -> string
   val simple_hash : string -> string
+  val ssz_hash_tree_root : string -> string
   val roundtrip_bool : bool -> bool
   val roundtrip_u32 : int -> int
   val roundtrip_string : string -> string
+  val roundtrip_u32_list : int list -> int list
 end
 
 val ocaml_to_rust_bytes : string -> string
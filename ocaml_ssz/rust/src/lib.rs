@@ -2,74 +2,49 @@
 //! 
 //! This module provides Rust implementations of SSZ serialization functions
 //! that can be called from OCaml code, enabling interoperability testing.
+//!
+//! Encodings cross the boundary as raw OCaml bytes, never as Rust `String`s:
+//! SSZ output is arbitrary binary and is not valid UTF-8 in general.
 
-use ocaml::{ToValue, FromValue, Value};
+use causality_core::{Hasher, Sha256Hasher};
+use ocaml::Value;
 
 // Import ocaml macros
 ocaml::export! {
     // Boolean serialization/deserialization
-    fn rust_serialize_bool(value: bool) -> String {
-        if value {
-            String::from("\u{01}")
-        } else {
-            String::from("\u{00}")
-        }
+    fn rust_serialize_bool(value: bool) -> Value {
+        bytes_value(&serialize_bool(value))
     }
 
-    fn rust_deserialize_bool(data: String) -> bool {
-        if data.is_empty() {
-            false
-        } else {
-            data.as_bytes()[0] == 1
-        }
+    fn rust_deserialize_bool(data: &[u8]) -> bool {
+        deserialize_bool(data)
     }
 
     // u32 serialization/deserialization
-    fn rust_serialize_u32(value: u32) -> String {
-        let mut result = String::with_capacity(4);
-        result.push(((value & 0xFF) as u8) as char);
-        result.push((((value >> 8) & 0xFF) as u8) as char);
-        result.push((((value >> 16) & 0xFF) as u8) as char);
-        result.push((((value >> 24) & 0xFF) as u8) as char);
-        result
+    fn rust_serialize_u32(value: u32) -> Value {
+        bytes_value(&serialize_u32(value))
     }
 
-    fn rust_deserialize_u32(data: String) -> u32 {
-        if data.len() < 4 {
-            return 0;
-        }
-        
-        let bytes = data.as_bytes();
-        let mut result: u32 = 0;
-        
-        result |= bytes[0] as u32;
-        result |= (bytes[1] as u32) << 8;
-        result |= (bytes[2] as u32) << 16;
-        result |= (bytes[3] as u32) << 24;
-        
-        result
+    fn rust_deserialize_u32(data: &[u8]) -> u32 {
+        deserialize_u32(data)
+    }
+
+    // u32 list serialization/deserialization in a single FFI crossing
+    fn rust_serialize_u32_list(values: Vec<u32>) -> Value {
+        bytes_value(&serialize_u32_list(&values))
+    }
+
+    fn rust_deserialize_u32_list(data: &[u8]) -> Vec<u32> {
+        deserialize_u32_list(data)
     }
 
     // String serialization/deserialization with length prefix
-    fn rust_serialize_string(value: String) -> String {
-        let len = value.len() as u32;
-        let len_bytes = rust_serialize_u32(len);
-        len_bytes + &value
+    fn rust_serialize_string(value: &[u8]) -> Value {
+        bytes_value(&serialize_string(value))
     }
 
-    fn rust_deserialize_string(data: String) -> String {
-        if data.len() < 4 {
-            return String::new();
-        }
-        
-        let len_bytes = data[0..4].to_string();
-        let len = rust_deserialize_u32(len_bytes) as usize;
-        
-        if data.len() < 4 + len {
-            return String::new();
-        }
-        
-        data[4..4+len].to_string()
+    fn rust_deserialize_string(data: &[u8]) -> Value {
+        bytes_value(deserialize_string(data))
     }
 
     // Simple hash function for hash tree root testing
@@ -95,19 +70,244 @@ ocaml::export! {
         result
     }
 
+    // SSZ hash_tree_root of the data as a byte list, hex-encoded
+    fn rust_ssz_hash_tree_root(data: String) -> String {
+        hex::encode(hash_tree_root(data.as_bytes()))
+    }
+
     // Roundtrip test helper function
     fn rust_roundtrip_bool(value: bool) -> bool {
-        let serialized = rust_serialize_bool(value);
-        rust_deserialize_bool(serialized)
+        deserialize_bool(&serialize_bool(value))
     }
 
     fn rust_roundtrip_u32(value: u32) -> u32 {
-        let serialized = rust_serialize_u32(value);
-        rust_deserialize_u32(serialized)
+        deserialize_u32(&serialize_u32(value))
+    }
+
+    fn rust_roundtrip_string(value: &[u8]) -> Value {
+        bytes_value(deserialize_string(&serialize_string(value)))
+    }
+
+    fn rust_roundtrip_u32_list(values: Vec<u32>) -> Vec<u32> {
+        deserialize_u32_list(&serialize_u32_list(&values))
+    }
+}
+
+/// SSZ hash_tree_root of `data` as a byte list, using the same SHA-256
+/// node hashing as `causality-core`'s `Sha256Hasher::merge`
+///
+/// The bytes are packed into zero-padded 32-byte chunks, merkleized over
+/// the next power of two of chunks, and the byte length is mixed in.
+fn hash_tree_root(data: &[u8]) -> [u8; 32] {
+    let mut layer: Vec<[u8; 32]> = data
+        .chunks(32)
+        .map(|bytes| {
+            let mut chunk = [0u8; 32];
+            chunk[..bytes.len()].copy_from_slice(bytes);
+            chunk
+        })
+        .collect();
+    layer.resize(layer.len().max(1).next_power_of_two(), [0u8; 32]);
+
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|pair| Sha256Hasher::merge(&pair[0], &pair[1]))
+            .collect();
+    }
+
+    let mut length = [0u8; 32];
+    length[..8].copy_from_slice(&(data.len() as u64).to_le_bytes());
+    Sha256Hasher::merge(&layer[0], &length)
+}
+
+/// Copy `data` into a freshly allocated OCaml `bytes` value
+fn bytes_value(data: &[u8]) -> Value {
+    unsafe { Value::bytes(data) }
+}
+
+/// Serialize a bool as a single 0 or 1 byte
+fn serialize_bool(value: bool) -> Vec<u8> {
+    vec![value as u8]
+}
+
+/// Deserialize a bool from the first byte of `data`, or false if empty
+fn deserialize_bool(data: &[u8]) -> bool {
+    data.first() == Some(&1)
+}
+
+/// Serialize a u32 as four little-endian bytes
+fn serialize_u32(value: u32) -> Vec<u8> {
+    value.to_le_bytes().to_vec()
+}
+
+/// Deserialize a u32 from the first four bytes of `data`, or 0 if too short
+fn deserialize_u32(data: &[u8]) -> u32 {
+    match data.get(..4) {
+        Some(bytes) => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        None => 0,
+    }
+}
+
+/// Serialize a byte string with a little-endian u32 length prefix
+fn serialize_string(value: &[u8]) -> Vec<u8> {
+    let mut encoded = serialize_u32(value.len() as u32);
+    encoded.extend_from_slice(value);
+    encoded
+}
+
+/// Deserialize a length-prefixed byte string, or empty if truncated
+fn deserialize_string(data: &[u8]) -> &[u8] {
+    let len = deserialize_u32(data) as usize;
+    data.get(4..)
+        .and_then(|rest| rest.get(..len))
+        .unwrap_or_default()
+}
+
+/// Serialize a list of u32s as the concatenation of their encodings
+fn serialize_u32_list(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
+
+/// Deserialize a list of u32s from consecutive 4-byte elements; a trailing
+/// partial element is ignored
+fn deserialize_u32_list(data: &[u8]) -> Vec<u32> {
+    data.chunks_exact(4).map(deserialize_u32).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Covers bytes at and above 0x80, which must survive as single bytes
+    const VALUES: [u32; 8] = [0, 1, 0x7f, 0x80, 0x0102_0304, 0x7f7f_7f7f, 0xff00_0000, u32::MAX];
+
+    #[test]
+    fn test_u32_list_matches_per_element_encoding() {
+        let per_element: Vec<u8> = VALUES.iter().flat_map(|&v| serialize_u32(v)).collect();
+        assert_eq!(serialize_u32_list(&VALUES), per_element);
+
+        let decoded: Vec<u32> = VALUES
+            .iter()
+            .map(|&v| deserialize_u32(&serialize_u32(v)))
+            .collect();
+        assert_eq!(deserialize_u32_list(&per_element), decoded);
+    }
+
+    #[test]
+    fn test_u32_list_encodes_one_byte_per_byte() {
+        assert_eq!(serialize_u32_list(&[0x80]), [0x80, 0x00, 0x00, 0x00]);
+        assert_eq!(serialize_u32_list(&VALUES).len(), VALUES.len() * 4);
+        assert_eq!(
+            serialize_u32_list(&[0xff00_0000, u32::MAX]),
+            [0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff]
+        );
+    }
+
+    #[test]
+    fn test_u32_list_round_trip() {
+        assert_eq!(deserialize_u32_list(&serialize_u32_list(&VALUES)), VALUES);
+        assert!(deserialize_u32_list(&serialize_u32_list(&[])).is_empty());
+    }
+
+    fn chunk(bytes: &[u8]) -> [u8; 32] {
+        let mut chunk = [0u8; 32];
+        chunk[..bytes.len()].copy_from_slice(bytes);
+        chunk
+    }
+
+    fn length(len: u64) -> [u8; 32] {
+        chunk(&len.to_le_bytes())
+    }
+
+    #[test]
+    fn test_hash_tree_root_matches_hasher_merkleization() {
+        let short = b"causality";
+        assert_eq!(
+            hash_tree_root(short),
+            Sha256Hasher::merge(&chunk(short), &length(9))
+        );
+
+        // Three chunks pad to four leaves
+        let long = [7u8; 70];
+        let leaves = [chunk(&long[..32]), chunk(&long[32..64]), chunk(&long[64..]), [0u8; 32]];
+        let root = Sha256Hasher::merge(
+            &Sha256Hasher::merge(&leaves[0], &leaves[1]),
+            &Sha256Hasher::merge(&leaves[2], &leaves[3]),
+        );
+        assert_eq!(hash_tree_root(&long), Sha256Hasher::merge(&root, &length(70)));
+
+        assert_eq!(hash_tree_root(&[]), Sha256Hasher::merge(&[0u8; 32], &length(0)));
     }
 
-    fn rust_roundtrip_string(value: String) -> String {
-        let serialized = rust_serialize_string(value);
-        rust_deserialize_string(serialized)
+    #[test]
+    fn test_u32_list_ignores_trailing_partial_element() {
+        let mut data = serialize_u32_list(&[7, 8]);
+        data.push(1);
+        assert_eq!(deserialize_u32_list(&data), [7, 8]);
+    }
+
+    const VECTORS: &str = include_str!("../../test/conformance_vectors.txt");
+
+    /// Fixed part of a record: bool (1) + u32 (4) + offset of the name (4)
+    const RECORD_FIXED_SIZE: u32 = 9;
+
+    fn parse_string(value: &str) -> &[u8] {
+        if value == "\"\"" { b"" } else { value.as_bytes() }
+    }
+
+    /// Encode a vector with the same functions the OCaml exports call
+    fn encode(kind: &str, value: &str) -> Vec<u8> {
+        match kind {
+            "bool" => serialize_bool(value.parse().unwrap()),
+            "u32" => serialize_u32(value.parse().unwrap()),
+            "string" => serialize_string(parse_string(value)),
+            "record" => {
+                let fields: Vec<&str> = value.split(',').collect();
+                let [active, count, name] = fields[..] else {
+                    panic!("Malformed record vector: {}", value);
+                };
+                let mut encoded = serialize_bool(active.parse().unwrap());
+                encoded.extend(serialize_u32(count.parse().unwrap()));
+                encoded.extend(serialize_u32(RECORD_FIXED_SIZE));
+                encoded.extend(serialize_string(parse_string(name)));
+                encoded
+            }
+            _ => panic!("Unknown vector kind: {}", kind),
+        }
+    }
+
+    #[test]
+    fn test_exports_match_conformance_vectors() {
+        let mut checked = 0;
+        let mut divergences = Vec::new();
+
+        for line in VECTORS.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parts: Vec<&str> = line.split(' ').collect();
+            let [kind, value, expected] = parts[..] else {
+                panic!("Malformed vector line: {}", line);
+            };
+
+            let encoded = encode(kind, value);
+            if hex::encode(&encoded) != expected {
+                divergences.push(format!("{} {}: expected {}, rust {}", kind, value, expected, hex::encode(&encoded)));
+            }
+
+            // Decoding the expected bytes gives back the vector's value
+            let bytes = hex::decode(expected).unwrap();
+            match kind {
+                "bool" => assert_eq!(deserialize_bool(&bytes), value.parse::<bool>().unwrap()),
+                "u32" => assert_eq!(deserialize_u32(&bytes), value.parse::<u32>().unwrap()),
+                "string" => assert_eq!(deserialize_string(&bytes), parse_string(value)),
+                _ => {}
+            }
+            checked += 1;
+        }
+
+        assert!(checked > 0, "No conformance vectors found");
+        assert!(divergences.is_empty(), "SSZ encoding diverged:\n{}", divergences.join("\n"));
     }
 } 
\ No newline at end of file
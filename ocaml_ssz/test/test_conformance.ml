@@ -0,0 +1,96 @@
+(* Cross-language SSZ conformance test
+
+   Encodes a fixed corpus with the OCaml encoders and with the Rust encoders
+   reached through Ssz_ffi, and checks both produce exactly the bytes recorded
+   in conformance_vectors.txt. The Rust side checks the same vectors in
+   crates/causality-ffi/tests/ssz_conformance_test.rs, so any drift between
+   the two implementations fails one of the two tests. *)
+
+open Ssz
+
+type encoder = {
+    bool : bool -> string
+  ; u32 : int -> string
+  ; string : string -> string
+}
+
+let ocaml_encoder =
+  {
+    bool = Basic.serialize_bool
+  ; u32 = Basic.serialize_uint32
+  ; string = Basic.serialize_string
+  }
+
+let rust_encoder =
+  {
+    bool = Ssz_ffi.rust_serialize_bool
+  ; u32 = Ssz_ffi.rust_serialize_u32
+  ; string = Ssz_ffi.rust_serialize_string
+  }
+
+(* Fixed part of a record: bool (1) + u32 (4) + offset of the name (4) *)
+let record_fixed_size = 9
+
+let encode_record enc active count name =
+  enc.bool active ^ enc.u32 count ^ enc.u32 record_fixed_size ^ enc.string name
+
+let to_hex s =
+  String.concat "" (List.map (fun c -> Printf.sprintf "%02x" (Char.code c)) (List.of_seq (String.to_seq s)))
+
+let parse_string = function "\"\"" -> "" | s -> s
+
+let encode enc kind value =
+  match kind with
+  | "bool" -> enc.bool (bool_of_string value)
+  | "u32" -> enc.u32 (int_of_string value)
+  | "string" -> enc.string (parse_string value)
+  | "record" -> (
+      match String.split_on_char ',' value with
+      | [ active; count; name ] ->
+          encode_record enc (bool_of_string active) (int_of_string count)
+            (parse_string name)
+      | _ -> failwith ("Malformed record vector: " ^ value))
+  | _ -> failwith ("Unknown vector kind: " ^ kind)
+
+let read_vectors path =
+  let ic = open_in path in
+  let rec loop acc =
+    match input_line ic with
+    | line ->
+        let line = String.trim line in
+        if line = "" || line.[0] = '#' then loop acc
+        else (
+          match String.split_on_char ' ' line with
+          | [ kind; value; expected ] -> loop ((kind, value, expected) :: acc)
+          | _ -> failwith ("Malformed vector line: " ^ line))
+    | exception End_of_file ->
+        close_in ic;
+        List.rev acc
+  in
+  loop []
+
+let () =
+  Printf.printf "Running cross-language SSZ conformance tests\n\n";
+
+  let vectors = read_vectors "conformance_vectors.txt" in
+  let failures =
+    List.filter
+      (fun (kind, value, expected) ->
+        let ocaml_hex = to_hex (encode ocaml_encoder kind value) in
+        let rust_hex = to_hex (encode rust_encoder kind value) in
+        let ok = ocaml_hex = expected && rust_hex = expected in
+        if ok then Printf.printf "%s %s: ok\n" kind value
+        else
+          Printf.printf
+            "%s %s: DIVERGENCE\n  expected: %s\n  ocaml:    %s\n  rust:     %s\n"
+            kind value expected ocaml_hex rust_hex;
+        not ok)
+      vectors
+  in
+
+  if failures <> [] then (
+    Printf.printf "\n%d of %d vectors diverged\n" (List.length failures)
+      (List.length vectors);
+    exit 1);
+
+  Printf.printf "\nAll %d vectors conform!\n" (List.length vectors)